@@ -61,6 +61,16 @@ pub fn criterion_benchmark(c: &mut Criterion) {
             );
         })
     });
+
+    c.bench_function("Gregorian to and from epoch", |b| {
+        b.iter(|| {
+            let e = Epoch::from_gregorian_tai_hms(black_box(2022), 5, 20, 17, 57, 43);
+            e.as_gregorian_tai();
+
+            let f = Epoch::from_gregorian_tai_hms(black_box(1815), 6, 18, 11, 30, 0);
+            f.as_gregorian_tai();
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
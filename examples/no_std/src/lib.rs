@@ -0,0 +1,85 @@
+//! Time keeping of a `no_std` flight software, without allocations nor atomics: the reference
+//! epochs are `const`, and the time tables are `static` slices.
+#![no_std]
+
+use hifitime::{
+    Duration, Dut1Table, Epoch, Errors, GnssTimeScale, LeapCursor, Met, MissionTime, SinexEpoch,
+    TimeSeries, TimeSystem, TtCorrectionTable, Unit,
+};
+
+/// Builds a duration from a number of nanoseconds in a const context.
+const fn nanoseconds(nanos: i128) -> Duration {
+    match Duration::try_from_total_nanoseconds(nanos) {
+        Ok(duration) => duration,
+        Err(_) => panic!("duration out of range"),
+    }
+}
+
+/// Liftoff of the mission, in TAI.
+pub const LIFTOFF: Epoch = Epoch::from_gregorian_tai_const(2022, 11, 16, 6, 48, 21, 0);
+
+/// UT1 - UTC on a few days after liftoff.
+static DUT1: [(Epoch, Duration); 2] = [
+    (
+        Epoch::from_gregorian_tai_const(2022, 11, 17, 0, 0, 37, 0),
+        nanoseconds(-13_400_000),
+    ),
+    (
+        Epoch::from_gregorian_tai_const(2022, 11, 18, 0, 0, 37, 0),
+        nanoseconds(-13_600_000),
+    ),
+];
+
+/// TT(BIPM) - TT on a few days after liftoff.
+static TT_CORRECTIONS: [(Epoch, Duration); 2] = [
+    (
+        Epoch::from_gregorian_tai_const(2022, 11, 17, 0, 0, 0, 0),
+        nanoseconds(27_662),
+    ),
+    (
+        Epoch::from_gregorian_tai_const(2022, 11, 18, 0, 0, 0, 0),
+        nanoseconds(27_663),
+    ),
+];
+
+/// Returns the epoch of the provided mission elapsed time, e.g. `T+00:08:02.5`.
+pub fn met_epoch(met: &str) -> Result<Epoch, Errors> {
+    MissionTime::new(LIFTOFF).parse(met)
+}
+
+/// Returns the mission elapsed time of the provided GPS week and time of week.
+pub fn gps_met(week: i32, tow: Duration) -> Met {
+    MissionTime::new(LIFTOFF).met(Epoch::from_week_tow(GnssTimeScale::GPST, week, tow))
+}
+
+/// Returns UT1 in seconds past 1900 at the provided epoch.
+pub fn ut1_seconds(epoch: Epoch) -> f64 {
+    epoch.as_ut1_seconds(&Dut1Table::new(&DUT1))
+}
+
+/// Returns TT(BIPM) in seconds past 1900 at the provided epoch.
+pub fn tt_bipm_seconds(epoch: Epoch) -> f64 {
+    epoch.as_tt_realized_seconds(&TtCorrectionTable::new(&TT_CORRECTIONS))
+}
+
+/// Returns the TDB seconds past J2000 of the epoch of the provided SINEX field in UTC.
+pub fn sinex_tdb_seconds(field: &str) -> Result<Option<f64>, Errors> {
+    Ok(SinexEpoch::parse_epoch(field, TimeSystem::UTC)?.map(|epoch| epoch.as_tdb_seconds()))
+}
+
+/// Fills the provided buffer with the UTC durations of the telemetry frames sent every second
+/// after liftoff, returning the number of frames.
+pub fn telemetry_schedule(frames: &mut [Duration]) -> usize {
+    let mut cursor = LeapCursor::new();
+    let series = TimeSeries::exclusive(
+        LIFTOFF,
+        LIFTOFF + Unit::Second * frames.len() as i64,
+        Unit::Second * 1,
+    );
+    let mut count = 0;
+    for (frame, epoch) in frames.iter_mut().zip(series) {
+        *frame = cursor.as_utc_duration(epoch);
+        count += 1;
+    }
+    count
+}
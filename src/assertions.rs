@@ -0,0 +1,205 @@
+use crate::{Duration, Epoch};
+use core::fmt;
+
+/// Asserts that two epochs are equal, optionally within a tolerance [`Duration`](crate::Duration).
+///
+/// On failure, both epochs are printed in UTC, TAI and TT, along with their exact TAI durations
+/// and their difference, instead of two opaque second counts. As with `assert_eq!`, a custom
+/// message may follow the tolerance.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate hifitime;
+/// use hifitime::{Epoch, TimeUnits};
+///
+/// fn main() {
+///     let epoch = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+///     assert_epoch_eq!(epoch, Epoch::from_tai_duration(epoch.as_tai_duration()));
+///     assert_epoch_eq!(epoch, epoch + 1.microseconds(), 1.microseconds());
+///     assert_epoch_eq!(epoch, epoch - 1.microseconds(), 1.microseconds(), "after {} turns", 3);
+/// }
+/// ```
+///
+/// A failure prints both epochs in several time scales:
+/// ```should_panic
+/// #[macro_use]
+/// extern crate hifitime;
+/// use hifitime::{Epoch, TimeUnits};
+///
+/// fn main() {
+///     let epoch = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+///     assert_epoch_eq!(epoch, epoch + 2.microseconds(), 1.microseconds());
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_epoch_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_epoch_eq!($left, $right, $crate::Duration::from_parts(0, 0))
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                let (left, right): (&$crate::Epoch, &$crate::Epoch) = (left, right);
+                let tolerance: &$crate::Duration = tolerance;
+                if (*left - *right).abs() > *tolerance {
+                    panic!(
+                        "assertion failed: `left == right` within {}\n{}",
+                        tolerance,
+                        $crate::EpochMismatch(*left, *right)
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                let (left, right): (&$crate::Epoch, &$crate::Epoch) = (left, right);
+                let tolerance: &$crate::Duration = tolerance;
+                if (*left - *right).abs() > *tolerance {
+                    panic!(
+                        "assertion failed: `left == right` within {}: {}\n{}",
+                        tolerance,
+                        format_args!($($arg)+),
+                        $crate::EpochMismatch(*left, *right)
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two durations are equal, optionally within a tolerance duration.
+///
+/// On failure, both durations are printed with their units, in seconds and as their exact
+/// centuries and nanoseconds, along with their difference. As with `assert_eq!`, a custom message
+/// may follow the tolerance.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate hifitime;
+/// use hifitime::TimeUnits;
+///
+/// fn main() {
+///     assert_duration_eq!(90.minutes(), 1.5.hours());
+///     assert_duration_eq!(1.days(), 1.days() + 3.nanoseconds(), 5.nanoseconds());
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_duration_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_duration_eq!($left, $right, $crate::Duration::from_parts(0, 0))
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                let (left, right): (&$crate::Duration, &$crate::Duration) = (left, right);
+                let tolerance: &$crate::Duration = tolerance;
+                if (*left - *right).abs() > *tolerance {
+                    panic!(
+                        "assertion failed: `left == right` within {}\n{}",
+                        tolerance,
+                        $crate::DurationMismatch(*left, *right)
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                let (left, right): (&$crate::Duration, &$crate::Duration) = (left, right);
+                let tolerance: &$crate::Duration = tolerance;
+                if (*left - *right).abs() > *tolerance {
+                    panic!(
+                        "assertion failed: `left == right` within {}: {}\n{}",
+                        tolerance,
+                        format_args!($($arg)+),
+                        $crate::DurationMismatch(*left, *right)
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Prints two epochs in several time scales, for the failure message of [`assert_epoch_eq`].
+#[doc(hidden)]
+pub struct EpochMismatch(pub Epoch, pub Epoch);
+
+impl fmt::Display for EpochMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, epoch) in [(" left", self.0), ("right", self.1)] {
+            let (centuries, nanoseconds) = epoch.as_tai_duration().to_parts();
+            writeln!(
+                f,
+                "{}: {} | {:x} | {:X} | TAI parts ({}, {})",
+                name, epoch, epoch, epoch, centuries, nanoseconds
+            )?;
+        }
+        write!(f, " diff: right - left = {}", self.1 - self.0)
+    }
+}
+
+/// Prints two durations exactly, for the failure message of [`assert_duration_eq`].
+#[doc(hidden)]
+pub struct DurationMismatch(pub Duration, pub Duration);
+
+impl fmt::Display for DurationMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, duration) in [(" left", self.0), ("right", self.1)] {
+            let (centuries, nanoseconds) = duration.to_parts();
+            writeln!(
+                f,
+                "{}: {} | {} s | parts ({}, {})",
+                name,
+                duration,
+                duration.in_seconds(),
+                centuries,
+                nanoseconds
+            )?;
+        }
+        write!(f, " diff: right - left = {}", self.1 - self.0)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{DurationMismatch, Epoch, EpochMismatch, TimeUnits};
+
+    #[test]
+    fn test_assert_epoch_eq() {
+        let epoch = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+        assert_epoch_eq!(epoch, epoch);
+        assert_epoch_eq!(epoch, epoch + 1.seconds(), 1.seconds());
+        assert_epoch_eq!(&epoch, &(epoch - 1.seconds()), 1.seconds(),);
+        assert_duration_eq!(1.days(), 24.hours());
+        assert_duration_eq!(1.days(), 24.hours() - 1.nanoseconds(), 1.nanoseconds());
+
+        let result = std::panic::catch_unwind(|| {
+            assert_epoch_eq!(epoch, epoch + 2.seconds(), 1.seconds(), "step {}", 3);
+        });
+        assert!(result.is_err());
+        let result = std::panic::catch_unwind(|| assert_duration_eq!(1.days(), 25.hours()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_mismatch_messages() {
+        let epoch = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+        assert_eq!(
+            format!("{}", EpochMismatch(epoch, epoch + 1.5.seconds())),
+            " left: 2022-05-20T17:57:43 UTC | 2022-05-20T17:58:20 TAI | 2022-05-20T17:58:52.184000015 TT | TAI parts (1, 706298300000000000)
+right: 2022-05-20T17:57:44.500000000 UTC | 2022-05-20T17:58:21.500000000 TAI | 2022-05-20T17:58:53.684000015 TT | TAI parts (1, 706298301500000000)
+ diff: right - left = 1 s 500 ms"
+        );
+        assert_eq!(
+            format!("{}", DurationMismatch(1.days(), 25.hours())),
+            " left: 1 days | 86400 s | parts (0, 86400000000000)
+right: 1 days 1 h | 90000 s | parts (0, 90000000000000)
+ diff: right - left = 1 h"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::duration::{Duration, Unit};
+use crate::{Epoch, TT_OFFSET_MS};
+
+/// A realization of Terrestrial Time other than the conventional TT(TAI) = TAI + 32.184 s, such as
+/// the TT(BIPMxx) realizations published yearly by the BIPM and used for pulsar timing.
+///
+/// Implementors return the correction `TT(realization) - TT(TAI)` at the provided epoch. This trait
+/// is implemented for any `Fn(Epoch) -> Duration`, and for [`TtCorrectionTable`].
+pub trait TtRealization {
+    /// Returns the correction `TT(realization) - TT(TAI)` at the provided epoch.
+    fn tt_correction(&self, epoch: Epoch) -> Duration;
+}
+
+impl<F: Fn(Epoch) -> Duration> TtRealization for F {
+    fn tt_correction(&self, epoch: Epoch) -> Duration {
+        self(epoch)
+    }
+}
+
+/// A table of `TT(realization) - TT(TAI)` corrections, linearly interpolated between its entries and
+/// held constant before the first entry and after the last one.
+///
+/// The entries must be sorted by increasing epoch. The interpolation is computed in integer
+/// nanoseconds, so it is exact to the nanosecond.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, TimeUnits, TtCorrectionTable};
+///
+/// let entries = [
+///     (Epoch::from_mjd_tai(59_000.0), 27_667.nanoseconds()),
+///     (Epoch::from_mjd_tai(59_010.0), 27_677.nanoseconds()),
+/// ];
+/// let bipm = TtCorrectionTable::new(&entries);
+/// let epoch = Epoch::from_mjd_tai(59_005.0);
+/// assert_eq!(epoch.as_tt_realized_duration(&bipm) - epoch.as_tt_duration(), 27_672.nanoseconds());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TtCorrectionTable<'a> {
+    entries: &'a [(Epoch, Duration)],
+}
+
+impl<'a> TtCorrectionTable<'a> {
+    /// Builds a new correction table from entries sorted by increasing epoch.
+    #[must_use]
+    pub const fn new(entries: &'a [(Epoch, Duration)]) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the epochs and corrections of this table, sorted by increasing epoch.
+    #[must_use]
+    pub const fn entries(&self) -> &'a [(Epoch, Duration)] {
+        self.entries
+    }
+}
+
+impl<'a> TtRealization for TtCorrectionTable<'a> {
+    fn tt_correction(&self, epoch: Epoch) -> Duration {
+        let idx = self.entries.partition_point(|(e, _)| *e <= epoch);
+        if idx == 0 {
+            self.entries.first().map_or(
+                Duration {
+                    centuries: 0,
+                    nanoseconds: 0,
+                },
+                |(_, c)| *c,
+            )
+        } else if idx == self.entries.len() {
+            self.entries[idx - 1].1
+        } else {
+            let (e0, c0) = self.entries[idx - 1];
+            let (e1, c1) = self.entries[idx];
+            let span = (e1 - e0).total_nanoseconds();
+            let elapsed = (epoch - e0).total_nanoseconds();
+            let delta = (c1 - c0).total_nanoseconds();
+            c0 + Duration::from_total_nanoseconds(delta * elapsed / span)
+        }
+    }
+}
+
+impl Epoch {
+    /// Returns the duration past TAI epoch in the provided realization of Terrestrial Time, i.e.
+    /// the TT(TAI) duration of [`Epoch::as_tt_duration`] plus the realization correction.
+    #[must_use]
+    pub fn as_tt_realized_duration<R: TtRealization>(&self, realization: &R) -> Duration {
+        self.as_tt_duration() + realization.tt_correction(*self)
+    }
+
+    /// Returns seconds past TAI epoch in the provided realization of Terrestrial Time.
+    #[must_use]
+    pub fn as_tt_realized_seconds<R: TtRealization>(&self, realization: &R) -> f64 {
+        self.as_tt_realized_duration(realization).in_seconds()
+    }
+
+    /// Returns days past TAI epoch in the provided realization of Terrestrial Time.
+    #[must_use]
+    pub fn as_tt_realized_days<R: TtRealization>(&self, realization: &R) -> f64 {
+        self.as_tt_realized_duration(realization).in_unit(Unit::Day)
+    }
+
+    /// Initialize an Epoch from the provided duration past TAI epoch in the provided realization of
+    /// Terrestrial Time. The correction is evaluated at the TT(TAI) epoch, which is accurate since
+    /// the corrections only vary by nanoseconds over years.
+    #[must_use]
+    pub fn from_tt_realized_duration<R: TtRealization>(
+        duration: Duration,
+        realization: &R,
+    ) -> Self {
+        let approx = Self::from_tai_duration(duration - Unit::Millisecond * TT_OFFSET_MS);
+        approx - realization.tt_correction(approx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Duration, Epoch, TimeUnits, TtCorrectionTable, TtRealization};
+
+    #[test]
+    fn test_tt_correction_table() {
+        let t0 = Epoch::from_gregorian_tai_at_midnight(2020, 1, 1);
+        let entries = [
+            (t0, 27_600.nanoseconds()),
+            (t0 + 10.days(), 27_610.nanoseconds()),
+            (t0 + 20.days(), 27_590.nanoseconds()),
+        ];
+        let table = TtCorrectionTable::new(&entries);
+        assert_eq!(table.entries().len(), 3);
+        assert_eq!(table.tt_correction(t0 - 1.days()), 27_600.nanoseconds());
+        assert_eq!(table.tt_correction(t0), 27_600.nanoseconds());
+        assert_eq!(table.tt_correction(t0 + 1.days()), 27_601.nanoseconds());
+        assert_eq!(table.tt_correction(t0 + 10.days()), 27_610.nanoseconds());
+        assert_eq!(table.tt_correction(t0 + 15.days()), 27_600.nanoseconds());
+        assert_eq!(table.tt_correction(t0 + 20.days()), 27_590.nanoseconds());
+        assert_eq!(table.tt_correction(t0 + 100.days()), 27_590.nanoseconds());
+        assert_eq!(
+            TtCorrectionTable::new(&[]).tt_correction(t0),
+            Duration::from_parts(0, 0)
+        );
+
+        let epoch = t0 + 5.days();
+        let tt_bipm = epoch.as_tt_realized_duration(&table);
+        assert_eq!(tt_bipm - epoch.as_tt_duration(), 27_605.nanoseconds());
+        assert_eq!(Epoch::from_tt_realized_duration(tt_bipm, &table), epoch);
+        // Seconds past 1900 as f64 are only precise to about a microsecond
+        assert!(
+            (epoch.as_tt_realized_seconds(&table) - epoch.as_tt_seconds() - 27.605e-6).abs() < 1e-6
+        );
+    }
+
+    #[test]
+    fn test_tt_realization_closure() {
+        let constant = |_| 27.microseconds();
+        let epoch = Epoch::from_gregorian_tai_at_noon(2022, 5, 20);
+        assert_eq!(
+            epoch.as_tt_realized_duration(&constant),
+            epoch.as_tt_duration() + 27.microseconds()
+        );
+        assert_eq!(
+            Epoch::from_tt_realized_duration(epoch.as_tt_realized_duration(&constant), &constant),
+            epoch
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use crate::duration::Unit;
+use crate::{Duration, Epoch, Errors, Interval, TimeSystem};
+
+/// A blackout which recurs every period (a day or a week), read on the clock of the time system of
+/// the calendar.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct RecurringBlackout {
+    period: Duration,
+    /// Start of the blackout since the start of the period, i.e. since midnight for daily
+    /// blackouts and since Monday at midnight for weekly blackouts
+    start: Duration,
+    duration: Duration,
+}
+
+impl RecurringBlackout {
+    /// Returns the time elapsed since the start of the occurrence of this blackout which contains
+    /// the provided clock reading, if any.
+    fn elapsed(&self, reading: Duration) -> Option<Duration> {
+        let period_ns = self.period.total_nanoseconds();
+        // The readings are counted from 1900 January 01 in every time system, including ET and
+        // TDB, and that day was a Monday, so the readings are aligned on the weeks
+        let elapsed_ns =
+            (reading.total_nanoseconds() - self.start.total_nanoseconds()).rem_euclid(period_ns);
+        let elapsed = Duration::from_total_nanoseconds(elapsed_ns);
+        if elapsed < self.duration {
+            Some(elapsed)
+        } else {
+            None
+        }
+    }
+}
+
+/// A calendar of blackouts for operations scheduling, e.g. maintenance windows and crew rest
+/// periods, made of one-off blackouts and of daily or weekly recurring blackouts.
+///
+/// Each blackout excludes its start but not its end, such that operations may resume exactly at
+/// the end of a blackout. The recurring blackouts are read on the clock of the time system of the
+/// calendar, e.g. a daily blackout from 02:00 to 04:00 UTC.
+///
+/// # Example
+/// ```
+/// use hifitime::{BlackoutCalendar, Epoch, Interval, TimeSystem, TimeUnits};
+///
+/// let mut calendar = BlackoutCalendar::new(TimeSystem::UTC);
+/// // Crew rest every night from 22:00 to 06:00
+/// calendar.add_daily(22.hours(), 8.hours()).unwrap();
+/// // Antenna maintenance on Saturdays from 10:00 to 12:00
+/// calendar.add_weekly(5, 10.hours(), 2.hours()).unwrap();
+/// // Station outage
+/// let outage_start = Epoch::from_gregorian_utc_hms(2022, 5, 20, 12, 0, 0);
+/// calendar.add_blackout(Interval::new(outage_start, outage_start + 1.hours()));
+///
+/// let friday = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// assert!(!calendar.is_available(friday));
+/// assert_eq!(calendar.next_available(friday), Some(friday + 6.hours()));
+/// assert!(calendar.is_available(friday + 11.hours()));
+/// assert_eq!(calendar.next_available(outage_start), Some(outage_start + 1.hours()));
+/// // Saturday morning is available until the maintenance
+/// let saturday = friday + 1.days();
+/// assert!(calendar.is_available(saturday + 9.hours()));
+/// assert_eq!(calendar.next_available(saturday + 10.hours()), Some(saturday + 12.hours()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlackoutCalendar {
+    time_system: TimeSystem,
+    blackouts: Vec<Interval>,
+    recurring: Vec<RecurringBlackout>,
+}
+
+impl BlackoutCalendar {
+    /// Builds an empty calendar, whose recurring blackouts are read in the provided time system.
+    #[must_use]
+    pub fn new(time_system: TimeSystem) -> Self {
+        Self {
+            time_system,
+            blackouts: Vec::new(),
+            recurring: Vec::new(),
+        }
+    }
+
+    /// Returns the time system in which the recurring blackouts are read.
+    #[must_use]
+    pub const fn time_system(&self) -> TimeSystem {
+        self.time_system
+    }
+
+    /// Returns the one-off blackouts, in the order they were added.
+    #[must_use]
+    pub fn blackouts(&self) -> &[Interval] {
+        &self.blackouts
+    }
+
+    /// Adds a one-off blackout.
+    pub fn add_blackout(&mut self, blackout: Interval) {
+        self.blackouts.push(blackout);
+    }
+
+    /// Adds a blackout which starts every day at the provided time of day, for the provided
+    /// duration, which may run into the next day.
+    ///
+    /// Returns an error if the time of day is not within a day, or if the duration is negative or
+    /// not shorter than a day.
+    pub fn add_daily(&mut self, start_of_day: Duration, duration: Duration) -> Result<(), Errors> {
+        self.add_recurring(Unit::Day * 1, start_of_day, duration)
+    }
+
+    /// Adds a blackout which starts every week on the provided day of the week, from 0 on Monday to
+    /// 6 on Sunday, at the provided time of day, for the provided duration.
+    ///
+    /// Returns an error if the day of the week is larger than 6, if the time of day is not within a
+    /// day, or if the duration is negative or not shorter than a week.
+    pub fn add_weekly(
+        &mut self,
+        weekday: u8,
+        start_of_day: Duration,
+        duration: Duration,
+    ) -> Result<(), Errors> {
+        if weekday > 6 || start_of_day >= Unit::Day * 1 {
+            return Err(Errors::Carry);
+        }
+        self.add_recurring(
+            Unit::Day * 7,
+            Unit::Day * i64::from(weekday) + start_of_day,
+            duration,
+        )
+    }
+
+    fn add_recurring(
+        &mut self,
+        period: Duration,
+        start: Duration,
+        duration: Duration,
+    ) -> Result<(), Errors> {
+        let zero = Unit::Second * 0;
+        if start < zero || start >= period || duration < zero || duration >= period {
+            return Err(Errors::Carry);
+        }
+        self.recurring.push(RecurringBlackout {
+            period,
+            start,
+            duration,
+        });
+        Ok(())
+    }
+
+    /// Returns true if the provided epoch is not within any blackout.
+    #[must_use]
+    pub fn is_available(&self, epoch: Epoch) -> bool {
+        self.blackout_end(epoch).is_none()
+    }
+
+    /// Returns the first available epoch at or after the provided one, i.e. the provided epoch if
+    /// it is available, or else the end of the blackouts which contain it, or `None` if the
+    /// recurring blackouts leave no time available.
+    #[must_use]
+    pub fn next_available(&self, epoch: Epoch) -> Option<Epoch> {
+        // The recurring blackouts repeat every week, so if there is no time available within a
+        // week of the last one-off blackout, there will never be.
+        let horizon = self
+            .blackouts
+            .iter()
+            .map(Interval::end)
+            .fold(epoch, |latest, end| if end > latest { end } else { latest })
+            + Unit::Day * 8;
+        let mut candidate = epoch;
+        while let Some(end) = self.blackout_end(candidate) {
+            if end > horizon {
+                return None;
+            }
+            candidate = end;
+        }
+        Some(candidate)
+    }
+
+    /// Returns the latest end of the blackouts which contain the provided epoch, if any.
+    fn blackout_end(&self, epoch: Epoch) -> Option<Epoch> {
+        let one_off = self
+            .blackouts
+            .iter()
+            .filter(|blackout| blackout.start() <= epoch && epoch < blackout.end())
+            .map(Interval::end);
+        let reading = epoch.duration_in(self.time_system);
+        let recurring = self.recurring.iter().filter_map(|blackout| {
+            blackout.elapsed(reading).map(|elapsed| {
+                Epoch::from_duration_in(reading - elapsed + blackout.duration, self.time_system)
+            })
+        });
+        one_off.chain(recurring).max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BlackoutCalendar, Epoch, Errors, Interval, TimeSystem, TimeUnits};
+
+    #[test]
+    fn test_blackout_calendar() {
+        // 2022 May 16 is a Monday
+        let monday = Epoch::from_gregorian_utc_at_midnight(2022, 5, 16);
+        let mut calendar = BlackoutCalendar::new(TimeSystem::UTC);
+        assert_eq!(calendar.time_system(), TimeSystem::UTC);
+        assert!(calendar.is_available(monday));
+        assert_eq!(calendar.next_available(monday), Some(monday));
+
+        calendar.add_daily(2.hours(), 2.hours()).unwrap();
+        // Sunday evening to Monday morning
+        calendar.add_weekly(6, 20.hours(), 8.hours()).unwrap();
+        // One-off blackout right after the daily one on Wednesday
+        let wednesday = monday + 2.days();
+        calendar.add_blackout(Interval::new(wednesday + 4.hours(), wednesday + 5.hours()));
+        assert_eq!(calendar.blackouts().len(), 1);
+
+        // The start of a blackout is excluded but not its end
+        assert!(calendar.is_available(wednesday + 2.hours() - 1.nanoseconds()));
+        assert!(!calendar.is_available(wednesday + 2.hours()));
+        assert!(calendar.is_available(monday + 1.days() + 4.hours()));
+        // Consecutive blackouts are skipped
+        assert_eq!(
+            calendar.next_available(wednesday + 3.hours()),
+            Some(wednesday + 5.hours())
+        );
+        // The weekly blackout covers the daily one on Monday, and wraps around the week
+        assert!(!calendar.is_available(monday));
+        assert_eq!(calendar.next_available(monday), Some(monday + 4.hours()));
+        let sunday = monday + 6.days();
+        assert!(calendar.is_available(sunday + 20.hours() - 1.nanoseconds()));
+        assert_eq!(
+            calendar.next_available(sunday + 23.hours()),
+            Some(sunday + 28.hours())
+        );
+
+        // Recurring blackouts are read in the time system of the calendar
+        let mut tai_calendar = BlackoutCalendar::new(TimeSystem::TAI);
+        tai_calendar.add_daily(2.hours(), 2.hours()).unwrap();
+        let two_tai = Epoch::from_gregorian_tai_hms(2022, 5, 18, 2, 0, 0);
+        assert!(!tai_calendar.is_available(two_tai));
+        assert!(calendar.is_available(two_tai - 37.seconds() - 1.nanoseconds()));
+        assert_eq!(
+            tai_calendar.next_available(two_tai),
+            Some(two_tai + 2.hours())
+        );
+    }
+
+    #[test]
+    fn test_blackout_calendar_et() {
+        // Weekly blackout on Monday from 00:00 to 02:00 ET, about a minute ahead of UTC
+        let mut calendar = BlackoutCalendar::new(TimeSystem::ET);
+        calendar.add_weekly(0, 0.hours(), 2.hours()).unwrap();
+        let monday = Epoch::from_ymd_sod(2022, 5, 16, 0.0, TimeSystem::ET);
+        assert_eq!(
+            monday,
+            Epoch::from_gregorian_utc(2022, 5, 15, 23, 58, 50, 815_065_000)
+        );
+        assert!(calendar.is_available(monday - 1.nanoseconds()));
+        assert!(!calendar.is_available(monday));
+        assert_eq!(calendar.next_available(monday), Some(monday + 2.hours()));
+        // Not on the other days of the week
+        for day in 1..7 {
+            assert!(calendar.is_available(monday + day * 1.days()));
+        }
+        assert!(!calendar.is_available(monday + 7.days() + 1.hours()));
+    }
+
+    #[test]
+    fn test_blackout_calendar_full() {
+        let mut calendar = BlackoutCalendar::new(TimeSystem::UTC);
+        calendar.add_daily(0.hours(), 12.hours()).unwrap();
+        let noon = Epoch::from_gregorian_utc_at_noon(2022, 5, 20);
+        assert_eq!(calendar.next_available(noon), Some(noon));
+        // The day is now fully blacked out
+        calendar.add_daily(12.hours(), 12.hours()).unwrap();
+        assert!(!calendar.is_available(noon));
+        assert_eq!(calendar.next_available(noon), None);
+
+        // Invalid recurring blackouts
+        assert_eq!(calendar.add_daily(0.hours(), 1.days()), Err(Errors::Carry));
+        assert_eq!(
+            calendar.add_daily(24.hours(), 1.hours()),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            calendar.add_daily(1.hours(), -1.hours()),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            calendar.add_weekly(7, 0.hours(), 1.hours()),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            calendar.add_weekly(0, 1.days(), 1.hours()),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            calendar.add_weekly(0, 0.hours(), 7.days()),
+            Err(Errors::Carry)
+        );
+        assert!(calendar.add_weekly(6, 23.hours(), 2.days()).is_ok());
+    }
+}
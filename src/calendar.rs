@@ -0,0 +1,629 @@
+use crate::duration::NANOSECONDS_PER_DAY;
+use crate::epoch::{days_from_gregorian, days_in_month, gregorian_from_days};
+use crate::{Duration, Epoch, Errors, ParsingErrors, TimeSystem, DAYS_PER_CENTURY_I64};
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// Returns the day of the week of the provided number of days since 1900 January 01, from zero
+/// on Monday to six on Sunday, as 1900 January 01 was a Monday.
+pub(crate) const fn weekday_index(days_since_1900: i64) -> u8 {
+    days_since_1900.rem_euclid(7) as u8
+}
+
+/// Returns the whole days since 1900 January 01 of the provided epoch in the provided time system,
+/// computed exactly from its centuries and nanoseconds.
+fn days_since_1900(epoch: Epoch, ts: TimeSystem) -> i64 {
+    let (centuries, nanoseconds) = epoch.duration_in(ts).to_parts();
+    i64::from(centuries) * DAYS_PER_CENTURY_I64 + (nanoseconds / NANOSECONDS_PER_DAY) as i64
+}
+
+/// Returns the days since 1900 January 01 of the Monday of the first ISO 8601 week of the provided
+/// year, i.e. the week which contains January 04.
+const fn first_iso_monday(year: i32) -> i64 {
+    let january_4 = days_from_gregorian(year, 1, 4);
+    january_4 - weekday_index(january_4) as i64
+}
+
+impl Epoch {
+    /// Returns the calendar quarter of this epoch in the provided time system, from 1 (January to
+    /// March) to 4 (October to December).
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+    /// assert_eq!(e.quarter(TimeSystem::UTC), 2);
+    /// ```
+    #[must_use]
+    pub fn quarter(&self, ts: TimeSystem) -> u8 {
+        let (_, month, _, _) = self.as_ymd_sod(ts);
+        (month - 1) / 3 + 1
+    }
+
+    /// Returns the week of the month of this epoch in the provided time system, starting at 1 for
+    /// the week of the first day of the month, where weeks start on Monday. A month spans four to
+    /// six such weeks.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    ///
+    /// // May 2022 started on a Sunday, so Monday the 2nd is in the second week
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2022, 5, 1);
+    /// assert_eq!(e.week_of_month(TimeSystem::UTC), 1);
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2022, 5, 2);
+    /// assert_eq!(e.week_of_month(TimeSystem::UTC), 2);
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2022, 5, 31);
+    /// assert_eq!(e.week_of_month(TimeSystem::UTC), 6);
+    /// ```
+    #[must_use]
+    pub fn week_of_month(&self, ts: TimeSystem) -> u8 {
+        let (year, month, day, _) = self.as_ymd_sod(ts);
+        let first_weekday = weekday_index(days_from_gregorian(year, month, 1));
+        (day - 1 + first_weekday) / 7 + 1
+    }
+
+    /// Returns the ISO 8601 week date of this epoch in the provided time system, as the ISO year,
+    /// the week of that year (from 1 to 53) and the day of the week (from 1 on Monday to 7 on
+    /// Sunday). The weeks start on Monday and the first week of a year is the one which contains
+    /// its first Thursday, so the ISO year differs from the calendar year around January 01.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+    /// assert_eq!(e.isoweekdate(TimeSystem::UTC), (2022, 20, 5));
+    /// // 2021 January 01 was a Friday, in the last week of 2020
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2021, 1, 1);
+    /// assert_eq!(e.isoweekdate(TimeSystem::UTC), (2020, 53, 5));
+    /// ```
+    #[must_use]
+    pub fn isoweekdate(&self, ts: TimeSystem) -> (i32, u8, u8) {
+        let days = days_since_1900(*self, ts);
+        let weekday = weekday_index(days);
+        // The ISO year is the calendar year of the Thursday of this week
+        let (year, _, _) = gregorian_from_days(days - i64::from(weekday) + 3);
+        let week = (days - first_iso_monday(year)) / 7 + 1;
+        (year, week as u8, weekday + 1)
+    }
+
+    /// Returns the ISO 8601 week date of this epoch in the provided time system in its extended
+    /// format, e.g. `2022-W20-5`, cf. [`Epoch::isoweekdate`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+    /// assert_eq!(e.as_isoweekdate_str(TimeSystem::UTC), "2022-W20-5");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn as_isoweekdate_str(&self, ts: TimeSystem) -> String {
+        let (year, week, weekday) = self.isoweekdate(ts);
+        format!("{:04}-W{:02}-{}", year, week, weekday)
+    }
+
+    /// Builds the epoch at midnight of the provided ISO 8601 week date in the provided time system,
+    /// cf. [`Epoch::isoweekdate`]. Returns `Errors::Carry` if the week does not exist in that ISO
+    /// year or if the day of the week is not between 1 and 7, and `Errors::Overflow` if the year is
+    /// beyond the range of an epoch.
+    pub fn maybe_from_isoweekdate(
+        year: i32,
+        week: u8,
+        weekday: u8,
+        ts: TimeSystem,
+    ) -> Result<Self, Errors> {
+        if week == 0 || week > 53 || weekday == 0 || weekday > 7 {
+            return Err(Errors::Carry);
+        }
+        let days = first_iso_monday(year) + 7 * (i64::from(week) - 1) + i64::from(weekday) - 1;
+        let duration = Duration::try_from_total_nanoseconds(
+            i128::from(days) * i128::from(NANOSECONDS_PER_DAY),
+        )?;
+        // Only the years which start or end on a Thursday have a 53rd week
+        if week == 53 && days >= first_iso_monday(year + 1) {
+            return Err(Errors::Carry);
+        }
+        Ok(Self::from_duration_in(duration, ts))
+    }
+
+    /// Parses an ISO 8601 week date in its extended format, e.g. `2022-W20-5`, or in its basic
+    /// format, e.g. `2022W205`, as the epoch at midnight of that day in the provided time system.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    ///
+    /// let e = Epoch::from_isoweekdate_str("2022-W20-5", TimeSystem::UTC).unwrap();
+    /// assert_eq!(e, Epoch::from_gregorian_utc_at_midnight(2022, 5, 20));
+    /// let e = Epoch::from_isoweekdate_str("2020W534", TimeSystem::UTC).unwrap();
+    /// assert_eq!(e, Epoch::from_gregorian_utc_at_midnight(2020, 12, 31));
+    /// ```
+    pub fn from_isoweekdate_str(s: &str, ts: TimeSystem) -> Result<Self, Errors> {
+        let bytes = s.as_bytes();
+        if !s.is_ascii() {
+            return Err(Errors::ParseError(ParsingErrors::ISO8601));
+        }
+        let (year, week, weekday) = match bytes.len() {
+            10 if bytes[4] == b'-' && bytes[5] == b'W' && bytes[8] == b'-' => {
+                (&s[..4], &s[6..8], &s[9..])
+            }
+            8 if bytes[4] == b'W' => (&s[..4], &s[5..7], &s[7..]),
+            _ => return Err(Errors::ParseError(ParsingErrors::ISO8601)),
+        };
+        if !(year.bytes().chain(week.bytes()).chain(weekday.bytes())).all(|b| b.is_ascii_digit()) {
+            return Err(Errors::ParseError(ParsingErrors::ISO8601));
+        }
+        Self::maybe_from_isoweekdate(year.parse()?, week.parse()?, weekday.parse()?, ts)
+    }
+}
+
+/// A span of calendar years, months and days, e.g. "one month later", whose length in time depends
+/// on the date it is added to, unlike the exact [`Duration`](crate::Duration).
+///
+/// Adding a civil duration to an epoch first adds the years and months to its date, clamping the
+/// day to the last day of the resulting month (e.g. January 30 plus one month is the last day of
+/// February), then adds the days, and keeps the time of day. Hence this addition is not
+/// commutative: adding one month then one day may differ from adding one day then one month, and
+/// subtracting one month after adding it may not return the original epoch. For that reason,
+/// only `Epoch + CivilDuration` is defined, and not `CivilDuration + Epoch`.
+///
+/// `Epoch + CivilDuration` uses the calendar of UTC, cf. [`Epoch::add_civil`] for other time
+/// systems.
+///
+/// # Example
+/// ```
+/// use hifitime::{CivilDuration, Epoch};
+///
+/// let e = Epoch::from_gregorian_utc_hms(2022, 1, 30, 17, 57, 43);
+/// let one_month = CivilDuration::new(0, 1, 0);
+/// let one_day = CivilDuration::new(0, 0, 1);
+/// assert_eq!(e + one_month, Epoch::from_gregorian_utc_hms(2022, 2, 28, 17, 57, 43));
+/// assert_eq!(e + one_month + one_day, Epoch::from_gregorian_utc_hms(2022, 3, 1, 17, 57, 43));
+/// assert_eq!(e + one_day + one_month, Epoch::from_gregorian_utc_hms(2022, 2, 28, 17, 57, 43));
+/// assert_ne!(e + one_month - one_month, e);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CivilDuration {
+    /// Number of calendar years, added together with the months
+    pub years: i32,
+    /// Number of calendar months
+    pub months: i32,
+    /// Number of calendar days, added after the years and months (and the clamping of the day)
+    pub days: i32,
+}
+
+impl CivilDuration {
+    /// Builds a civil duration of the provided calendar years, months and days, any of which may
+    /// be negative.
+    #[must_use]
+    pub const fn new(years: i32, months: i32, days: i32) -> Self {
+        Self {
+            years,
+            months,
+            days,
+        }
+    }
+}
+
+impl Neg for CivilDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.years, -self.months, -self.days)
+    }
+}
+
+impl Epoch {
+    /// Returns this epoch shifted by the provided calendar span, computed on the calendar of the
+    /// provided time system, cf. [`CivilDuration`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{CivilDuration, Epoch, TimeSystem};
+    ///
+    /// let e = Epoch::from_gregorian_tai_at_midnight(2020, 2, 29);
+    /// let next_year = e.add_civil(CivilDuration::new(1, 0, 0), TimeSystem::TAI);
+    /// assert_eq!(next_year, Epoch::from_gregorian_tai_at_midnight(2021, 2, 28));
+    /// ```
+    #[must_use]
+    pub fn add_civil(&self, span: CivilDuration, ts: TimeSystem) -> Self {
+        let days = days_since_1900(*self, ts);
+        let (year, month, day) = gregorian_from_days(days);
+        // Months since year zero, in which the years and months of the span are added
+        let months = 12 * (i64::from(year) + i64::from(span.years)) + i64::from(month) - 1
+            + i64::from(span.months);
+        let year = months.div_euclid(12) as i32;
+        let month = (months.rem_euclid(12) + 1) as u8;
+        let day = day.min(days_in_month(year, month));
+        let shifted = days_from_gregorian(year, month, day) + i64::from(span.days);
+        let shift = Duration::from_total_nanoseconds(
+            i128::from(shifted - days) * i128::from(NANOSECONDS_PER_DAY),
+        );
+        Self::from_duration_in(self.duration_in(ts) + shift, ts)
+    }
+}
+
+impl Add<CivilDuration> for Epoch {
+    type Output = Self;
+
+    fn add(self, span: CivilDuration) -> Self {
+        self.add_civil(span, TimeSystem::UTC)
+    }
+}
+
+impl AddAssign<CivilDuration> for Epoch {
+    fn add_assign(&mut self, span: CivilDuration) {
+        *self = *self + span;
+    }
+}
+
+impl Sub<CivilDuration> for Epoch {
+    type Output = Self;
+
+    fn sub(self, span: CivilDuration) -> Self {
+        self.add_civil(-span, TimeSystem::UTC)
+    }
+}
+
+impl SubAssign<CivilDuration> for Epoch {
+    fn sub_assign(&mut self, span: CivilDuration) {
+        *self = *self - span;
+    }
+}
+
+/// A fiscal calendar, whose fiscal year starts on the first day of a given month, for reporting
+/// by fiscal year, quarter and period (i.e. the month of the fiscal year).
+///
+/// A fiscal year is named after the calendar year in which it ends, e.g. the fiscal year 2022 of
+/// the US federal government started on 2021 October 01.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, FiscalCalendar, TimeSystem};
+///
+/// let e = Epoch::from_gregorian_utc_hms(2022, 11, 20, 17, 57, 43);
+/// let fiscal = FiscalCalendar::US_FEDERAL;
+/// assert_eq!(fiscal.year(e, TimeSystem::UTC), 2023);
+/// assert_eq!(fiscal.quarter(e, TimeSystem::UTC), 1);
+/// assert_eq!(fiscal.period(e, TimeSystem::UTC), 2);
+///
+/// // The fiscal year of the United Kingdom starts in April
+/// let fiscal = FiscalCalendar::new(4).unwrap();
+/// assert_eq!(fiscal.year(e, TimeSystem::UTC), 2023);
+/// assert_eq!(fiscal.quarter(e, TimeSystem::UTC), 3);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FiscalCalendar {
+    start_month: u8,
+}
+
+impl FiscalCalendar {
+    /// The calendar year, starting in January, where fiscal quarters are calendar quarters.
+    pub const CALENDAR: Self = Self { start_month: 1 };
+
+    /// The fiscal year of the US federal government, starting in October.
+    pub const US_FEDERAL: Self = Self { start_month: 10 };
+
+    /// Builds a fiscal calendar whose fiscal year starts in the provided month (from 1 to 12).
+    /// Returns `Errors::Carry` if the month is invalid.
+    pub const fn new(start_month: u8) -> Result<Self, Errors> {
+        if start_month == 0 || start_month > 12 {
+            Err(Errors::Carry)
+        } else {
+            Ok(Self { start_month })
+        }
+    }
+
+    /// Returns the month in which the fiscal year starts, from 1 to 12.
+    #[must_use]
+    pub const fn start_month(&self) -> u8 {
+        self.start_month
+    }
+
+    /// Returns the fiscal year of the provided epoch in the provided time system.
+    #[must_use]
+    pub fn year(&self, epoch: Epoch, ts: TimeSystem) -> i32 {
+        let (year, month, _, _) = epoch.as_ymd_sod(ts);
+        if self.start_month > 1 && month >= self.start_month {
+            year + 1
+        } else {
+            year
+        }
+    }
+
+    /// Returns the fiscal period of the provided epoch in the provided time system, i.e. its month
+    /// in the fiscal year, from 1 to 12.
+    #[must_use]
+    pub fn period(&self, epoch: Epoch, ts: TimeSystem) -> u8 {
+        let (_, month, _, _) = epoch.as_ymd_sod(ts);
+        (month + 12 - self.start_month) % 12 + 1
+    }
+
+    /// Returns the fiscal quarter of the provided epoch in the provided time system, from 1 to 4.
+    #[must_use]
+    pub fn quarter(&self, epoch: Epoch, ts: TimeSystem) -> u8 {
+        (self.period(epoch, ts) - 1) / 3 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::weekday_index;
+    use crate::epoch::days_from_gregorian;
+    use crate::{
+        CivilDuration, Epoch, Errors, FiscalCalendar, ParsingErrors, TimeSystem, TimeUnits,
+    };
+
+    #[test]
+    fn test_weekday_index() {
+        // 1900 January 01 was a Monday, and 2022 May 20 a Friday
+        assert_eq!(weekday_index(0), 0);
+        assert_eq!(weekday_index(days_from_gregorian(2022, 5, 20)), 4);
+        assert_eq!(weekday_index(days_from_gregorian(1899, 12, 31)), 6);
+        assert_eq!(weekday_index(days_from_gregorian(1815, 6, 18)), 6);
+    }
+
+    #[test]
+    fn test_quarter_week_of_month() {
+        for (month, quarter) in [
+            (1, 1),
+            (3, 1),
+            (4, 2),
+            (6, 2),
+            (7, 3),
+            (9, 3),
+            (10, 4),
+            (12, 4),
+        ] {
+            let e = Epoch::from_gregorian_utc_at_midnight(2022, month, 15);
+            assert_eq!(e.quarter(TimeSystem::UTC), quarter);
+        }
+        // The quarter depends on the time system at its bounds
+        let e = Epoch::from_gregorian_utc_at_midnight(2022, 4, 1) - 1.seconds();
+        assert_eq!(e.quarter(TimeSystem::UTC), 1);
+        assert_eq!(e.quarter(TimeSystem::TAI), 2);
+
+        // August 2022 started on a Monday
+        let start = Epoch::from_gregorian_utc_at_midnight(2022, 8, 1);
+        for day in 0..31_i64 {
+            let e = start + day * 1.days() + 12.hours();
+            assert_eq!(e.week_of_month(TimeSystem::UTC), (day / 7 + 1) as u8);
+        }
+        // February 2021 had exactly four weeks
+        let e = Epoch::from_gregorian_utc_at_midnight(2021, 2, 28);
+        assert_eq!(e.week_of_month(TimeSystem::UTC), 4);
+
+        // ET and TDB are about a minute ahead of UTC
+        let e = Epoch::from_gregorian_utc_at_midnight(2022, 4, 1) - 1.minutes();
+        for ts in [TimeSystem::ET, TimeSystem::TDB] {
+            assert_eq!(e.quarter(ts), 2);
+            assert_eq!(e.week_of_month(ts), 1);
+            assert_eq!((e - 1.minutes()).quarter(ts), 1);
+            assert_eq!((e - 1.minutes()).week_of_month(ts), 5);
+        }
+    }
+
+    #[test]
+    fn test_isoweekdate() {
+        for ((year, month, day), weekdate) in [
+            ((2022, 5, 20), (2022, 20, 5)),
+            ((2005, 1, 1), (2004, 53, 6)),
+            ((2007, 12, 31), (2008, 1, 1)),
+            ((2008, 12, 28), (2008, 52, 7)),
+            ((2009, 12, 31), (2009, 53, 4)),
+            ((2010, 1, 3), (2009, 53, 7)),
+            ((1900, 1, 1), (1900, 1, 1)),
+            ((1700, 6, 15), (1700, 24, 2)),
+        ] {
+            let e = Epoch::from_gregorian_utc_at_midnight(year, month, day);
+            assert_eq!(e.isoweekdate(TimeSystem::UTC), weekdate);
+            assert_eq!(
+                (e + 1.days() - 1.nanoseconds()).isoweekdate(TimeSystem::UTC),
+                weekdate
+            );
+            let (iso_year, week, weekday) = weekdate;
+            assert_eq!(
+                Epoch::maybe_from_isoweekdate(iso_year, week, weekday, TimeSystem::UTC),
+                Ok(e)
+            );
+            #[cfg(feature = "std")]
+            assert_eq!(
+                Epoch::from_isoweekdate_str(
+                    &e.as_isoweekdate_str(TimeSystem::UTC),
+                    TimeSystem::UTC
+                ),
+                Ok(e)
+            );
+        }
+        // The week date depends on the time system at midnight
+        let e = Epoch::from_gregorian_utc_at_midnight(2022, 5, 23) - 1.seconds();
+        assert_eq!(e.isoweekdate(TimeSystem::UTC), (2022, 20, 7));
+        assert_eq!(e.isoweekdate(TimeSystem::TAI), (2022, 21, 1));
+        #[cfg(feature = "std")]
+        assert_eq!(e.as_isoweekdate_str(TimeSystem::TAI), "2022-W21-1");
+
+        // ET and TDB are about a minute ahead of UTC
+        let e = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+        for ts in [TimeSystem::ET, TimeSystem::TDB] {
+            assert_eq!(e.isoweekdate(ts), (2022, 20, 5));
+            assert_eq!(
+                Epoch::maybe_from_isoweekdate(2022, 20, 5, ts),
+                Ok(Epoch::from_ymd_sod(2022, 5, 20, 0.0, ts))
+            );
+            let sunday_end = Epoch::from_gregorian_utc_at_midnight(2022, 5, 23) - 1.minutes();
+            assert_eq!(sunday_end.isoweekdate(ts), (2022, 21, 1));
+        }
+
+        // Centuries away from 1900, and years beyond the range of an epoch
+        for year in [2_300, -2_000_000] {
+            let e = Epoch::maybe_from_isoweekdate(year, 10, 3, TimeSystem::TAI).unwrap();
+            assert_eq!(e.isoweekdate(TimeSystem::TAI), (year, 10, 3));
+        }
+        assert_eq!(
+            Epoch::maybe_from_isoweekdate(i32::MAX, 53, 1, TimeSystem::TAI),
+            Err(Errors::Overflow)
+        );
+        assert_eq!(
+            Epoch::maybe_from_isoweekdate(-4_000_000, 1, 1, TimeSystem::UTC),
+            Err(Errors::Overflow)
+        );
+
+        // Only some years have 53 weeks
+        assert_eq!(
+            Epoch::maybe_from_isoweekdate(2022, 53, 1, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            Epoch::maybe_from_isoweekdate(2022, 20, 8, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            Epoch::from_isoweekdate_str("2022-W00-1", TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+        for invalid in [
+            "2022-W20",
+            "2022-W2-05",
+            "2022W20-5",
+            "2022-w20-5",
+            "+022-W20-5",
+            "2022W\u{20ac}",
+        ] {
+            assert_eq!(
+                Epoch::from_isoweekdate_str(invalid, TimeSystem::UTC),
+                Err(Errors::ParseError(ParsingErrors::ISO8601))
+            );
+        }
+    }
+
+    #[test]
+    fn test_fiscal_calendar() {
+        let calendar = FiscalCalendar::CALENDAR;
+        let us = FiscalCalendar::US_FEDERAL;
+        let e = Epoch::from_gregorian_utc_at_midnight(2022, 9, 30);
+        assert_eq!(calendar.year(e, TimeSystem::UTC), 2022);
+        assert_eq!(
+            calendar.quarter(e, TimeSystem::UTC),
+            e.quarter(TimeSystem::UTC)
+        );
+        assert_eq!(calendar.period(e, TimeSystem::UTC), 9);
+        assert_eq!(us.year(e, TimeSystem::UTC), 2022);
+        assert_eq!(us.quarter(e, TimeSystem::UTC), 4);
+        assert_eq!(us.period(e, TimeSystem::UTC), 12);
+        let e = e + 1.days();
+        assert_eq!(us.year(e, TimeSystem::UTC), 2023);
+        assert_eq!(us.quarter(e, TimeSystem::UTC), 1);
+        assert_eq!(us.period(e, TimeSystem::UTC), 1);
+
+        let july = FiscalCalendar::new(7).unwrap();
+        assert_eq!(july.start_month(), 7);
+        let e = Epoch::from_gregorian_utc_at_midnight(2022, 6, 30);
+        assert_eq!(
+            (
+                july.year(e, TimeSystem::UTC),
+                july.quarter(e, TimeSystem::UTC)
+            ),
+            (2022, 4)
+        );
+
+        // ET and TDB are about a minute ahead of UTC
+        let e = Epoch::from_gregorian_utc_at_midnight(2022, 10, 1) - 1.minutes();
+        for ts in [TimeSystem::ET, TimeSystem::TDB] {
+            assert_eq!(
+                (us.year(e, ts), us.quarter(e, ts), us.period(e, ts)),
+                (2023, 1, 1)
+            );
+            let e = e - 1.minutes();
+            assert_eq!(
+                (us.year(e, ts), us.quarter(e, ts), us.period(e, ts)),
+                (2022, 4, 12)
+            );
+        }
+
+        assert_eq!(FiscalCalendar::new(0), Err(Errors::Carry));
+        assert_eq!(FiscalCalendar::new(13), Err(Errors::Carry));
+    }
+
+    #[test]
+    fn test_civil_duration() {
+        let e = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 123_456_789);
+        let span = CivilDuration::new(1, 2, 3);
+        assert_eq!(
+            e + span,
+            Epoch::from_gregorian_utc(2023, 7, 23, 17, 57, 43, 123_456_789)
+        );
+        assert_eq!(e + span - span, e);
+        assert_eq!(
+            e - CivilDuration::new(0, 17, 0),
+            Epoch::from_gregorian_utc(2020, 12, 20, 17, 57, 43, 123_456_789)
+        );
+        let mut later = e;
+        later += CivilDuration::new(0, 0, 365);
+        assert_eq!(later - e, 365.days());
+        later -= CivilDuration::default();
+        assert_eq!(later, e + 365.days());
+
+        // Clamped to the end of the month, including leap years
+        let e = Epoch::from_gregorian_utc_at_midnight(2020, 3, 31);
+        assert_eq!(
+            e - CivilDuration::new(0, 1, 0),
+            Epoch::from_gregorian_utc_at_midnight(2020, 2, 29)
+        );
+        assert_eq!(
+            e - CivilDuration::new(1, 1, 0),
+            Epoch::from_gregorian_utc_at_midnight(2019, 2, 28)
+        );
+        assert_eq!(
+            e + CivilDuration::new(0, -1, 1),
+            Epoch::from_gregorian_utc_at_midnight(2020, 3, 1)
+        );
+
+        // Keeps the UTC time of day across a leap second, which one day of TAI does not
+        let e = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+        let next = e + CivilDuration::new(0, 0, 1);
+        assert_eq!(next, Epoch::from_gregorian_utc_at_noon(2017, 1, 1));
+        assert_eq!(next - e, 1.days() + 1.seconds());
+        assert_eq!(
+            e.add_civil(CivilDuration::new(0, 0, 1), TimeSystem::TAI),
+            e + 1.days()
+        );
+
+        // Before 1800
+        let e = Epoch::from_gregorian_tai_hms(1700, 1, 31, 6, 0, 0);
+        assert_eq!(
+            e.add_civil(CivilDuration::new(0, 1, 0), TimeSystem::TAI),
+            Epoch::from_gregorian_tai_hms(1700, 2, 28, 6, 0, 0)
+        );
+        assert_eq!(-CivilDuration::new(1, -2, 3), CivilDuration::new(-1, 2, -3));
+
+        // Four centuries always have the same number of days
+        let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
+        assert_eq!(
+            e.add_civil(CivilDuration::new(400, 0, 0), TimeSystem::TAI) - e,
+            146_097 * 1.days()
+        );
+        assert_eq!(
+            e.add_civil(CivilDuration::new(0, -4_800, 0), TimeSystem::TAI),
+            Epoch::from_gregorian_tai_hms(1622, 5, 20, 17, 57, 43)
+        );
+
+        // In ET and TDB, which are about a minute ahead of UTC, January 30 is already the 31st
+        let e = Epoch::from_gregorian_utc_hms(2022, 1, 30, 23, 59, 30);
+        let expected = Epoch::from_gregorian_utc_hms(2022, 2, 27, 23, 59, 30);
+        assert_eq!(
+            e.add_civil(CivilDuration::new(0, 1, 0), TimeSystem::ET),
+            expected
+        );
+        assert!(
+            (e.add_civil(CivilDuration::new(0, 1, 0), TimeSystem::TDB) - expected).abs()
+                < 1.milliseconds()
+        );
+        assert_eq!(
+            e + CivilDuration::new(0, 1, 0),
+            Epoch::from_gregorian_utc_hms(2022, 2, 28, 23, 59, 30)
+        );
+    }
+}
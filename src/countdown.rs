@@ -0,0 +1,323 @@
+use crate::{Duration, Epoch, Errors, Interval, Met, MissionTime, ParsingErrors};
+use core::fmt;
+use std::str::FromStr;
+
+/// A launch countdown clock, which stops during holds, such that the countdown time (e.g.
+/// `T-00:10:00`) differs from the wall clock time elapsed since the count started by the total
+/// duration of the holds, and the liftoff slips by that same duration.
+///
+/// The clock is formatted, and parsed back, as a plain text handover of its state, one field per
+/// line, e.g.:
+///
+/// ```text
+/// START 2022-05-20T15:00:00 UTC
+/// COUNTDOWN T-02:00:00
+/// HOLD 2022-05-20T15:30:00 UTC 2022-05-20T15:45:00 UTC
+/// HOLD 2022-05-20T16:10:00 UTC
+/// ```
+///
+/// where the last hold has no end if the clock is currently holding.
+///
+/// # Example
+/// ```
+/// use hifitime::{CountdownClock, Epoch, TimeUnits};
+/// use std::str::FromStr;
+///
+/// let start = Epoch::from_gregorian_utc_hms(2022, 5, 20, 15, 0, 0);
+/// let mut clock = CountdownClock::new(start, 2.hours());
+/// assert!(clock.hold(start + 30.minutes()));
+/// assert!(clock.resume(start + 45.minutes()));
+///
+/// let now = start + 1.hours();
+/// assert_eq!(clock.wall_clock(now), 1.hours());
+/// assert_eq!(format!("{}", clock.countdown(now)), "T-01:15:00");
+/// assert_eq!(clock.liftoff(now), start + 2.hours() + 15.minutes());
+///
+/// let handover = format!("{}", clock);
+/// assert_eq!(CountdownClock::from_str(&handover).unwrap(), clock);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountdownClock {
+    start: Epoch,
+    countdown: Duration,
+    holds: Vec<Interval>,
+    hold_start: Option<Epoch>,
+}
+
+impl CountdownClock {
+    /// Builds a countdown clock which started at the provided epoch, counting down from the
+    /// provided duration, e.g. two hours for a count started at `T-02:00:00`.
+    #[must_use]
+    pub fn new(start: Epoch, countdown: Duration) -> Self {
+        Self {
+            start,
+            countdown,
+            holds: Vec::new(),
+            hold_start: None,
+        }
+    }
+
+    /// Returns the wall clock epoch at which the count started.
+    #[must_use]
+    pub const fn start(&self) -> Epoch {
+        self.start
+    }
+
+    /// Returns the countdown time at which the count started, e.g. `T-02:00:00`.
+    #[must_use]
+    pub fn countdown_start(&self) -> Met {
+        Met(-self.countdown)
+    }
+
+    /// Returns the completed holds, in chronological order.
+    #[must_use]
+    pub fn holds(&self) -> &[Interval] {
+        &self.holds
+    }
+
+    /// Returns the start of the current hold, if the clock is holding.
+    #[must_use]
+    pub const fn hold_start(&self) -> Option<Epoch> {
+        self.hold_start
+    }
+
+    /// Returns whether the clock is holding, i.e. whether the countdown is stopped.
+    #[must_use]
+    pub const fn is_holding(&self) -> bool {
+        self.hold_start.is_some()
+    }
+
+    /// Returns the last epoch at which the clock held or resumed, or the start of the count.
+    fn last_event(&self) -> Epoch {
+        match (self.hold_start, self.holds.last()) {
+            (Some(hold_start), _) => hold_start,
+            (None, Some(hold)) => hold.end(),
+            (None, None) => self.start,
+        }
+    }
+
+    /// Holds the count at the provided epoch. Returns false, and does nothing, if the clock is
+    /// already holding or if the epoch is before the count started or before the last resume.
+    pub fn hold(&mut self, at: Epoch) -> bool {
+        if self.is_holding() || at < self.last_event() {
+            return false;
+        }
+        self.hold_start = Some(at);
+        true
+    }
+
+    /// Resumes the count at the provided epoch. Returns false, and does nothing, if the clock is
+    /// not holding or if the epoch is before the start of the hold.
+    pub fn resume(&mut self, at: Epoch) -> bool {
+        match self.hold_start {
+            Some(hold_start) if hold_start <= at => {
+                self.holds.push(Interval::new(hold_start, at));
+                self.hold_start = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the wall clock time elapsed since the count started.
+    #[must_use]
+    pub fn wall_clock(&self, at: Epoch) -> Duration {
+        at.since(self.start)
+    }
+
+    /// Returns the total duration of the holds up to the provided epoch, including the current
+    /// hold if any.
+    #[must_use]
+    pub fn held(&self, at: Epoch) -> Duration {
+        let mut held = self
+            .holds
+            .iter()
+            .filter(|hold| hold.start() < at)
+            .map(|hold| {
+                if hold.end() < at {
+                    hold.duration()
+                } else {
+                    at - hold.start()
+                }
+            })
+            .sum::<Duration>();
+        if let Some(hold_start) = self.hold_start {
+            if hold_start < at {
+                held += at - hold_start;
+            }
+        }
+        held
+    }
+
+    /// Returns the countdown time at the provided epoch, e.g. `T-00:10:00`, which is positive after
+    /// liftoff.
+    #[must_use]
+    pub fn countdown(&self, at: Epoch) -> Met {
+        Met(self.wall_clock(at) - self.held(at) - self.countdown)
+    }
+
+    /// Returns the liftoff epoch as projected at the provided epoch, i.e. the planned liftoff
+    /// delayed by the holds so far.
+    #[must_use]
+    pub fn liftoff(&self, at: Epoch) -> Epoch {
+        self.start + self.countdown + self.held(at)
+    }
+
+    /// Returns the mission time anchored at the liftoff as projected at the provided epoch.
+    #[must_use]
+    pub fn mission_time(&self, at: Epoch) -> MissionTime {
+        MissionTime::new(self.liftoff(at))
+    }
+}
+
+impl fmt::Display for CountdownClock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "START {}", self.start)?;
+        write!(f, "COUNTDOWN {}", self.countdown_start())?;
+        for hold in &self.holds {
+            write!(f, "\nHOLD {} {}", hold.start(), hold.end())?;
+        }
+        if let Some(hold_start) = self.hold_start {
+            write!(f, "\nHOLD {}", hold_start)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for CountdownClock {
+    type Err = Errors;
+
+    /// Parses the handover text of a countdown clock, as formatted by its `Display` implementation.
+    fn from_str(s: &str) -> Result<Self, Errors> {
+        let unknown = Errors::ParseError(ParsingErrors::UnknownFormat);
+        let mut lines = s.lines().map(str::trim).filter(|line| !line.is_empty());
+        let start = match lines.next().and_then(|line| line.strip_prefix("START ")) {
+            Some(start) => Epoch::from_str(start)?,
+            None => return Err(unknown),
+        };
+        let countdown = match lines
+            .next()
+            .and_then(|line| line.strip_prefix("COUNTDOWN "))
+        {
+            Some(countdown) => -Met::from_str(countdown)?.0,
+            None => return Err(unknown),
+        };
+        let mut clock = Self::new(start, countdown);
+        for line in lines {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            // Each epoch is formatted as its date and time followed by its time system
+            let valid = match tokens.as_slice() {
+                ["HOLD", date, ts] => clock.hold(Epoch::from_str(&format!("{} {}", date, ts))?),
+                ["HOLD", date, ts, end_date, end_ts] => {
+                    clock.hold(Epoch::from_str(&format!("{} {}", date, ts))?)
+                        && clock.resume(Epoch::from_str(&format!("{} {}", end_date, end_ts))?)
+                }
+                _ => false,
+            };
+            if !valid {
+                return Err(unknown);
+            }
+        }
+        Ok(clock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CountdownClock, Epoch, Errors, Interval, Met, ParsingErrors, TimeUnits};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_countdown_clock() {
+        let start = Epoch::from_gregorian_utc_hms(2022, 5, 20, 15, 0, 0);
+        let mut clock = CountdownClock::new(start, 2.hours());
+        assert_eq!(clock.start(), start);
+        assert_eq!(clock.countdown_start(), Met(-(2.hours())));
+        assert_eq!(clock.countdown(start), Met(-(2.hours())));
+        assert_eq!(clock.liftoff(start), start + 2.hours());
+
+        // Cannot resume without holding, nor hold before the count started
+        assert!(!clock.resume(start + 10.minutes()));
+        assert!(!clock.hold(start - 1.seconds()));
+
+        assert!(clock.hold(start + 30.minutes()));
+        assert!(clock.is_holding());
+        assert!(!clock.hold(start + 35.minutes()));
+        // The countdown stops during the hold, and the liftoff slips
+        let during = start + 40.minutes();
+        assert_eq!(clock.countdown(during), Met(-(90.minutes())));
+        assert_eq!(clock.wall_clock(during), 40.minutes());
+        assert_eq!(clock.held(during), 10.minutes());
+        assert_eq!(clock.liftoff(during), start + 130.minutes());
+        assert!(!clock.resume(start + 20.minutes()));
+        assert!(clock.resume(start + 45.minutes()));
+        assert!(!clock.is_holding());
+        assert_eq!(
+            clock.holds(),
+            &[Interval::new(start + 30.minutes(), start + 45.minutes())]
+        );
+        // Cannot hold before the last resume
+        assert!(!clock.hold(start + 44.minutes()));
+
+        // Before, during and after the hold
+        assert_eq!(clock.held(start + 20.minutes()), 0.minutes());
+        assert_eq!(clock.held(start + 31.minutes()), 1.minutes());
+        assert_eq!(clock.held(start + 1.hours()), 15.minutes());
+        assert_eq!(clock.countdown(start + 20.minutes()), Met(-(100.minutes())));
+        assert_eq!(clock.countdown(start + 1.hours()), Met(-(75.minutes())));
+
+        assert!(clock.hold(start + 70.minutes()));
+        assert_eq!(clock.hold_start(), Some(start + 70.minutes()));
+        assert_eq!(clock.held(start + 80.minutes()), 25.minutes());
+        assert!(clock.resume(start + 80.minutes()));
+
+        // Liftoff and mission time
+        let liftoff = start + 2.hours() + 25.minutes();
+        assert_eq!(clock.liftoff(liftoff), liftoff);
+        assert_eq!(clock.countdown(liftoff), Met(0.seconds()));
+        assert_eq!(clock.countdown(liftoff + 5.seconds()), Met(5.seconds()));
+        assert_eq!(
+            clock.mission_time(liftoff).met(liftoff + 1.minutes()),
+            Met(1.minutes())
+        );
+    }
+
+    #[test]
+    fn test_countdown_clock_handover() {
+        let start = Epoch::from_gregorian_utc_hms(2022, 5, 20, 15, 0, 0);
+        let mut clock = CountdownClock::new(start, 2.hours() + 500.milliseconds());
+        let handover = "START 2022-05-20T15:00:00 UTC\nCOUNTDOWN T-02:00:00.5";
+        assert_eq!(format!("{}", clock), handover);
+        assert_eq!(CountdownClock::from_str(handover), Ok(clock.clone()));
+
+        clock.hold(start + 30.minutes());
+        clock.resume(start + 45.minutes());
+        clock.hold(start + 70.minutes());
+        let handover = format!("{}", clock);
+        assert_eq!(
+            handover,
+            "START 2022-05-20T15:00:00 UTC
+COUNTDOWN T-02:00:00.5
+HOLD 2022-05-20T15:30:00 UTC 2022-05-20T15:45:00 UTC
+HOLD 2022-05-20T16:10:00 UTC"
+        );
+        assert_eq!(CountdownClock::from_str(&handover), Ok(clock));
+
+        for s in [
+            "",
+            "COUNTDOWN T-02:00:00",
+            "START 2022-05-20T15:00:00 UTC",
+            "START 2022-05-20T15:00:00 UTC\nCOUNTDOWN T-02:00:00\nPAUSE 2022-05-20T15:30:00 UTC",
+            // Holds out of order
+            "START 2022-05-20T15:00:00 UTC\nCOUNTDOWN T-02:00:00\nHOLD 2022-05-20T15:30:00 UTC 2022-05-20T15:20:00 UTC",
+            "START 2022-05-20T15:00:00 UTC\nCOUNTDOWN T-02:00:00\nHOLD 2022-05-20T15:30:00 UTC\nHOLD 2022-05-20T15:40:00 UTC",
+        ] {
+            assert_eq!(
+                CountdownClock::from_str(s),
+                Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+                "{}",
+                s
+            );
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use crate::duration::NANOSECONDS_PER_DAY;
+use crate::epoch::gregorian_from_days;
+use crate::math::div_rem_i128;
+use crate::{
+    Duration, Epoch, Errors, ParsingErrors, Unit, J1900_OFFSET_DURATION, JDE_OFFSET_DURATION,
+};
+use core::fmt;
+use std::str::FromStr;
+
+/// The names of the time systems supported by [`Epoch::from_str`], which cannot be registered.
+const BUILT_IN_NAMES: [&str; 11] = [
+    "UTC", "TT", "TAI", "TDB", "ET", "TCG", "TCB", "LORAN", "GPST", "GST", "UT1",
+];
+
+/// A time scale defined at runtime, e.g. the onboard time of a spacecraft, by the conversion
+/// functions of its durations to and from TAI.
+///
+/// As for the built-in time systems, the durations of a custom time scale are counted from
+/// 1900 January 01 at midnight in that time scale, e.g. the duration of `SEC 17.2 MYSCALE` is
+/// 17.2 seconds.
+pub struct CustomTimeScale {
+    name: String,
+    to_tai: Box<dyn Fn(Duration) -> Duration + Send + Sync>,
+    from_tai: Box<dyn Fn(Duration) -> Duration + Send + Sync>,
+}
+
+impl CustomTimeScale {
+    /// Builds a time scale from the functions converting its durations to TAI durations, and TAI
+    /// durations to its durations, which must be the inverse of each other.
+    ///
+    /// The name must start with a letter and only contain ASCII letters and digits, and must not be
+    /// a built-in time system, otherwise `Errors::ParseError(ParsingErrors::TimeSystem)` is returned.
+    pub fn new<F, G>(name: &str, to_tai: F, from_tai: G) -> Result<Self, Errors>
+    where
+        F: Fn(Duration) -> Duration + Send + Sync + 'static,
+        G: Fn(Duration) -> Duration + Send + Sync + 'static,
+    {
+        let valid = name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric())
+            && !BUILT_IN_NAMES.contains(&name);
+        if !valid {
+            return Err(Errors::ParseError(ParsingErrors::TimeSystem));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            to_tai: Box::new(to_tai),
+            from_tai: Box::new(from_tai),
+        })
+    }
+
+    /// Builds a time scale which is ahead of TAI by a constant offset, e.g. a free running
+    /// onboard clock synchronized once.
+    pub fn from_tai_offset(name: &str, offset: Duration) -> Result<Self, Errors> {
+        Self::new(name, move |d| d - offset, move |d| d + offset)
+    }
+
+    /// Returns the name of this time scale, as used when parsing epochs.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the epoch of the provided duration in this time scale.
+    #[must_use]
+    pub fn to_epoch(&self, duration: Duration) -> Epoch {
+        Epoch::from_tai_duration((self.to_tai)(duration))
+    }
+
+    /// Returns the duration of the provided epoch in this time scale.
+    #[must_use]
+    pub fn from_epoch(&self, epoch: Epoch) -> Duration {
+        (self.from_tai)(epoch.as_tai_duration())
+    }
+}
+
+impl fmt::Debug for CustomTimeScale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CustomTimeScale")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A registry of custom time scales, which parses and formats epochs in these time scales in
+/// addition to the built-in time systems.
+///
+/// # Example
+/// ```
+/// use hifitime::{CustomTimeScale, Epoch, TimeScaleRegistry, TimeUnits};
+/// use std::str::FromStr;
+///
+/// let mut registry = TimeScaleRegistry::new();
+/// registry
+///     .register(CustomTimeScale::from_tai_offset("SCLK", 1.5.seconds()).unwrap())
+///     .unwrap();
+///
+/// let e = registry.parse("SEC 12.3 SCLK").unwrap();
+/// assert_eq!(e, Epoch::from_tai_seconds(10.8));
+/// let e = registry.parse("2022-05-20T17:57:43 SCLK").unwrap();
+/// assert_eq!(registry.format(e, "SCLK").unwrap(), "2022-05-20T17:57:43 SCLK");
+/// // Built-in time systems are still supported
+/// assert_eq!(
+///     registry.parse("MJD 51544.5 TAI").unwrap(),
+///     Epoch::from_str("MJD 51544.5 TAI").unwrap()
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct TimeScaleRegistry {
+    scales: Vec<CustomTimeScale>,
+}
+
+impl TimeScaleRegistry {
+    /// Builds an empty registry, which only supports the built-in time systems.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a custom time scale. Returns `Errors::ParseError(ParsingErrors::TimeSystem)` if a
+    /// time scale of the same name is already registered.
+    pub fn register(&mut self, scale: CustomTimeScale) -> Result<(), Errors> {
+        if self.get(scale.name()).is_some() {
+            return Err(Errors::ParseError(ParsingErrors::TimeSystem));
+        }
+        self.scales.push(scale);
+        Ok(())
+    }
+
+    /// Returns the custom time scale of the provided name, if registered.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&CustomTimeScale> {
+        self.scales.iter().find(|scale| scale.name() == name)
+    }
+
+    /// Parses an epoch in a custom time scale, either as a Gregorian date followed by the name of
+    /// the time scale (e.g. `2022-05-20T17:57:43 SCLK`), or in the `JD`, `MJD` and `SEC` formats of
+    /// [`Epoch::from_str`] (e.g. `SEC 12.3 SCLK` or `MJD_SCLK 59000.0`). Any other string is parsed
+    /// with [`Epoch::from_str`].
+    pub fn parse(&self, s: &str) -> Result<Epoch, Errors> {
+        let s = s.trim();
+        let (format, value, scale) = match s.rsplit_once(' ') {
+            Some((rest, name)) if self.get(name).is_some() => {
+                let scale = self.get(name).unwrap();
+                // A Gregorian date is parsed as TAI, whose durations are then those of the scale
+                if let Ok(e) = Epoch::from_gregorian_str(&format!("{} TAI", rest)) {
+                    return Ok(scale.to_epoch(e.as_tai_duration()));
+                }
+                match rest.split_once(' ') {
+                    Some((format, value)) => (format, value, scale),
+                    None => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+                }
+            }
+            // The time scale may be combined with the format, e.g. `MJD_SCLK 59000.0`
+            _ => match s
+                .split_once(' ')
+                .and_then(|(head, value)| Some((head.split_once('_')?, value)))
+            {
+                Some(((format, name), value)) if self.get(name).is_some() => {
+                    (format, value, self.get(name).unwrap())
+                }
+                _ => return Epoch::from_str(s),
+            },
+        };
+        let value = value
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| Errors::ParseError(ParsingErrors::UnknownFormat))?;
+        let duration = match format {
+            "JD" => value * Unit::Day - JDE_OFFSET_DURATION,
+            "MJD" => value * Unit::Day - J1900_OFFSET_DURATION,
+            "SEC" => value * Unit::Second,
+            _ => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+        };
+        Ok(scale.to_epoch(duration))
+    }
+
+    /// Formats an epoch as a Gregorian date in the custom time scale of the provided name, followed
+    /// by that name, which can be parsed back with [`TimeScaleRegistry::parse`]. Returns
+    /// `Errors::ParseError(ParsingErrors::TimeSystem)` if no such time scale is registered.
+    pub fn format(&self, epoch: Epoch, name: &str) -> Result<String, Errors> {
+        let scale = self
+            .get(name)
+            .ok_or(Errors::ParseError(ParsingErrors::TimeSystem))?;
+        // Split the duration exactly, rather than through the seconds as a float
+        let (days, nanos) = div_rem_i128(
+            scale.from_epoch(epoch).total_nanoseconds(),
+            i128::from(NANOSECONDS_PER_DAY),
+        );
+        let (y, mm, dd) = gregorian_from_days(days as i64);
+        let (secs, nanos) = (
+            (nanos / 1_000_000_000) as u32,
+            (nanos % 1_000_000_000) as u32,
+        );
+        let (hh, min, s) = (secs / 3_600, secs / 60 % 60, secs % 60);
+        if nanos == 0 {
+            Ok(format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02} {}",
+                y, mm, dd, hh, min, s, name
+            ))
+        } else {
+            Ok(format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09} {}",
+                y, mm, dd, hh, min, s, nanos, name
+            ))
+        }
+    }
+
+    /// Formats an epoch as the seconds of its duration in the custom time scale of the provided
+    /// name, e.g. `SEC 12.3 SCLK`. Returns `Errors::ParseError(ParsingErrors::TimeSystem)` if no
+    /// such time scale is registered.
+    pub fn format_seconds(&self, epoch: Epoch, name: &str) -> Result<String, Errors> {
+        let scale = self
+            .get(name)
+            .ok_or(Errors::ParseError(ParsingErrors::TimeSystem))?;
+        Ok(format!(
+            "SEC {} {}",
+            scale.from_epoch(epoch).in_seconds(),
+            name
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CustomTimeScale, TimeScaleRegistry};
+    use crate::{Duration, Epoch, Errors, ParsingErrors, TimeUnits, Unit};
+    use std::str::FromStr;
+
+    /// An onboard clock which runs fast by 10 ppm since 2022 January 01 TAI
+    fn drifting_clock() -> CustomTimeScale {
+        let sync = Epoch::from_gregorian_tai_at_midnight(2022, 1, 1).as_tai_duration();
+        let scaled = move |d: Duration, num: i128, den: i128| {
+            sync + Duration::from_total_nanoseconds((d - sync).total_nanoseconds() * num / den)
+        };
+        CustomTimeScale::new(
+            "OBT",
+            move |d| scaled(d, 100_000, 100_001),
+            move |d| scaled(d, 100_001, 100_000),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_custom_scale() {
+        let scale = drifting_clock();
+        assert_eq!(scale.name(), "OBT");
+        let e = Epoch::from_gregorian_tai_at_midnight(2022, 1, 2);
+        assert_eq!(
+            scale.from_epoch(e) - e.as_tai_duration(),
+            864.milliseconds()
+        );
+        assert_eq!(scale.to_epoch(scale.from_epoch(e)), e);
+        assert_eq!(
+            format!("{:?}", scale),
+            "CustomTimeScale { name: \"OBT\", .. }"
+        );
+
+        for name in ["", "TAI", "GPST", "2B", "MY_SCALE", "MY SCALE"] {
+            assert_eq!(
+                CustomTimeScale::from_tai_offset(name, 1.seconds()).unwrap_err(),
+                Errors::ParseError(ParsingErrors::TimeSystem)
+            );
+        }
+    }
+
+    #[test]
+    fn test_time_scale_registry() {
+        let mut registry = TimeScaleRegistry::new();
+        registry.register(drifting_clock()).unwrap();
+        registry
+            .register(CustomTimeScale::from_tai_offset("MYSCALE", -(2.seconds())).unwrap())
+            .unwrap();
+        assert_eq!(
+            registry.register(CustomTimeScale::from_tai_offset("OBT", 1.seconds()).unwrap()),
+            Err(Errors::ParseError(ParsingErrors::TimeSystem))
+        );
+        assert!(registry.get("MYSCALE").is_some());
+        assert!(registry.get("NOPE").is_none());
+
+        // Seconds, days and Gregorian dates in a custom time scale
+        let e = registry.parse("SEC 12.3 MYSCALE").unwrap();
+        assert_eq!(e, Epoch::from_tai_seconds(14.3));
+        assert_eq!(
+            registry.format_seconds(e, "MYSCALE").unwrap(),
+            "SEC 12.3 MYSCALE"
+        );
+        assert_eq!(registry.parse("SEC_MYSCALE 12.3").unwrap(), e);
+        let e = registry.parse("MJD 59000.5 MYSCALE").unwrap();
+        assert_eq!(e, Epoch::from_mjd_tai(59000.5) + 2 * Unit::Second);
+        assert_eq!(registry.parse("JD 2459001.0 MYSCALE").unwrap(), e);
+        assert_eq!(registry.parse("MJD_MYSCALE 59000.5").unwrap(), e);
+
+        let e = Epoch::from_gregorian_tai(2022, 1, 2, 0, 0, 0, 864_000_000);
+        let s = registry.format(Epoch::from_gregorian_tai_at_midnight(2022, 1, 2), "OBT");
+        assert_eq!(s.unwrap(), "2022-01-02T00:00:00.864000000 OBT");
+        assert_eq!(
+            registry.parse("2022-01-02T00:00:00.864 OBT").unwrap(),
+            Epoch::from_gregorian_tai_at_midnight(2022, 1, 2)
+        );
+        assert_eq!(
+            registry.format(e, "MYSCALE").unwrap(),
+            "2022-01-01T23:59:58.864000000 MYSCALE"
+        );
+
+        // Built-in time systems are parsed as usual
+        assert_eq!(
+            registry.parse("SEC 0.5 TAI").unwrap(),
+            Epoch::from_str("SEC 0.5 TAI").unwrap()
+        );
+        assert_eq!(
+            registry.parse("2022-01-02T00:00:00 UTC").unwrap(),
+            Epoch::from_gregorian_utc_at_midnight(2022, 1, 2)
+        );
+        assert_eq!(
+            registry.parse("SEC 0.5 NOPE"),
+            Err(Errors::ParseError(ParsingErrors::TimeSystem))
+        );
+        assert_eq!(
+            registry.parse("DAYS 0.5 MYSCALE"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            registry.format(e, "NOPE"),
+            Err(Errors::ParseError(ParsingErrors::TimeSystem))
+        );
+    }
+}
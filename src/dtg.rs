@@ -0,0 +1,179 @@
+use crate::format::year_from_short_year;
+use crate::{Epoch, Errors, ParsingErrors, TimeSystem};
+use core::fmt;
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Parses exactly two ASCII digits
+fn two_digits(s: &[u8]) -> Result<u8, Errors> {
+    match s {
+        [tens, units] if tens.is_ascii_digit() && units.is_ascii_digit() => {
+            Ok((tens - b'0') * 10 + (units - b'0'))
+        }
+        _ => Err(Errors::ParseError(ParsingErrors::ParseIntError)),
+    }
+}
+
+/// Parses `HHMMZ` into the hours and minutes
+fn parse_hhmmz(s: &[u8]) -> Result<(u8, u8), Errors> {
+    if s.len() != 5 || !s[4].eq_ignore_ascii_case(&b'Z') {
+        return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+    }
+    Ok((two_digits(&s[..2])?, two_digits(&s[2..4])?))
+}
+
+/// The military / aviation date time group (DTG) of an epoch, e.g. `201757Z MAY 22`, always in UTC
+/// and truncated to the minute.
+///
+/// # Example
+/// ```
+/// use hifitime::{DateTimeGroup, Epoch};
+///
+/// let epoch = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 0);
+/// assert_eq!(format!("{}", DateTimeGroup(epoch)), "201757Z MAY 22");
+/// assert_eq!(
+///     Epoch::from_dtg("201757Z MAY 22").unwrap(),
+///     Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 0)
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DateTimeGroup(pub Epoch);
+
+impl fmt::Display for DateTimeGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (y, mm, dd, hh, min, _, _) = self.0.as_gregorian(TimeSystem::UTC);
+        write!(
+            f,
+            "{:02}{:02}{:02}Z {} {:02}",
+            dd,
+            hh,
+            min,
+            MONTHS[(mm - 1) as usize],
+            y.rem_euclid(100)
+        )
+    }
+}
+
+/// The short zulu time of an epoch, e.g. `1757Z`, always in UTC and truncated to the minute.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ZuluTime(pub Epoch);
+
+impl fmt::Display for ZuluTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (_, _, _, hh, min, _, _) = self.0.as_gregorian(TimeSystem::UTC);
+        write!(f, "{:02}{:02}Z", hh, min)
+    }
+}
+
+impl Epoch {
+    /// Parses a military / aviation date time group (DTG) in UTC, `DDHHMMZ MON YY`.
+    /// The spaces are optional and the month abbreviation is case insensitive. Two digit years
+    /// 50 to 99 map to 1950 to 1999 and 00 to 49 map to 2000 to 2049.
+    pub fn from_dtg(s: &str) -> Result<Self, Errors> {
+        let s = s.trim().as_bytes();
+        if s.len() < 12 {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+        let day = two_digits(&s[..2])?;
+        let (hour, minute) = parse_hhmmz(&s[2..7])?;
+        // At most one space before and after the month
+        let rest = s[7..].strip_prefix(b" ").unwrap_or(&s[7..]);
+        if rest.len() < 5 {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+        let month = MONTHS
+            .iter()
+            .position(|name| name.as_bytes().eq_ignore_ascii_case(&rest[..3]))
+            .ok_or(Errors::ParseError(ParsingErrors::UnknownFormat))?;
+        let rest = rest[3..].strip_prefix(b" ").unwrap_or(&rest[3..]);
+        if rest.len() != 2 {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+        let year = year_from_short_year(two_digits(rest)?);
+        Self::maybe_from_gregorian(
+            year,
+            month as u8 + 1,
+            day,
+            hour,
+            minute,
+            0,
+            0,
+            TimeSystem::UTC,
+        )
+    }
+
+    /// Parses a short zulu time `HHMMZ` on the UTC date of the provided context epoch.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, ZuluTime};
+    ///
+    /// let context = Epoch::from_gregorian_utc_at_noon(2022, 5, 20);
+    /// let epoch = Epoch::from_zulu_time("1757Z", context).unwrap();
+    /// assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 0));
+    /// assert_eq!(format!("{}", ZuluTime(epoch)), "1757Z");
+    /// ```
+    pub fn from_zulu_time(s: &str, context: Self) -> Result<Self, Errors> {
+        let (hour, minute) = parse_hhmmz(s.trim().as_bytes())?;
+        let (year, month, day, _, _, _, _) = context.as_gregorian(TimeSystem::UTC);
+        Self::maybe_from_gregorian(year, month, day, hour, minute, 0, 0, TimeSystem::UTC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, Errors, ParsingErrors};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_dtg_format() {
+        use crate::{DateTimeGroup, ZuluTime};
+        let epoch = Epoch::from_gregorian_utc(1999, 1, 2, 3, 4, 59, 999_000_000);
+        assert_eq!(format!("{}", DateTimeGroup(epoch)), "020304Z JAN 99");
+        assert_eq!(format!("{}", ZuluTime(epoch)), "0304Z");
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2022, 12, 31);
+        assert_eq!(format!("{}", DateTimeGroup(epoch)), "310000Z DEC 22");
+        assert_eq!(
+            Epoch::from_dtg(&format!("{}", DateTimeGroup(epoch))).unwrap(),
+            epoch
+        );
+    }
+
+    #[test]
+    fn test_dtg_parse() {
+        let expected = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 0);
+        for dtg in [
+            "201757Z MAY 22",
+            "201757ZMAY22",
+            "201757z may 22",
+            " 201757Z MAY22 ",
+        ] {
+            assert_eq!(Epoch::from_dtg(dtg), Ok(expected), "{}", dtg);
+        }
+        assert_eq!(
+            Epoch::from_dtg("011200Z FEB 75"),
+            Ok(Epoch::from_gregorian_utc_at_noon(1975, 2, 1))
+        );
+
+        assert_eq!(
+            Epoch::from_dtg("201757Z MAI 22"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            Epoch::from_dtg("2O1757Z MAY 22"),
+            Err(Errors::ParseError(ParsingErrors::ParseIntError))
+        );
+        assert!(Epoch::from_dtg("201757 MAY 22").is_err());
+        assert!(Epoch::from_dtg("201757Z MAY 2022").is_err());
+        assert!(Epoch::from_dtg("201757Z  MAY 22").is_err());
+        assert_eq!(Epoch::from_dtg("301200Z FEB 22"), Err(Errors::Carry));
+
+        let context = Epoch::from_gregorian_utc_hms(2022, 5, 20, 23, 59, 59);
+        assert_eq!(Epoch::from_zulu_time("1757Z", context), Ok(expected));
+        assert_eq!(Epoch::from_zulu_time("1757z", context), Ok(expected));
+        assert!(Epoch::from_zulu_time("1757", context).is_err());
+        assert_eq!(Epoch::from_zulu_time("2460Z", context), Err(Errors::Carry));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::math::{abs, div_rem_i128, floor, powi};
 #[cfg(feature = "std")]
 use crate::ParsingErrors;
 use crate::{
@@ -8,7 +9,8 @@ use crate::{
 use core::cmp::Ordering;
 use core::convert::TryInto;
 use core::fmt;
-use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 #[cfg(feature = "std")]
 use super::regex::Regex;
@@ -17,13 +19,16 @@ use super::serde::{de, Deserialize, Deserializer};
 #[cfg(feature = "std")]
 use std::str::FromStr;
 
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+
 const DAYS_PER_CENTURY_U64: u64 = 36_525;
 const NANOSECONDS_PER_MICROSECOND: u64 = 1_000;
 const NANOSECONDS_PER_MILLISECOND: u64 = 1_000 * NANOSECONDS_PER_MICROSECOND;
 const NANOSECONDS_PER_SECOND: u64 = 1_000 * NANOSECONDS_PER_MILLISECOND;
 const NANOSECONDS_PER_MINUTE: u64 = 60 * NANOSECONDS_PER_SECOND;
 const NANOSECONDS_PER_HOUR: u64 = 60 * NANOSECONDS_PER_MINUTE;
-const NANOSECONDS_PER_DAY: u64 = 24 * NANOSECONDS_PER_HOUR;
+pub(crate) const NANOSECONDS_PER_DAY: u64 = 24 * NANOSECONDS_PER_HOUR;
 const NANOSECONDS_PER_CENTURY: u64 = DAYS_PER_CENTURY_U64 * NANOSECONDS_PER_DAY;
 
 /// Defines generally usable durations for nanosecond precision valid for 32,768 centuries in either direction, and only on 80 bits / 10 octets.
@@ -35,7 +40,8 @@ const NANOSECONDS_PER_CENTURY: u64 = DAYS_PER_CENTURY_U64 * NANOSECONDS_PER_DAY;
 /// That difference is exactly 1 nanoseconds, where the former duration is "closer to zero" than the latter.
 /// As such, the largest negative duration that can be represented sets the centuries to i16::MAX and its nanoseconds to NANOSECONDS_PER_CENTURY.
 /// 2. It was also decided that opposite durations are equal, e.g. -15 minutes == 15 minutes. If the direction of time matters, use the signum function.
-#[derive(Clone, Copy, Debug, PartialOrd, Eq, Ord)]
+#[allow(clippy::doc_lazy_continuation)]
+#[derive(Clone, Copy, PartialOrd, Eq, Ord)]
 pub struct Duration {
     pub(crate) centuries: i16,
     pub(crate) nanoseconds: u64,
@@ -141,19 +147,38 @@ impl Duration {
         }
     }
 
+    /// Converts the total nanoseconds as i128 into this Duration, or returns an overflow error if they
+    /// do not fit (where `from_total_nanoseconds` saturates). This is usable in a const context.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, Unit};
+    ///
+    /// const HALF_DAY: Duration = match Duration::try_from_total_nanoseconds(43_200_000_000_000) {
+    ///     Ok(duration) => duration,
+    ///     Err(_) => panic!("overflow"),
+    /// };
+    /// assert_eq!(HALF_DAY, Unit::Hour * 12);
+    /// assert!(Duration::try_from_total_nanoseconds(i128::MAX).is_err());
+    /// ```
+    pub const fn try_from_total_nanoseconds(nanos: i128) -> Result<Self, Errors> {
+        let centuries = nanos.div_euclid(NANOSECONDS_PER_CENTURY as i128);
+        if centuries > i16::MAX as i128 || centuries < i16::MIN as i128 {
+            Err(Errors::Overflow)
+        } else {
+            Ok(Self {
+                centuries: centuries as i16,
+                nanoseconds: nanos.rem_euclid(NANOSECONDS_PER_CENTURY as i128) as u64,
+            })
+        }
+    }
+
     /// Returns the total nanoseconds in a signed 128 bit integer
     #[must_use]
     pub fn total_nanoseconds(&self) -> i128 {
-        if self.centuries == -1 {
-            -i128::from(NANOSECONDS_PER_CENTURY - self.nanoseconds)
-        } else if self.centuries >= 0 {
-            i128::from(self.centuries) * i128::from(NANOSECONDS_PER_CENTURY)
-                + i128::from(self.nanoseconds)
-        } else {
-            // Centuries negative by a decent amount
-            i128::from(self.centuries + 1) * i128::from(NANOSECONDS_PER_CENTURY)
-                + i128::from(self.nanoseconds)
-        }
+        // The nanoseconds are always counted forward from the start of the century
+        i128::from(self.centuries) * i128::from(NANOSECONDS_PER_CENTURY)
+            + i128::from(self.nanoseconds)
     }
 
     /// Returns the truncated nanoseconds in a signed 64 bit integer, if the duration fits.
@@ -220,6 +245,71 @@ impl Duration {
         unit * value
     }
 
+    /// Converts the provided seconds exactly from the binary value of the f64, rounded to the
+    /// nearest nanosecond, and returns that duration along with the rounding error of the f64 at
+    /// this magnitude, i.e. half the spacing between two consecutive f64, to the nearest nanosecond.
+    ///
+    /// That error is the precision lost when the value was stored as an f64 (e.g. about 60 ns for
+    /// the current UNIX time in seconds), which the other f64 constructors absorb silently.
+    /// Returns an Overflow error if the seconds are not finite or do not fit in a duration.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, TimeUnits};
+    ///
+    /// assert_eq!(
+    ///     Duration::try_from_seconds_f64(0.5),
+    ///     Ok((500.milliseconds(), 0.nanoseconds()))
+    /// );
+    /// let (duration, error) = Duration::try_from_seconds_f64(1_653_069_463.123_456_7).unwrap();
+    /// assert_eq!(duration, 1_653_069_463.seconds() + 123_456_717.nanoseconds());
+    /// assert_eq!(error, 119.nanoseconds());
+    /// ```
+    pub fn try_from_seconds_f64(seconds: f64) -> Result<(Self, Self), Errors> {
+        let bits = seconds.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7FF) as i32;
+        if biased_exponent == 0x7FF {
+            return Err(Errors::Overflow);
+        }
+        let fraction = bits & ((1 << 52) - 1);
+        // The value is `mantissa * 2^exponent`, where subnormal numbers have no implicit bit
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+        let scaled = i128::from(mantissa) * i128::from(NANOSECONDS_PER_SECOND);
+        // Multiplies by 2^exponent, rounding to the nearest integer
+        let times_power = |value: i128, exponent: i32| -> Option<i128> {
+            if exponent >= 0 {
+                // Beyond the range of durations, whose total nanoseconds take 77 bits
+                if exponent > 43 {
+                    None
+                } else {
+                    Some(value << exponent)
+                }
+            } else if exponent < -100 {
+                Some(0)
+            } else {
+                Some((value + (1 << (-exponent - 1))) >> -exponent)
+            }
+        };
+        let nanoseconds = match times_power(scaled, exponent) {
+            Some(nanoseconds) => nanoseconds,
+            None => return Err(Errors::Overflow),
+        };
+        let nanoseconds = if seconds.is_sign_negative() {
+            -nanoseconds
+        } else {
+            nanoseconds
+        };
+        let error = times_power(i128::from(NANOSECONDS_PER_SECOND), exponent - 1).unwrap_or(0);
+        Ok((
+            Self::try_from_total_nanoseconds(nanoseconds)?,
+            Self::try_from_total_nanoseconds(error)?,
+        ))
+    }
+
     /// Returns this duration in seconds f64.
     /// For high fidelity comparisons, it is recommended to keep using the Duration structure.
     #[must_use]
@@ -427,6 +517,133 @@ impl Duration {
         }
     }
 
+    /// Divides this duration by the provided step, returning both the quotient and the remainder,
+    /// such that `self == step * quotient + remainder` exactly.
+    ///
+    /// This is an Euclidean division: the remainder is always positive and smaller than the absolute
+    /// value of the step, e.g. the sample index and the phase error of a quantization.
+    /// The quotient saturates at the bounds of an i64, which may only happen for steps of a few
+    /// nanoseconds and durations of several centuries.
+    ///
+    /// # Panics
+    /// This function panics if the step is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, TimeUnits};
+    ///
+    /// let two_hours_three_min = 2.hours() + 3.minutes();
+    /// assert_eq!(two_hours_three_min.div_mod(1.hours()), (2, 3.minutes()));
+    /// assert_eq!(two_hours_three_min.div_mod(45.minutes()), (2, 33.minutes()));
+    /// assert_eq!((-two_hours_three_min).div_mod(1.hours()), (-3, 57.minutes()));
+    /// ```
+    #[must_use]
+    pub fn div_mod(&self, step: Self) -> (i64, Self) {
+        let total = self.total_nanoseconds();
+        let step = step.total_nanoseconds();
+        assert!(step != 0, "cannot divide a duration by a zero step");
+        let quotient = total.div_euclid(step);
+        let quotient = if quotient > i128::from(i64::MAX) {
+            i64::MAX
+        } else if quotient < i128::from(i64::MIN) {
+            i64::MIN
+        } else {
+            quotient as i64
+        };
+        (
+            quotient,
+            Self::from_total_nanoseconds(total.rem_euclid(step)),
+        )
+    }
+
+    /// Returns a fixed width representation of this duration, for durations to line up in tables.
+    ///
+    /// The longest unit is the first field, which includes all of the longer units, e.g.
+    /// `DDD HH:MM:SS.sss` for days and `HH:MM:SS.sss` for hours. Centuries are shown as days, and
+    /// units shorter than a second as seconds. The precision is the number of decimals of the seconds,
+    /// at most nine, which are truncated. Negative durations are prefixed with a minus sign.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{TimeUnits, Unit};
+    ///
+    /// let duration = 1.days() + 2.hours() + 3.minutes() + 4.seconds() + 567.milliseconds();
+    /// assert_eq!(format!("{}", duration.aligned(Unit::Day, 3)), "001 02:03:04.567");
+    /// assert_eq!(format!("{}", duration.aligned(Unit::Hour, 1)), "26:03:04.5");
+    /// assert_eq!(format!("{}", 3.seconds().aligned(Unit::Minute, 0)), "00:03");
+    /// ```
+    #[must_use]
+    pub const fn aligned(&self, longest_unit: Unit, precision: u8) -> AlignedDuration {
+        AlignedDuration {
+            duration: *self,
+            longest_unit,
+            precision: if precision > 9 { 9 } else { precision },
+        }
+    }
+
+    /// Returns a fixed width representation of this duration as a string, cf. [`Duration::aligned`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{TimeUnits, Unit};
+    ///
+    /// let durations = [2.5.seconds(), -(1.days() + 20.minutes()), 15.hours()];
+    /// let column: Vec<String> = durations.iter().map(|d| d.to_aligned_string(Unit::Day, 3)).collect();
+    /// assert_eq!(column, ["000 00:00:02.500", "-001 00:20:00.000", "000 15:00:00.000"]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_aligned_string(&self, longest_unit: Unit, precision: u8) -> String {
+        format!("{}", self.aligned(longest_unit, precision))
+    }
+
+    /// Returns a representation of this duration in seconds with an SI prefix, i.e. in engineering
+    /// notation, e.g. `1.234 ms` or `3.2 ks`, for logs where the decomposition in days and hours
+    /// is unwanted.
+    ///
+    /// The prefix is chosen such that the value is between 1 and 1000, from nanoseconds up to
+    /// terasecond. The value is rounded (half away from zero) to the provided number of
+    /// significant digits, at least one, and padded with trailing zeros if needed.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// assert_eq!(format!("{}", 1_234_567.nanoseconds().si(4)), "1.235 ms");
+    /// assert_eq!(format!("{}", 56.7.microseconds().si(3)), "56.7 µs");
+    /// assert_eq!(format!("{}", 3_210.seconds().si(2)), "3.2 ks");
+    /// assert_eq!(format!("{}", (-1.days()).si(3)), "-86.4 ks");
+    /// ```
+    #[must_use]
+    pub const fn si(&self, significant_digits: u8) -> SiDuration {
+        SiDuration {
+            duration: *self,
+            significant_digits: if significant_digits == 0 {
+                1
+            } else if significant_digits > 30 {
+                30
+            } else {
+                significant_digits
+            },
+        }
+    }
+
+    /// Returns a representation of this duration in seconds with an SI prefix as a string, cf.
+    /// [`Duration::si`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// assert_eq!(999_999.nanoseconds().to_si_string(3), "1.00 ms");
+    /// assert_eq!(1.seconds().to_si_string(4), "1.000 s");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_si_string(&self, significant_digits: u8) -> String {
+        format!("{}", self.si(significant_digits))
+    }
+
     /// A duration of exactly zero nanoseconds
     const ZERO: Self = Self {
         centuries: 0,
@@ -445,13 +662,13 @@ impl Duration {
         nanoseconds: NANOSECONDS_PER_CENTURY,
     };
 
-    /// Smallest duration that can be represented
+    /// Smallest positive duration that can be represented, i.e. one nanosecond
     pub const EPSILON: Self = Self {
         centuries: 0,
         nanoseconds: 1,
     };
 
-    /// Minimum positive duration is one nanoseconds
+    /// Minimum positive duration is one nanosecond
     pub const MIN_POSITIVE: Self = Self::EPSILON;
 
     /// Minimum negative duration is minus one nanosecond
@@ -472,6 +689,21 @@ impl<'de> Deserialize<'de> for Duration {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Duration {
+    /// Generates a normalized duration from its centuries and nanoseconds, spanning the whole range
+    /// of durations.
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let centuries = i16::arbitrary(u)?;
+        let nanoseconds = u.int_in_range(0..=NANOSECONDS_PER_CENTURY - 1)?;
+        Ok(Self::from_parts(centuries, nanoseconds))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(i16::size_hint(depth), u64::size_hint(depth))
+    }
+}
+
 impl Mul<i64> for Duration {
     type Output = Duration;
     fn mul(self, q: i64) -> Self::Output {
@@ -482,6 +714,30 @@ impl Mul<i64> for Duration {
     }
 }
 
+impl MulAssign<i64> for Duration {
+    fn mul_assign(&mut self, q: i64) {
+        *self = *self * q;
+    }
+}
+
+impl MulAssign<f64> for Duration {
+    fn mul_assign(&mut self, q: f64) {
+        *self = *self * q;
+    }
+}
+
+impl Sum for Duration {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, duration| acc + duration)
+    }
+}
+
+impl<'a> Sum<&'a Duration> for Duration {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl Mul<f64> for Duration {
     type Output = Duration;
     fn mul(self, q: f64) -> Self::Output {
@@ -491,14 +747,14 @@ impl Mul<f64> for Duration {
         let ten: f64 = 10.0;
 
         loop {
-            if (new_val.floor() - new_val).abs() < f64::EPSILON {
+            if abs(floor(new_val) - new_val) < f64::EPSILON {
                 // Yay, we've found the precision of this number
                 break;
             }
             // Multiply by the precision
             // https://play.rust-lang.org/?version=stable&mode=debug&edition=2018&gist=b760579f103b7192c20413ebbe167b90
             p += 1;
-            new_val = q * ten.powi(p);
+            new_val = q * powi(ten, p);
         }
 
         Duration::from_total_nanoseconds(
@@ -514,6 +770,7 @@ impl Mul<i64> for Unit {
 
     /// Converts the input values to i128 and creates a duration from that
     /// This method will necessarily ignore durations below nanoseconds
+    #[allow(clippy::unnecessary_cast)]
     fn mul(self, q: i64) -> Duration {
         let total_ns = match self {
             Unit::Century => q * (NANOSECONDS_PER_CENTURY as i64),
@@ -595,6 +852,12 @@ macro_rules! impl_ops_for_type {
             }
         }
 
+        impl DivAssign<$type> for Duration {
+            fn div_assign(&mut self, q: $type) {
+                *self = *self / q;
+            }
+        }
+
         #[allow(clippy::suspicious_arithmetic_impl)]
         impl Div<$type> for Duration {
             type Output = Duration;
@@ -620,6 +883,19 @@ macro_rules! impl_ops_for_type {
     };
 }
 
+impl fmt::Debug for Duration {
+    /// Prints the exact centuries and nanoseconds of this duration followed by its units, e.g.
+    /// `Duration { centuries: 0, nanoseconds: 90000000000000 } (1 days 1 h)`. The alternate mode
+    /// (`{:#?}`) prints each part on its own line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Duration")
+            .field("centuries", &self.centuries)
+            .field("nanoseconds", &self.nanoseconds)
+            .finish()?;
+        write!(f, " ({})", self)
+    }
+}
+
 impl fmt::Display for Duration {
     // Prints this duration with automatic selection of the units, i.e. everything that isn't zero is ignored
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -649,11 +925,116 @@ impl fmt::Display for Duration {
     }
 }
 
+/// A fixed width representation of a duration, cf. [`Duration::aligned`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AlignedDuration {
+    duration: Duration,
+    longest_unit: Unit,
+    precision: u8,
+}
+
+impl fmt::Display for AlignedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let total_ns = self.duration.total_nanoseconds();
+        if total_ns < 0 {
+            write!(f, "-")?;
+        }
+        let ns_left = total_ns.abs();
+        let (seconds, ns_left) = div_rem_i128(ns_left, i128::from(NANOSECONDS_PER_SECOND));
+        match self.longest_unit {
+            Unit::Century | Unit::Day => {
+                let (days, seconds) = div_rem_i128(seconds, 86_400);
+                let (hours, seconds) = div_rem_i128(seconds, 3_600);
+                let (minutes, seconds) = div_rem_i128(seconds, 60);
+                write!(f, "{:03} {:02}:{:02}:{:02}", days, hours, minutes, seconds)?;
+            }
+            Unit::Hour => {
+                let (hours, seconds) = div_rem_i128(seconds, 3_600);
+                let (minutes, seconds) = div_rem_i128(seconds, 60);
+                write!(f, "{:02}:{:02}:{:02}", hours, minutes, seconds)?;
+            }
+            Unit::Minute => {
+                let (minutes, seconds) = div_rem_i128(seconds, 60);
+                write!(f, "{:02}:{:02}", minutes, seconds)?;
+            }
+            _ => write!(f, "{:02}", seconds)?,
+        }
+        if self.precision > 0 {
+            let decimals = ns_left / 10_i128.pow(9 - u32::from(self.precision));
+            write!(
+                f,
+                ".{:0width$}",
+                decimals,
+                width = usize::from(self.precision)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A representation of a duration in seconds with an SI prefix, cf. [`Duration::si`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SiDuration {
+    duration: Duration,
+    significant_digits: u8,
+}
+
+impl fmt::Display for SiDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const PREFIXES: [&str; 8] = ["n", "µ", "m", "", "k", "M", "G", "T"];
+        let total_ns = self.duration.total_nanoseconds();
+        if total_ns == 0 {
+            return write!(f, "0 s");
+        } else if total_ns < 0 {
+            write!(f, "-")?;
+        }
+        let digits = u32::from(self.significant_digits);
+        let ns = total_ns.unsigned_abs();
+        // Number of digits of the nanoseconds, i.e. position of the most significant one
+        let mut ns_digits = ns.ilog10() + 1;
+        let mantissa = if ns_digits > digits {
+            let divisor = 10_u128.pow(ns_digits - digits);
+            let mut mantissa = ns / divisor;
+            if 2 * (ns % divisor) >= divisor {
+                mantissa += 1;
+                if mantissa == 10_u128.pow(digits) {
+                    // Rounded up to the next power of ten
+                    mantissa /= 10;
+                    ns_digits += 1;
+                }
+            }
+            mantissa
+        } else {
+            ns * 10_u128.pow(digits - ns_digits)
+        };
+        // Index of the prefix, where nanoseconds are the shortest unit
+        let prefix = ((ns_digits - 1) / 3).min(PREFIXES.len() as u32 - 1);
+        let integer_digits = ns_digits - 3 * prefix;
+        if integer_digits >= digits {
+            write!(f, "{}", mantissa)?;
+            for _ in digits..integer_digits {
+                write!(f, "0")?;
+            }
+        } else {
+            let decimals = digits - integer_digits;
+            let (integer, fraction) = div_rem_i128(mantissa as i128, 10_i128.pow(decimals));
+            write!(
+                f,
+                "{}.{:0width$}",
+                integer,
+                fraction,
+                width = decimals as usize
+            )?;
+        }
+        write!(f, " {}s", PREFIXES[prefix as usize])
+    }
+}
+
 impl fmt::LowerExp for Duration {
     // Prints the duration with appropriate units
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let seconds_f64 = self.in_seconds();
-        let seconds_f64_abs = seconds_f64.abs();
+        let seconds_f64_abs = abs(seconds_f64);
         if seconds_f64_abs < 1e-5 {
             fmt::Display::fmt(&(seconds_f64 * 1e9), f)?;
             write!(f, " ns")
@@ -810,6 +1191,7 @@ impl PartialOrd<Unit> for Duration {
 impl Neg for Duration {
     type Output = Self;
 
+    #[allow(unused_attributes)]
     #[must_use]
     fn neg(self) -> Self::Output {
         Self::from_parts(
@@ -1007,8 +1389,179 @@ impl Unit {
 impl_ops_for_type!(f64);
 impl_ops_for_type!(i64);
 
-const fn div_rem_i128(me: i128, rhs: i128) -> (i128, i128) {
-    (me.div_euclid(rhs), me.rem_euclid(rhs))
+forward_ref_binop! { impl Add, add for Duration, Duration }
+forward_ref_binop! { impl Sub, sub for Duration, Duration }
+forward_ref_binop! { impl Add, add for Duration, Unit }
+forward_ref_binop! { impl Sub, sub for Duration, Unit }
+forward_ref_binop! { impl Mul, mul for Duration, i64 }
+forward_ref_binop! { impl Mul, mul for Duration, f64 }
+forward_ref_binop! { impl Div, div for Duration, i64 }
+forward_ref_binop! { impl Div, div for Duration, f64 }
+forward_ref_binop! { impl Mul, mul for i64, Duration }
+forward_ref_binop! { impl Mul, mul for f64, Duration }
+forward_ref_op_assign! { impl AddAssign, add_assign for Duration, Duration }
+forward_ref_op_assign! { impl SubAssign, sub_assign for Duration, Duration }
+
+impl Neg for &Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        -*self
+    }
+}
+
+/// A number of units added to a [`DurationBuilder`]: integers (up to 64 bits) are counted exactly,
+/// and floating point values are split into their exact integer part and a fraction rounded to the
+/// nearest nanosecond.
+pub trait DurationComponent: Copy {
+    /// Returns this number of the provided unit in nanoseconds, or `None` if it is not finite.
+    fn nanoseconds_in(self, unit: Unit) -> Option<i128>;
+}
+
+macro_rules! impl_duration_component_for_int {
+    ($($type:ident),*) => {
+        $(
+            impl DurationComponent for $type {
+                fn nanoseconds_in(self, unit: Unit) -> Option<i128> {
+                    Some(i128::from(self).saturating_mul((unit * 1).total_nanoseconds()))
+                }
+            }
+        )*
+    };
+}
+
+impl_duration_component_for_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl DurationComponent for f64 {
+    fn nanoseconds_in(self, unit: Unit) -> Option<i128> {
+        if !self.is_finite() {
+            return None;
+        }
+        let nanos_per_unit: i128 = (unit * 1).total_nanoseconds();
+        // Split the value so that its integer part is exact (casts saturate on large values)
+        let whole = self as i128;
+        let fraction = (self - whole as f64) * nanos_per_unit as f64;
+        let fraction_ns = if fraction < 0.0 {
+            (fraction - 0.5) as i128
+        } else {
+            (fraction + 0.5) as i128
+        };
+        Some(
+            whole
+                .saturating_mul(nanos_per_unit)
+                .saturating_add(fraction_ns),
+        )
+    }
+}
+
+impl DurationComponent for f32 {
+    fn nanoseconds_in(self, unit: Unit) -> Option<i128> {
+        f64::from(self).nanoseconds_in(unit)
+    }
+}
+
+/// A builder of durations from several components, e.g. for generated code or configuration files.
+///
+/// The components are summed exactly in nanoseconds: integer values never lose precision and only
+/// the fractional part of each component is rounded to the nearest nanosecond, cf.
+/// [`DurationComponent`]. The first invalid
+/// component (infinite or NaN) is reported when calling `build`, as is an overflow of the total.
+///
+/// # Example
+/// ```
+/// use hifitime::{Duration, TimeUnits};
+///
+/// let duration = Duration::builder().days(1).hours(2).seconds(3.5).build().unwrap();
+/// assert_eq!(duration, 1.days() + 2.hours() + 3.seconds() + 500.milliseconds());
+/// assert!(Duration::builder().seconds(f64::NAN).build().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DurationBuilder {
+    total_nanoseconds: i128,
+    invalid: bool,
+}
+
+impl Duration {
+    /// Returns a builder of a duration from several components, cf. [`DurationBuilder`].
+    #[must_use]
+    pub fn builder() -> DurationBuilder {
+        DurationBuilder::default()
+    }
+}
+
+impl DurationBuilder {
+    /// Adds the provided number of the provided unit to this duration.
+    #[must_use]
+    pub fn add<T: DurationComponent>(mut self, value: T, unit: Unit) -> Self {
+        match value.nanoseconds_in(unit) {
+            Some(nanoseconds) => {
+                self.total_nanoseconds = self.total_nanoseconds.saturating_add(nanoseconds);
+            }
+            None => self.invalid = true,
+        }
+        self
+    }
+
+    /// Adds the provided number of centuries, cf. [`DurationBuilder::add`].
+    #[must_use]
+    pub fn centuries<T: DurationComponent>(self, value: T) -> Self {
+        self.add(value, Unit::Century)
+    }
+
+    /// Adds the provided number of days, cf. [`DurationBuilder::add`].
+    #[must_use]
+    pub fn days<T: DurationComponent>(self, value: T) -> Self {
+        self.add(value, Unit::Day)
+    }
+
+    /// Adds the provided number of hours, cf. [`DurationBuilder::add`].
+    #[must_use]
+    pub fn hours<T: DurationComponent>(self, value: T) -> Self {
+        self.add(value, Unit::Hour)
+    }
+
+    /// Adds the provided number of minutes, cf. [`DurationBuilder::add`].
+    #[must_use]
+    pub fn minutes<T: DurationComponent>(self, value: T) -> Self {
+        self.add(value, Unit::Minute)
+    }
+
+    /// Adds the provided number of seconds, cf. [`DurationBuilder::add`].
+    #[must_use]
+    pub fn seconds<T: DurationComponent>(self, value: T) -> Self {
+        self.add(value, Unit::Second)
+    }
+
+    /// Adds the provided number of milliseconds, cf. [`DurationBuilder::add`].
+    #[must_use]
+    pub fn milliseconds<T: DurationComponent>(self, value: T) -> Self {
+        self.add(value, Unit::Millisecond)
+    }
+
+    /// Adds the provided number of microseconds, cf. [`DurationBuilder::add`].
+    #[must_use]
+    pub fn microseconds<T: DurationComponent>(self, value: T) -> Self {
+        self.add(value, Unit::Microsecond)
+    }
+
+    /// Adds the provided number of nanoseconds, cf. [`DurationBuilder::add`].
+    #[must_use]
+    pub fn nanoseconds<T: DurationComponent>(self, value: T) -> Self {
+        self.add(value, Unit::Nanosecond)
+    }
+
+    /// Returns the built duration, or `Errors::Overflow` if a component is not finite or if the
+    /// total does not fit in a Duration.
+    pub fn build(self) -> Result<Duration, Errors> {
+        if self.invalid
+            || self.total_nanoseconds > Duration::MAX.total_nanoseconds()
+            || self.total_nanoseconds < Duration::MIN.total_nanoseconds()
+        {
+            Err(Errors::Overflow)
+        } else {
+            Ok(Duration::from_total_nanoseconds(self.total_nanoseconds))
+        }
+    }
 }
 
 const fn div_rem_i64(me: i64, rhs: i64) -> (i64, i64) {
@@ -1016,8 +1569,9 @@ const fn div_rem_i64(me: i64, rhs: i64) -> (i64, i64) {
 }
 
 #[cfg(test)]
+#[allow(clippy::legacy_numeric_constants, clippy::neg_multiply)]
 mod tests {
-    use crate::{duration::NANOSECONDS_PER_MINUTE, Duration, Freq, TimeUnits, Unit};
+    use crate::{duration::NANOSECONDS_PER_MINUTE, Duration, Errors, Freq, TimeUnits, Unit};
 
     #[test]
     fn time_unit() {
@@ -1230,6 +1784,237 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_div_mod() {
+        let step = 33.milliseconds() + 333.microseconds();
+        for offset in [
+            -10_000_000,
+            -1,
+            0,
+            1,
+            33_333_332,
+            33_333_333,
+            123_456_789_012,
+        ] {
+            let duration = offset.nanoseconds();
+            let (quotient, remainder) = duration.div_mod(step);
+            assert!(remainder >= Duration::ZERO && remainder < step);
+            assert_eq!(step * quotient + remainder, duration);
+            // A negative step yields the opposite quotient and the same remainder
+            assert_eq!(duration.div_mod(-step), (-quotient, remainder));
+        }
+        assert_eq!(5.days().div_mod(1.days()), (5, Duration::ZERO));
+        assert_eq!(
+            Duration::MAX.div_mod(1.nanoseconds()),
+            (i64::MAX, Duration::ZERO)
+        );
+        assert_eq!(
+            Duration::MIN.div_mod(1.nanoseconds()),
+            (i64::MIN, Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        use crate::Errors;
+
+        assert_eq!(Duration::builder().build(), Ok(Duration::ZERO));
+        assert_eq!(
+            Duration::builder()
+                .days(1)
+                .hours(2)
+                .minutes(-3)
+                .seconds(4.5)
+                .milliseconds(6)
+                .microseconds(7)
+                .nanoseconds(8)
+                .build()
+                .unwrap(),
+            1.days() + 2.hours() - 3.minutes()
+                + 4.seconds()
+                + 506.milliseconds()
+                + 7.microseconds()
+                + 8.nanoseconds()
+        );
+        // Fractions are rounded to the nanosecond
+        assert_eq!(
+            Duration::builder().seconds(1e-10).build().unwrap(),
+            Duration::ZERO
+        );
+        assert_eq!(
+            Duration::builder().seconds(-0.1).build().unwrap(),
+            -100.milliseconds()
+        );
+        assert_eq!(Duration::builder().days(1.25).build().unwrap(), 30.hours());
+        // Integers are exact, even for large values
+        assert_eq!(
+            Duration::builder()
+                .centuries(12)
+                .nanoseconds(1)
+                .build()
+                .unwrap(),
+            Duration::from_parts(12, 1)
+        );
+        assert_eq!(
+            Duration::builder()
+                .nanoseconds(i64::MAX)
+                .nanoseconds(1_u64)
+                .build()
+                .unwrap(),
+            Duration::from_total_nanoseconds(i128::from(i64::MAX) + 1)
+        );
+        assert_eq!(
+            Duration::builder()
+                .nanoseconds(u64::MAX)
+                .seconds(-1.5_f32)
+                .build()
+                .unwrap(),
+            Duration::from_total_nanoseconds(i128::from(u64::MAX) - 1_500_000_000)
+        );
+        // Invalid components
+        assert_eq!(
+            Duration::builder().seconds(1).hours(f64::INFINITY).build(),
+            Err(Errors::Overflow)
+        );
+        assert_eq!(
+            Duration::builder().centuries(40_000).build(),
+            Err(Errors::Overflow)
+        );
+        assert_eq!(
+            Duration::builder().centuries(-40_000).build(),
+            Err(Errors::Overflow)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_aligned() {
+        let duration =
+            123.days() + 4.hours() + 5.minutes() + 6.seconds() + 789_012_345.nanoseconds();
+        assert_eq!(
+            duration.to_aligned_string(Unit::Century, 9),
+            "123 04:05:06.789012345"
+        );
+        assert_eq!(
+            duration.to_aligned_string(Unit::Day, 12),
+            "123 04:05:06.789012345"
+        );
+        assert_eq!(duration.to_aligned_string(Unit::Day, 0), "123 04:05:06");
+        assert_eq!(duration.to_aligned_string(Unit::Hour, 2), "2956:05:06.78");
+        assert_eq!(duration.to_aligned_string(Unit::Minute, 1), "177365:06.7");
+        assert_eq!(duration.to_aligned_string(Unit::Second, 3), "10641906.789");
+        assert_eq!(
+            duration.to_aligned_string(Unit::Nanosecond, 3),
+            "10641906.789"
+        );
+        assert_eq!(
+            Duration::from_parts(0, 0).to_aligned_string(Unit::Day, 3),
+            "000 00:00:00.000"
+        );
+        assert_eq!(
+            (-1.nanoseconds()).to_aligned_string(Unit::Hour, 9),
+            "-00:00:00.000000001"
+        );
+        assert_eq!(
+            (-1.nanoseconds()).to_aligned_string(Unit::Hour, 3),
+            "-00:00:00.000"
+        );
+        assert_eq!(1234.days().to_aligned_string(Unit::Day, 0), "1234 00:00:00");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_debug() {
+        let d = 25.hours();
+        assert_eq!(
+            format!("{:?}", d),
+            "Duration { centuries: 0, nanoseconds: 90000000000000 } (1 days 1 h)"
+        );
+        assert_eq!(
+            format!("{:#?}", d),
+            "Duration {\n    centuries: 0,\n    nanoseconds: 90000000000000,\n} (1 days 1 h)"
+        );
+        assert_eq!(
+            format!("{:?}", -(1.5.seconds())),
+            "Duration { centuries: -1, nanoseconds: 3155759998500000000 } (-1 s 500 ms)"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_si() {
+        assert_eq!(1_234_567.nanoseconds().to_si_string(4), "1.235 ms");
+        assert_eq!(1_234_567.nanoseconds().to_si_string(0), "1 ms");
+        assert_eq!(1_234_567.nanoseconds().to_si_string(9), "1.23456700 ms");
+        assert_eq!(56.7.microseconds().to_si_string(3), "56.7 µs");
+        assert_eq!(3_200.seconds().to_si_string(2), "3.2 ks");
+        assert_eq!(3_250.seconds().to_si_string(2), "3.3 ks");
+        assert_eq!(56.7.seconds().to_si_string(1), "60 s");
+        assert_eq!(567.seconds().to_si_string(2), "570 s");
+        assert_eq!(1.nanoseconds().to_si_string(3), "1.00 ns");
+        assert_eq!(999.nanoseconds().to_si_string(2), "1.0 µs");
+        assert_eq!(999.nanoseconds().to_si_string(3), "999 ns");
+        assert_eq!((-(1.5.milliseconds())).to_si_string(2), "-1.5 ms");
+        assert_eq!(0.seconds().to_si_string(3), "0 s");
+        assert_eq!(1.days().to_si_string(3), "86.4 ks");
+        assert_eq!(Duration::from_parts(100, 0).to_si_string(3), "316 Gs");
+        // The longest durations are in terasecond
+        assert_eq!(Duration::MAX.to_si_string(3), "103 Ts");
+        assert_eq!(Duration::MIN.to_si_string(2), "-100 Ts");
+        assert_eq!(
+            Duration::MAX.to_si_string(30),
+            "103.407943680000000000000000000 Ts"
+        );
+    }
+
+    #[test]
+    fn test_try_from_seconds_f64() {
+        assert_eq!(
+            Duration::try_from_seconds_f64(-1.5),
+            Ok((-(1.5.seconds()), 0.nanoseconds()))
+        );
+        assert_eq!(
+            Duration::try_from_seconds_f64(0.0),
+            Ok((0.nanoseconds(), 0.nanoseconds()))
+        );
+        // 0.1 is not exact in binary, but within half a nanosecond of 100 ms
+        assert_eq!(
+            Duration::try_from_seconds_f64(0.1),
+            Ok((100.milliseconds(), 0.nanoseconds()))
+        );
+        assert_eq!(
+            Duration::try_from_seconds_f64(1e-10),
+            Ok((0.nanoseconds(), 0.nanoseconds()))
+        );
+        assert_eq!(
+            Duration::try_from_seconds_f64(6e-10),
+            Ok((1.nanoseconds(), 0.nanoseconds()))
+        );
+        // Where the multiplication in f64 loses the last nanoseconds, this conversion is exact
+        let seconds = 3_155_716_800.123_456_7;
+        let (duration, error) = Duration::try_from_seconds_f64(seconds).unwrap();
+        assert_eq!(error, 238.nanoseconds());
+        assert!((duration - (3_155_716_800.seconds() + 123_456_700.nanoseconds())).abs() <= error);
+        // A million years in seconds are only precise to a few milliseconds
+        let (duration, error) = Duration::try_from_seconds_f64(3.155_76e13).unwrap();
+        assert_eq!(duration, Duration::from_parts(10_000, 0));
+        assert_eq!(error, 1_953_125.nanoseconds());
+
+        assert_eq!(
+            Duration::try_from_seconds_f64(f64::NAN),
+            Err(Errors::Overflow)
+        );
+        assert_eq!(
+            Duration::try_from_seconds_f64(f64::NEG_INFINITY),
+            Err(Errors::Overflow)
+        );
+        assert_eq!(Duration::try_from_seconds_f64(1e20), Err(Errors::Overflow));
+        assert_eq!(
+            Duration::try_from_seconds_f64(f64::MAX),
+            Err(Errors::Overflow)
+        );
+    }
+
     #[test]
     fn test_extremes() {
         let d = Duration::from_total_nanoseconds(i128::MAX);
@@ -1237,6 +2022,15 @@ mod tests {
         assert_eq!(Duration::from_total_nanoseconds(d.total_nanoseconds()), d);
         let d = Duration::from_total_nanoseconds(i128::MIN + 1);
         assert_eq!(d, Duration::MIN);
+        // More than a century in the past
+        for centuries in [-2, -10, i16::MIN + 1] {
+            let d = Duration::from_parts(centuries, 1);
+            assert_eq!(
+                d.total_nanoseconds(),
+                i128::from(centuries) * 3_155_760_000_000_000_000 + 1
+            );
+            assert_eq!(Duration::from_total_nanoseconds(d.total_nanoseconds()), d);
+        }
         // Test min positive
         let d_min = Duration::from_total_nanoseconds(Duration::MIN_POSITIVE.total_nanoseconds());
         assert_eq!(d_min, Duration::MIN_POSITIVE);
@@ -1317,4 +2111,84 @@ mod tests {
         assert_eq!(d.round(4.minutes()), 4.minutes());
         assert_eq!(d.round(1.seconds()), 4.minutes() + 14.seconds());
     }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use crate::duration::NANOSECONDS_PER_CENTURY;
+        use crate::Epoch;
+        use arbitrary::{Arbitrary, Unstructured};
+
+        assert_eq!(Duration::size_hint(0), (10, Some(10)));
+        let bytes: [u8; 64] = core::array::from_fn(|i| (i as u8).wrapping_mul(157));
+        let mut u = Unstructured::new(&bytes);
+        for _ in 0..6 {
+            let d = Duration::arbitrary(&mut u).unwrap();
+            let (centuries, nanoseconds) = d.to_parts();
+            assert!(nanoseconds < NANOSECONDS_PER_CENTURY);
+            assert_eq!(Duration::from_parts(centuries, nanoseconds), d);
+            let e = Epoch::arbitrary(&mut u).unwrap();
+            assert_eq!(Epoch::from_tai_duration(e.as_tai_duration()), e);
+        }
+        // Exhausted data still generates values
+        assert_eq!(
+            Duration::arbitrary(&mut Unstructured::new(&[])).unwrap(),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_ops_assign_sum() {
+        let mut d = 1.hours();
+        d *= 3;
+        assert_eq!(d, 3.hours());
+        d *= 0.5;
+        assert_eq!(d, 90.minutes());
+        d /= 3;
+        assert_eq!(d, 30.minutes());
+        d /= 2.0;
+        assert_eq!(d, 15.minutes());
+
+        let steps = [1.seconds(), 2.minutes(), -(3.seconds())];
+        assert_eq!(steps.iter().sum::<Duration>(), 118.seconds());
+        assert_eq!(steps.iter().copied().sum::<Duration>(), 118.seconds());
+        assert_eq!([].iter().sum::<Duration>(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_ref_ops() {
+        use core::ops::{Add, Mul};
+        // Generic code with operator bounds over references
+        fn sum_scaled<'a, T>(values: &'a [T], scale: i64) -> T
+        where
+            T: Copy,
+            &'a T: Mul<i64, Output = T>,
+            T: Add<&'a T, Output = T>,
+        {
+            values[1..]
+                .iter()
+                .fold(&values[0] * scale, |acc, v| acc + v)
+        }
+        let durations = [1.seconds(), 2.seconds(), 3.seconds()];
+        assert_eq!(sum_scaled(&durations, 2), 7.seconds());
+
+        let (a, b) = (&1.hours(), &30.minutes());
+        assert_eq!(a + b, 90.minutes());
+        assert_eq!(a - b, *b);
+        assert_eq!(a + 30.minutes(), 90.minutes());
+        assert_eq!(1.hours() - b, *b);
+        assert_eq!(a + Unit::Minute, 61.minutes());
+        assert_eq!(a - Unit::Minute, 59.minutes());
+        assert_eq!(a * 2, 2.hours());
+        assert_eq!(a * 0.5, *b);
+        assert_eq!(a / 2, *b);
+        assert_eq!(a / 2.0, *b);
+        assert_eq!(2 * a, 2.hours());
+        assert_eq!(0.5 * a, *b);
+        assert_eq!(-a, -(1.hours()));
+        let mut d = 1.hours();
+        d += b;
+        d -= &(1.minutes());
+        assert_eq!(d, 89.minutes());
+    }
 }
@@ -1,8 +1,14 @@
-use crate::duration::{Duration, Unit};
+use crate::duration::{Duration, Unit, NANOSECONDS_PER_DAY};
+use crate::leap_seconds::{delta_at, whole_seconds, with_active_leap_seconds};
+use crate::math::{div_rem_f64, div_rem_i128, floor, rem_euclid_f64, sin};
 use crate::{
-    Errors, TimeSystem, DAYS_GPS_TAI_OFFSET, ET_EPOCH_S, J1900_OFFSET, J2000_OFFSET, MJD_OFFSET,
-    SECONDS_GPS_TAI_OFFSET, SECONDS_GPS_TAI_OFFSET_I64, SECONDS_PER_DAY, UNIX_REF_EPOCH,
+    EpochRef, Errors, TimeSystem, DAYS_GPS_TAI_OFFSET, DAYS_GST_TAI_OFFSET, ET_EPOCH_S,
+    J1900_OFFSET_DURATION, J1950_REF_EPOCH, J2000_OFFSET_DURATION, JDE_OFFSET_DURATION,
+    MJD_OFFSET_DURATION, SECONDS_GPS_TAI_OFFSET, SECONDS_GPS_TAI_OFFSET_I64,
+    SECONDS_GST_TAI_OFFSET, SECONDS_GST_TAI_OFFSET_I64, SECONDS_LORAN_TAI_OFFSET_I64,
+    SECONDS_PER_DAY, TAI58_REF_EPOCH, UNIX_REF_EPOCH,
 };
+use core::convert::TryFrom;
 use core::fmt;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 
@@ -18,56 +24,94 @@ use std::str::FromStr;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
-const TT_OFFSET_MS: i64 = 32_184;
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+
+pub(crate) const TT_OFFSET_MS: i64 = 32_184;
 const ET_OFFSET_US: i64 = 32_184_935;
+/// GPS time is a constant 19 seconds behind TAI
+pub(crate) const TAI_GPST_OFFSET_S: i64 = 19;
+/// LORAN time is a constant 10 seconds behind TAI
+const LORAN_TAI_OFFSET_S: i64 = 10;
+/// GLONASS time is UTC(SU), i.e. Moscow time, a constant 3 hours ahead of UTC
+const GLONASST_UTC_OFFSET_S: i64 = 3 * 3_600;
+/// 1996 January 01 at midnight in GLONASS time, i.e. the start of the first four year interval
+/// counted by GLONASS, in UTC seconds past 1900 January 01 at midnight
+const GLONASST_REF_EPOCH_UTC_S: i64 = 35_063 * 86_400 - GLONASST_UTC_OFFSET_S;
+/// L_G, the rate of TCG with respect to TT defined by the IAU 2000 Resolution B1.9, times 1e19
+const TCG_TT_RATE_E19: i128 = 6_969_290_134;
+/// 1977 January 01 at midnight TAI, when TCG and TT coincide, in nanoseconds past the TAI epoch in TT
+const TCG_TT_T0_NS: i128 = (28_124 * 86_400 * 1_000_000_000) + TT_OFFSET_MS as i128 * 1_000_000;
+/// L_B, the rate of TCB with respect to TDB defined by the IAU 2006 Resolution B3, times 1e19
+const TCB_TDB_RATE_E19: i128 = 155_051_976_800;
+/// TDB_0, the offset of TDB with respect to TCB defined by the IAU 2006 Resolution B3, in nanoseconds
+const TCB_TDB_0_NS: i128 = -65_500;
+/// J2000 (2000 January 01 at noon) in days since 1900 January 01 at midnight
+const J2000_UT1_DAYS_J1900: f64 = 36_524.5;
 
 /// From https://www.ietf.org/timezones/data/leap-seconds.list .
-const LEAP_SECONDS: [f64; 28] = [
-    2_272_060_800.0, //	10	# 1 Jan 1972
-    2_287_785_600.0, //	11	# 1 Jul 1972
-    2_303_683_200.0, //	12	# 1 Jan 1973
-    2_335_219_200.0, //	13	# 1 Jan 1974
-    2_366_755_200.0, //	14	# 1 Jan 1975
-    2_398_291_200.0, //	15	# 1 Jan 1976
-    2_429_913_600.0, //	16	# 1 Jan 1977
-    2_461_449_600.0, //	17	# 1 Jan 1978
-    2_492_985_600.0, //	18	# 1 Jan 1979
-    2_524_521_600.0, //	19	# 1 Jan 1980
-    2_571_782_400.0, //	20	# 1 Jul 1981
-    2_603_318_400.0, //	21	# 1 Jul 1982
-    2_634_854_400.0, //	22	# 1 Jul 1983
-    2_698_012_800.0, //	23	# 1 Jul 1985
-    2_776_982_400.0, //	24	# 1 Jan 1988
-    2_840_140_800.0, //	25	# 1 Jan 1990
-    2_871_676_800.0, //	26	# 1 Jan 1991
-    2_918_937_600.0, //	27	# 1 Jul 1992
-    2_950_473_600.0, //	28	# 1 Jul 1993
-    2_982_009_600.0, //	29	# 1 Jul 1994
-    3_029_443_200.0, //	30	# 1 Jan 1996
-    3_076_704_000.0, //	31	# 1 Jul 1997
-    3_124_137_600.0, //	32	# 1 Jan 1999
-    3_345_062_400.0, //	33	# 1 Jan 2006
-    3_439_756_800.0, //	34	# 1 Jan 2009
-    3_550_089_600.0, //	35	# 1 Jul 2012
-    3_644_697_600.0, //	36	# 1 Jul 2015
-    3_692_217_600.0, //	37	# 1 Jan 2017
+pub(crate) const LEAP_SECONDS: [i64; 28] = [
+    2_272_060_800, //	10	# 1 Jan 1972
+    2_287_785_600, //	11	# 1 Jul 1972
+    2_303_683_200, //	12	# 1 Jan 1973
+    2_335_219_200, //	13	# 1 Jan 1974
+    2_366_755_200, //	14	# 1 Jan 1975
+    2_398_291_200, //	15	# 1 Jan 1976
+    2_429_913_600, //	16	# 1 Jan 1977
+    2_461_449_600, //	17	# 1 Jan 1978
+    2_492_985_600, //	18	# 1 Jan 1979
+    2_524_521_600, //	19	# 1 Jan 1980
+    2_571_782_400, //	20	# 1 Jul 1981
+    2_603_318_400, //	21	# 1 Jul 1982
+    2_634_854_400, //	22	# 1 Jul 1983
+    2_698_012_800, //	23	# 1 Jul 1985
+    2_776_982_400, //	24	# 1 Jan 1988
+    2_840_140_800, //	25	# 1 Jan 1990
+    2_871_676_800, //	26	# 1 Jan 1991
+    2_918_937_600, //	27	# 1 Jul 1992
+    2_950_473_600, //	28	# 1 Jul 1993
+    2_982_009_600, //	29	# 1 Jul 1994
+    3_029_443_200, //	30	# 1 Jan 1996
+    3_076_704_000, //	31	# 1 Jul 1997
+    3_124_137_600, //	32	# 1 Jan 1999
+    3_345_062_400, //	33	# 1 Jan 2006
+    3_439_756_800, //	34	# 1 Jan 2009
+    3_550_089_600, //	35	# 1 Jul 2012
+    3_644_697_600, //	36	# 1 Jul 2015
+    3_692_217_600, //	37	# 1 Jan 2017
 ];
 
-const JANUARY_YEARS: [i32; 17] = [
+/// Expiration date of the leap second table above, in UTC seconds since 1900 (28 June 2026), as
+/// published in the `leap-seconds.list` following IERS Bulletin C 70.
+const LEAP_SECONDS_EXPIRY_S: i64 = 3_991_593_600;
+
+pub(crate) const JANUARY_YEARS: [i32; 17] = [
     1972, 1973, 1974, 1975, 1976, 1977, 1978, 1979, 1980, 1988, 1990, 1991, 1996, 1999, 2006, 2009,
     2017,
 ];
 
-const JULY_YEARS: [i32; 11] = [
+pub(crate) const JULY_YEARS: [i32; 11] = [
     1972, 1981, 1982, 1983, 1985, 1992, 1993, 1994, 1997, 2012, 2015,
 ];
 
-const USUAL_DAYS_PER_MONTH: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+/// Number of days in each month of a common year (index 0) and of a leap year (index 1).
+pub const DAYS_PER_MONTH: [[u8; 12]; 2] = [
+    [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+    [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+];
+
+/// Number of days before the first day of each month in a common year (index 0) and in a leap year
+/// (index 1), followed by the number of days in that year. The day of the year (starting at 1) of a
+/// date is `CUMULATIVE_DAYS_FOR_MONTH[leap][month - 1] + day`.
+pub const CUMULATIVE_DAYS_FOR_MONTH: [[u16; 13]; 2] = [
+    [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334, 365],
+    [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335, 366],
+];
 
 /// Defines an Epoch in TAI (temps atomique international) in seconds past 1900 January 01 at midnight (like the Network Time Protocol).
 ///
 /// Refer to the appropriate functions for initializing this Epoch from different time systems or representations.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Epoch(Duration);
 
 impl Sub for Epoch {
@@ -102,6 +146,37 @@ impl Add<f64> for Epoch {
     }
 }
 
+impl AddAssign<f64> for Epoch {
+    /// WARNING: Adding seconds directly to an Epoch is _discouraged_, cf. `Add<f64>`.
+    fn add_assign(&mut self, seconds: f64) {
+        *self = *self + seconds;
+    }
+}
+
+impl Sub<f64> for Epoch {
+    type Output = Self;
+
+    /// WARNING: Subtracting seconds directly from an Epoch is _discouraged_, cf. `Add<f64>`.
+    fn sub(self, seconds: f64) -> Self {
+        self + (-seconds)
+    }
+}
+
+impl SubAssign<f64> for Epoch {
+    /// WARNING: Subtracting seconds directly from an Epoch is _discouraged_, cf. `Add<f64>`.
+    fn sub_assign(&mut self, seconds: f64) {
+        *self = *self - seconds;
+    }
+}
+
+impl Add<Epoch> for f64 {
+    type Output = Epoch;
+
+    fn add(self, epoch: Epoch) -> Epoch {
+        epoch + self
+    }
+}
+
 impl Add<Duration> for Epoch {
     type Output = Self;
 
@@ -110,6 +185,22 @@ impl Add<Duration> for Epoch {
     }
 }
 
+impl Add<Epoch> for Duration {
+    type Output = Epoch;
+
+    fn add(self, epoch: Epoch) -> Epoch {
+        epoch + self
+    }
+}
+
+impl Add<Epoch> for Unit {
+    type Output = Epoch;
+
+    fn add(self, epoch: Epoch) -> Epoch {
+        epoch + self
+    }
+}
+
 impl AddAssign<Unit> for Epoch {
     #[allow(clippy::identity_op)]
     fn add_assign(&mut self, unit: Unit) {
@@ -148,23 +239,23 @@ impl AddAssign<Duration> for Epoch {
     }
 }
 
+forward_ref_binop! { impl Sub, sub for Epoch, Epoch }
+forward_ref_binop! { impl Add, add for Epoch, Duration }
+forward_ref_binop! { impl Sub, sub for Epoch, Duration }
+forward_ref_binop! { impl Add, add for Epoch, Unit }
+forward_ref_binop! { impl Sub, sub for Epoch, Unit }
+forward_ref_binop! { impl Add, add for Epoch, f64 }
+forward_ref_binop! { impl Sub, sub for Epoch, f64 }
+forward_ref_binop! { impl Add, add for Duration, Epoch }
+forward_ref_op_assign! { impl AddAssign, add_assign for Epoch, Duration }
+forward_ref_op_assign! { impl SubAssign, sub_assign for Epoch, Duration }
+
 impl Epoch {
     #[must_use]
-    /// Get the accumulated number of leap seconds up to this Epoch.
+    /// Get the accumulated number of leap seconds up to this Epoch, from the active leap second
+    /// provider, cf. [`set_leap_second_provider`](crate::set_leap_second_provider).
     pub fn get_num_leap_seconds(&self) -> i32 {
-        let mut cnt = 0;
-        for tai_ts in LEAP_SECONDS.iter() {
-            if self.0.in_seconds() >= *tai_ts {
-                if cnt == 0 {
-                    cnt = 10;
-                } else {
-                    cnt += 1;
-                }
-            } else {
-                break; // No more leap seconds to process
-            }
-        }
-        cnt
+        with_active_leap_seconds(|entries| delta_at(entries, self.0))
     }
 
     #[must_use]
@@ -205,6 +296,7 @@ impl Epoch {
         let mut e = Self::from_tai_seconds(seconds);
         // Compute the TAI to UTC offset at this time.
         let cnt = e.get_num_leap_seconds();
+        trace_conversion!(utc_seconds = seconds, leap_seconds = cnt, "UTC to TAI");
         // We have the time in TAI. But we were given UTC.
         // Hence, we need to _add_ the leap seconds to get the actual TAI time.
         // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
@@ -218,6 +310,7 @@ impl Epoch {
         let mut e = Self::from_tai_days(days);
         // Compute the TAI to UTC offset at this time.
         let cnt = e.get_num_leap_seconds();
+        trace_conversion!(utc_days = days, leap_seconds = cnt, "UTC to TAI");
         // We have the time in TAI. But we were given UTC.
         // Hence, we need to _add_ the leap seconds to get the actual TAI time.
         // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
@@ -231,14 +324,16 @@ impl Epoch {
             days.is_finite(),
             "Attempted to initialize Epoch with non finite number"
         );
-        Self((days - J1900_OFFSET) * Unit::Day)
+        Self(days * Unit::Day - J1900_OFFSET_DURATION)
     }
 
     #[must_use]
     pub fn from_mjd_utc(days: f64) -> Self {
         let mut e = Self::from_mjd_tai(days);
+        let cnt = e.get_num_leap_seconds();
+        trace_conversion!(mjd_utc_days = days, leap_seconds = cnt, "UTC to TAI");
         // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-        e.0 += i64::from(e.get_num_leap_seconds()) * Unit::Second;
+        e.0 += i64::from(cnt) * Unit::Second;
         e
     }
 
@@ -248,17 +343,55 @@ impl Epoch {
             days.is_finite(),
             "Attempted to initialize Epoch with non finite number"
         );
-        Self((days - J1900_OFFSET - MJD_OFFSET) * Unit::Day)
+        Self(days * Unit::Day - JDE_OFFSET_DURATION)
     }
 
     #[must_use]
     pub fn from_jde_utc(days: f64) -> Self {
         let mut e = Self::from_jde_tai(days);
+        let cnt = e.get_num_leap_seconds();
+        trace_conversion!(jde_utc_days = days, leap_seconds = cnt, "UTC to TAI");
         // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-        e.0 += i64::from(e.get_num_leap_seconds()) * Unit::Second;
+        e.0 += i64::from(cnt) * Unit::Second;
         e
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided Modified Julian Date in Terrestrial Time (TT)
+    pub fn from_mjd_tt(days: f64) -> Self {
+        Self::from_mjd_tai(days) - Unit::Millisecond * TT_OFFSET_MS
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Julian Date in Terrestrial Time (TT)
+    pub fn from_jde_tt(days: f64) -> Self {
+        Self::from_jde_tai(days) - Unit::Millisecond * TT_OFFSET_MS
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Modified Julian Date in GPS Time (GPST), i.e. TAI - 19 seconds
+    pub fn from_mjd_gpst(days: f64) -> Self {
+        Self::from_mjd_tai(days) + Unit::Second * TAI_GPST_OFFSET_S
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Julian Date in GPS Time (GPST), i.e. TAI - 19 seconds
+    pub fn from_jde_gpst(days: f64) -> Self {
+        Self::from_jde_tai(days) + Unit::Second * TAI_GPST_OFFSET_S
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Modified Julian Date in Galileo System Time (GST), i.e. TAI - 19 seconds
+    pub fn from_mjd_gst(days: f64) -> Self {
+        Self::from_mjd_tai(days) + Unit::Second * TAI_GPST_OFFSET_S
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Julian Date in Galileo System Time (GST), i.e. TAI - 19 seconds
+    pub fn from_jde_gst(days: f64) -> Self {
+        Self::from_jde_tai(days) + Unit::Second * TAI_GPST_OFFSET_S
+    }
+
     #[must_use]
     /// Initialize an Epoch from the provided TT seconds (approximated to 32.184s delta from TAI)
     pub fn from_tt_seconds(seconds: f64) -> Self {
@@ -266,9 +399,85 @@ impl Epoch {
             seconds.is_finite(),
             "Attempted to initialize Epoch with non finite number"
         );
+        trace_conversion!(
+            tt_seconds = seconds,
+            tt_offset_ms = TT_OFFSET_MS,
+            "TT to TAI"
+        );
         Self::from_tai_seconds(seconds) - Unit::Millisecond * TT_OFFSET_MS
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided duration past TAI epoch in Geocentric Coordinate Time
+    /// (TCG), which runs faster than TT by the defining rate L_G since 1977 January 01 at midnight TAI,
+    /// i.e. `TT = TCG - L_G × (TCG - T0)`. The conversion is computed in integer nanoseconds.
+    pub fn from_tcg_duration(duration: Duration) -> Self {
+        let since_t0 = duration.total_nanoseconds() - TCG_TT_T0_NS;
+        let tt = duration
+            - Duration::from_total_nanoseconds(since_t0 * TCG_TT_RATE_E19 / 10_i128.pow(19));
+        Self(tt - Unit::Millisecond * TT_OFFSET_MS)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided seconds past TAI epoch in Geocentric Coordinate Time (TCG)
+    pub fn from_tcg_seconds(seconds: f64) -> Self {
+        assert!(
+            seconds.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcg_duration(seconds * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Modified Julian Date in Geocentric Coordinate Time (TCG)
+    pub fn from_mjd_tcg(days: f64) -> Self {
+        Self::from_tcg_duration(Self::from_mjd_tai(days).0)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Julian Date in Geocentric Coordinate Time (TCG)
+    pub fn from_jde_tcg(days: f64) -> Self {
+        Self::from_tcg_duration(Self::from_jde_tai(days).0)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided duration past TAI epoch in Barycentric Coordinate Time
+    /// (TCB), which relates to TDB by the defining rate L_B and offset TDB_0 since 1977 January 01 at
+    /// midnight TAI, i.e. `TDB = TCB - L_B × (TCB - T0) + TDB_0`.
+    pub fn from_tcb_duration(duration: Duration) -> Self {
+        let since_t0 = duration.total_nanoseconds() - TCG_TT_T0_NS;
+        let tdb = duration
+            - Duration::from_total_nanoseconds(
+                since_t0 * TCB_TDB_RATE_E19 / 10_i128.pow(19) - TCB_TDB_0_NS,
+            );
+        // The periodic terms of TDB - TT vary slowly enough to be evaluated at TT = TDB
+        let approx = Self(tdb - Unit::Millisecond * TT_OFFSET_MS);
+        let tdb_delta = (0.001_658 * sin(approx.inner_g_rad())) * Unit::Second;
+        Self(tdb - tdb_delta - Unit::Millisecond * TT_OFFSET_MS)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided seconds past TAI epoch in Barycentric Coordinate Time (TCB)
+    pub fn from_tcb_seconds(seconds: f64) -> Self {
+        assert!(
+            seconds.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcb_duration(seconds * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Modified Julian Date in Barycentric Coordinate Time (TCB)
+    pub fn from_mjd_tcb(days: f64) -> Self {
+        Self::from_tcb_duration(Self::from_mjd_tai(days).0)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Julian Date in Barycentric Coordinate Time (TCB)
+    pub fn from_jde_tcb(days: f64) -> Self {
+        Self::from_tcb_duration(Self::from_jde_tai(days).0)
+    }
+
     #[must_use]
     /// Initialized from the Ephemeris Time seconds
     pub fn from_et_seconds(seconds: f64) -> Epoch {
@@ -276,6 +485,12 @@ impl Epoch {
             seconds.is_finite(),
             "Attempted to initialize Epoch with non finite number"
         );
+        trace_conversion!(
+            et_seconds = seconds,
+            et_offset_us = ET_OFFSET_US,
+            et_epoch_s = ET_EPOCH_S,
+            "ET to TAI"
+        );
         Self::from_tai_seconds(seconds) + Unit::Second * ET_EPOCH_S
             - Unit::Microsecond * (ET_OFFSET_US)
     }
@@ -301,9 +516,49 @@ impl Epoch {
         let g_rad = (PI / 180.0) * (357.528 + 35_999.050 * tt_centuries_j2k);
 
         // Decimal does not provide trig functions, so let's define the parts of the trig separately.
-        let inner = g_rad + 0.0167 * g_rad.sin();
+        let inner = g_rad + 0.0167 * sin(g_rad);
+        trace_conversion!(
+            tdb = ?duration,
+            tt_offset_ms = TT_OFFSET_MS,
+            tdb_correction_s = 0.001_658 * sin(inner),
+            "TDB to TAI"
+        );
+
+        Self(tt_duration + ((ET_EPOCH_S as f64) - (0.001_658 * sin(inner))) * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize from the Ephemeris Time duration past J2000, cf. [`Epoch::as_et_duration`].
+    pub fn from_et_duration(duration: Duration) -> Self {
+        trace_conversion!(
+            et = ?duration,
+            et_offset_us = ET_OFFSET_US,
+            et_epoch_s = ET_EPOCH_S,
+            "ET to TAI"
+        );
+        Self(duration + Unit::Second * ET_EPOCH_S - Unit::Microsecond * ET_OFFSET_US)
+    }
 
-        Self(tt_duration + ((ET_EPOCH_S as f64) - (0.001_658 * inner.sin())) * Unit::Second)
+    #[must_use]
+    /// Initialize from the Dynamic Barycentric Time (TDB) duration past J2000, cf.
+    /// [`Epoch::as_tdb_duration`].
+    pub fn from_tdb_duration(duration: Duration) -> Self {
+        use core::f64::consts::PI;
+        // The periodic term is evaluated at the TDB instead of the TT, which are within two
+        // milliseconds of each other: this changes the term by less than a picosecond.
+        let g_rad = (PI / 180.0) * (357.528 + 35_999.050 * duration.in_unit(Unit::Century));
+        let inner = g_rad + 0.0167 * sin(g_rad);
+        trace_conversion!(
+            tdb = ?duration,
+            tt_offset_ms = TT_OFFSET_MS,
+            tdb_correction_s = 0.001_658 * sin(inner),
+            "TDB to TAI"
+        );
+        Self(
+            duration + Unit::Second * ET_EPOCH_S
+                - Unit::Millisecond * TT_OFFSET_MS
+                - (0.001_658 * sin(inner)) * Unit::Second,
+        )
     }
 
     #[must_use]
@@ -326,6 +581,27 @@ impl Epoch {
         Self::from_jde_tai(days) - Unit::Microsecond * ET_OFFSET_US
     }
 
+    #[must_use]
+    /// Initialize from Dynamic Barycentric Time (TDB) (same as SPICE ephemeris time) in MJD days,
+    /// cf. [`Epoch::as_mjd_tdb_days`].
+    pub fn from_mjd_tdb(days: f64) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tdb_duration(days * Unit::Day - J2000_OFFSET_DURATION)
+    }
+
+    #[must_use]
+    /// Initialize from the Ephemeris Time in MJD days, cf. [`Epoch::as_mjd_et_days`].
+    pub fn from_mjd_et(days: f64) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_et_duration(days * Unit::Day - J2000_OFFSET_DURATION)
+    }
+
     #[must_use]
     /// Initialize an Epoch from the number of seconds since the GPS Time Epoch,
     /// defined as UTC midnight of January 5th to 6th 1980 (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#GPS_Time_.28GPST.29>).
@@ -351,18 +627,142 @@ impl Epoch {
         }) + Unit::Second * SECONDS_GPS_TAI_OFFSET
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the number of seconds since the Galileo System Time Epoch,
+    /// defined as midnight of August 21st to 22nd 1999 in GST, i.e. GPS week 1024 (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#Galileo_System_Time_.28GST.29>).
+    /// Since GST is aligned with GPS time, its epoch is 13 leap seconds before midnight UTC.
+    pub fn from_gst_seconds(seconds: f64) -> Self {
+        Self::from_tai_seconds(seconds) + Unit::Second * SECONDS_GST_TAI_OFFSET
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of days since the Galileo System Time Epoch,
+    /// defined as midnight of August 21st to 22nd 1999 in GST, i.e. GPS week 1024 (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#Galileo_System_Time_.28GST.29>).
+    pub fn from_gst_days(days: f64) -> Self {
+        Self::from_tai_days(days) + Unit::Day * DAYS_GST_TAI_OFFSET
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of nanoseconds since the Galileo System Time Epoch,
+    /// defined as midnight of August 21st to 22nd 1999 in GST, i.e. GPS week 1024 (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#Galileo_System_Time_.28GST.29>).
+    pub fn from_gst_nanoseconds(nanoseconds: u64) -> Self {
+        Self(Duration {
+            centuries: 0,
+            nanoseconds,
+        }) + Unit::Second * SECONDS_GST_TAI_OFFSET_I64
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the duration since the GLONASS time (GLONASST) epoch, defined as
+    /// midnight of December 31st 1995 to January 1st 1996 in GLONASST (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#GLONASS_Time_.28GLONASST.29>).
+    /// GLONASST is UTC(SU) plus three hours: unlike the other GNSS times, it includes the leap
+    /// seconds of UTC, so this duration does not count them.
+    pub fn from_glonasst_duration(duration: Duration) -> Self {
+        let utc = duration + Unit::Second * GLONASST_REF_EPOCH_UTC_S;
+        Self(utc + tai_utc_offset_of_utc(utc))
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of seconds since the GLONASS time (GLONASST) epoch, cf.
+    /// [`from_glonasst_duration`](Self::from_glonasst_duration).
+    pub fn from_glonasst_seconds(seconds: f64) -> Self {
+        assert!(
+            seconds.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_glonasst_duration(seconds * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of days since the GLONASS time (GLONASST) epoch, cf.
+    /// [`from_glonasst_duration`](Self::from_glonasst_duration).
+    pub fn from_glonasst_days(days: f64) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_glonasst_duration(days * Unit::Day)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of nanoseconds since the GLONASS time (GLONASST) epoch,
+    /// cf. [`from_glonasst_duration`](Self::from_glonasst_duration).
+    pub fn from_glonasst_nanoseconds(nanoseconds: u64) -> Self {
+        Self::from_glonasst_duration(Duration {
+            centuries: 0,
+            nanoseconds,
+        })
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of seconds since the original TAI epoch of 1958 January 01
+    /// at midnight TAI, as counted by many radar and heritage systems, cf. [`TAI58_REF_EPOCH`].
+    pub fn from_tai58_seconds(seconds: f64) -> Self {
+        TAI58_REF_EPOCH + seconds * Unit::Second
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the duration since the original TAI epoch of 1958 January 01 at midnight TAI.
+    pub fn from_tai58_duration(duration: Duration) -> Self {
+        TAI58_REF_EPOCH + duration
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the duration past J1950 TT, cf. [`J1950_REF_EPOCH`].
+    pub fn from_tt_since_j1950(duration: Duration) -> Self {
+        J1950_REF_EPOCH + duration
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of seconds since the LORAN Epoch, defined as midnight
+    /// of January 1st 1958 in LORAN time, which is TAI - 10 seconds without leap seconds.
+    pub fn from_loran_seconds(seconds: f64) -> Self {
+        Self::from_loran_duration(seconds * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the duration since the LORAN Epoch, e.g. the time of transmission
+    /// of an eLoran signal, cf. [`Epoch::from_loran_seconds`].
+    pub fn from_loran_duration(duration: Duration) -> Self {
+        Self::from_tai_duration(duration) + Unit::Second * SECONDS_LORAN_TAI_OFFSET_I64
+    }
+
     #[must_use]
     /// Initialize an Epoch from the provided UNIX second timestamp since UTC midnight 1970 January 01.
     pub fn from_unix_seconds(seconds: f64) -> Self {
-        let utc_seconds = UNIX_REF_EPOCH.as_utc_duration() + seconds * Unit::Second;
-        Self::from_utc_seconds(utc_seconds.in_unit(Unit::Second))
+        Self::from_unix_duration(seconds * Unit::Second)
     }
 
     #[must_use]
     /// Initialize an Epoch from the provided UNIX milisecond timestamp since UTC midnight 1970 January 01.
     pub fn from_unix_milliseconds(millisecond: f64) -> Self {
-        let utc_seconds = UNIX_REF_EPOCH.as_utc_duration() + millisecond * Unit::Millisecond;
-        Self::from_utc_seconds(utc_seconds.in_unit(Unit::Second))
+        Self::from_unix_duration(millisecond * Unit::Millisecond)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided whole UNIX seconds and nanoseconds since UTC midnight
+    /// 1970 January 01, exactly, e.g. from the `tv_sec` and `tv_nsec` fields of a `libc::timespec`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// assert_eq!(
+    ///     Epoch::from_unix_timespec(1_653_069_463, 123_456_789),
+    ///     Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43) + 123_456_789.nanoseconds()
+    /// );
+    /// ```
+    pub fn from_unix_timespec(seconds: i64, nanoseconds: u32) -> Self {
+        Self::from_unix_duration(Unit::Second * seconds + Unit::Nanosecond * i64::from(nanoseconds))
+    }
+
+    /// Initialize an Epoch from the provided duration since the UNIX epoch, exactly.
+    pub(crate) fn from_unix_duration(duration: Duration) -> Self {
+        let mut e = Self(UNIX_REF_EPOCH.as_utc_duration() + duration);
+        // TAI = UTC + leap_seconds, cf. `from_utc_seconds`
+        let cnt = e.get_num_leap_seconds();
+        e.0 += i64::from(cnt) * Unit::Second;
+        e
     }
 
     /// Attempts to build an Epoch from the provided Gregorian date and time in TAI.
@@ -388,6 +788,20 @@ impl Epoch {
     }
 
     /// Attempts to build an Epoch from the provided Gregorian date and time in the provided time system.
+    ///
+    /// As in ISO 8601, `24:00:00` is the end of the day, i.e. midnight of the next day. Returns
+    /// `Errors::Carry` for an invalid date or time, and `Errors::Overflow` for a date beyond the
+    /// range of an epoch.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Errors, TimeSystem};
+    ///
+    /// let end_of_day = Epoch::maybe_from_gregorian(2022, 12, 31, 24, 0, 0, 0, TimeSystem::UTC);
+    /// assert_eq!(end_of_day, Ok(Epoch::from_gregorian_utc_at_midnight(2023, 1, 1)));
+    /// let invalid = Epoch::maybe_from_gregorian(2022, 12, 31, 24, 0, 1, 0, TimeSystem::UTC);
+    /// assert_eq!(invalid, Err(Errors::Carry));
+    /// ```
     #[allow(clippy::too_many_arguments)]
     pub fn maybe_from_gregorian(
         year: i32,
@@ -403,42 +817,138 @@ impl Epoch {
             return Err(Errors::Carry);
         }
 
-        let mut seconds_wrt_1900 = Unit::Day * i64::from(365 * (year - 1900).abs());
-        // Now add the seconds for all the years prior to the current year
-        for year in 1900..year {
-            if is_leap_year(year) {
-                seconds_wrt_1900 += Unit::Day;
-            }
-        }
-        // Add the seconds for the months prior to the current month
-        for month in 0..month - 1 {
-            seconds_wrt_1900 += Unit::Day * i64::from(USUAL_DAYS_PER_MONTH[(month) as usize]);
-        }
-        if is_leap_year(year) && month > 2 {
-            // NOTE: If on 29th of February, then the day is not finished yet, and therefore
-            // the extra seconds are added below as per a normal day.
-            seconds_wrt_1900 += Unit::Day;
-        }
-        seconds_wrt_1900 += Unit::Day * i64::from(day - 1)
-            + Unit::Hour * i64::from(hour)
-            + Unit::Minute * i64::from(minute)
-            + Unit::Second * i64::from(second)
-            + Unit::Nanosecond * i64::from(nanos);
+        // Count in 128 bits: the nanoseconds of a few centuries already overflow 64 bits
+        let mut nanoseconds_wrt_1900 = i128::from(days_from_gregorian(year, month, day))
+            * i128::from(NANOSECONDS_PER_DAY)
+            + ((i128::from(hour) * 60 + i128::from(minute)) * 60 + i128::from(second))
+                * 1_000_000_000
+            + i128::from(nanos);
         if second == 60 {
             // Herein lies the whole ambiguity of leap seconds. Two different UTC dates exist at the
             // same number of second afters J1900.0.
-            seconds_wrt_1900 -= Unit::Second;
+            nanoseconds_wrt_1900 -= 1_000_000_000;
         }
 
+        let duration = Duration::try_from_total_nanoseconds(nanoseconds_wrt_1900)?;
         Ok(match ts {
+            // As in `as_gregorian`, the Gregorian fields of ET and TDB count their duration past
+            // J2000 from 1900 January 01.
+            TimeSystem::ET => Self::from_et_duration(duration),
+            TimeSystem::TDB => Self::from_tdb_seconds_d(duration),
+            _ => Self::from_duration_in(duration, ts),
+        })
+    }
+
+    /// Builds an Epoch from Gregorian date and time fields in the provided time system, carrying
+    /// the fields which overflow into the next larger one instead of rejecting them, e.g. when
+    /// building epochs from accumulated components. Any field may be negative or beyond its usual
+    /// range: months past December carry into the year, day 0 is the last day of the previous
+    /// month, and 75 seconds are one minute and 15 seconds.
+    ///
+    /// Minutes are carried as 60 seconds, so a leap second may not be expressed as the 60th second
+    /// of a minute: 23:59:60 is midnight of the next day.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    ///
+    /// let e = Epoch::from_gregorian_carrying(2022, 5, 20, 17, 56, 103, 0, TimeSystem::UTC);
+    /// assert_eq!(e, Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43));
+    /// let e = Epoch::from_gregorian_carrying(2022, 13, 0, 24, 0, 0, 1_500_000_000, TimeSystem::TAI);
+    /// assert_eq!(e, Epoch::from_gregorian_tai(2023, 1, 1, 0, 0, 1, 500_000_000));
+    /// ```
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_gregorian_carrying(
+        year: i32,
+        month: i32,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+        nanos: i64,
+        ts: TimeSystem,
+    ) -> Self {
+        // Months carry into the year, and the other fields are counted from the first of that month
+        let year = year + (month - 1).div_euclid(12);
+        let month = (month - 1).rem_euclid(12) as u8 + 1;
+        let days = i128::from(days_from_gregorian(year, month, 1)) + i128::from(day) - 1;
+        let nanoseconds = days * i128::from(NANOSECONDS_PER_DAY)
+            + ((i128::from(hour) * 60 + i128::from(minute)) * 60 + i128::from(second))
+                * 1_000_000_000
+            + i128::from(nanos);
+        Self::from_duration_in(Duration::from_total_nanoseconds(nanoseconds), ts)
+    }
+
+    /// Builds an Epoch from the duration since 1900 January 01 at midnight in the provided time
+    /// system, cf. `duration_in`.
+    pub(crate) fn from_duration_in(seconds_wrt_1900: Duration, ts: TimeSystem) -> Self {
+        match ts {
             TimeSystem::TAI => Self(seconds_wrt_1900),
             TimeSystem::TT => Self(seconds_wrt_1900 - Unit::Millisecond * TT_OFFSET_MS),
-            TimeSystem::ET => Self(
-                seconds_wrt_1900 + Unit::Second * ET_EPOCH_S - Unit::Microsecond * ET_OFFSET_US,
-            ),
-            TimeSystem::TDB => Self::from_tdb_seconds_d(seconds_wrt_1900),
-            TimeSystem::UTC => panic!("use maybe_from_gregorian_utc for UTC time system"),
-        })
+            TimeSystem::ET => Self::from_et_duration(seconds_wrt_1900 - Unit::Second * ET_EPOCH_S),
+            TimeSystem::TDB => {
+                Self::from_tdb_duration(seconds_wrt_1900 - Unit::Second * ET_EPOCH_S)
+            }
+            TimeSystem::TCG => Self::from_tcg_duration(seconds_wrt_1900),
+            TimeSystem::TCB => Self::from_tcb_duration(seconds_wrt_1900),
+            TimeSystem::LORAN => Self(seconds_wrt_1900 + Unit::Second * LORAN_TAI_OFFSET_S),
+            TimeSystem::UTC => {
+                let mut if_tai = Self(seconds_wrt_1900);
+                // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
+                if_tai.0 += i64::from(if_tai.get_num_leap_seconds()) * Unit::Second;
+                if_tai
+            }
+        }
+    }
+
+    /// Attempts to build an Epoch from the provided date and seconds of day in the provided time
+    /// system, as used by several geodesy formats (e.g. the `YY:DOY:SOD` epochs of SINEX).
+    ///
+    /// The seconds of day are rounded to the nanosecond, and must be less than 86400 (or 86401 on a
+    /// UTC day which ends with a leap second), otherwise `Errors::Carry` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    ///
+    /// let e = Epoch::maybe_from_ymd_sod(2022, 5, 20, 64_663.25, TimeSystem::UTC).unwrap();
+    /// assert_eq!(e, Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 250_000_000));
+    /// assert_eq!(e.as_ymd_sod(TimeSystem::UTC), (2022, 5, 20, 64_663.25));
+    /// ```
+    pub fn maybe_from_ymd_sod(
+        year: i32,
+        month: u8,
+        day: u8,
+        sod: f64,
+        ts: TimeSystem,
+    ) -> Result<Self, Errors> {
+        let leap_day = ts == TimeSystem::UTC && is_gregorian_valid(year, month, day, 23, 59, 60, 0);
+        let day_s = if leap_day { 86_401.0 } else { 86_400.0 };
+        if !is_gregorian_valid(year, month, day, 0, 0, 0, 0) || !(0.0..day_s).contains(&sod) {
+            return Err(Errors::Carry);
+        }
+        let (whole, fraction) = div_rem_f64(sod, 1.0);
+        let sod = i128::from(whole) * 1_000_000_000 + floor(fraction * 1e9 + 0.5) as i128;
+        let mut nanoseconds_wrt_1900 = i128::from(days_from_gregorian(year, month, day))
+            * i128::from(NANOSECONDS_PER_DAY)
+            + sod;
+        if leap_day && sod >= i128::from(NANOSECONDS_PER_DAY) {
+            // As in `maybe_from_gregorian`, a leap second shares its seconds with the next second.
+            nanoseconds_wrt_1900 -= 1_000_000_000;
+        }
+        Ok(Self::from_duration_in(
+            Duration::try_from_total_nanoseconds(nanoseconds_wrt_1900)?,
+            ts,
+        ))
+    }
+
+    #[must_use]
+    /// Builds an Epoch from the provided date and seconds of day in the provided time system. If an
+    /// invalid date or seconds of day is provided, this function will panic.
+    /// Use maybe_from_ymd_sod if unsure.
+    pub fn from_ymd_sod(year: i32, month: u8, day: u8, sod: f64, ts: TimeSystem) -> Self {
+        Self::maybe_from_ymd_sod(year, month, day, sod, ts).expect("invalid date or seconds of day")
     }
 
     #[must_use]
@@ -471,6 +981,47 @@ impl Epoch {
             .expect("invalid Gregorian date")
     }
 
+    #[must_use]
+    /// Initialize from the Gregorian date and time in TAI in a const context, e.g. to declare the
+    /// reference epochs of an application as `const` on targets where `LazyEpoch` is unavailable.
+    /// Leap seconds are not supported: the seconds must be below 60.
+    ///
+    /// # Panics
+    /// If the date or time is invalid, which is a compilation error in a const context.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// const MISSION_START: Epoch = Epoch::from_gregorian_tai_const(2022, 5, 20, 17, 57, 43, 0);
+    /// assert_eq!(MISSION_START, Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43));
+    /// ```
+    pub const fn from_gregorian_tai_const(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Self {
+        if month == 0 || month > 12 || day == 0 || hour > 23 || minute > 59 || second > 59 {
+            panic!("invalid Gregorian date");
+        }
+        if day > days_in_month(year, month) || nanos >= 1_000_000_000 {
+            panic!("invalid Gregorian date");
+        }
+        let seconds_of_day = hour as i128 * 3_600 + minute as i128 * 60 + second as i128;
+        let nanoseconds = days_from_gregorian(year, month, day) as i128
+            * NANOSECONDS_PER_DAY as i128
+            + seconds_of_day * 1_000_000_000
+            + nanos as i128;
+        match Duration::try_from_total_nanoseconds(nanoseconds) {
+            Ok(duration) => Self(duration),
+            Err(_) => panic!("Gregorian date out of range"),
+        }
+    }
+
     #[must_use]
     /// Initialize from the Gregorian date and time (without the nanoseconds) in TAI
     pub fn from_gregorian_tai_hms(
@@ -575,12 +1126,69 @@ impl Epoch {
     }
 
     #[must_use]
-    /// Returns the number of days since J1900 in TAI
-    pub fn as_tai_days(&self) -> f64 {
-        self.as_tai(Unit::Day)
-    }
-
-    #[must_use]
+    /// Returns the centuries and nanoseconds of the duration since 1900 January 01 at midnight in
+    /// the provided time system, i.e. an exact fixed point representation of this epoch in that time
+    /// system, e.g. to store epochs in TT losslessly. All time systems are counted from 1900,
+    /// including ET and TDB whose durations are otherwise counted from J2000. Use
+    /// [`Epoch::to_gpst_parts`] for GPS time.
+    ///
+    /// The epoch is restored with [`Epoch::from_parts_in`], exactly for the time systems which
+    /// differ from TAI by a constant (TAI, TT, ET and LORAN) or by a linear rate (TCG), and within a
+    /// nanosecond for TDB and TCB. In UTC, an inserted leap second shares its parts with the next
+    /// second.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    ///
+    /// let e = Epoch::from_gregorian_tai(2022, 5, 20, 17, 57, 43, 1);
+    /// let (centuries, nanoseconds) = e.to_parts_in(TimeSystem::TT);
+    /// assert_eq!((centuries, nanoseconds), (1, 706_298_295_184_000_001));
+    /// assert_eq!(Epoch::from_parts_in(TimeSystem::TT, centuries, nanoseconds), e);
+    /// ```
+    pub fn to_parts_in(&self, ts: TimeSystem) -> (i16, u64) {
+        self.duration_in(ts).to_parts()
+    }
+
+    #[must_use]
+    /// Creates a new Epoch from the centuries and nanoseconds since 1900 January 01 at midnight in
+    /// the provided time system, cf. [`Epoch::to_parts_in`].
+    pub fn from_parts_in(ts: TimeSystem, centuries: i16, nanoseconds: u64) -> Self {
+        Self::from_duration_in(Duration::from_parts(centuries, nanoseconds), ts)
+    }
+
+    #[must_use]
+    /// Returns the centuries and nanoseconds of the duration past the GPS Time Epoch in GPS time,
+    /// i.e. an exact fixed point representation of this epoch in GPST, cf. [`Epoch::to_parts_in`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// let e = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 1);
+    /// let (centuries, nanoseconds) = e.to_gpst_parts();
+    /// assert_eq!((centuries, nanoseconds), (0, 1_337_104_681_000_000_001));
+    /// assert_eq!(Epoch::from_gpst_parts(centuries, nanoseconds), e);
+    /// ```
+    pub fn to_gpst_parts(&self) -> (i16, u64) {
+        self.as_gpst_duration().to_parts()
+    }
+
+    #[must_use]
+    /// Creates a new Epoch from the centuries and nanoseconds past the GPS Time Epoch in GPS time,
+    /// cf. [`Epoch::to_gpst_parts`].
+    pub fn from_gpst_parts(centuries: i16, nanoseconds: u64) -> Self {
+        Self(Duration::from_parts(centuries, nanoseconds))
+            + Unit::Second * SECONDS_GPS_TAI_OFFSET_I64
+    }
+
+    #[must_use]
+    /// Returns the number of days since J1900 in TAI
+    pub fn as_tai_days(&self) -> f64 {
+        self.as_tai(Unit::Day)
+    }
+
+    #[must_use]
     /// Returns the number of UTC seconds since the TAI epoch
     pub fn as_utc_seconds(&self) -> f64 {
         self.as_utc(Unit::Second)
@@ -590,6 +1198,7 @@ impl Epoch {
     /// Returns this time in a Duration past J1900 counted in UTC
     pub fn as_utc_duration(&self) -> Duration {
         let cnt = self.get_num_leap_seconds();
+        trace_conversion!(tai = ?self.0, leap_seconds = cnt, "TAI to UTC");
         // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
         self.0 + i64::from(-cnt) * Unit::Second
     }
@@ -622,7 +1231,7 @@ impl Epoch {
     #[must_use]
     /// Returns this epoch as a duration in the requested units in MJD TAI
     pub fn as_mjd_tai(&self, unit: Unit) -> f64 {
-        (self.0 + Unit::Day * J1900_OFFSET).in_unit(unit)
+        (self.0 + J1900_OFFSET_DURATION).in_unit(unit)
     }
 
     #[must_use]
@@ -634,7 +1243,7 @@ impl Epoch {
     #[must_use]
     /// Returns the Modified Julian Date in the provided unit in UTC.
     pub fn as_mjd_utc(&self, unit: Unit) -> f64 {
-        (self.as_utc_duration() + Unit::Day * J1900_OFFSET).in_unit(unit)
+        (self.as_utc_duration() + J1900_OFFSET_DURATION).in_unit(unit)
     }
 
     #[must_use]
@@ -658,7 +1267,7 @@ impl Epoch {
 
     #[must_use]
     pub fn as_jde_tai_duration(&self) -> Duration {
-        self.0 + Unit::Day * J1900_OFFSET + Unit::Day * MJD_OFFSET
+        self.0 + JDE_OFFSET_DURATION
     }
 
     #[must_use]
@@ -675,7 +1284,7 @@ impl Epoch {
 
     #[must_use]
     pub fn as_jde_utc_duration(&self) -> Duration {
-        self.as_utc_duration() + Unit::Day * (J1900_OFFSET + MJD_OFFSET)
+        self.as_utc_duration() + JDE_OFFSET_DURATION
     }
 
     #[must_use]
@@ -693,6 +1302,7 @@ impl Epoch {
 
     #[must_use]
     pub fn as_tt_duration(&self) -> Duration {
+        trace_conversion!(tai = ?self.0, tt_offset_ms = TT_OFFSET_MS, "TAI to TT");
         self.0 + Unit::Millisecond * TT_OFFSET_MS
     }
 
@@ -714,6 +1324,18 @@ impl Epoch {
         self.as_tt_duration() - Unit::Second * ET_EPOCH_S
     }
 
+    #[must_use]
+    /// Returns the duration past J1950 TT
+    pub fn as_tt_since_j1950(&self) -> Duration {
+        *self - J1950_REF_EPOCH
+    }
+
+    #[must_use]
+    /// Returns the days past J1950 TT
+    pub fn as_tt_days_since_j1950(&self) -> f64 {
+        self.as_tt_since_j1950().in_unit(Unit::Day)
+    }
+
     #[must_use]
     /// Returns days past Julian epoch in Terrestrial Time (TT) (previously called Terrestrial Dynamical Time (TDT))
     pub fn as_jde_tt_days(&self) -> f64 {
@@ -722,7 +1344,7 @@ impl Epoch {
 
     #[must_use]
     pub fn as_jde_tt_duration(&self) -> Duration {
-        self.as_tt_duration() + Unit::Day * (J1900_OFFSET + MJD_OFFSET)
+        self.as_tt_duration() + JDE_OFFSET_DURATION
     }
 
     #[must_use]
@@ -733,7 +1355,77 @@ impl Epoch {
 
     #[must_use]
     pub fn as_mjd_tt_duration(&self) -> Duration {
-        self.as_tt_duration() + Unit::Day * J1900_OFFSET
+        self.as_tt_duration() + J1900_OFFSET_DURATION
+    }
+
+    #[must_use]
+    /// Returns the duration past TAI epoch in Geocentric Coordinate Time (TCG), i.e. TT plus
+    /// `L_G / (1 - L_G) × (TT - T0)`, computed in integer nanoseconds.
+    pub fn as_tcg_duration(&self) -> Duration {
+        let tt = self.as_tt_duration();
+        let since_t0 = tt.total_nanoseconds() - TCG_TT_T0_NS;
+        tt + Duration::from_total_nanoseconds(
+            since_t0 * TCG_TT_RATE_E19 / (10_i128.pow(19) - TCG_TT_RATE_E19),
+        )
+    }
+
+    #[must_use]
+    /// Returns seconds past TAI epoch in Geocentric Coordinate Time (TCG)
+    pub fn as_tcg_seconds(&self) -> f64 {
+        self.as_tcg_duration().in_seconds()
+    }
+
+    #[must_use]
+    /// Returns days past TAI epoch in Geocentric Coordinate Time (TCG)
+    pub fn as_tcg_days(&self) -> f64 {
+        self.as_tcg_duration().in_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns days past Modified Julian epoch in Geocentric Coordinate Time (TCG)
+    pub fn as_mjd_tcg_days(&self) -> f64 {
+        (self.as_tcg_duration() + J1900_OFFSET_DURATION).in_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns days past Julian epoch in Geocentric Coordinate Time (TCG)
+    pub fn as_jde_tcg_days(&self) -> f64 {
+        (self.as_tcg_duration() + JDE_OFFSET_DURATION).in_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns the duration past TAI epoch in Barycentric Coordinate Time (TCB), i.e. TDB minus TDB_0
+    /// plus `L_B / (1 - L_B) × (TDB - TDB_0 - T0)`, where TDB is that of [`Epoch::as_jde_tdb_duration`].
+    pub fn as_tcb_duration(&self) -> Duration {
+        let tdb = self.as_jde_tdb_duration() - JDE_OFFSET_DURATION;
+        let since_t0 = tdb.total_nanoseconds() - TCB_TDB_0_NS - TCG_TT_T0_NS;
+        tdb + Duration::from_total_nanoseconds(
+            since_t0 * TCB_TDB_RATE_E19 / (10_i128.pow(19) - TCB_TDB_RATE_E19) - TCB_TDB_0_NS,
+        )
+    }
+
+    #[must_use]
+    /// Returns seconds past TAI epoch in Barycentric Coordinate Time (TCB)
+    pub fn as_tcb_seconds(&self) -> f64 {
+        self.as_tcb_duration().in_seconds()
+    }
+
+    #[must_use]
+    /// Returns days past TAI epoch in Barycentric Coordinate Time (TCB)
+    pub fn as_tcb_days(&self) -> f64 {
+        self.as_tcb_duration().in_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns days past Modified Julian epoch in Barycentric Coordinate Time (TCB)
+    pub fn as_mjd_tcb_days(&self) -> f64 {
+        (self.as_tcb_duration() + J1900_OFFSET_DURATION).in_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns days past Julian epoch in Barycentric Coordinate Time (TCB)
+    pub fn as_jde_tcb_days(&self) -> f64 {
+        (self.as_tcb_duration() + JDE_OFFSET_DURATION).in_unit(Unit::Day)
     }
 
     #[must_use]
@@ -764,9 +1456,111 @@ impl Epoch {
         self.as_gpst_duration().in_unit(Unit::Day)
     }
 
+    #[must_use]
+    /// Returns seconds past Galileo System Time Epoch, defined as midnight of August 21st to 22nd 1999 in GST (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#Galileo_System_Time_.28GST.29>).
+    pub fn as_gst_seconds(&self) -> f64 {
+        self.as_gst_duration().in_seconds()
+    }
+
+    #[must_use]
+    pub fn as_gst_duration(&self) -> Duration {
+        self.as_tai_duration() - Unit::Second * SECONDS_GST_TAI_OFFSET_I64
+    }
+
+    /// Returns nanoseconds past Galileo System Time Epoch, defined as midnight of August 21st to 22nd 1999 in GST (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#Galileo_System_Time_.28GST.29>).
+    /// NOTE: This function will return an error if the epoch is before the GST epoch.
+    pub fn as_gst_nanoseconds(&self) -> Result<u64, Errors> {
+        let (centuries, nanoseconds) = self.as_gst_duration().to_parts();
+        if centuries != 0 {
+            Err(Errors::Overflow)
+        } else {
+            Ok(nanoseconds)
+        }
+    }
+
+    #[must_use]
+    /// Returns days past Galileo System Time Epoch, defined as midnight of August 21st to 22nd 1999 in GST (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#Galileo_System_Time_.28GST.29>).
+    pub fn as_gst_days(&self) -> f64 {
+        self.as_gst_duration().in_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns the duration past the GLONASS time (GLONASST) epoch, defined as midnight of December
+    /// 31st 1995 to January 1st 1996 in GLONASST (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#GLONASS_Time_.28GLONASST.29>).
+    /// GLONASST is UTC(SU) plus three hours, so this duration does not count the leap seconds.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// // The GLONASST epoch is three hours before midnight UTC
+    /// let glonasst_epoch = Epoch::from_gregorian_utc(1995, 12, 31, 21, 0, 0, 0);
+    /// assert_eq!(glonasst_epoch.as_glonasst_duration(), 0.seconds());
+    /// // The eight leap seconds since then, the first one three hours later, are not counted
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// assert_eq!(
+    ///     epoch.as_glonasst_duration(),
+    ///     epoch - glonasst_epoch - 8.seconds()
+    /// );
+    /// assert_eq!(Epoch::from_glonasst_duration(epoch.as_glonasst_duration()), epoch);
+    /// ```
+    pub fn as_glonasst_duration(&self) -> Duration {
+        self.0 - tai_utc_offset_at(*self) - Unit::Second * GLONASST_REF_EPOCH_UTC_S
+    }
+
+    #[must_use]
+    /// Returns seconds past the GLONASS time (GLONASST) epoch, cf.
+    /// [`as_glonasst_duration`](Self::as_glonasst_duration).
+    pub fn as_glonasst_seconds(&self) -> f64 {
+        self.as_glonasst_duration().in_seconds()
+    }
+
+    /// Returns nanoseconds past the GLONASS time (GLONASST) epoch, cf.
+    /// [`as_glonasst_duration`](Self::as_glonasst_duration).
+    /// NOTE: This function will return an error if the epoch is before the GLONASST epoch.
+    pub fn as_glonasst_nanoseconds(&self) -> Result<u64, Errors> {
+        let (centuries, nanoseconds) = self.as_glonasst_duration().to_parts();
+        if centuries != 0 {
+            Err(Errors::Overflow)
+        } else {
+            Ok(nanoseconds)
+        }
+    }
+
+    #[must_use]
+    /// Returns days past the GLONASS time (GLONASST) epoch, cf.
+    /// [`as_glonasst_duration`](Self::as_glonasst_duration).
+    pub fn as_glonasst_days(&self) -> f64 {
+        self.as_glonasst_duration().in_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns seconds past the original TAI epoch of 1958 January 01 at midnight TAI.
+    pub fn as_tai58_seconds(&self) -> f64 {
+        self.as_tai58_duration().in_seconds()
+    }
+
+    #[must_use]
+    /// Returns the duration past the original TAI epoch of 1958 January 01 at midnight TAI.
+    pub fn as_tai58_duration(&self) -> Duration {
+        *self - TAI58_REF_EPOCH
+    }
+
+    #[must_use]
+    /// Returns seconds past the LORAN Epoch, defined as midnight of January 1st 1958 in LORAN time.
+    pub fn as_loran_seconds(&self) -> f64 {
+        self.as_loran_duration().in_seconds()
+    }
+
+    #[must_use]
+    /// Returns the duration past the LORAN Epoch, defined as midnight of January 1st 1958 in LORAN time.
+    pub fn as_loran_duration(&self) -> Duration {
+        self.as_tai_duration() - Unit::Second * SECONDS_LORAN_TAI_OFFSET_I64
+    }
+
     #[must_use]
     ///Returns the Duration since the UNIX epoch UTC midnight 01 Jan 1970.
-    fn as_unix_duration(&self) -> Duration {
+    pub(crate) fn as_unix_duration(&self) -> Duration {
         let cnt = self.get_num_leap_seconds();
         // TAI = UNIX + leap_seconds + UNIX_OFFSET_UTC_SECONDS <=> UNIX = TAI - leap_seconds - UNIX_OFFSET_UTC_SECONDS
         self.0 + i64::from(-cnt) * Unit::Second - UNIX_REF_EPOCH.as_utc_duration()
@@ -804,6 +1598,12 @@ impl Epoch {
 
     #[must_use]
     pub fn as_et_duration(&self) -> Duration {
+        trace_conversion!(
+            tai = ?self.0,
+            et_offset_us = ET_OFFSET_US,
+            et_epoch_s = ET_EPOCH_S,
+            "TAI to ET"
+        );
         self.as_tai_duration() + Unit::Microsecond * ET_OFFSET_US - Unit::Second * ET_EPOCH_S
     }
 
@@ -811,9 +1611,14 @@ impl Epoch {
     /// Returns the Dynamics Barycentric Time (TDB) as a high precision Duration
     pub fn as_tdb_duration(&self) -> Duration {
         let inner = self.inner_g_rad();
+        trace_conversion!(
+            tai = ?self.0,
+            tdb_correction_s = 0.001_658 * sin(inner),
+            "TAI to TDB"
+        );
 
         self.as_tt_duration() - (ET_EPOCH_S * Unit::Second)
-            + (0.001_658 * inner.sin()) * Unit::Second
+            + (0.001_658 * sin(inner)) * Unit::Second
     }
 
     #[must_use]
@@ -821,7 +1626,12 @@ impl Epoch {
     pub fn as_tdb_seconds(&self) -> f64 {
         // Note that we redo the calculation of as_tdb_duration to save computational cost
         let inner = self.inner_g_rad();
-        self.as_tt_seconds() - (ET_EPOCH_S as f64) + (0.001_658 * inner.sin())
+        trace_conversion!(
+            tai = ?self.0,
+            tdb_correction_s = 0.001_658 * sin(inner),
+            "TAI to TDB"
+        );
+        self.as_tt_seconds() - (ET_EPOCH_S as f64) + (0.001_658 * sin(inner))
     }
 
     /// For TDB computation, we're using f64 only because BigDecimal is far too slow for Nyx (uses FromStr).
@@ -829,7 +1639,7 @@ impl Epoch {
         use core::f64::consts::PI;
         let g_rad = (PI / 180.0) * (357.528 + 35_999.050 * self.as_tt_centuries_j2k());
 
-        g_rad + 0.0167 * g_rad.sin()
+        g_rad + 0.0167 * sin(g_rad)
     }
 
     #[must_use]
@@ -851,7 +1661,7 @@ impl Epoch {
     #[must_use]
     pub fn as_jde_tdb_duration(&self) -> Duration {
         let inner = self.inner_g_rad();
-        let tdb_delta = (0.001_658 * inner.sin()) * Unit::Second;
+        let tdb_delta = (0.001_658 * sin(inner)) * Unit::Second;
         self.as_jde_tt_duration() + tdb_delta
     }
 
@@ -861,10 +1671,22 @@ impl Epoch {
         self.as_jde_tdb_duration().in_unit(Unit::Day)
     }
 
+    #[must_use]
+    /// Returns the Modified Julian Date in Dynamic Barycentric Time (TDB), cf. [`Epoch::from_mjd_tdb`].
+    pub fn as_mjd_tdb_days(&self) -> f64 {
+        (self.as_tdb_duration() + J2000_OFFSET_DURATION).in_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns the Modified Julian Date in Ephemeris Time, cf. [`Epoch::from_mjd_et`].
+    pub fn as_mjd_et_days(&self) -> f64 {
+        (self.as_et_duration() + J2000_OFFSET_DURATION).in_unit(Unit::Day)
+    }
+
     #[must_use]
     /// Returns the duration since Dynamic Barycentric Time (TDB) J2000 (used for Archinal et al. rotations)
     pub fn as_tdb_duration_since_j2000(&self) -> Duration {
-        self.as_jde_tdb_duration() - MJD_OFFSET * Unit::Day - J2000_OFFSET * Unit::Day
+        self.as_jde_tdb_duration() - MJD_OFFSET_DURATION - J2000_OFFSET_DURATION
     }
 
     #[must_use]
@@ -882,7 +1704,7 @@ impl Epoch {
     #[must_use]
     /// Returns the duration since Ephemeris Time (ET) J2000 (used for Archinal et al. rotations)
     pub fn as_et_duration_since_j2000(&self) -> Duration {
-        self.as_jde_et_duration() - MJD_OFFSET * Unit::Day - J2000_OFFSET * Unit::Day
+        self.as_jde_et_duration() - MJD_OFFSET_DURATION - J2000_OFFSET_DURATION
     }
 
     #[must_use]
@@ -945,73 +1767,93 @@ impl Epoch {
         Self::compute_gregorian(self.as_tai_seconds())
     }
 
-    fn compute_gregorian(absolute_seconds: f64) -> (i32, u8, u8, u8, u8, u8, u32) {
-        let (mut year, mut year_fraction) = div_rem_f64(absolute_seconds, 365.0 * SECONDS_PER_DAY);
-        // TAI is defined at 1900, so a negative time is before 1900 and positive is after 1900.
-        year += 1900;
-        // Base calculation was on 365 days, so we need to remove one day in seconds per leap year
-        // between 1900 and `year`
-        for year in 1900..year {
-            if is_leap_year(year) {
-                year_fraction -= SECONDS_PER_DAY;
-            }
-        }
+    #[must_use]
+    /// Converts the Epoch to the Gregorian equivalent in the provided time system as (year, month, day, hour, minute, second, nanoseconds).
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    /// let dt = Epoch::from_gregorian_tai_at_midnight(2000, 1, 1);
+    /// assert_eq!(dt.as_gregorian(TimeSystem::TAI), (2000, 1, 1, 0, 0, 0, 0));
+    /// assert_eq!(dt.as_gregorian(TimeSystem::UTC), (1999, 12, 31, 23, 59, 28, 0));
+    /// ```
+    pub fn as_gregorian(&self, ts: TimeSystem) -> (i32, u8, u8, u8, u8, u8, u32) {
+        Self::compute_gregorian(match ts {
+            TimeSystem::ET => self.as_et_seconds(),
+            TimeSystem::TT => self.as_tt_seconds(),
+            TimeSystem::TAI => self.as_tai_seconds(),
+            TimeSystem::TDB => self.as_tdb_seconds(),
+            TimeSystem::UTC => self.as_utc_seconds(),
+            TimeSystem::TCG => self.as_tcg_seconds(),
+            TimeSystem::TCB => self.as_tcb_seconds(),
+            TimeSystem::LORAN => self.as_tai_seconds() - LORAN_TAI_OFFSET_S as f64,
+        })
+    }
 
-        // Get the month from the exact number of seconds between the start of the year and now
-        let mut seconds_til_this_month = 0.0;
-        let mut month = 1;
-        if year_fraction < 0.0 {
-            month = 12;
-            year -= 1;
-        } else {
-            loop {
-                seconds_til_this_month +=
-                    SECONDS_PER_DAY * f64::from(USUAL_DAYS_PER_MONTH[(month - 1) as usize]);
-                if is_leap_year(year) && month == 2 {
-                    seconds_til_this_month += SECONDS_PER_DAY;
-                }
-                if seconds_til_this_month > year_fraction {
-                    break;
-                }
-                month += 1;
-            }
-        }
-        let mut days_this_month = USUAL_DAYS_PER_MONTH[(month - 1) as usize];
-        if month == 2 && is_leap_year(year) {
-            days_this_month += 1;
-        }
-        // Get the month fraction by the number of seconds in this month from the number of
-        // seconds since the start of this month.
-        let (_, month_fraction) = div_rem_f64(
-            year_fraction - seconds_til_this_month,
-            f64::from(days_this_month) * SECONDS_PER_DAY,
-        );
-        // Get the day by the exact number of seconds in a day
-        let (mut day, day_fraction) = div_rem_f64(month_fraction, SECONDS_PER_DAY);
-        if day < 0 {
-            // Overflow backwards (this happens for end of year calculations)
-            month -= 1;
-            if month == 0 {
-                month = 12;
-                year -= 1;
-            }
-            day = USUAL_DAYS_PER_MONTH[(month - 1) as usize] as i32;
+    #[must_use]
+    /// Converts the Epoch to the Gregorian equivalent in the provided time system as (year, month, day, hour, minute, second),
+    /// where the second includes its fraction, as expected by SOFA's `dtf2d` and many FORTRAN heritage interfaces.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    /// let dt = Epoch::from_gregorian_tai(2000, 1, 1, 12, 30, 15, 250_000_000);
+    /// assert_eq!(dt.as_gregorian_fractional(TimeSystem::TAI), (2000, 1, 1, 12, 30, 15.25));
+    /// ```
+    pub fn as_gregorian_fractional(&self, ts: TimeSystem) -> (i32, u8, u8, u8, u8, f64) {
+        let (y, mm, dd, hh, min, s, nanos) = self.as_gregorian(ts);
+        (y, mm, dd, hh, min, f64::from(s) + f64::from(nanos) * 1e-9)
+    }
+
+    /// Returns the duration since 1900 January 01 at midnight in the provided time system, including
+    /// in ET and TDB whose durations are otherwise counted from J2000.
+    pub(crate) fn duration_in(&self, ts: TimeSystem) -> Duration {
+        match ts {
+            TimeSystem::ET => self.as_et_duration() + Unit::Second * ET_EPOCH_S,
+            TimeSystem::TT => self.as_tt_duration(),
+            TimeSystem::TAI => self.0,
+            TimeSystem::TDB => self.as_tdb_duration() + Unit::Second * ET_EPOCH_S,
+            TimeSystem::UTC => self.as_utc_duration(),
+            TimeSystem::TCG => self.as_tcg_duration(),
+            TimeSystem::TCB => self.as_tcb_duration(),
+            TimeSystem::LORAN => self.0 - Unit::Second * LORAN_TAI_OFFSET_S,
         }
-        day += 1; // Otherwise the day count starts at 0
-                  // Get the hours by the exact number of seconds in an hour
+    }
+
+    #[must_use]
+    /// Converts the Epoch to its date and seconds of day in the provided time system, as used by
+    /// several geodesy formats (e.g. IONEX, or the `YY:DOY:SOD` epochs of SINEX).
+    ///
+    /// The seconds of day are computed from the exact duration in that time system, so they are
+    /// precise to the nanosecond, unlike the seconds of [`Epoch::as_gregorian_fractional`]. The
+    /// date in ET and TDB is that of their calendar since 1900, unlike the date of
+    /// [`Epoch::as_gregorian`] which counts their duration past J2000 from 1900.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSystem};
+    ///
+    /// let e = Epoch::from_gregorian_tai(2022, 5, 20, 17, 57, 43, 1);
+    /// assert_eq!(e.as_ymd_sod(TimeSystem::TAI), (2022, 5, 20, 64_663.000_000_001));
+    /// ```
+    pub fn as_ymd_sod(&self, ts: TimeSystem) -> (i32, u8, u8, f64) {
+        let (days, nanos) = div_rem_i128(
+            self.duration_in(ts).total_nanoseconds(),
+            i128::from(NANOSECONDS_PER_DAY),
+        );
+        let (year, month, day) = gregorian_from_days(days as i64);
+        (year, month, day, nanos as f64 / 1e9)
+    }
+
+    fn compute_gregorian(absolute_seconds: f64) -> (i32, u8, u8, u8, u8, u8, u32) {
+        let (days, day_fraction) = div_rem_f64(absolute_seconds, SECONDS_PER_DAY);
+        let (year, month, day) = gregorian_from_days(i64::from(days));
+        // Get the hours by the exact number of seconds in an hour
         let (hours, hours_fraction) = div_rem_f64(day_fraction, 60.0 * 60.0);
         // Get the minutes and seconds by the exact number of seconds in a minute
         let (mins, secs) = div_rem_f64(hours_fraction, 60.0);
         let nanos = (div_rem_f64(secs, 1.0).1 * 1e9) as u32;
-        (
-            year,
-            month as u8,
-            day as u8,
-            hours as u8,
-            mins as u8,
-            secs as u8,
-            nanos,
-        )
+        (year, month, day, hours as u8, mins as u8, secs as u8, nanos)
     }
 
     /// Floors this epoch to the closest provided duration
@@ -1061,6 +1903,163 @@ impl Epoch {
     pub fn round(&self, duration: Duration) -> Self {
         Self(self.0.round(duration))
     }
+
+    /// Returns the next representable epoch, i.e. one nanosecond ([`Duration::EPSILON`]) after
+    /// this one, such that no epoch is strictly between both. This saturates at the latest epoch.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
+    /// assert!(e.next_after() > e);
+    /// assert_eq!(e.next_after() - e, 1.nanoseconds());
+    /// assert_eq!(e.next_after().prev_before(), e);
+    /// ```
+    #[must_use]
+    pub fn next_after(&self) -> Self {
+        Self(self.0 + Duration::EPSILON)
+    }
+
+    /// Returns the previous representable epoch, i.e. one nanosecond ([`Duration::EPSILON`])
+    /// before this one, such that no epoch is strictly between both. This saturates at the
+    /// earliest epoch.
+    #[must_use]
+    pub fn prev_before(&self) -> Self {
+        if self.0.to_parts() == (i16::MIN, 0) {
+            // Subtracting from the earliest duration would overflow its centuries
+            *self
+        } else {
+            Self(self.0 - Duration::EPSILON)
+        }
+    }
+
+    /// Returns the duration elapsed since the reference epoch, e.g. the time since mission start,
+    /// which is negative if this epoch is before the reference.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// assert_eq!((start + 2.hours()).since(start), 2.hours());
+    /// assert_eq!(start.since(start + 2.hours()), -(2.hours()));
+    /// ```
+    #[must_use]
+    pub fn since(&self, reference: Self) -> Duration {
+        self.0 - reference.0
+    }
+
+    /// Initialize an Epoch from the duration elapsed since a reference epoch, e.g. a mission
+    /// elapsed time since launch. A negative duration yields an epoch before the reference.
+    /// This is the inverse of [`Epoch::duration_since`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let launch = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// let epoch = Epoch::from_duration_since(launch, 2.hours());
+    /// assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2022, 5, 20, 2, 0, 0));
+    /// assert_eq!(epoch.duration_since(launch), 2.hours());
+    /// ```
+    #[must_use]
+    pub fn from_duration_since(reference: Self, duration: Duration) -> Self {
+        Self(reference.0 + duration)
+    }
+
+    /// Returns the duration elapsed from the reference epoch until this epoch, i.e. `self -
+    /// reference`, which is negative if this epoch is before the reference, cf. [`Epoch::since`].
+    /// This is the inverse of [`Epoch::from_duration_since`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let launch = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// let before = Epoch::from_gregorian_utc_hms(2022, 5, 19, 23, 0, 0);
+    /// assert_eq!(before.duration_since(launch), -(1.hours()));
+    /// assert_eq!(launch.duration_since(before), 1.hours());
+    /// ```
+    #[must_use]
+    pub fn duration_since(&self, reference: Self) -> Duration {
+        self.since(reference)
+    }
+
+    /// Returns this epoch relative to the reference epoch, which converts into the duration since
+    /// that reference, cf. [`EpochRef`].
+    #[must_use]
+    pub const fn relative_to(&self, reference: Self) -> EpochRef {
+        EpochRef::new(*self, reference)
+    }
+
+    /// Returns the first `N` powers of the time elapsed since the reference epoch, expressed in the
+    /// provided unit, i.e. `[Δt, Δt², ..., Δtᴺ]`, as needed to evaluate clock polynomials or
+    /// precession series.
+    ///
+    /// The interval is computed exactly and only converted to a float at the end, splitting the
+    /// whole number of units from the remainder. This avoids the cancellation which occurs when
+    /// subtracting two large floating point values (e.g. centuries since J1900).
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits, Unit};
+    ///
+    /// let t0 = Epoch::from_gregorian_tai_at_noon(2000, 1, 1);
+    /// let powers: [f64; 3] = (t0 + 2.hours()).powers_of_interval(t0, Unit::Hour);
+    /// assert_eq!(powers, [2.0, 4.0, 8.0]);
+    /// let powers: [f64; 2] = (t0 + 1.nanoseconds()).powers_of_interval(t0, Unit::Second);
+    /// assert_eq!(powers, [1e-9, 1e-18]);
+    /// ```
+    #[must_use]
+    pub fn powers_of_interval<const N: usize>(&self, reference: Self, unit: Unit) -> [f64; N] {
+        let elapsed = (self.0 - reference.0).total_nanoseconds();
+        let per_unit = (unit * 1).total_nanoseconds();
+        let dt = elapsed.div_euclid(per_unit) as f64
+            + elapsed.rem_euclid(per_unit) as f64 / per_unit as f64;
+        let mut powers = [dt; N];
+        for i in 1..N {
+            powers[i] = powers[i - 1] * dt;
+        }
+        powers
+    }
+
+    /// Returns the Earth Rotation Angle (ERA) in turns, i.e. normalized between 0 and 1, as defined
+    /// by the IAU 2000 resolutions (cf. SOFA `iauEra00`).
+    ///
+    /// The ERA is a function of UT1, which is computed from UTC and the provided UT1 - UTC offset
+    /// (DUT1, as published by the IERS in Bulletin A, always smaller than 0.9 seconds).
+    /// The days since J2000 are split into their whole and fractional parts to preserve precision.
+    #[must_use]
+    pub fn earth_rotation_angle_turns(&self, ut1_utc: Duration) -> f64 {
+        // UT1 time since J2000 UT1, i.e. 2000 January 01 at noon
+        let ut1_j2k = self.as_utc_duration() + ut1_utc - Unit::Day * J2000_UT1_DAYS_J1900;
+        let elapsed = ut1_j2k.total_nanoseconds();
+        let per_day = (Unit::Day * 1).total_nanoseconds();
+        let fraction = elapsed.rem_euclid(per_day) as f64 / per_day as f64;
+        let tu = elapsed.div_euclid(per_day) as f64 + fraction;
+        // The integer number of days are whole turns, which cancel out
+        rem_euclid_f64(
+            fraction + 0.779_057_273_264_0 + 0.002_737_811_911_354_48 * tu,
+            1.0,
+        )
+    }
+
+    /// Returns the Earth Rotation Angle (ERA) in radians, between 0 and 2π, cf. [`Epoch::earth_rotation_angle_turns`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// // Validation against SOFA `iauEra00(2400000.5, 54388.0)`, i.e. 2007 October 15 at midnight UT1
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2007, 10, 15);
+    /// let era = epoch.earth_rotation_angle(0.seconds());
+    /// assert!((era - 0.402_283_724_002_815_8).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn earth_rotation_angle(&self, ut1_utc: Duration) -> f64 {
+        2.0 * core::f64::consts::PI * self.earth_rotation_angle_turns(ut1_utc)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -1102,7 +2101,7 @@ impl Epoch {
     /// ```
     pub fn from_gregorian_str(s: &str) -> Result<Self, Errors> {
         let reg: Regex = Regex::new(
-            r"^(\d{4})-(\d{2})-(\d{2})(?:T|\W)(\d{2}):(\d{2}):(\d{2})\.?(\d+)?\W?(\w{2,3})?$",
+            r"^(\d{4})-(\d{2})-(\d{2})(?:T|\W)(\d{2}):(\d{2}):(\d{2})\.?(\d+)?\W?(\w{2,5})?$",
         )
         .unwrap();
         match reg.captures(s) {
@@ -1179,13 +2178,7 @@ impl Epoch {
     #[must_use]
     /// Converts the Epoch to Gregorian in the provided time system and in the ISO8601 format with the time system appended to the string
     pub fn as_gregorian_str(&self, ts: TimeSystem) -> String {
-        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(match ts {
-            TimeSystem::ET => self.as_et_seconds(),
-            TimeSystem::TT => self.as_tt_seconds(),
-            TimeSystem::TAI => self.as_tai_seconds(),
-            TimeSystem::TDB => self.as_tdb_seconds(),
-            TimeSystem::UTC => self.as_utc_seconds(),
-        });
+        let (y, mm, dd, hh, min, s, nanos) = self.as_gregorian(ts);
         if nanos == 0 {
             format!(
                 "{:04}-{:02}-{:02}T{:02}:{:02}:{:02} {:?}",
@@ -1199,17 +2192,162 @@ impl Epoch {
         }
     }
 
-    /// Initializes a new Epoch from `now`.
-    /// WARNING: This assumes that the system time returns the time in UTC (which is the case on Linux)
-    /// Uses [`std::time::SystemTime::now`](https://doc.rust-lang.org/std/time/struct.SystemTime.html#method.now) under the hood
-    pub fn now() -> Result<Self, Errors> {
-        match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
-            Ok(std_duration) => Ok(Self::from_unix_seconds(std_duration.as_secs_f64())),
-            Err(_) => Err(Errors::SystemTimeError),
-        }
-    }
-}
-
+    /// Returns this epoch in UTC in the RFC 3339 format with nanosecond precision and the `Z`
+    /// suffix, e.g. `2022-05-20T17:57:43.500000000Z`, cf. [`Epoch::as_rfc3339_with_precision`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+    /// assert_eq!(e.as_rfc3339(), "2022-05-20T17:57:43.000000000Z");
+    /// assert_eq!((e + 500.milliseconds()).as_rfc3339(), "2022-05-20T17:57:43.500000000Z");
+    /// ```
+    #[must_use]
+    pub fn as_rfc3339(&self) -> String {
+        self.as_rfc3339_with_precision(9)
+    }
+
+    /// Returns this epoch in UTC in the RFC 3339 format with the `Z` suffix and exactly the
+    /// provided number of digits of fractional seconds, truncated and zero padded. A precision of
+    /// zero omits the fractional seconds, and a precision above nine is taken as nine. The second
+    /// is 60 during an inserted leap second, cf. [`Epoch::to_utc_parts_checked`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43) + 5.milliseconds();
+    /// assert_eq!(e.as_rfc3339_with_precision(0), "2022-05-20T17:57:43Z");
+    /// assert_eq!(e.as_rfc3339_with_precision(3), "2022-05-20T17:57:43.005Z");
+    /// assert_eq!(e.as_rfc3339_with_precision(6), "2022-05-20T17:57:43.005000Z");
+    /// ```
+    #[must_use]
+    pub fn as_rfc3339_with_precision(&self, precision: u8) -> String {
+        let parts = self.to_utc_parts_checked();
+        let precision = precision.min(9);
+        let mut rfc3339 = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            parts.year, parts.month, parts.day, parts.hour, parts.minute, parts.second
+        );
+        if precision > 0 {
+            let fraction = parts.nanos / 10_u32.pow(9 - u32::from(precision));
+            rfc3339.push_str(&format!(
+                ".{:0width$}",
+                fraction,
+                width = usize::from(precision)
+            ));
+        }
+        rfc3339.push('Z');
+        rfc3339
+    }
+
+    /// Initializes a new Epoch from `now`, exactly to the resolution of the system clock.
+    /// WARNING: This assumes that the system time returns the time in UTC (which is the case on Linux)
+    /// Uses [`std::time::SystemTime::now`](https://doc.rust-lang.org/std/time/struct.SystemTime.html#method.now) under the hood
+    /// With the `test_clock` feature, this returns the epoch set with `test_clock::set_now` if any.
+    pub fn now() -> Result<Self, Errors> {
+        #[cfg(feature = "test_clock")]
+        if let Some(epoch) = crate::test_clock::mocked_now() {
+            return Ok(epoch);
+        }
+        match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(std_duration) => Ok(Self::from_unix_timespec(
+                std_duration.as_secs() as i64,
+                std_duration.subsec_nanos(),
+            )),
+            Err(_) => Err(Errors::SystemTimeError),
+        }
+    }
+
+    /// Initializes a new Epoch from the current UTC time with nanosecond resolution, without any
+    /// floating point conversion.
+    ///
+    /// On Unix systems (including macOS), this reads `clock_gettime(CLOCK_REALTIME)`: Linux provides
+    /// nanoseconds, while macOS only provides microseconds. On other systems (e.g. Windows), this
+    /// falls back to [`std::time::SystemTime::now`], which has a resolution of 100 ns on Windows.
+    /// With the `test_clock` feature, this returns the epoch set with `test_clock::set_now` if any.
+    #[cfg(feature = "precise-now")]
+    pub fn now_precise() -> Result<Self, Errors> {
+        #[cfg(feature = "test_clock")]
+        if let Some(epoch) = crate::test_clock::mocked_now() {
+            return Ok(epoch);
+        }
+        #[cfg(unix)]
+        {
+            let mut ts = libc::timespec {
+                tv_sec: 0,
+                tv_nsec: 0,
+            };
+            // SAFETY: `ts` is a valid and writable timespec for the duration of the call.
+            if unsafe { libc::clock_gettime(libc::CLOCK_REALTIME, &mut ts) } != 0 {
+                return Err(Errors::SystemTimeError);
+            }
+            Ok(Self::from_timespec(ts))
+        }
+        #[cfg(not(unix))]
+        match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(std_duration) => Ok(Self::from_unix_timespec(
+                std_duration.as_secs() as i64,
+                std_duration.subsec_nanos(),
+            )),
+            Err(_) => Err(Errors::SystemTimeError),
+        }
+    }
+}
+
+impl TryFrom<(i32, u8, u8, u8, u8, u8, u32)> for Epoch {
+    type Error = Errors;
+
+    /// Builds an Epoch from a UTC Gregorian `(year, month, day, hour, minute, second, nanos)` tuple,
+    /// as returned by [`Epoch::as_gregorian_utc`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use hifitime::Epoch;
+    ///
+    /// let epoch = Epoch::try_from((2022, 5, 20, 17, 57, 43, 0)).unwrap();
+    /// assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43));
+    /// assert_eq!(Epoch::try_from(epoch.as_gregorian_utc()), Ok(epoch));
+    /// assert!(Epoch::try_from((2022, 2, 30, 0, 0, 0, 0)).is_err());
+    /// ```
+    fn try_from(
+        (year, month, day, hour, minute, second, nanos): (i32, u8, u8, u8, u8, u8, u32),
+    ) -> Result<Self, Self::Error> {
+        Self::maybe_from_gregorian_utc(year, month, day, hour, minute, second, nanos)
+    }
+}
+
+impl TryFrom<[u32; 6]> for Epoch {
+    type Error = Errors;
+
+    /// Builds an Epoch from a UTC Gregorian `[year, month, day, hour, minute, second]` array.
+    ///
+    /// # Example
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use hifitime::Epoch;
+    ///
+    /// let epoch = Epoch::try_from([2022, 5, 20, 17, 57, 43]).unwrap();
+    /// assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43));
+    /// assert!(Epoch::try_from([2022, 5, 20, 256, 0, 0]).is_err());
+    /// ```
+    fn try_from([year, month, day, hour, minute, second]: [u32; 6]) -> Result<Self, Self::Error> {
+        let year = i32::try_from(year).map_err(|_| Errors::Overflow)?;
+        let to_u8 = |value: u32| u8::try_from(value).map_err(|_| Errors::Carry);
+        Self::maybe_from_gregorian_utc(
+            year,
+            to_u8(month)?,
+            to_u8(day)?,
+            to_u8(hour)?,
+            to_u8(minute)?,
+            to_u8(second)?,
+            0,
+        )
+    }
+}
+
 #[cfg(feature = "std")]
 impl FromStr for Epoch {
     type Err = Errors;
@@ -1220,6 +2358,10 @@ impl FromStr for Epoch {
     ///  + JD: Julian days
     ///  + MJD: Modified Julian days
     ///  + SEC: Seconds past a given epoch (e.g. SEC 17.2 TAI is 17.2 seconds past TAI Epoch)
+    ///
+    /// Each of them supports the TAI, UTC, TT, TDB, ET, TCG, TCB, LORAN and GPST time systems, where the seconds
+    /// in GPST are past the GPS Time Epoch. The time system either follows the value, or is combined
+    /// with the format identifier as in several ingest formats, e.g. `JDTT 2459000.5` or `MJD_UTC 59000.0`.
     /// # Example
     /// ```
     /// use hifitime::Epoch;
@@ -1229,32 +2371,76 @@ impl FromStr for Epoch {
     /// assert!(Epoch::from_str("JD 2452312.500372511 ET").is_ok());
     /// assert!(Epoch::from_str("JD 2452312.500372511 TAI").is_ok());
     /// assert!(Epoch::from_str("MJD 51544.5 TAI").is_ok());
+    /// assert!(Epoch::from_str("MJD 59720.0 TT").is_ok());
+    /// assert!(Epoch::from_str("MJD 59720.0 GPST").is_ok());
+    /// assert!(Epoch::from_str("SEC 720000000.0 GST").is_ok());
     /// assert!(Epoch::from_str("SEC 0.5 TAI").is_ok());
     /// assert!(Epoch::from_str("SEC 66312032.18493909 TDB").is_ok());
+    /// assert_eq!(
+    ///     Epoch::from_str("MJD_UTC 59000.0").unwrap(),
+    ///     Epoch::from_str("MJD 59000.0 UTC").unwrap()
+    /// );
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let reg: Regex = Regex::new(r"^(\w{2,3})\W?(\d+\.?\d+)\W?(\w{2,3})?$").unwrap();
+        let reg: Regex =
+            Regex::new(r"^(JD|MJD|SEC)(?:_?(UT1|[A-Z]{2,5}))?\W?(\d+\.?\d+)\W?(\w{2,5})?$")
+                .unwrap();
         // Try to match Gregorian date
         match Self::from_gregorian_str(s) {
             Ok(e) => Ok(e),
             Err(_) => match reg.captures(s) {
                 Some(cap) => {
                     let format = cap[1].to_owned().parse::<String>().unwrap();
-                    let value = cap[2].to_owned().parse::<f64>().unwrap();
-                    let ts = TimeSystem::from_str(&cap[3])?;
+                    let value = cap[3].to_owned().parse::<f64>().unwrap();
+                    // The time system is either combined with the format (e.g. `MJD_UTC`) or trailing
+                    let ts_str = match (cap.get(2), cap.get(4)) {
+                        (Some(ts_str), None) | (None, Some(ts_str)) => ts_str.as_str(),
+                        _ => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+                    };
+                    // GPS Time is not a time system of its own since it is a constant offset from TAI
+                    if ts_str == "GPST" {
+                        return match format.as_str() {
+                            "JD" => Ok(Self::from_jde_gpst(value)),
+                            "MJD" => Ok(Self::from_mjd_gpst(value)),
+                            "SEC" => Ok(Self::from_gpst_seconds(value)),
+                            _ => Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+                        };
+                    }
+                    // Nor is Galileo System Time, which is aligned with GPS Time
+                    if ts_str == "GST" {
+                        return match format.as_str() {
+                            "JD" => Ok(Self::from_jde_gst(value)),
+                            "MJD" => Ok(Self::from_mjd_gst(value)),
+                            "SEC" => Ok(Self::from_gst_seconds(value)),
+                            _ => Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+                        };
+                    }
+                    let ts = TimeSystem::from_str(ts_str)?;
 
                     match format.as_str() {
                         "JD" => match ts {
                             TimeSystem::ET => Ok(Self::from_jde_et(value)),
                             TimeSystem::TAI => Ok(Self::from_jde_tai(value)),
                             TimeSystem::TDB => Ok(Self::from_jde_tdb(value)),
+                            TimeSystem::TT => Ok(Self::from_jde_tt(value)),
                             TimeSystem::UTC => Ok(Self::from_jde_utc(value)),
-                            _ => Err(Errors::ParseError(ParsingErrors::UnsupportedTimeSystem)),
+                            TimeSystem::TCG => Ok(Self::from_jde_tcg(value)),
+                            TimeSystem::TCB => Ok(Self::from_jde_tcb(value)),
+                            TimeSystem::LORAN => {
+                                Ok(Self::from_jde_tai(value) + Unit::Second * LORAN_TAI_OFFSET_S)
+                            }
                         },
                         "MJD" => match ts {
+                            TimeSystem::ET => Ok(Self::from_mjd_et(value)),
                             TimeSystem::TAI => Ok(Self::from_mjd_tai(value)),
+                            TimeSystem::TDB => Ok(Self::from_mjd_tdb(value)),
+                            TimeSystem::TT => Ok(Self::from_mjd_tt(value)),
                             TimeSystem::UTC => Ok(Self::from_mjd_utc(value)),
-                            _ => Err(Errors::ParseError(ParsingErrors::UnsupportedTimeSystem)),
+                            TimeSystem::TCG => Ok(Self::from_mjd_tcg(value)),
+                            TimeSystem::TCB => Ok(Self::from_mjd_tcb(value)),
+                            TimeSystem::LORAN => {
+                                Ok(Self::from_mjd_tai(value) + Unit::Second * LORAN_TAI_OFFSET_S)
+                            }
                         },
                         "SEC" => match ts {
                             TimeSystem::TAI => Ok(Self::from_tai_seconds(value)),
@@ -1262,6 +2448,9 @@ impl FromStr for Epoch {
                             TimeSystem::TDB => Ok(Self::from_tdb_seconds(value)),
                             TimeSystem::TT => Ok(Self::from_tt_seconds(value)),
                             TimeSystem::UTC => Ok(Self::from_utc_seconds(value)),
+                            TimeSystem::TCG => Ok(Self::from_tcg_seconds(value)),
+                            TimeSystem::TCB => Ok(Self::from_tcb_seconds(value)),
+                            TimeSystem::LORAN => Ok(Self::from_loran_seconds(value)),
                         },
                         _ => Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
                     }
@@ -1283,6 +2472,32 @@ impl<'de> Deserialize<'de> for Epoch {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Epoch {
+    /// Generates an epoch from an arbitrary duration since the TAI reference epoch.
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(Duration::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Duration::size_hint(depth)
+    }
+}
+
+impl fmt::Debug for Epoch {
+    /// Prints the exact TAI centuries and nanoseconds of this epoch followed by its UTC date time,
+    /// e.g. `Epoch { centuries: 1, nanoseconds: 537582752000000000 } (2017-01-14T00:31:55 UTC)`.
+    /// The alternate mode (`{:#?}`) prints each part on its own line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (centuries, nanoseconds) = self.0.to_parts();
+        f.debug_struct("Epoch")
+            .field("centuries", &centuries)
+            .field("nanoseconds", &nanoseconds)
+            .finish()?;
+        write!(f, " ({})", self)
+    }
+}
+
 impl fmt::Display for Epoch {
     /// The default format of an epoch is in UTC
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -1403,7 +2618,8 @@ impl fmt::Octal for Epoch {
 }
 
 #[must_use]
-/// Returns true if the provided Gregorian date is valid. Leap second days may have 60 seconds.
+/// Returns true if the provided Gregorian date is valid. Leap second days may have 60 seconds, and
+/// the hour may be 24 at exactly `24:00:00`, the ISO 8601 notation of the end of the day.
 pub fn is_gregorian_valid(
     year: i32,
     month: u8,
@@ -1414,7 +2630,7 @@ pub fn is_gregorian_valid(
     nanos: u32,
 ) -> bool {
     let max_seconds = if (month == 12 || month == 6)
-        && day == USUAL_DAYS_PER_MONTH[month as usize - 1]
+        && day == DAYS_PER_MONTH[0][month as usize - 1]
         && hour == 23
         && minute == 59
         && ((month == 6 && JULY_YEARS.contains(&year))
@@ -1432,55 +2648,242 @@ pub fn is_gregorian_valid(
         || hour > 24
         || minute > 59
         || second > max_seconds
-        || f64::from(nanos) > 1e9
+        || nanos >= 1_000_000_000
+        || (hour == 24 && (minute, second, nanos) != (0, 0, 0))
     {
         return false;
     }
-    if day > USUAL_DAYS_PER_MONTH[month as usize - 1] && (month != 2 || !is_leap_year(year)) {
-        // Not in February or not a leap year
+    if day > days_in_month(year, month) {
         return false;
     }
     true
 }
 
+/// Returns the TAI - UTC offset in effect at the provided epoch, using the built-in leap second table.
+///
+/// The new offset applies from the leap instant inclusive, i.e. from 00:00:00 UTC following the
+/// inserted leap second. During the inserted second itself (23:59:60 UTC), the previous offset
+/// still applies. Prior to 1972, this offset is zero.
+///
+/// # Example
+/// ```
+/// use hifitime::{tai_utc_offset_at, Epoch, TimeUnits};
+///
+/// let leap_instant = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+/// assert_eq!(tai_utc_offset_at(leap_instant), 37.seconds());
+/// assert_eq!(tai_utc_offset_at(leap_instant - 1.nanoseconds()), 36.seconds());
+/// ```
+#[must_use]
+pub fn tai_utc_offset_at(epoch: Epoch) -> Duration {
+    with_active_leap_seconds(|entries| {
+        // The leap second table is in UTC, so shift by the new offset to get the leap instant in TAI.
+        let seconds = whole_seconds(epoch.0);
+        let idx = entries
+            .partition_point(|leap| seconds >= leap.timestamp_utc_s + i64::from(leap.delta_at));
+        let cnt = match idx {
+            0 => 0,
+            _ => entries[idx - 1].delta_at,
+        };
+        Unit::Second * i64::from(cnt)
+    })
+}
+
+/// Returns the TAI - UTC offset in effect at the provided duration past 1900 January 01 at midnight
+/// counted in UTC, i.e. as [`tai_utc_offset_at`] but from a UTC reading instead of an epoch.
+pub(crate) fn tai_utc_offset_of_utc(utc: Duration) -> Duration {
+    Unit::Second * i64::from(with_active_leap_seconds(|entries| delta_at(entries, utc)))
+}
+
+/// Returns the GPS - UTC offset in effect at the provided epoch, i.e. the TAI - UTC offset minus
+/// the 19 seconds between TAI and GPS time. The leap instants are handled as in [`tai_utc_offset_at`].
+///
+/// # Example
+/// ```
+/// use hifitime::{gps_utc_offset_at, Epoch, TimeUnits};
+///
+/// let gps_epoch = Epoch::from_gregorian_utc_at_midnight(1980, 1, 6);
+/// assert_eq!(gps_utc_offset_at(gps_epoch), 0.seconds());
+/// let leap_instant = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+/// assert_eq!(gps_utc_offset_at(leap_instant), 18.seconds());
+/// assert_eq!(gps_utc_offset_at(leap_instant - 1.nanoseconds()), 17.seconds());
+/// ```
+#[must_use]
+pub fn gps_utc_offset_at(epoch: Epoch) -> Duration {
+    tai_utc_offset_at(epoch) - Unit::Second * TAI_GPST_OFFSET_S
+}
+
+/// The time elapsed between two UTC epochs, both as the exact duration, which includes any leap
+/// second inserted in between, and as the civil duration read on UTC clocks, which does not.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UtcElapsed {
+    /// The exact (TAI) duration between both epochs
+    pub elapsed: Duration,
+    /// The difference of the UTC clock readings of both epochs, where leap seconds are not counted
+    pub civil: Duration,
+}
+
+impl UtcElapsed {
+    /// Computes the time elapsed from `start` to `end`, which is negative if `end` is before `start`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits, UtcElapsed};
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+    /// let end = Epoch::from_gregorian_utc_at_noon(2017, 1, 1);
+    /// let elapsed = UtcElapsed::between(start, end);
+    /// assert_eq!(elapsed.elapsed, 86_401.seconds());
+    /// assert_eq!(elapsed.civil, 1.days());
+    /// assert_eq!(elapsed.leap_seconds(), 1.seconds());
+    /// ```
+    #[must_use]
+    pub fn between(start: Epoch, end: Epoch) -> Self {
+        let elapsed = end - start;
+        Self {
+            elapsed,
+            civil: elapsed - (tai_utc_offset_at(end) - tai_utc_offset_at(start)),
+        }
+    }
+
+    /// Returns the leap seconds inserted between both epochs, i.e. the exact minus the civil duration.
+    #[must_use]
+    pub fn leap_seconds(&self) -> Duration {
+        self.elapsed - self.civil
+    }
+}
+
+/// Returns the time elapsed between two Gregorian dates, in UTC unless their string specifies
+/// another time system, both as the exact duration and as the civil duration, cf. [`UtcElapsed`].
+///
+/// # Example
+/// ```
+/// use hifitime::{utc_elapsed_between, TimeUnits};
+///
+/// let elapsed = utc_elapsed_between("2016-12-31T12:00:00", "2017-01-01T12:00:00").unwrap();
+/// assert_eq!(elapsed.elapsed, 86_401.seconds());
+/// assert_eq!(elapsed.civil, 86_400.seconds());
+/// ```
+#[cfg(feature = "std")]
+pub fn utc_elapsed_between(start: &str, end: &str) -> Result<UtcElapsed, Errors> {
+    Ok(UtcElapsed::between(
+        Epoch::from_gregorian_str(start)?,
+        Epoch::from_gregorian_str(end)?,
+    ))
+}
+
+/// Returns the epoch until which the built-in leap second table is known to be valid, as published
+/// by the IERS. A leap second announced after this table was published may happen after this epoch.
+///
+/// # Example
+/// ```
+/// use hifitime::{leap_second_table_expiry, Epoch};
+///
+/// assert_eq!(
+///     leap_second_table_expiry(),
+///     Epoch::from_gregorian_utc_at_midnight(2026, 6, 28)
+/// );
+/// ```
+#[must_use]
+pub fn leap_second_table_expiry() -> Epoch {
+    let utc = Unit::Second * LEAP_SECONDS_EXPIRY_S;
+    Epoch(utc + tai_utc_offset_at(Epoch(utc)))
+}
+
+/// Returns true if the provided epoch (typically the current time) is past the expiration of the
+/// built-in leap second table, in which case the UTC conversions may miss recent leap seconds.
+///
+/// # Example
+/// ```
+/// use hifitime::{is_table_stale, Epoch};
+///
+/// assert!(!is_table_stale(Epoch::from_gregorian_utc_at_midnight(2022, 5, 20)));
+/// assert!(is_table_stale(Epoch::from_gregorian_utc_at_midnight(2030, 1, 1)));
+/// ```
+#[must_use]
+pub fn is_table_stale(now: Epoch) -> bool {
+    now > leap_second_table_expiry()
+}
+
 /// `is_leap_year` returns whether the provided year is a leap year or not.
 /// Tests for this function are part of the Datetime tests.
-fn is_leap_year(year: i32) -> bool {
+pub(crate) const fn is_leap_year(year: i32) -> bool {
     (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
 }
 
-fn div_rem_f64(me: f64, rhs: f64) -> (i32, f64) {
-    ((div_euclid_f64(me, rhs) as i32), rem_euclid_f64(me, rhs))
+/// Number of 400 year eras by which the dates are shifted in `days_from_gregorian` and
+/// `gregorian_from_days` to compute with unsigned integers, covering every year of an i32.
+const GREGORIAN_ERAS_SHIFT: i64 = 5_368_710;
+/// Number of days in a 400 year era of the Gregorian calendar
+const DAYS_PER_GREGORIAN_ERA: i64 = 146_097;
+/// Number of days from 0000 March 01 to 1900 January 01 in the proleptic Gregorian calendar
+const DAYS_0000_03_01_TO_1900: i64 = 693_901;
+
+/// Returns the number of days since 1900 January 01 of the provided Gregorian date, using the
+/// Euclidean affine functions of Neri & Schneider (<https://doi.org/10.1002/spe.3172>) on a
+/// calendar starting in March, such that the leap day is the last day of the year.
+pub(crate) const fn days_from_gregorian(year: i32, month: u8, day: u8) -> i64 {
+    let january_or_february = (month <= 2) as u64;
+    let year = (year as i64 + 400 * GREGORIAN_ERAS_SHIFT) as u64 - january_or_february;
+    let month = month as u64 + 12 * january_or_february;
+    let century = year / 100;
+    let year_days = 1_461 * year / 4 - century + century / 4;
+    let month_days = (979 * month - 2_919) / 32;
+    (year_days + month_days + day as u64 - 1) as i64
+        - DAYS_PER_GREGORIAN_ERA * GREGORIAN_ERAS_SHIFT
+        - DAYS_0000_03_01_TO_1900
 }
 
-fn div_euclid_f64(lhs: f64, rhs: f64) -> f64 {
-    let q = (lhs / rhs).trunc();
-    if lhs % rhs < 0.0 {
-        return if rhs > 0.0 { q - 1.0 } else { q + 1.0 };
-    }
-    q
+/// Returns the Gregorian date (year, month, day) of the provided number of days since 1900
+/// January 01, which is the inverse of `days_from_gregorian`.
+pub(crate) const fn gregorian_from_days(days: i64) -> (i32, u8, u8) {
+    let n = (days + DAYS_0000_03_01_TO_1900 + DAYS_PER_GREGORIAN_ERA * GREGORIAN_ERAS_SHIFT) as u64;
+    // Century and day of the century
+    let n1 = 4 * n + 3;
+    let century = n1 / DAYS_PER_GREGORIAN_ERA as u64;
+    let n2 = n1 % DAYS_PER_GREGORIAN_ERA as u64 / 4 * 4 + 3;
+    // Year of the century and day of the year, starting in March
+    let p2 = 2_939_745 * n2;
+    let year_of_century = p2 >> 32;
+    let day_of_year = (p2 & 0xFFFF_FFFF) / 2_939_745 / 4;
+    // Month and day of the month, starting in March
+    let n3 = 2_141 * day_of_year + 197_913;
+    let month = n3 >> 16;
+    let day = (n3 & 0xFFFF) / 2_141;
+    // January and February are the months 13 and 14 of the previous year
+    let january_or_february = (day_of_year >= 306) as u64;
+    (
+        ((100 * century + year_of_century + january_or_february) as i64
+            - 400 * GREGORIAN_ERAS_SHIFT) as i32,
+        (month - 12 * january_or_february) as u8,
+        (day + 1) as u8,
+    )
 }
 
-fn rem_euclid_f64(lhs: f64, rhs: f64) -> f64 {
-    let r = lhs % rhs;
-    if r < 0.0 {
-        r + rhs.abs()
-    } else {
-        r
-    }
+/// Returns the number of days of the provided month (from 1 to 12) of the provided year.
+pub(crate) const fn days_in_month(year: i32, month: u8) -> u8 {
+    DAYS_PER_MONTH[is_leap_year(year) as usize][month as usize - 1]
 }
 
-#[test]
-fn div_rem_f64_test() {
-    assert_eq!(div_rem_f64(24.0, 6.0), (4, 0.0));
-    assert_eq!(div_rem_f64(25.0, 6.0), (4, 1.0));
-    assert_eq!(div_rem_f64(6.0, 6.0), (1, 0.0));
-    assert_eq!(div_rem_f64(5.0, 6.0), (0, 5.0));
-    assert_eq!(div_rem_f64(3540.0, 3600.0), (0, 3540.0));
-    assert_eq!(div_rem_f64(3540.0, 60.0), (59, 0.0));
-    assert_eq!(div_rem_f64(24.0, -6.0), (-4, 0.0));
-    assert_eq!(div_rem_f64(-24.0, 6.0), (-4, 0.0));
-    assert_eq!(div_rem_f64(-24.0, -6.0), (4, 0.0));
+/// Returns the number of days of the provided year.
+pub(crate) const fn days_in_year(year: i32) -> u16 {
+    CUMULATIVE_DAYS_FOR_MONTH[is_leap_year(year) as usize][12]
+}
+
+/// Returns the day of the year (starting at 1) of the provided Gregorian date.
+pub(crate) const fn day_of_year(year: i32, month: u8, day: u8) -> u16 {
+    CUMULATIVE_DAYS_FOR_MONTH[is_leap_year(year) as usize][month as usize - 1] + day as u16
+}
+
+/// Returns the month and the day of the month of the provided day of the year (starting at 1),
+/// or None if that day does not exist in this year.
+pub(crate) fn month_day_from_day_of_year(year: i32, day_of_year: u16) -> Option<(u8, u8)> {
+    let cumulative = &CUMULATIVE_DAYS_FOR_MONTH[is_leap_year(year) as usize];
+    if day_of_year == 0 || day_of_year > cumulative[12] {
+        return None;
+    }
+    // First month which ends on or after that day
+    let month = cumulative.partition_point(|days| *days < day_of_year);
+    Some((month as u8, (day_of_year - cumulative[month - 1]) as u8))
 }
 
 #[test]
@@ -1494,18 +2897,31 @@ fn test_days_tdb_j2000() {
 
 #[test]
 fn test_const_ops() {
+    use crate::{J1900_OFFSET, J2000_OFFSET, MJD_OFFSET};
     // Tests that multiplying a constant with a unit returns the correct number in that same unit
     let mjd_offset = MJD_OFFSET * Unit::Day;
     assert!((mjd_offset.in_unit(Unit::Day) - MJD_OFFSET).abs() < f64::EPSILON);
     let j2000_offset = J2000_OFFSET * Unit::Day;
     assert!((j2000_offset.in_unit(Unit::Day) - J2000_OFFSET).abs() < f64::EPSILON);
+    // The exact durations of the offsets
+    assert_eq!(MJD_OFFSET_DURATION, mjd_offset);
+    assert_eq!(J2000_OFFSET_DURATION, j2000_offset);
+    assert_eq!(J1900_OFFSET_DURATION, J1900_OFFSET * Unit::Day);
+    assert_eq!(
+        JDE_OFFSET_DURATION,
+        MJD_OFFSET_DURATION + J1900_OFFSET_DURATION
+    );
+    assert_eq!(JDE_OFFSET_DURATION.in_seconds(), crate::JDE_OFFSET_SECONDS);
 }
 
 #[cfg(test)]
+#[allow(clippy::legacy_numeric_constants)]
 mod tests {
     use crate::{
-        epoch::is_leap_year, is_gregorian_valid, Duration, Epoch, TimeSystem, Unit,
-        DAYS_GPS_TAI_OFFSET, J1900_OFFSET, SECONDS_GPS_TAI_OFFSET, SECONDS_PER_DAY,
+        epoch::{is_leap_year, LEAP_SECONDS},
+        is_gregorian_valid, is_table_stale, leap_second_table_expiry, Duration, Epoch, Errors,
+        TimeSystem, Unit, DAYS_GPS_TAI_OFFSET, ET_EPOCH_S, J1900_OFFSET, J1950_REF_EPOCH,
+        SECONDS_GPS_TAI_OFFSET, SECONDS_PER_DAY, TAI58_REF_EPOCH,
     };
 
     #[allow(clippy::float_equality_without_abs)]
@@ -1821,11 +3237,337 @@ mod tests {
         }
     }
 
+    #[test]
+    fn gregorian_days() {
+        use crate::epoch::{days_from_gregorian, gregorian_from_days, DAYS_PER_MONTH};
+        assert_eq!(days_from_gregorian(1900, 1, 1), 0);
+        assert_eq!(days_from_gregorian(1970, 1, 1), 25_567);
+        assert_eq!(days_from_gregorian(2000, 3, 1), 36_584);
+        assert_eq!(days_from_gregorian(1899, 12, 31), -1);
+        assert_eq!(gregorian_from_days(-1), (1899, 12, 31));
+        assert_eq!(gregorian_from_days(36_583), (2000, 2, 29));
+
+        // Every day of four centuries around 1900 matches the day by day count
+        let mut date = (1700, 1, 1);
+        for days in days_from_gregorian(1700, 1, 1)..days_from_gregorian(2100, 1, 1) {
+            assert_eq!(gregorian_from_days(days), date);
+            assert_eq!(days_from_gregorian(date.0, date.1, date.2), days);
+            let (year, month, day) = date;
+            date = if day < DAYS_PER_MONTH[is_leap_year(year) as usize][month as usize - 1] {
+                (year, month, day + 1)
+            } else if month < 12 {
+                (year, month + 1, 1)
+            } else {
+                (year + 1, 1, 1)
+            };
+        }
+
+        // Extreme years, within the range of epochs
+        for year in [-3_200_000, -4_713, -1, 0, 1, 99_999, 3_200_000] {
+            for (month, day) in [(1, 1), (2, 28), (3, 1), (12, 31)] {
+                let days = days_from_gregorian(year, month, day);
+                assert_eq!(gregorian_from_days(days), (year, month, day));
+                assert_eq!(
+                    gregorian_from_days(days + 1).0,
+                    if month == 12 { year + 1 } else { year }
+                );
+            }
+        }
+        // Bounds of an i32 year
+        for year in [i32::MIN, i32::MAX] {
+            let days = days_from_gregorian(year, 6, 15);
+            assert_eq!(gregorian_from_days(days), (year, 6, 15));
+        }
+        assert_eq!(
+            days_from_gregorian(i32::MIN + 400, 1, 1) - days_from_gregorian(i32::MIN, 1, 1),
+            146_097
+        );
+        // 400 years always have the same number of days
+        assert_eq!(
+            days_from_gregorian(-3_200_000, 1, 1) - days_from_gregorian(-3_200_400, 1, 1),
+            146_097
+        );
+
+        // Dates before 1900 round trip through epochs
+        let epoch = Epoch::from_gregorian_tai(1815, 6, 18, 11, 30, 0, 0);
+        assert_eq!(epoch.as_gregorian_tai(), (1815, 6, 18, 11, 30, 0, 0));
+        assert_eq!(
+            epoch.as_tai_duration(),
+            Unit::Hour * 11.5 - Unit::Day * 30_878
+        );
+    }
+
+    #[test]
+    fn gregorian_extreme_years() {
+        for year in [
+            2_200, 2_500, 1_000_000, 3_000_000, 1_500, -1_000_000, -3_000_000,
+        ] {
+            let february_end = 28 + u8::from(is_leap_year(year));
+            let epoch = Epoch::maybe_from_gregorian_tai(year, 2, february_end, 1, 2, 3, 4).unwrap();
+            assert_eq!(
+                epoch.as_gregorian_tai(),
+                (year, 2, february_end, 1, 2, 3, 0)
+            );
+            assert_eq!(
+                epoch - Epoch::from_gregorian_tai_at_midnight(year, 3, 1),
+                -(Unit::Day * 1 - Unit::Nanosecond * 3_723_000_000_004)
+            );
+            let epoch = Epoch::maybe_from_ymd_sod(year, 12, 31, 43_200.5, TimeSystem::TAI).unwrap();
+            assert_eq!(
+                epoch,
+                Epoch::from_gregorian_tai(year, 12, 31, 12, 0, 0, 500_000_000)
+            );
+        }
+        assert_eq!(
+            Epoch::from_gregorian_tai_at_midnight(2200, 1, 1)
+                - Epoch::from_gregorian_tai_at_midnight(1900, 1, 1),
+            Unit::Day * 109_573.0
+        );
+        assert_eq!(
+            Epoch::from_gregorian_utc_at_midnight(2200, 1, 1).as_gregorian_utc(),
+            (2200, 1, 1, 0, 0, 0, 0)
+        );
+
+        // Years beyond the range of an epoch, up to the bounds of an i32
+        for year in [3_400_000, -3_400_000, i32::MAX, i32::MIN] {
+            assert_eq!(
+                Epoch::maybe_from_gregorian_tai(year, 1, 1, 0, 0, 0, 0),
+                Err(Errors::Overflow)
+            );
+            assert_eq!(
+                Epoch::maybe_from_gregorian_utc(year, 1, 1, 0, 0, 0, 0),
+                Err(Errors::Overflow)
+            );
+            assert_eq!(
+                Epoch::maybe_from_gregorian(year, 1, 1, 0, 0, 0, 0, TimeSystem::TT),
+                Err(Errors::Overflow)
+            );
+            assert_eq!(
+                Epoch::maybe_from_ymd_sod(year, 1, 1, 0.0, TimeSystem::TAI),
+                Err(Errors::Overflow)
+            );
+        }
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(
+                Epoch::from_gregorian_str("2200-01-01T00:00:00 TAI"),
+                Ok(Epoch::from_gregorian_tai_at_midnight(2200, 1, 1))
+            );
+            assert_eq!(
+                Epoch::from_gregorian_str("9999-12-31T23:59:59.5 TT"),
+                Epoch::maybe_from_gregorian(9999, 12, 31, 23, 59, 59, 500_000_000, TimeSystem::TT)
+            );
+            assert_eq!(
+                Epoch::from_gregorian_str("1200-03-01T12:00:00 UTC")
+                    .unwrap()
+                    .as_gregorian_utc(),
+                (1200, 3, 1, 12, 0, 0, 0)
+            );
+        }
+    }
+
+    #[test]
+    fn month_tables() {
+        use crate::epoch::{
+            day_of_year, days_from_gregorian, month_day_from_day_of_year,
+            CUMULATIVE_DAYS_FOR_MONTH, DAYS_PER_MONTH,
+        };
+        for leap in 0..2 {
+            for month in 0..12 {
+                assert_eq!(
+                    CUMULATIVE_DAYS_FOR_MONTH[leap][month + 1]
+                        - CUMULATIVE_DAYS_FOR_MONTH[leap][month],
+                    u16::from(DAYS_PER_MONTH[leap][month])
+                );
+            }
+        }
+        // Every day of a common and of a leap year round trips through its day of the year
+        for year in [2023, 2024] {
+            let first = days_from_gregorian(year, 1, 1);
+            for month in 1..=12 {
+                for day in 1..=DAYS_PER_MONTH[is_leap_year(year) as usize][month as usize - 1] {
+                    let doy = day_of_year(year, month, day);
+                    assert_eq!(
+                        i64::from(doy),
+                        days_from_gregorian(year, month, day) - first + 1
+                    );
+                    assert_eq!(month_day_from_day_of_year(year, doy), Some((month, day)));
+                }
+            }
+        }
+        assert_eq!(month_day_from_day_of_year(2024, 366), Some((12, 31)));
+        assert_eq!(month_day_from_day_of_year(2023, 366), None);
+        assert_eq!(month_day_from_day_of_year(2023, 0), None);
+        assert!(is_gregorian_valid(2024, 2, 29, 0, 0, 0, 0));
+        assert!(!is_gregorian_valid(2024, 4, 31, 0, 0, 0, 0));
+    }
+
     #[test]
     fn datetime_invalid_dates() {
         assert!(!is_gregorian_valid(2001, 2, 29, 22, 8, 47, 0));
         assert!(!is_gregorian_valid(2016, 12, 31, 23, 59, 61, 0));
         assert!(!is_gregorian_valid(2015, 6, 30, 23, 59, 61, 0));
+        assert!(!is_gregorian_valid(2022, 5, 20, 25, 0, 0, 0));
+        assert!(!is_gregorian_valid(2022, 5, 20, 24, 0, 1, 0));
+        assert!(!is_gregorian_valid(2022, 5, 20, 24, 59, 0, 0));
+        assert!(!is_gregorian_valid(2022, 5, 20, 24, 0, 0, 1));
+        assert!(!is_gregorian_valid(2022, 5, 20, 17, 57, 43, 1_000_000_000));
+        assert!(is_gregorian_valid(2022, 5, 20, 17, 57, 43, 999_999_999));
+        // The end of the day, but never a leap second
+        assert!(is_gregorian_valid(2022, 5, 20, 24, 0, 0, 0));
+        assert!(!is_gregorian_valid(2016, 12, 31, 24, 0, 60, 0));
+    }
+
+    #[test]
+    fn parts_in() {
+        let epochs = [
+            Epoch::from_gregorian_tai(2022, 5, 20, 17, 57, 43, 1),
+            Epoch::from_gregorian_tai(1815, 6, 18, 11, 30, 0, 999_999_999),
+            Epoch::from_tai_parts(3, 5),
+            Epoch::from_tai_parts(0, 0),
+        ];
+        for epoch in epochs {
+            assert_eq!(epoch.to_parts_in(TimeSystem::TAI), epoch.to_tai_parts());
+            for ts in [
+                TimeSystem::TAI,
+                TimeSystem::TT,
+                TimeSystem::ET,
+                TimeSystem::LORAN,
+                TimeSystem::TCG,
+                TimeSystem::UTC,
+            ] {
+                let (centuries, nanoseconds) = epoch.to_parts_in(ts);
+                assert_eq!(
+                    Epoch::from_parts_in(ts, centuries, nanoseconds),
+                    epoch,
+                    "{:?}",
+                    ts
+                );
+            }
+            for ts in [TimeSystem::TDB, TimeSystem::TCB] {
+                let (centuries, nanoseconds) = epoch.to_parts_in(ts);
+                let round_trip = Epoch::from_parts_in(ts, centuries, nanoseconds);
+                assert!(
+                    (round_trip - epoch).abs() <= Unit::Nanosecond * 1,
+                    "{:?}",
+                    ts
+                );
+            }
+            let (centuries, nanoseconds) = epoch.to_gpst_parts();
+            assert_eq!(Epoch::from_gpst_parts(centuries, nanoseconds), epoch);
+        }
+
+        // ET and TDB are counted from 1900 as the other time systems, i.e. 2022 is one century and
+        // a bit more than 22 years in
+        let e = Epoch::from_gregorian_tai(2022, 5, 20, 17, 57, 43, 1);
+        let (centuries, nanoseconds) = e.to_parts_in(TimeSystem::ET);
+        assert_eq!((centuries, nanoseconds), (1, 706_298_295_184_935_001));
+        let (centuries, nanoseconds) = e.to_parts_in(TimeSystem::TDB);
+        assert_eq!(centuries, 1);
+        assert!(nanoseconds.abs_diff(706_298_295_184_935_001) < 2_000_000);
+
+        // GPST is counted from the GPS Time Epoch, 19 seconds behind TAI
+        assert_eq!(
+            Epoch::from_gpst_parts(0, 0),
+            Epoch::from_gregorian_utc_at_midnight(1980, 1, 6)
+        );
+        assert_eq!(
+            Epoch::from_gpst_parts(-1, 3_155_759_999_999_999_999),
+            Epoch::from_gregorian_utc_at_midnight(1980, 1, 6) - Unit::Nanosecond * 1
+        );
+        let e = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 1);
+        let (centuries, nanoseconds) = e.to_parts_in(TimeSystem::UTC);
+        assert_eq!(e.as_utc_duration().to_parts(), (centuries, nanoseconds));
+        assert_eq!(
+            Epoch::from_parts_in(TimeSystem::UTC, centuries, nanoseconds),
+            e
+        );
+        assert_eq!(
+            Epoch::from_parts_in(TimeSystem::TT, 0, 32_184_000_000),
+            Epoch::from_tai_parts(0, 0)
+        );
+    }
+
+    #[test]
+    fn gregorian_carrying() {
+        let e = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+        assert_eq!(
+            Epoch::maybe_from_gregorian_utc(2022, 5, 20, 17, 57, 42, 1_000_000_000),
+            Err(Errors::Carry)
+        );
+        for (fields, expected) in [
+            ((2022, 5, 20, 17, 56, 103, 0), e),
+            ((2022, 5, 20, 17, 57, 42, 1_000_000_000), e),
+            ((2022, 5, 19, 41, 57, 43, 0), e),
+            ((2022, 5, 20, 18, -3, 43, 0), e),
+            ((2022, 4, 50, 17, 57, 43, 0), e),
+            ((2021, 17, 20, 17, 57, 43, 0), e),
+            ((2023, -7, 20, 17, 57, 43, 0), e),
+            ((2022, 5, 20, 0, 0, 64_663, 0), e),
+            (
+                (2022, 3, 0, 0, 0, 0, -1),
+                Epoch::from_gregorian_utc(2022, 2, 27, 23, 59, 59, 999_999_999),
+            ),
+        ] {
+            let (year, month, day, hour, minute, second, nanos) = fields;
+            assert_eq!(
+                Epoch::from_gregorian_carrying(
+                    year,
+                    month,
+                    day,
+                    hour,
+                    minute,
+                    second,
+                    nanos,
+                    TimeSystem::UTC
+                ),
+                expected,
+                "{:?}",
+                fields
+            );
+        }
+        // Valid fields are unchanged
+        assert_eq!(
+            Epoch::from_gregorian_carrying(2016, 12, 31, 23, 59, 59, 0, TimeSystem::TT),
+            Epoch::maybe_from_gregorian(2016, 12, 31, 23, 59, 59, 0, TimeSystem::TT).unwrap()
+        );
+    }
+
+    #[test]
+    fn end_of_day() {
+        let midnight = Epoch::from_gregorian_utc_at_midnight(2022, 5, 21);
+        assert_eq!(
+            Epoch::from_gregorian_utc_hms(2022, 5, 20, 24, 0, 0),
+            midnight
+        );
+        assert_eq!(
+            Epoch::from_gregorian_tai_hms(2024, 2, 28, 24, 0, 0),
+            Epoch::from_gregorian_tai_at_midnight(2024, 2, 29)
+        );
+        // At the end of a leap second day, after the leap second
+        assert_eq!(
+            Epoch::from_gregorian_utc_hms(2016, 12, 31, 24, 0, 0),
+            Epoch::from_gregorian_utc_at_midnight(2017, 1, 1)
+        );
+        #[cfg(feature = "std")]
+        {
+            use core::str::FromStr;
+            assert_eq!(Epoch::from_str("2022-05-20T24:00:00 UTC"), Ok(midnight));
+            assert_eq!(Epoch::from_str("2022-05-20T24:00:00"), Ok(midnight));
+            assert_eq!(
+                Epoch::from_str("2022-12-31T24:00:00 TAI"),
+                Ok(Epoch::from_gregorian_tai_at_midnight(2023, 1, 1))
+            );
+            assert_eq!(
+                Epoch::from_gregorian_str("2022-05-20T24:00:00.5 UTC"),
+                Err(Errors::Carry)
+            );
+            assert_eq!(
+                Epoch::from_gregorian_str("2022-05-20T24:01:00 UTC"),
+                Err(Errors::Carry)
+            );
+        }
     }
 
     #[test]
@@ -1896,32 +3638,481 @@ mod tests {
     }
 
     #[test]
-    fn unix() {
-        use core::f64::EPSILON;
-        let now = Epoch::from_gregorian_utc_hms(2022, 5, 2, 10, 39, 15);
-        assert!((now.as_unix_seconds() - 1651487955.0_f64).abs() < EPSILON);
-        assert!((now.as_unix_milliseconds() - 1651487955000.0_f64).abs() < EPSILON);
+    fn gregorian_tai_const() {
+        const START: Epoch = Epoch::from_gregorian_tai_const(2022, 5, 20, 17, 57, 43, 1);
+        assert_eq!(START, Epoch::from_gregorian_tai(2022, 5, 20, 17, 57, 43, 1));
+        const LEAP_DAY: Epoch =
+            Epoch::from_gregorian_tai_const(2020, 2, 29, 23, 59, 59, 999_999_999);
         assert_eq!(
-            Epoch::from_unix_seconds(now.as_unix_seconds()),
-            now,
-            "To/from UNIX seconds failed"
+            LEAP_DAY,
+            Epoch::from_gregorian_tai(2020, 2, 29, 23, 59, 59, 999_999_999)
         );
+        // Before the reference epoch of 1900
         assert_eq!(
-            Epoch::from_unix_milliseconds(now.as_unix_milliseconds()),
-            now,
-            "To/from UNIX milliseconds failed"
+            Epoch::from_gregorian_tai_const(1815, 6, 18, 11, 30, 0, 0),
+            Epoch::from_gregorian_tai(1815, 6, 18, 11, 30, 0, 0)
         );
+    }
 
-        let unix_epoch = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1);
-        #[cfg(feature = "std")]
-        {
-            assert_eq!(
-                unix_epoch.as_gregorian_str(TimeSystem::UTC),
-                "1970-01-01T00:00:00 UTC"
-            );
-            assert_eq!(
-                unix_epoch.as_gregorian_str(TimeSystem::TAI),
-                "1970-01-01T00:00:00 TAI"
+    #[test]
+    #[should_panic]
+    fn gregorian_tai_const_invalid() {
+        let _ = Epoch::from_gregorian_tai_const(2022, 2, 29, 0, 0, 0, 0);
+    }
+
+    #[test]
+    fn glonasst() {
+        // GLONASST started three hours before midnight UTC on January 1st 1996
+        let glonasst_epoch = Epoch::from_gregorian_utc(1995, 12, 31, 21, 0, 0, 0);
+        assert_eq!(glonasst_epoch, Epoch::from_glonasst_seconds(0.0));
+        assert_eq!(glonasst_epoch, Epoch::from_glonasst_days(0.0));
+        assert_eq!(glonasst_epoch, Epoch::from_glonasst_nanoseconds(0));
+        assert_eq!(glonasst_epoch.as_glonasst_duration(), Unit::Second * 0);
+        assert_eq!(glonasst_epoch.as_glonasst_nanoseconds(), Ok(0));
+        assert_eq!(
+            Epoch::from_glonasst_days(1.0),
+            Epoch::from_gregorian_utc_hms(1996, 1, 1, 21, 0, 0)
+        );
+
+        // GLONASST reads UTC + 3 hours, including around the leap second at the end of 2016
+        let leap_instant = Epoch::from_gregorian_tai_hms(2017, 1, 1, 0, 0, 37);
+        let before = leap_instant - Unit::Second * 2;
+        assert_eq!(
+            leap_instant.as_glonasst_duration() - before.as_glonasst_duration(),
+            Unit::Second * 1,
+            "GLONASST does not skip the leap second"
+        );
+        let day = Unit::Day * 7_671;
+        assert_eq!(leap_instant.as_glonasst_duration(), day + Unit::Hour * 3);
+        assert_eq!(
+            Epoch::from_glonasst_duration(day + Unit::Hour * 3),
+            leap_instant
+        );
+        assert_eq!(
+            Epoch::from_glonasst_duration(day + Unit::Hour * 3 - Unit::Second * 1),
+            before
+        );
+
+        let now = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
+        assert_eq!(
+            Epoch::from_glonasst_nanoseconds(now.as_glonasst_nanoseconds().unwrap()),
+            now,
+            "To/from GLONASST nanoseconds failed"
+        );
+        assert_eq!(
+            Epoch::from_glonasst_duration(now.as_glonasst_duration()),
+            now
+        );
+        assert!(
+            (now.as_glonasst_seconds() - now.as_glonasst_duration().in_seconds()).abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (Epoch::from_glonasst_seconds(now.as_glonasst_seconds()) - now).abs()
+                < Unit::Microsecond
+        );
+        assert!(
+            (Epoch::from_glonasst_days(now.as_glonasst_days()) - now).abs() < Unit::Microsecond
+        );
+
+        // Before the GLONASST epoch
+        let epoch = Epoch::from_gregorian_utc_at_midnight(1995, 12, 31);
+        assert_eq!(epoch.as_glonasst_duration(), Unit::Hour * -21);
+        assert_eq!(epoch.as_glonasst_nanoseconds(), Err(Errors::Overflow));
+    }
+
+    #[test]
+    fn gst() {
+        // GST started at GPS week 1024, i.e. 13 leap seconds before midnight UTC
+        let gst_epoch = Epoch::from_gregorian_utc(1999, 8, 21, 23, 59, 47, 0);
+        assert_eq!(
+            gst_epoch,
+            Epoch::from_gregorian_tai_hms(1999, 8, 22, 0, 0, 19)
+        );
+        assert_eq!(gst_epoch, Epoch::from_gst_seconds(0.0));
+        assert_eq!(gst_epoch, Epoch::from_gst_days(0.0));
+        assert_eq!(gst_epoch, Epoch::from_gst_nanoseconds(0));
+        assert_eq!(gst_epoch.as_gst_duration(), Unit::Second * 0);
+        assert_eq!(gst_epoch.as_gst_nanoseconds(), Ok(0));
+        assert_eq!(
+            gst_epoch.as_gpst_duration(),
+            Unit::Day * (1024 * 7),
+            "GST epoch is not GPS week 1024"
+        );
+        assert_eq!(
+            gst_epoch,
+            crate::GnssTimeScale::GST.reference_epoch(),
+            "GST epoch differs from the GNSS reference epoch"
+        );
+
+        let now = Epoch::from_gregorian_tai_hms(2019, 8, 24, 3, 49, 9);
+        assert_eq!(
+            Epoch::from_gst_nanoseconds(now.as_gst_nanoseconds().unwrap()),
+            now,
+            "To/from GST nanoseconds failed"
+        );
+        assert_eq!(
+            now.as_gpst_duration() - now.as_gst_duration(),
+            Unit::Day * (1024 * 7)
+        );
+        assert!((now.as_gst_seconds() - now.as_gst_duration().in_seconds()).abs() < f64::EPSILON);
+        assert!((Epoch::from_gst_seconds(now.as_gst_seconds()) - now).abs() < Unit::Microsecond);
+        assert!((Epoch::from_gst_days(now.as_gst_days()) - now).abs() < Unit::Microsecond);
+        // GST is aligned with GPST, so their Julian dates match
+        assert_eq!(
+            Epoch::from_mjd_gst(59_000.0),
+            Epoch::from_mjd_gpst(59_000.0)
+        );
+        assert_eq!(
+            Epoch::from_jde_gst(2_459_000.5),
+            Epoch::from_jde_gpst(2_459_000.5)
+        );
+
+        // Before the GST epoch
+        let epoch = Epoch::from_gregorian_utc_at_midnight(1999, 8, 1);
+        assert!(epoch.as_gst_seconds() < 0.0);
+        assert_eq!(epoch.as_gst_nanoseconds(), Err(Errors::Overflow));
+
+        #[cfg(feature = "std")]
+        {
+            use core::str::FromStr;
+            assert_eq!(Epoch::from_str("SEC 0.0 GST").unwrap(), gst_epoch);
+            assert_eq!(
+                Epoch::from_str("SEC 86400.5 GST").unwrap(),
+                gst_epoch + Unit::Day * 1 + Unit::Millisecond * 500
+            );
+            assert_eq!(
+                Epoch::from_str("MJD 51412.0 GST").unwrap(),
+                Epoch::from_mjd_gst(51_412.0)
+            );
+            assert_eq!(
+                Epoch::from_str("JD 2451412.5 GST").unwrap(),
+                Epoch::from_jde_gst(2_451_412.5)
+            );
+        }
+    }
+
+    #[test]
+    fn tai58_j1950() {
+        assert_eq!(
+            TAI58_REF_EPOCH,
+            Epoch::from_gregorian_tai_at_midnight(1958, 1, 1)
+        );
+        assert_eq!(
+            J1950_REF_EPOCH,
+            Epoch::maybe_from_gregorian(1950, 1, 1, 0, 0, 0, 0, TimeSystem::TT).unwrap()
+        );
+        // J1950 is 50 Julian years before J2000
+        assert_eq!(
+            Epoch::from_tt_seconds(ET_EPOCH_S as f64).as_tt_since_j1950(),
+            Unit::Day * 18_262.5
+        );
+
+        let epoch = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
+        assert_eq!(
+            epoch.as_tai58_duration(),
+            epoch - Epoch::from_gregorian_tai_at_midnight(1958, 1, 1)
+        );
+        assert_eq!(Epoch::from_tai58_duration(epoch.as_tai58_duration()), epoch);
+        assert!(
+            (Epoch::from_tai58_seconds(epoch.as_tai58_seconds()) - epoch).abs()
+                < Unit::Microsecond * 1
+        );
+        // The LORAN epoch is 10 seconds after the TAI58 epoch
+        assert_eq!(Epoch::from_loran_seconds(0.0).as_tai58_seconds(), 10.0);
+        assert_eq!(
+            Epoch::from_tt_since_j1950(Unit::Day * 2.5).as_tt_days_since_j1950(),
+            2.5
+        );
+    }
+
+    #[test]
+    fn loran() {
+        // The LORAN epoch is 10 seconds after midnight TAI, on January 1st 1958
+        let loran_epoch = Epoch::from_gregorian_tai(1958, 1, 1, 0, 0, 10, 0);
+        assert_eq!(Epoch::from_loran_seconds(0.0), loran_epoch);
+        assert_eq!(loran_epoch.as_loran_duration(), Unit::Second * 0);
+        assert_eq!(
+            loran_epoch.as_gregorian(TimeSystem::LORAN),
+            (1958, 1, 1, 0, 0, 0, 0)
+        );
+
+        // LORAN time is 9 seconds ahead of GPS time, and 27 seconds ahead of UTC since 2017
+        let epoch = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+        assert_eq!(
+            Epoch::from_gpst_seconds(0.0).as_loran_duration(),
+            Unit::Day * 8_040 + Unit::Second * 9
+        );
+        assert_eq!(
+            epoch.as_gregorian(TimeSystem::LORAN),
+            (2022, 5, 20, 17, 58, 10, 0)
+        );
+        assert_eq!(
+            Epoch::maybe_from_gregorian(2022, 5, 20, 17, 58, 10, 0, TimeSystem::LORAN).unwrap(),
+            epoch
+        );
+        assert_eq!(Epoch::from_loran_duration(epoch.as_loran_duration()), epoch);
+        assert!(
+            (Epoch::from_loran_seconds(epoch.as_loran_seconds()) - epoch).abs()
+                < Unit::Microsecond * 1
+        );
+
+        #[cfg(feature = "std")]
+        {
+            use std::str::FromStr;
+            let greg = "2022-05-20T17:58:10 LORAN";
+            assert_eq!(Epoch::from_str(greg).unwrap(), epoch);
+            assert_eq!(epoch.as_gregorian_str(TimeSystem::LORAN), greg);
+            assert_eq!(
+                Epoch::from_str("SEC 10.0 LORAN").unwrap(),
+                loran_epoch + Unit::Second * 10
+            );
+            assert_eq!(
+                Epoch::from_str("MJD 59720.0 LORAN").unwrap(),
+                Epoch::from_mjd_tai(59720.0) + Unit::Second * 10
+            );
+        }
+    }
+
+    #[test]
+    fn tcg() {
+        // TCG and TT coincide on 1977 January 01 at midnight TAI
+        let t0 = Epoch::from_gregorian_tai_at_midnight(1977, 1, 1);
+        assert_eq!(t0.as_tcg_duration(), t0.as_tt_duration());
+        assert_eq!(Epoch::from_tcg_duration(t0.as_tcg_duration()), t0);
+        let (y, mm, dd, hh, min, s, _) = t0.as_gregorian(TimeSystem::TCG);
+        assert_eq!((y, mm, dd, hh, min, s), (1977, 1, 1, 0, 0, 32));
+
+        // TCG - TT = L_G / (1 - L_G) × (TT - T0), where T0 is JD 2443144.5003725 TT
+        let epoch = Epoch::from_jde_tt(2_453_750.892_482_639);
+        let tcg_tt = epoch.as_tcg_duration() - epoch.as_tt_duration();
+        assert!((tcg_tt.in_seconds() - 0.638_660_366_859).abs() < 1e-8);
+        assert!((epoch.as_jde_tcg_days() - 2_453_750.892_490_031).abs() < 1e-8);
+        assert!((epoch.as_mjd_tcg_days() - 53_750.392_490_031).abs() < 1e-8);
+        // TCG is behind TT before T0
+        let before = Epoch::from_gregorian_tai_at_midnight(1957, 1, 1);
+        assert!(before.as_tcg_duration() < before.as_tt_duration());
+
+        for epoch in [epoch, before, t0 + Unit::Nanosecond * 1] {
+            let round_trip = Epoch::from_tcg_duration(epoch.as_tcg_duration());
+            assert!((round_trip - epoch).abs() <= Unit::Nanosecond * 1);
+            assert!(
+                (Epoch::from_tcg_seconds(epoch.as_tcg_seconds()) - epoch).abs()
+                    < Unit::Microsecond * 1
+            );
+            assert!(
+                (Epoch::from_mjd_tcg(epoch.as_mjd_tcg_days()) - epoch).abs()
+                    < Unit::Microsecond * 100
+            );
+            assert!(
+                (Epoch::from_jde_tcg(epoch.as_jde_tcg_days()) - epoch).abs()
+                    < Unit::Microsecond * 100
+            );
+        }
+        assert_eq!(
+            Epoch::maybe_from_gregorian(1977, 1, 1, 0, 0, 32, 184_000_000, TimeSystem::TCG),
+            Ok(t0)
+        );
+
+        #[cfg(feature = "std")]
+        {
+            use std::str::FromStr;
+            assert_eq!(TimeSystem::from_str("TCG"), Ok(TimeSystem::TCG));
+            let s = format!("SEC {} TCG", epoch.as_tcg_seconds());
+            let parsed = Epoch::from_str(&s).unwrap();
+            assert!((parsed - epoch).abs() < Unit::Microsecond * 1);
+            assert_eq!(format!("SEC {} TCG", parsed.as_tcg_seconds()), s);
+            assert_eq!(
+                Epoch::from_str("MJD 43144.0003725 TCG").unwrap(),
+                Epoch::from_mjd_tcg(43_144.000_372_5)
+            );
+            assert_eq!(
+                Epoch::from_str("JD_TCG 2443144.5003725").unwrap(),
+                Epoch::from_jde_tcg(2_443_144.500_372_5)
+            );
+            assert!(t0.as_gregorian_str(TimeSystem::TCG).ends_with(" TCG"));
+            assert_eq!(Epoch::from_str("1977-01-01T00:00:32.184 TCG").unwrap(), t0);
+        }
+    }
+
+    #[test]
+    fn tcb() {
+        // TCB is ahead of TDB by -TDB_0 on 1977 January 01 at midnight TAI
+        let t0 = Epoch::from_gregorian_tai_at_midnight(1977, 1, 1);
+        let tdb = |e: Epoch| e.as_jde_tdb_duration() - crate::JDE_OFFSET_DURATION;
+        assert_eq!(t0.as_tcb_duration() - tdb(t0), Unit::Microsecond * 65.5);
+
+        // TCB - TDB = L_B / (1 - L_B) × (TDB - TDB_0 - T0) - TDB_0, where T0 is JD 2443144.5003725
+        let epoch = Epoch::from_jde_tt(2_453_750.892_855_137);
+        let tcb_tdb = epoch.as_tcb_duration() - tdb(epoch);
+        assert!((tcb_tdb.in_seconds() - 14.208_909_647).abs() < 1e-6);
+        assert!(
+            (epoch.as_jde_tcb_days() - epoch.as_jde_tdb_days() - 14.208_909_647 / 86_400.0).abs()
+                < 1e-9
+        );
+        // TCB is behind TDB long before T0
+        let before = Epoch::from_gregorian_tai_at_midnight(1957, 1, 1);
+        assert!(before.as_tcb_duration() < tdb(before));
+
+        for epoch in [epoch, before, t0] {
+            let round_trip = Epoch::from_tcb_duration(epoch.as_tcb_duration());
+            assert!((round_trip - epoch).abs() <= Unit::Nanosecond * 2);
+            assert!(
+                (Epoch::from_tcb_seconds(epoch.as_tcb_seconds()) - epoch).abs()
+                    < Unit::Microsecond * 1
+            );
+            assert!(
+                (Epoch::from_mjd_tcb(epoch.as_mjd_tcb_days()) - epoch).abs()
+                    < Unit::Microsecond * 100
+            );
+            assert!(
+                (Epoch::from_jde_tcb(epoch.as_jde_tcb_days()) - epoch).abs()
+                    < Unit::Microsecond * 100
+            );
+            assert!((epoch.as_tcb_days() - epoch.as_tcb_seconds() / 86_400.0).abs() < 1e-9);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            use std::str::FromStr;
+            assert_eq!(TimeSystem::from_str("TCB"), Ok(TimeSystem::TCB));
+            let s = format!("SEC {} TCB", epoch.as_tcb_seconds());
+            let parsed = Epoch::from_str(&s).unwrap();
+            assert!((parsed - epoch).abs() < Unit::Microsecond * 1);
+            assert_eq!(format!("SEC {} TCB", parsed.as_tcb_seconds()), s);
+            assert_eq!(
+                Epoch::from_str("MJD 53750.5 TCB").unwrap(),
+                Epoch::from_mjd_tcb(53_750.5)
+            );
+            assert_eq!(
+                Epoch::from_str("JD_TCB 2453751.0").unwrap(),
+                Epoch::from_jde_tcb(2_453_751.0)
+            );
+            let greg = epoch.as_gregorian_str(TimeSystem::TCB);
+            assert!(greg.starts_with("2006-01-15T09:25:") && greg.ends_with(" TCB"));
+            let parsed = Epoch::from_str("2006-01-15T09:25:00 TCB").unwrap();
+            assert_eq!(parsed.as_gregorian(TimeSystem::TCB).4, 25);
+            assert!(
+                (parsed.as_tcb_duration()
+                    - Epoch::from_gregorian_tai(2006, 1, 15, 9, 25, 0, 0).as_tai_duration())
+                .abs()
+                    <= Unit::Nanosecond * 2
+            );
+        }
+    }
+
+    #[test]
+    fn ymd_sod() {
+        let e = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 123_456_789);
+        assert_eq!(
+            e.as_ymd_sod(TimeSystem::UTC),
+            (2022, 5, 20, 64_663.123_456_789)
+        );
+        assert_eq!(
+            Epoch::from_ymd_sod(2022, 5, 20, 64_663.123_456_789, TimeSystem::UTC),
+            e
+        );
+        assert_eq!(
+            e.as_ymd_sod(TimeSystem::TAI),
+            (2022, 5, 20, 64_700.123_456_789)
+        );
+        assert_eq!(
+            e.as_ymd_sod(TimeSystem::TT),
+            (2022, 5, 20, 64_732.307_456_789)
+        );
+        assert_eq!(
+            e.as_ymd_sod(TimeSystem::LORAN),
+            (2022, 5, 20, 64_690.123_456_789)
+        );
+        for ts in [
+            TimeSystem::TAI,
+            TimeSystem::TT,
+            TimeSystem::ET,
+            TimeSystem::TDB,
+            TimeSystem::UTC,
+            TimeSystem::LORAN,
+            TimeSystem::TCG,
+            TimeSystem::TCB,
+        ] {
+            let (y, mm, dd, sod) = e.as_ymd_sod(ts);
+            assert_eq!((y, mm, dd), (2022, 5, 20), "{:?}", ts);
+            let round_trip = Epoch::from_ymd_sod(y, mm, dd, sod, ts);
+            assert!((round_trip - e).abs() <= Unit::Nanosecond * 1, "{:?}", ts);
+        }
+        // ET and TDB are about 69 seconds ahead of UTC in 2022
+        let (_, _, _, sod) = e.as_ymd_sod(TimeSystem::ET);
+        assert!((sod - 64_663.123_456_789 - 69.184_935).abs() < 1e-6);
+        let (_, _, _, tdb_sod) = e.as_ymd_sod(TimeSystem::TDB);
+        assert!((tdb_sod - sod).abs() < 2e-3);
+        assert_eq!(
+            Epoch::from_ymd_sod(2022, 5, 20, 0.0, TimeSystem::ET),
+            Epoch::from_gregorian_utc(2022, 5, 19, 23, 58, 50, 815_065_000)
+        );
+
+        // Midnight, before 1900, and the rounding of the seconds of day to the next day
+        assert_eq!(
+            Epoch::from_gregorian_tai_at_midnight(1850, 3, 1).as_ymd_sod(TimeSystem::TAI),
+            (1850, 3, 1, 0.0)
+        );
+        assert_eq!(
+            Epoch::from_ymd_sod(2022, 5, 20, 86_399.999_999_999_9, TimeSystem::TAI),
+            Epoch::from_gregorian_tai_at_midnight(2022, 5, 21)
+        );
+
+        // The leap second of a UTC day which ends with one
+        assert_eq!(
+            Epoch::from_ymd_sod(2016, 12, 31, 86_400.5, TimeSystem::UTC),
+            Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000)
+        );
+        assert_eq!(
+            Epoch::maybe_from_ymd_sod(2016, 12, 31, 86_400.5, TimeSystem::TAI),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            Epoch::maybe_from_ymd_sod(2016, 12, 30, 86_400.5, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            Epoch::maybe_from_ymd_sod(2022, 2, 29, 0.0, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            Epoch::maybe_from_ymd_sod(2022, 2, 28, -1.0, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            Epoch::maybe_from_ymd_sod(2022, 2, 28, f64::NAN, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+    }
+
+    #[test]
+    fn unix() {
+        use core::f64::EPSILON;
+        let now = Epoch::from_gregorian_utc_hms(2022, 5, 2, 10, 39, 15);
+        assert!((now.as_unix_seconds() - 1651487955.0_f64).abs() < EPSILON);
+        assert!((now.as_unix_milliseconds() - 1651487955000.0_f64).abs() < EPSILON);
+        assert_eq!(
+            Epoch::from_unix_seconds(now.as_unix_seconds()),
+            now,
+            "To/from UNIX seconds failed"
+        );
+        assert_eq!(
+            Epoch::from_unix_milliseconds(now.as_unix_milliseconds()),
+            now,
+            "To/from UNIX milliseconds failed"
+        );
+
+        let unix_epoch = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1);
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(
+                unix_epoch.as_gregorian_str(TimeSystem::UTC),
+                "1970-01-01T00:00:00 UTC"
+            );
+            assert_eq!(
+                unix_epoch.as_gregorian_str(TimeSystem::TAI),
+                "1970-01-01T00:00:00 TAI"
             );
             // Print as UNIX seconds
             assert_eq!(format!("{:p}", unix_epoch), "0");
@@ -1947,6 +4138,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mjd_tdb_et() {
+        for mjd in [
+            51_544.5,
+            52_312.000_372_511,
+            59_719.748_414_351,
+            45_000.25,
+            70_000.0,
+        ] {
+            let tdb = Epoch::from_mjd_tdb(mjd);
+            assert!((tdb.as_mjd_tdb_days() - mjd).abs() < 1e-11, "{}", mjd);
+            assert_eq!(Epoch::from_tdb_duration(tdb.as_tdb_duration()), tdb);
+            let et = Epoch::from_mjd_et(mjd);
+            assert!((et.as_mjd_et_days() - mjd).abs() < 1e-11, "{}", mjd);
+            assert_eq!(Epoch::from_et_duration(et.as_et_duration()), et);
+            assert_eq!(et, Epoch::from_et_seconds(et.as_et_seconds()));
+            // TDB and ET only differ by the periodic term
+            assert!((tdb - et).abs() < Unit::Millisecond * 2);
+        }
+    }
+
     #[test]
     fn spice_et_tdb() {
         use crate::J2000_NAIF;
@@ -2025,9 +4237,38 @@ mod tests {
         assert!((J2000_NAIF - sp_ex.as_jde_tdb_days()).abs() < 1e-7);
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_rfc3339() {
+        use crate::TimeUnits;
+        let midnight = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+        let leap = midnight - 500.milliseconds();
+        assert_eq!(leap.as_rfc3339(), "2016-12-31T23:59:60.500000000Z");
+        assert_eq!(leap.as_rfc3339_with_precision(1), "2016-12-31T23:59:60.5Z");
+        assert_eq!(
+            (midnight + 1.nanoseconds()).as_rfc3339_with_precision(12),
+            "2017-01-01T00:00:00.000000001Z"
+        );
+        assert_eq!(
+            (midnight + 999.milliseconds()).as_rfc3339_with_precision(2),
+            "2017-01-01T00:00:00.99Z"
+        );
+        // The fractional seconds are always printed to the requested precision
+        assert_eq!(midnight.as_rfc3339(), "2017-01-01T00:00:00.000000000Z");
+        assert_eq!(
+            midnight.as_rfc3339_with_precision(3),
+            "2017-01-01T00:00:00.000Z"
+        );
+        assert_eq!(
+            midnight.as_rfc3339_with_precision(0),
+            "2017-01-01T00:00:00Z"
+        );
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_from_str() {
+        use crate::ParsingErrors;
         use core::f64::EPSILON;
         use std::str::FromStr;
 
@@ -2060,6 +4301,59 @@ mod tests {
         );
         assert!((Epoch::from_str("SEC 0.5 TAI").unwrap().as_tai_seconds() - 0.5).abs() < EPSILON);
 
+        // MJD and JD in TT, TDB, ET and GPST
+        let mjd_tt = Epoch::from_str("MJD 59720.0 TT").unwrap();
+        assert!((mjd_tt.as_mjd_tt_days() - 59720.0).abs() < SPICE_EPSILON);
+        assert_eq!(
+            mjd_tt,
+            Epoch::from_mjd_tai(59720.0) - Unit::Millisecond * crate::TT_OFFSET_MS
+        );
+        let jd_tt = Epoch::from_str("JD 2459720.5 TT").unwrap();
+        assert!((jd_tt.as_jde_tt_days() - 2_459_720.5).abs() < SPICE_EPSILON);
+        assert!((mjd_tt - jd_tt).abs() < Unit::Microsecond * 1);
+        let mjd_tdb = Epoch::from_str("MJD 52312.000372511 TDB").unwrap();
+        // JD days in f64 are only precise to tens of microseconds
+        assert!((mjd_tdb - as_tdb).abs() < Unit::Microsecond * 100);
+        assert!((mjd_tdb.as_mjd_tdb_days() - 52_312.000_372_511).abs() < SPICE_EPSILON);
+        let mjd_et = Epoch::from_str("MJD 52312.000372511 ET").unwrap();
+        assert!((mjd_et - as_et).abs() < Unit::Microsecond * 100);
+        assert!((mjd_et.as_mjd_et_days() - 52_312.000_372_511).abs() < SPICE_EPSILON);
+        let gpst = Epoch::from_str("MJD 59720.0 GPST").unwrap();
+        assert_eq!(gpst, Epoch::from_mjd_tai(59720.0) + Unit::Second * 19);
+        let jd_gpst = Epoch::from_str("JD 2459720.5 GPST").unwrap();
+        assert!((jd_gpst - gpst).abs() < Unit::Microsecond * 1);
+        assert_eq!(
+            Epoch::from_str("SEC 86400.0 GPST").unwrap(),
+            Epoch::from_gpst_days(1.0)
+        );
+        assert!(Epoch::from_str("MJD 59720.0 GPS").is_err());
+
+        // Time system combined with the format
+        assert_eq!(Epoch::from_str("JDTT 2459720.5").unwrap(), jd_tt);
+        assert_eq!(Epoch::from_str("JD_TT 2459720.5").unwrap(), jd_tt);
+        assert_eq!(Epoch::from_str("MJD_GPST 59720.0").unwrap(), gpst);
+        assert_eq!(
+            Epoch::from_str("MJD_UTC 59000").unwrap(),
+            Epoch::from_mjd_utc(59000.0)
+        );
+        assert_eq!(
+            Epoch::from_str("SECTAI 0.5").unwrap(),
+            Epoch::from_tai_seconds(0.5)
+        );
+        assert_eq!(
+            Epoch::from_str("JD_UT1 2459720.5"),
+            Err(Errors::ParseError(ParsingErrors::TimeSystem))
+        );
+        // Exactly one time system must be provided
+        assert_eq!(
+            Epoch::from_str("MJD_UTC 59000.0 TAI"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            Epoch::from_str("MJD 59000.0"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+
         // Must account for the precision error
         assert!(
             (Epoch::from_str("SEC 66312032.18493909 TDB")
@@ -2099,7 +4393,7 @@ mod tests {
     #[test]
     fn test_range() {
         let start = Epoch::from_gregorian_utc_hms(2012, 2, 7, 11, 22, 33);
-        let middle = Epoch::from_gregorian_utc_hms(2012, 2, 30, 0, 11, 22);
+        let middle = Epoch::from_gregorian_utc_hms(2012, 2, 29, 0, 11, 22);
         let end = Epoch::from_gregorian_utc_hms(2012, 3, 7, 11, 22, 33);
         let rng = start..end;
         assert_eq!(rng, core::ops::Range { start, end });
@@ -2147,6 +4441,291 @@ mod tests {
         assert_eq!(epoch_from_utc_greg1.get_num_leap_seconds(), 11);
     }
 
+    #[test]
+    fn test_tai_utc_offset_at() {
+        use crate::{gps_utc_offset_at, tai_utc_offset_at, TimeUnits};
+        // Prior to 1972, there is no offset
+        let before = Epoch::from_gregorian_utc_hms(1971, 12, 31, 23, 59, 59);
+        assert_eq!(tai_utc_offset_at(before), 0.seconds());
+        assert_eq!(gps_utc_offset_at(before), -19.seconds());
+
+        for (expected, utc_ts) in (10..).zip(LEAP_SECONDS.iter()) {
+            // The leap instant in TAI is the UTC time of the leap plus the new offset.
+            let leap_instant =
+                Epoch::from_tai_duration(Unit::Second * *utc_ts) + expected * Unit::Second;
+            assert_eq!(
+                tai_utc_offset_at(leap_instant),
+                expected * Unit::Second,
+                "wrong offset at {}",
+                leap_instant
+            );
+            assert_eq!(
+                gps_utc_offset_at(leap_instant),
+                (expected - 19) * Unit::Second
+            );
+            // One nanosecond before the leap instant, we're in the leap second itself, so the previous offset applies.
+            let previous = if expected == 10 { 0 } else { expected - 1 };
+            assert_eq!(
+                tai_utc_offset_at(leap_instant - 1.nanoseconds()),
+                previous * Unit::Second,
+                "wrong offset just before {}",
+                leap_instant
+            );
+            // Same thing one second before the leap instant
+            assert_eq!(
+                tai_utc_offset_at(leap_instant - 1.seconds()),
+                previous * Unit::Second
+            );
+            // The leap instant is midnight UTC
+            assert_eq!(
+                leap_instant,
+                Epoch::from_utc_seconds(*utc_ts as f64),
+                "leap instant is not midnight UTC"
+            );
+        }
+
+        // Before and after the 2016 leap second, in UTC
+        assert_eq!(
+            tai_utc_offset_at(Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 59)),
+            36.seconds()
+        );
+        assert_eq!(
+            tai_utc_offset_at(Epoch::from_gregorian_utc_at_midnight(2017, 1, 1)),
+            37.seconds()
+        );
+        assert_eq!(
+            gps_utc_offset_at(Epoch::from_gregorian_utc_at_midnight(2022, 5, 20)),
+            18.seconds()
+        );
+    }
+
+    #[test]
+    fn test_epoch_ops_symmetry() {
+        use crate::TimeUnits;
+        let e = Epoch::from_gregorian_tai_at_midnight(2022, 5, 20);
+        // Adding seconds as f64 rounds through the seconds since 1900
+        assert!((e + 1.5 - (e + 1.5.seconds())).abs() < 1.microseconds());
+        assert_eq!(1.5 + e, e + 1.5);
+        assert_eq!(e - 1.5, e + (-1.5));
+        let mut e2 = e;
+        e2 += 1.5;
+        assert_eq!(e2, e + 1.5);
+        e2 -= 1.5;
+        assert_eq!(e2, e + 1.5 - 1.5);
+        assert_eq!(1.hours() + e, e + 1.hours());
+        assert_eq!(Unit::Day + e, e + Unit::Day);
+    }
+
+    #[test]
+    fn test_epoch_ref_ops() {
+        use crate::TimeUnits;
+        let (e, d) = (
+            &Epoch::from_gregorian_tai_at_midnight(2022, 5, 20),
+            &1.hours(),
+        );
+        let later = &(e + d);
+        assert_eq!(*later, *e + 1.hours());
+        assert_eq!(later - e, *d);
+        assert_eq!(*later - e, *d);
+        assert_eq!(later - *e, *d);
+        assert_eq!(later - d, *e);
+        assert_eq!(d + e, *later);
+        assert_eq!(e + Unit::Hour, *later);
+        assert_eq!(later - Unit::Hour, *e);
+        assert_eq!(e + 1.5, *e + 1.5);
+        assert_eq!(e - 1.5, *e - 1.5);
+        let mut e2 = *e;
+        e2 += d;
+        assert_eq!(e2, *later);
+        e2 -= d;
+        assert_eq!(e2, *e);
+    }
+
+    #[test]
+    fn test_utc_elapsed() {
+        use crate::{TimeUnits, UtcElapsed};
+        // Across the 2016 leap second
+        let start = Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 59);
+        let end = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+        let elapsed = UtcElapsed::between(start, end);
+        assert_eq!(elapsed.elapsed, 2.seconds());
+        assert_eq!(elapsed.civil, 1.seconds());
+        assert_eq!(elapsed.leap_seconds(), 1.seconds());
+        // Backward
+        let elapsed = UtcElapsed::between(end, start);
+        assert_eq!(elapsed.elapsed, -(2.seconds()));
+        assert_eq!(elapsed.civil, -(1.seconds()));
+        // Across all of the leap seconds since 1972
+        let elapsed = UtcElapsed::between(
+            Epoch::from_gregorian_utc_at_midnight(1972, 1, 1),
+            Epoch::from_gregorian_utc_at_midnight(2022, 1, 1),
+        );
+        assert_eq!(elapsed.leap_seconds(), 27.seconds());
+        assert_eq!(elapsed.civil, 18_263.days());
+        // Without any leap second
+        let elapsed = UtcElapsed::between(
+            Epoch::from_gregorian_utc_at_midnight(2018, 1, 1),
+            Epoch::from_gregorian_utc_at_midnight(2022, 1, 1),
+        );
+        assert_eq!(elapsed.elapsed, elapsed.civil);
+
+        #[cfg(feature = "std")]
+        {
+            use crate::utc_elapsed_between;
+            let elapsed =
+                utc_elapsed_between("2016-12-31T12:00:00", "2017-01-01T12:00:00 UTC").unwrap();
+            assert_eq!(elapsed.elapsed, 86_401.seconds());
+            assert_eq!(elapsed.civil, 86_400.seconds());
+            assert!(utc_elapsed_between("2016-12-31", "2017-01-01T12:00:00").is_err());
+        }
+    }
+
+    #[cfg(feature = "trace-conversions")]
+    #[test]
+    fn test_trace_conversions() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        static EVENTS: AtomicUsize = AtomicUsize::new(0);
+
+        /// Counts the conversion events
+        struct Counter;
+
+        impl Subscriber for Counter {
+            fn enabled(&self, metadata: &Metadata) -> bool {
+                metadata.target() == "hifitime::conversions"
+            }
+            fn new_span(&self, _: &Attributes) -> Id {
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, _: &Record) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event) {
+                EVENTS.fetch_add(1, Ordering::SeqCst);
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        tracing::dispatcher::set_global_default(tracing::Dispatch::new(Counter)).unwrap();
+
+        // Other tests may run concurrently, so only check that each conversion emits events.
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+        for convert in [
+            Epoch::as_utc_duration,
+            Epoch::as_tt_duration,
+            Epoch::as_et_duration,
+            Epoch::as_tdb_duration,
+        ] {
+            let before = EVENTS.load(Ordering::SeqCst);
+            convert(&epoch);
+            assert!(EVENTS.load(Ordering::SeqCst) > before);
+        }
+        let before = EVENTS.load(Ordering::SeqCst);
+        let _ = Epoch::from_tdb_seconds(epoch.as_tdb_seconds());
+        assert!(EVENTS.load(Ordering::SeqCst) >= before + 2);
+    }
+
+    #[test]
+    fn test_try_from_gregorian() {
+        use core::convert::TryFrom;
+        let epoch = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0);
+        assert_eq!(Epoch::try_from((2016, 12, 31, 23, 59, 60, 0)), Ok(epoch));
+        assert_eq!(Epoch::try_from([2016, 12, 31, 23, 59, 60]), Ok(epoch));
+        let epoch = Epoch::from_gregorian_utc_at_noon(1900, 1, 1);
+        assert_eq!(Epoch::try_from(epoch.as_gregorian_utc()), Ok(epoch));
+        assert_eq!(
+            Epoch::try_from((2017, 12, 31, 23, 59, 60, 0)),
+            Err(Errors::Carry)
+        );
+        assert_eq!(Epoch::try_from([2022, 13, 1, 0, 0, 0]), Err(Errors::Carry));
+        assert_eq!(Epoch::try_from([2022, 1, 1, 0, 300, 0]), Err(Errors::Carry));
+        assert_eq!(
+            Epoch::try_from([u32::MAX, 1, 1, 0, 0, 0]),
+            Err(Errors::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_gregorian_fractional() {
+        let epoch = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 250_000_000);
+        let (y, mm, dd, hh, min, s) = epoch.as_gregorian_fractional(TimeSystem::UTC);
+        assert_eq!((y, mm, dd, hh, min), (2022, 5, 20, 17, 57));
+        assert!((s - 43.25).abs() < 1e-6);
+        let epoch = Epoch::from_gregorian_tai_at_midnight(2022, 5, 20);
+        assert_eq!(
+            epoch.as_gregorian_fractional(TimeSystem::TAI),
+            (2022, 5, 20, 0, 0, 0.0)
+        );
+        let (_, _, _, _, _, s) = epoch.as_gregorian_fractional(TimeSystem::TT);
+        assert!((s - 32.184).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_leap_second_table_expiry() {
+        let expiry = leap_second_table_expiry();
+        assert_eq!(expiry.as_gregorian_utc(), (2026, 6, 28, 0, 0, 0, 0));
+        // The expiry is after the last leap second of the table
+        let last = Epoch::from_utc_seconds(LEAP_SECONDS[LEAP_SECONDS.len() - 1] as f64);
+        assert!(expiry > last);
+        assert!(!is_table_stale(last));
+        assert!(!is_table_stale(expiry));
+        assert!(is_table_stale(expiry + Unit::Nanosecond * 1));
+    }
+
+    #[test]
+    fn test_earth_rotation_angle() {
+        use crate::TimeUnits;
+        use core::f64::consts::PI;
+        // At J2000 UT1, the ERA is the constant of the IAU 2000 definition
+        let j2000_ut1 = Epoch::from_gregorian_utc_at_noon(2000, 1, 1);
+        assert!(
+            (j2000_ut1.earth_rotation_angle_turns(0.seconds()) - 0.779_057_273_264).abs() < 1e-13
+        );
+        // DUT1 shifts UT1
+        assert_eq!(
+            (j2000_ut1 - 355.milliseconds()).earth_rotation_angle_turns(355.milliseconds()),
+            j2000_ut1.earth_rotation_angle_turns(0.seconds())
+        );
+        // The Earth rotates by one turn in a stellar day, i.e. slightly less than a solar day
+        let stellar_day = Unit::Second * (86_400.0 / 1.002_737_811_911_354_5);
+        let delta = (j2000_ut1 + 20 * 365.days() + stellar_day).earth_rotation_angle(0.seconds())
+            - (j2000_ut1 + 20 * 365.days()).earth_rotation_angle(0.seconds());
+        assert!(delta.abs() < 1e-9, "{}", delta);
+        // Always normalized
+        for days in [-40_000, -1, 0, 1, 12_345, 40_000] {
+            let era = (j2000_ut1 + days.days()).earth_rotation_angle(0.seconds());
+            assert!((0.0..2.0 * PI).contains(&era));
+        }
+    }
+
+    #[test]
+    fn test_powers_of_interval() {
+        use crate::TimeUnits;
+        let j2000 = Epoch::from_gregorian_tai_at_noon(2000, 1, 1);
+
+        let powers: [f64; 0] = j2000.powers_of_interval(j2000, Unit::Second);
+        assert_eq!(powers, []);
+        let powers: [f64; 4] = (j2000 - 2.days()).powers_of_interval(j2000, Unit::Day);
+        assert_eq!(powers, [-2.0, 4.0, -8.0, 16.0]);
+
+        // A single nanosecond after J2000 is lost when subtracting centuries since J1900
+        let epoch = j2000 + 1.nanoseconds();
+        let naive = epoch.as_tai_seconds() - j2000.as_tai_seconds();
+        assert!((naive - 1e-9).abs() > 1e-10);
+        let powers: [f64; 3] = epoch.powers_of_interval(j2000, Unit::Second);
+        assert!((powers[0] - 1e-9).abs() < f64::EPSILON * 1e-9);
+        assert!((powers[2] - 1e-27).abs() < f64::EPSILON * 1e-27);
+
+        // Fractional centuries: 36525 days and one hour
+        let epoch = j2000 + 36_525.days() + 1.hours();
+        let powers: [f64; 2] = epoch.powers_of_interval(j2000, Unit::Century);
+        let expected = 1.0 + 1.0 / (24.0 * 36_525.0);
+        assert!((powers[0] - expected).abs() < f64::EPSILON);
+        assert!((powers[1] - expected * expected).abs() < 4.0 * f64::EPSILON);
+    }
+
     #[test]
     fn et_init() {
         // Test for https://github.com/nyx-space/hifitime/issues/106
@@ -2179,6 +4758,45 @@ mod tests {
         println!("{now}");
     }
 
+    #[cfg(feature = "precise-now")]
+    #[test]
+    fn test_now_precise() {
+        use crate::TimeUnits;
+        let precise = Epoch::now_precise().unwrap();
+        let now = Epoch::now().unwrap();
+        assert!((now - precise).abs() < 1.seconds());
+    }
+
+    #[test]
+    fn test_unix_timespec() {
+        use crate::TimeUnits;
+        // The UNIX conversion is exact to the nanosecond
+        let epoch = Epoch::from_unix_timespec(1_653_069_463, 123_456_789);
+        assert_eq!(
+            epoch,
+            Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43) + 123_456_789.nanoseconds()
+        );
+        assert_eq!(
+            Epoch::from_unix_timespec(0, 0),
+            Epoch::from_gregorian_utc_at_midnight(1970, 1, 1)
+        );
+        assert_eq!(
+            Epoch::from_unix_timespec(-1, 500_000_000),
+            Epoch::from_gregorian_utc_at_midnight(1970, 1, 1) - 500.milliseconds()
+        );
+        assert_eq!(
+            Epoch::from_unix_seconds(1_653_069_463.5),
+            Epoch::from_unix_timespec(1_653_069_463, 500_000_000)
+        );
+        // The f64 milliseconds themselves are only precise to about a microsecond
+        assert!(
+            (Epoch::from_unix_milliseconds(1_653_069_463_250.0)
+                - Epoch::from_unix_timespec(1_653_069_463, 250_000_000))
+            .abs()
+                < 1.microseconds()
+        );
+    }
+
     #[test]
     fn test_floor_ceil_round() {
         // NOTE: This test suite is more limited than the Duration equivalent because Epoch uses Durations for these operations.
@@ -2199,6 +4817,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_next_after_prev_before() {
+        let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
+        assert_eq!(e.next_after(), e + Duration::EPSILON);
+        assert_eq!(e.prev_before(), e - Duration::MIN_POSITIVE);
+        assert!(e.prev_before() < e && e < e.next_after());
+        // Across the TAI reference epoch, where the centuries change
+        let zero = Epoch::from_tai_duration(Duration::from_parts(0, 0));
+        assert_eq!(zero.prev_before().as_tai_duration(), Duration::MIN_NEGATIVE);
+        assert_eq!(zero.prev_before().next_after(), zero);
+        // Saturation at the bounds
+        let latest = Epoch::from_tai_duration(Duration::MAX);
+        assert_eq!(latest.next_after(), latest);
+        let earliest = Epoch::from_tai_parts(i16::MIN, 0);
+        assert_eq!(earliest.prev_before(), earliest);
+    }
+
+    #[test]
+    fn test_duration_since() {
+        let launch = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+        let epoch = Epoch::from_duration_since(launch, Unit::Day * 3 + Unit::Second * 1.5);
+        assert_eq!(epoch, launch + Unit::Day * 3 + Unit::Second * 1.5);
+        assert_eq!(
+            epoch.duration_since(launch),
+            Unit::Day * 3 + Unit::Second * 1.5
+        );
+        assert_eq!(
+            launch.duration_since(epoch),
+            -(Unit::Day * 3 + Unit::Second * 1.5)
+        );
+        assert_eq!(epoch.duration_since(launch), epoch - launch);
+        // Negative durations are before the reference
+        let before = Epoch::from_duration_since(launch, Unit::Hour * -2);
+        assert!(before < launch);
+        assert_eq!(before.duration_since(launch), Unit::Hour * -2);
+        // Across a leap second, the duration is in TAI
+        let reference = Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 59);
+        let after = Epoch::from_gregorian_utc_hms(2017, 1, 1, 0, 0, 0);
+        assert_eq!(after.duration_since(reference), Unit::Second * 2);
+        assert_eq!(
+            Epoch::from_duration_since(reference, Unit::Second * 2),
+            after
+        );
+        assert_eq!(
+            Epoch::from_duration_since(launch, launch.duration_since(reference)),
+            launch + (launch - reference)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_debug() {
+        let e = Epoch::from_tai_parts(1, 537_582_752_000_000_000);
+        assert_eq!(
+            format!("{:?}", e),
+            "Epoch { centuries: 1, nanoseconds: 537582752000000000 } (2017-01-14T00:31:55 UTC)"
+        );
+        assert_eq!(
+            format!("{:#?}", e),
+            "Epoch {\n    centuries: 1,\n    nanoseconds: 537582752000000000,\n} (2017-01-14T00:31:55 UTC)"
+        );
+        // Lossless, unlike the date time which goes through the seconds
+        let next = e + Unit::Nanosecond * 1;
+        assert_eq!(
+            format!("{:?}", next),
+            "Epoch { centuries: 1, nanoseconds: 537582752000000001 } (2017-01-14T00:31:55 UTC)"
+        );
+    }
+
     #[test]
     fn test_ord() {
         let epoch1 =
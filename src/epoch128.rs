@@ -0,0 +1,249 @@
+use crate::math::{div_rem_i128, floor};
+use crate::{Duration, Epoch, Errors, Unit};
+use core::convert::TryFrom;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+
+/// An epoch stored as a 128-bit number of TAI nanoseconds past 1900 January 01 at midnight,
+/// available with the `long-horizon` feature.
+///
+/// Its range of about 5.4e21 years in either direction covers spans beyond the ±32,768 centuries of
+/// [`Epoch`], e.g. long-horizon orbital evolution over millions of years, still at nanosecond
+/// resolution. Epochs within range convert exactly to and from an [`Epoch`], and durations are
+/// added exactly. As with [`Epoch128::offset_by`], additions and subtractions saturate at the
+/// bounds of the range.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, Epoch128, TimeUnits, Unit};
+/// use std::convert::TryFrom;
+///
+/// let start = Epoch128::from(Epoch::from_gregorian_tai_at_noon(2000, 1, 1));
+/// // Ten million years later, out of range of an Epoch
+/// let later = start.offset_by(100_000.0, Unit::Century);
+/// assert_eq!(later.nanoseconds_since(start), 100_000 * 36_525 * 86_400 * 1_000_000_000);
+/// assert!(Epoch::try_from(later).is_err());
+/// // Exact round trip of epochs within range
+/// assert_eq!(
+///     Epoch::try_from(start + 1.days()),
+///     Ok(Epoch::from_gregorian_tai_at_noon(2000, 1, 2))
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Epoch128 {
+    nanoseconds: i128,
+}
+
+impl Epoch128 {
+    /// Initialize an Epoch128 from the provided TAI nanoseconds since 1900 January 01 at midnight.
+    #[must_use]
+    pub const fn from_tai_nanoseconds(nanoseconds: i128) -> Self {
+        Self { nanoseconds }
+    }
+
+    /// Initialize an Epoch128 from the provided TAI value since 1900 January 01 at midnight, in the
+    /// provided unit, rounded to the nanosecond. The value is split into its whole and fractional
+    /// units to preserve precision, and saturates at the bounds of the range.
+    #[must_use]
+    pub fn from_tai(value: f64, unit: Unit) -> Self {
+        Self::from_tai_nanoseconds(0).offset_by(value, unit)
+    }
+
+    /// Initialize an Epoch128 from the provided TAI seconds since 1900 January 01 at midnight.
+    #[must_use]
+    pub fn from_tai_seconds(seconds: f64) -> Self {
+        Self::from_tai(seconds, Unit::Second)
+    }
+
+    /// Returns the TAI nanoseconds since 1900 January 01 at midnight.
+    #[must_use]
+    pub const fn as_tai_nanoseconds(&self) -> i128 {
+        self.nanoseconds
+    }
+
+    /// Returns the TAI time since 1900 January 01 at midnight in the provided unit. The whole units
+    /// are converted separately from the remainder to preserve precision.
+    #[must_use]
+    pub fn as_tai(&self, unit: Unit) -> f64 {
+        let per_unit = (unit * 1).total_nanoseconds();
+        let (whole, rem) = div_rem_i128(self.nanoseconds, per_unit);
+        whole as f64 + rem as f64 / per_unit as f64
+    }
+
+    /// Returns the TAI seconds since 1900 January 01 at midnight.
+    #[must_use]
+    pub fn as_tai_seconds(&self) -> f64 {
+        self.as_tai(Unit::Second)
+    }
+
+    /// Returns this epoch shifted by the provided value in the provided unit, which may exceed the
+    /// range of a [`Duration`], rounded to the nanosecond.
+    ///
+    /// The result saturates at the bounds of the range, including for infinite values, and a NaN
+    /// value leaves the epoch unchanged.
+    #[must_use]
+    pub fn offset_by(&self, value: f64, unit: Unit) -> Self {
+        if value.is_nan() {
+            return *self;
+        } else if value.is_infinite() {
+            return Self::from_tai_nanoseconds(if value > 0.0 { i128::MAX } else { i128::MIN });
+        }
+        let per_unit = (unit * 1).total_nanoseconds();
+        let whole = floor(value);
+        let fraction = ((value - whole) * per_unit as f64 + 0.5) as i128;
+        Self::from_tai_nanoseconds(
+            self.nanoseconds
+                .saturating_add((whole as i128).saturating_mul(per_unit))
+                .saturating_add(fraction),
+        )
+    }
+
+    /// Returns the exact number of nanoseconds elapsed since the reference epoch, which is negative
+    /// if this epoch is before the reference.
+    #[must_use]
+    pub const fn nanoseconds_since(&self, reference: Self) -> i128 {
+        self.nanoseconds - reference.nanoseconds
+    }
+
+    /// Returns the number of seconds elapsed since the reference epoch, cf.
+    /// [`Epoch128::nanoseconds_since`].
+    #[must_use]
+    pub fn seconds_since(&self, reference: Self) -> f64 {
+        let (whole, rem) = div_rem_i128(self.nanoseconds_since(reference), NANOSECONDS_PER_SECOND);
+        whole as f64 + rem as f64 / NANOSECONDS_PER_SECOND as f64
+    }
+}
+
+impl From<Epoch> for Epoch128 {
+    fn from(epoch: Epoch) -> Self {
+        Self::from_tai_nanoseconds(epoch.as_tai_duration().total_nanoseconds())
+    }
+}
+
+impl TryFrom<Epoch128> for Epoch {
+    type Error = Errors;
+
+    /// Converts the epoch exactly, or returns `Errors::Overflow` if it is beyond the range of an Epoch.
+    fn try_from(epoch: Epoch128) -> Result<Self, Errors> {
+        Duration::try_from_total_nanoseconds(epoch.nanoseconds).map(Epoch::from_tai_duration)
+    }
+}
+
+impl Add<Duration> for Epoch128 {
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self {
+        Self::from_tai_nanoseconds(
+            self.nanoseconds
+                .saturating_add(duration.total_nanoseconds()),
+        )
+    }
+}
+
+impl Sub<Duration> for Epoch128 {
+    type Output = Self;
+
+    fn sub(self, duration: Duration) -> Self {
+        Self::from_tai_nanoseconds(
+            self.nanoseconds
+                .saturating_sub(duration.total_nanoseconds()),
+        )
+    }
+}
+
+impl AddAssign<Duration> for Epoch128 {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl SubAssign<Duration> for Epoch128 {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, Epoch128, Errors, TimeUnits, Unit};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_epoch128() {
+        let epoch = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43) + 1.nanoseconds();
+        let long = Epoch128::from(epoch);
+        assert_eq!(
+            long.as_tai_nanoseconds(),
+            epoch.as_tai_duration().total_nanoseconds()
+        );
+        // Before 1800, i.e. more than a century before the reference epoch
+        let early = Epoch::from_gregorian_tai_at_midnight(1700, 1, 1);
+        assert_eq!(
+            Epoch128::from(early).as_tai_nanoseconds(),
+            -73_048 * 86_400 * 1_000_000_000
+        );
+        assert_eq!(Epoch::try_from(Epoch128::from(early)), Ok(early));
+        assert_eq!(
+            Epoch128::from(early) + 1.days(),
+            Epoch128::from(early + 1.days())
+        );
+        assert_eq!(Epoch::try_from(long), Ok(epoch));
+        assert_eq!(long.as_tai_seconds(), epoch.as_tai_seconds());
+        assert_eq!(long.as_tai(Unit::Day), epoch.as_tai_days());
+
+        // Bounds of an Epoch
+        let latest = Epoch::from_tai_parts(i16::MAX, 3_155_759_999_999_999_999);
+        let earliest = Epoch::from_tai_parts(i16::MIN, 0);
+        assert_eq!(Epoch::try_from(Epoch128::from(latest)), Ok(latest));
+        assert_eq!(Epoch::try_from(Epoch128::from(earliest)), Ok(earliest));
+        assert_eq!(
+            Epoch::try_from(Epoch128::from(latest) + 1.nanoseconds()),
+            Err(Errors::Overflow)
+        );
+        assert_eq!(
+            Epoch::try_from(Epoch128::from(earliest) - 1.nanoseconds()),
+            Err(Errors::Overflow)
+        );
+
+        // A million years before and after, to the nanosecond
+        let mut later = long.offset_by(10_000.0, Unit::Century);
+        let earlier = long.offset_by(-10_000.0, Unit::Century);
+        assert!(earlier < long && long < later);
+        assert_eq!(
+            later.nanoseconds_since(earlier),
+            20_000 * 36_525 * 86_400 * 1_000_000_000
+        );
+        assert_eq!(later.seconds_since(earlier), 2e6 * 365.25 * 86_400.0);
+        later += 1.nanoseconds();
+        assert_eq!(later.nanoseconds_since(long) % 1_000_000_000, 1);
+        later -= 1.nanoseconds();
+        assert_eq!(later.offset_by(-10_000.0, Unit::Century), long);
+
+        // Fractional units
+        let start = Epoch128::from_tai_seconds(1.5);
+        assert_eq!(start.as_tai_nanoseconds(), 1_500_000_000);
+        assert_eq!(
+            start.offset_by(-0.25, Unit::Day).as_tai(Unit::Hour),
+            -6.0 + 1.5 / 3600.0
+        );
+        assert_eq!(
+            Epoch128::from_tai(-1.5, Unit::Second),
+            start.offset_by(-3.0, Unit::Second)
+        );
+        assert_eq!(Epoch128::default(), Epoch128::from_tai_nanoseconds(0));
+
+        // Saturation at the bounds of the range
+        let last = Epoch128::from_tai_nanoseconds(i128::MAX);
+        let first = Epoch128::from_tai_nanoseconds(i128::MIN);
+        assert_eq!(last + 1.nanoseconds(), last);
+        assert_eq!(first - 1.days(), first);
+        assert_eq!(last.offset_by(1.0, Unit::Day), last);
+        assert_eq!(first.offset_by(-1.0, Unit::Day), first);
+        assert_eq!(long.offset_by(f64::INFINITY, Unit::Second), last);
+        assert_eq!(long.offset_by(f64::NEG_INFINITY, Unit::Nanosecond), first);
+        assert_eq!(long.offset_by(f64::NAN, Unit::Day), long);
+        assert_eq!(Epoch128::from_tai(f64::INFINITY, Unit::Day), last);
+        assert_eq!(Epoch128::from_tai_seconds(f64::NAN), Epoch128::default());
+    }
+}
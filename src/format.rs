@@ -0,0 +1,772 @@
+use crate::epoch::{day_of_year, month_day_from_day_of_year};
+use crate::{Epoch, Errors, GnssTimeScale, ParsingErrors, TimeSystem, Unit};
+use core::fmt;
+
+/// A token of a fixed-width format descriptor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Token {
+    /// `YYYY`: four digit year
+    Year,
+    /// `YY`: two digit year, where 50 to 99 map to 1950 to 1999 and 00 to 49 map to 2000 to 2049
+    ShortYear,
+    /// `MM` prior to any hour token: month
+    Month,
+    /// `DD`: day of the month
+    Day,
+    /// `DDD`: day of the year, starting at 001
+    DayOfYear,
+    /// `HH`: hour
+    Hour,
+    /// `MM` after an hour token: minute
+    Minute,
+    /// `SS`: second
+    Second,
+    /// `SSSSS`: second of the day
+    SecondOfDay,
+    /// `WWWW`: full GPS week number, never rolled over
+    GpsWeek,
+    /// `SSSSSS`: second of the GPS week
+    SecondOfWeek,
+    /// `mmm`: milliseconds
+    Millisecond,
+    /// `uuu`: microseconds within the millisecond
+    Microsecond,
+    /// `nnn`: nanoseconds within the microsecond
+    Nanosecond,
+    /// `s` to `sssssssss`: fraction of the second with as many digits as repetitions
+    Fraction(u8),
+    /// Any other character is copied as is in the output and must be matched exactly when parsing.
+    Literal(char),
+}
+
+impl Token {
+    /// Number of bytes of this token, in the descriptor and in the formatted epoch
+    const fn width(&self) -> usize {
+        match self {
+            Self::SecondOfWeek => 6,
+            Self::SecondOfDay => 5,
+            Self::Year | Self::GpsWeek => 4,
+            Self::DayOfYear | Self::Millisecond | Self::Microsecond | Self::Nanosecond => 3,
+            Self::Fraction(digits) => *digits as usize,
+            Self::Literal(c) => c.len_utf8(),
+            _ => 2,
+        }
+    }
+}
+
+/// Returns the full year of a two digit year: 50 to 99 map to 1950 to 1999 and 00 to 49 map to 2000 to 2049.
+pub(crate) const fn year_from_short_year(short_year: u8) -> i32 {
+    if short_year >= 50 {
+        1900 + short_year as i32
+    } else {
+        2000 + short_year as i32
+    }
+}
+
+/// Iterator over the tokens of a format descriptor
+#[derive(Clone)]
+struct Tokens<'a> {
+    descriptor: &'a str,
+    pos: usize,
+    seen_hour: bool,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Result<Token, Errors>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.descriptor[self.pos..];
+        let first = rest.chars().next()?;
+        // Count the repetitions of the first character
+        let reps = rest.chars().take_while(|c| *c == first).count();
+        let token = match (first, reps) {
+            ('Y', 4) => Token::Year,
+            ('Y', 2) => Token::ShortYear,
+            ('M', 2) if self.seen_hour => Token::Minute,
+            ('M', 2) => Token::Month,
+            ('D', 3) => Token::DayOfYear,
+            ('D', 2) => Token::Day,
+            ('H', 2) => {
+                self.seen_hour = true;
+                Token::Hour
+            }
+            ('S', 2) => Token::Second,
+            ('S', 5) => Token::SecondOfDay,
+            ('S', 6) => Token::SecondOfWeek,
+            ('W', 4) => Token::GpsWeek,
+            ('s', 1..=9) => Token::Fraction(reps as u8),
+            ('m', 3) => Token::Millisecond,
+            ('u', 3) => Token::Microsecond,
+            ('n', 3) => Token::Nanosecond,
+            ('Y', _)
+            | ('M', _)
+            | ('D', _)
+            | ('H', _)
+            | ('S', _)
+            | ('m', _)
+            | ('u', _)
+            | ('n', _)
+            | ('W', _)
+            | ('s', _) => {
+                // Stop iterating after an error
+                self.pos = self.descriptor.len();
+                return Some(Err(Errors::ParseError(ParsingErrors::UnknownFormat)));
+            }
+            (c, _) => Token::Literal(c),
+        };
+        self.pos += token.width();
+        Some(Ok(token))
+    }
+}
+
+/// A fixed-width (column oriented) format descriptor, used both to parse and to format epochs.
+///
+/// Supported tokens:
+///  + `YYYY`: four digit year
+///  + `YY`: two digit year, where 50 to 99 map to 1950 to 1999 and 00 to 49 map to 2000 to 2049
+///  + `MM`: month if no hour token precedes it, minute otherwise
+///  + `DD`: day of the month
+///  + `DDD`: day of the year, starting at 001
+///  + `HH`: hour
+///  + `SS`: second
+///  + `SSSSS`: second of the day, instead of the hour, minute and second tokens
+///  + `mmm`, `uuu`, `nnn`: milliseconds, microseconds within the millisecond, nanoseconds within the microsecond
+///  + `s` to `sssssssss`: fraction of the second, with one to nine digits
+///  + `WWWW`: full GPS week number (never rolled over), instead of any date token
+///  + `SSSSSS`: second of the GPS week, only with a GPS week token
+///
+/// Any other character is a literal which is copied when formatting and must match when parsing.
+/// No separators are needed between tokens since each token has a fixed width.
+///
+/// The GPS week and second of week are always expressed in GPS time: the time system provided
+/// when parsing or formatting is ignored by formats with a GPS week token.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, Format, TimeSystem};
+///
+/// let fmt = Format::new("YYDDDHHMMSSmmm").unwrap();
+/// let epoch = Epoch::from_format("22140175743250", fmt, TimeSystem::UTC).unwrap();
+/// assert_eq!(epoch, Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 250_000_000));
+/// assert_eq!(format!("{}", epoch.format(fmt, TimeSystem::UTC)), "22140175743250");
+///
+/// let fmt = Format::new("YYYYMMDDHHMMSS").unwrap();
+/// assert_eq!(format!("{}", epoch.format(fmt, TimeSystem::UTC)), "20220520175743");
+///
+/// // Day of year and second of day, as used by some ground systems
+/// let fmt = Format::new("YYYY:DDD:SSSSS").unwrap();
+/// assert_eq!(format!("{}", epoch.format(fmt, TimeSystem::UTC)), "2022:140:64663");
+///
+/// // GPS week and second of week
+/// let fmt = Format::new("WWWW:SSSSSS.ssss").unwrap();
+/// assert_eq!(format!("{}", epoch.format(fmt, TimeSystem::UTC)), "2210:496681.2500");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Format<'a> {
+    descriptor: &'a str,
+}
+
+impl<'a> Format<'a> {
+    /// Builds a new format from its descriptor, returning an error if the descriptor is invalid.
+    ///
+    /// A descriptor is invalid if it contains a malformed token (e.g. `YYY`), if it provides both
+    /// a day of year and a month or day of the month, if it does not define a complete date, or if
+    /// it mixes a second of day with hours, minutes or seconds, or if it provides any part of the
+    /// fraction of seconds twice (e.g. two `sss` tokens, or `sss` and `mmm`). A descriptor with a
+    /// GPS week token is invalid if it contains any date or time of day token.
+    pub fn new(descriptor: &'a str) -> Result<Self, Errors> {
+        let me = Self { descriptor };
+        let (mut year, mut month, mut day, mut doy) = (false, false, false, false);
+        let (mut hms, mut sod, mut week, mut sow) = (false, false, false, false);
+        // Parts of the fraction of seconds already provided, as bits of milli, micro and nanoseconds
+        let (mut fraction, mut repeated_fraction) = (0_u8, false);
+        for token in me.tokens() {
+            let token = token?;
+            let fraction_bits = match token {
+                Token::Millisecond => 0b001,
+                Token::Microsecond => 0b010,
+                Token::Nanosecond => 0b100,
+                Token::Fraction(_) => 0b111,
+                _ => 0,
+            };
+            repeated_fraction |= fraction & fraction_bits != 0;
+            fraction |= fraction_bits;
+            match token {
+                Token::Year | Token::ShortYear => year = true,
+                Token::Month => month = true,
+                Token::Day => day = true,
+                Token::DayOfYear => doy = true,
+                Token::Hour | Token::Minute | Token::Second => hms = true,
+                Token::SecondOfDay => sod = true,
+                Token::GpsWeek => week = true,
+                Token::SecondOfWeek => sow = true,
+                _ => {}
+            }
+        }
+        let invalid = repeated_fraction
+            || if week {
+                year || month || day || doy || hms || sod
+            } else {
+                sow || !year || (doy && (month || day)) || !(doy || month && day) || (sod && hms)
+            };
+        if invalid {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+        Ok(me)
+    }
+
+    /// Returns the descriptor of this format
+    #[must_use]
+    pub const fn descriptor(&self) -> &'a str {
+        self.descriptor
+    }
+
+    /// Returns the number of bytes of any epoch formatted or parsed with this format, which is its
+    /// number of characters unless the format contains non-ASCII literals.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tokens()
+            .map(|token| token.map_or(0, |token| token.width()))
+            .sum()
+    }
+
+    /// Returns true if this format does not contain any token (never the case for a valid format).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.descriptor.is_empty()
+    }
+
+    /// Returns true if this format expresses epochs as a GPS week and second of week.
+    fn is_gps_week(&self) -> bool {
+        self.tokens().any(|token| token == Ok(Token::GpsWeek))
+    }
+
+    fn tokens(&self) -> Tokens<'a> {
+        Tokens {
+            descriptor: self.descriptor,
+            pos: 0,
+            seen_hour: false,
+        }
+    }
+
+    /// Parses the start of the provided bytes with this format in the provided time system,
+    /// returning the epoch and the number of bytes consumed.
+    pub(crate) fn parse_prefix(
+        &self,
+        bytes: &[u8],
+        ts: TimeSystem,
+    ) -> Result<(Epoch, usize), Errors> {
+        let mut pos = 0;
+        let (mut year, mut month, mut day, mut doy) = (0_i32, 1_u8, 1_u8, None);
+        let (mut hour, mut minute, mut second, mut nanos) = (0_u8, 0_u8, 0_u8, 0_u32);
+        let (mut week, mut second_of_week) = (None, 0_u32);
+
+        for token in self.tokens() {
+            let token = token?;
+            let width = token.width();
+            if pos + width > bytes.len() {
+                return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+            }
+            let field = &bytes[pos..pos + width];
+            pos += width;
+            if let Token::Literal(c) = token {
+                if field != c.encode_utf8(&mut [0; 4]).as_bytes() {
+                    return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+                }
+                continue;
+            }
+            let mut value: u32 = 0;
+            for digit in field {
+                if !digit.is_ascii_digit() {
+                    return Err(Errors::ParseError(ParsingErrors::ParseIntError));
+                }
+                value = value * 10 + u32::from(digit - b'0');
+            }
+            match token {
+                Token::Year => year = value as i32,
+                Token::ShortYear => year = year_from_short_year(value as u8),
+                Token::Month => month = value as u8,
+                Token::Day => day = value as u8,
+                Token::DayOfYear => doy = Some(value as u16),
+                Token::Hour => hour = value as u8,
+                Token::Minute => minute = value as u8,
+                Token::Second => second = value as u8,
+                Token::SecondOfDay => {
+                    if value > 86_400 {
+                        return Err(Errors::Carry);
+                    }
+                    // Second 86400 is a leap second, whose validity depends on the date
+                    let (h, m, s) = if value == 86_400 {
+                        (23, 59, 60)
+                    } else {
+                        (value / 3_600, (value / 60) % 60, value % 60)
+                    };
+                    hour = h as u8;
+                    minute = m as u8;
+                    second = s as u8;
+                }
+                Token::GpsWeek => week = Some(value as i32),
+                Token::SecondOfWeek => second_of_week = value,
+                // Each part of the fraction is provided at most once, cf. `Format::new`, so the
+                // nanoseconds remain below one second
+                Token::Millisecond => nanos += value * 1_000_000,
+                Token::Microsecond => nanos += value * 1_000,
+                Token::Nanosecond => nanos += value,
+                Token::Fraction(digits) => nanos += value * 10_u32.pow(9 - u32::from(digits)),
+                Token::Literal(_) => unreachable!(),
+            }
+        }
+
+        if let Some(week) = week {
+            if second_of_week >= 604_800 {
+                return Err(Errors::Carry);
+            }
+            let tow =
+                Unit::Second * i64::from(second_of_week) + Unit::Nanosecond * i64::from(nanos);
+            return Ok((Epoch::from_week_tow(GnssTimeScale::GPST, week, tow), pos));
+        }
+
+        if let Some(doy) = doy {
+            let (m, d) = month_day_from_day_of_year(year, doy).ok_or(Errors::Carry)?;
+            month = m;
+            day = d;
+        }
+
+        let epoch = Epoch::maybe_from_gregorian(year, month, day, hour, minute, second, nanos, ts)?;
+        Ok((epoch, pos))
+    }
+}
+
+/// An epoch formatted with a fixed-width [`Format`] in a given time system, cf. [`Epoch::format`].
+#[derive(Copy, Clone, Debug)]
+pub struct FormattedEpoch<'a> {
+    epoch: Epoch,
+    format: Format<'a>,
+    ts: TimeSystem,
+}
+
+impl<'a> fmt::Display for FormattedEpoch<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (y, mm, dd, hh, min, s, mut nanos) = self.epoch.as_gregorian(self.ts);
+        let (week, tow) = self.epoch.as_week_tow(GnssTimeScale::GPST);
+        let tow = tow.total_nanoseconds();
+        if self.format.is_gps_week() {
+            nanos = (tow % 1_000_000_000) as u32;
+        }
+        for token in self.format.tokens() {
+            match token.map_err(|_| fmt::Error)? {
+                Token::Year => write!(f, "{:04}", y)?,
+                Token::ShortYear => write!(f, "{:02}", y.rem_euclid(100))?,
+                Token::Month => write!(f, "{:02}", mm)?,
+                Token::Day => write!(f, "{:02}", dd)?,
+                Token::DayOfYear => write!(f, "{:03}", day_of_year(y, mm, dd))?,
+                Token::Hour => write!(f, "{:02}", hh)?,
+                Token::Minute => write!(f, "{:02}", min)?,
+                Token::Second => write!(f, "{:02}", s)?,
+                Token::SecondOfDay => write!(
+                    f,
+                    "{:05}",
+                    u32::from(hh) * 3_600 + u32::from(min) * 60 + u32::from(s)
+                )?,
+                Token::GpsWeek => write!(f, "{:04}", week)?,
+                Token::SecondOfWeek => write!(f, "{:06}", tow / 1_000_000_000)?,
+                Token::Millisecond => write!(f, "{:03}", nanos / 1_000_000)?,
+                Token::Microsecond => write!(f, "{:03}", (nanos / 1_000) % 1_000)?,
+                Token::Nanosecond => write!(f, "{:03}", nanos % 1_000)?,
+                Token::Fraction(digits) => write!(
+                    f,
+                    "{:0width$}",
+                    nanos / 10_u32.pow(9 - u32::from(digits)),
+                    width = digits as usize
+                )?,
+                Token::Literal(c) => write!(f, "{}", c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An incremental parser of epochs written back to back with a fixed-width [`Format`], e.g. in a
+/// dense telemetry log, without splitting the input into lines or fields first.
+///
+/// The input is read as bytes, so the timestamps may be embedded in binary data, and each parse
+/// reports the number of bytes consumed. Separators between consecutive timestamps may be included
+/// as trailing literals of the format.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, EpochParser, Format, TimeSystem, TimeUnits};
+///
+/// let parser = EpochParser::new(Format::new("YYDDDHHMMSSmmm").unwrap(), TimeSystem::UTC);
+/// let stream = b"2214017574325022140175744250221401757";
+///
+/// let (first, consumed) = parser.parse(stream).unwrap();
+/// assert_eq!(first, Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 250_000_000));
+/// assert_eq!(consumed, 14);
+///
+/// let mut epochs = parser.parse_all(stream);
+/// assert_eq!(epochs.next(), Some(Ok(first)));
+/// assert_eq!(epochs.next(), Some(Ok(first + 1.seconds())));
+/// assert_eq!(epochs.next(), None);
+/// // The incomplete timestamp is left for the next chunk of the stream
+/// assert_eq!(epochs.remainder(), b"221401757");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EpochParser<'a> {
+    format: Format<'a>,
+    ts: TimeSystem,
+}
+
+impl<'a> EpochParser<'a> {
+    /// Builds a parser of epochs expressed in the provided time system with the provided format.
+    #[must_use]
+    pub const fn new(format: Format<'a>, ts: TimeSystem) -> Self {
+        Self { format, ts }
+    }
+
+    /// Returns the format of the parsed epochs.
+    #[must_use]
+    pub const fn format(&self) -> Format<'a> {
+        self.format
+    }
+
+    /// Returns the time system in which the parsed epochs are expressed.
+    #[must_use]
+    pub const fn time_system(&self) -> TimeSystem {
+        self.ts
+    }
+
+    /// Parses the epoch at the start of the provided bytes, returning it with the number of bytes
+    /// consumed, i.e. the length of the format. Any bytes after the epoch are ignored.
+    pub fn parse(&self, bytes: &[u8]) -> Result<(Epoch, usize), Errors> {
+        self.format.parse_prefix(bytes, self.ts)
+    }
+
+    /// Returns an iterator over the epochs written back to back in the provided bytes.
+    ///
+    /// The iteration stops after the first parsing error, or before an incomplete trailing
+    /// timestamp, which is then available with [`ParsedEpochs::remainder`].
+    #[must_use]
+    pub fn parse_all<'b>(&self, bytes: &'b [u8]) -> ParsedEpochs<'a, 'b> {
+        ParsedEpochs {
+            parser: *self,
+            bytes,
+            pos: 0,
+            failed: false,
+        }
+    }
+}
+
+/// An iterator over the epochs written back to back in a byte stream, cf. [`EpochParser::parse_all`].
+#[derive(Clone, Debug)]
+pub struct ParsedEpochs<'a, 'b> {
+    parser: EpochParser<'a>,
+    bytes: &'b [u8],
+    pos: usize,
+    failed: bool,
+}
+
+impl<'a, 'b> ParsedEpochs<'a, 'b> {
+    /// Returns the number of bytes consumed by the epochs parsed so far.
+    #[must_use]
+    pub const fn consumed(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the bytes which have not been parsed yet, e.g. an incomplete timestamp at the end
+    /// of a chunk of the stream, or the timestamp which failed to parse.
+    #[must_use]
+    pub fn remainder(&self) -> &'b [u8] {
+        &self.bytes[self.pos..]
+    }
+}
+
+impl<'a, 'b> Iterator for ParsedEpochs<'a, 'b> {
+    type Item = Result<Epoch, Errors>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remainder = self.remainder();
+        if self.failed || remainder.len() < self.parser.format.len() {
+            return None;
+        }
+        match self.parser.parse(remainder) {
+            Ok((epoch, consumed)) => {
+                self.pos += consumed;
+                Some(Ok(epoch))
+            }
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl Epoch {
+    /// Parses the provided string, expressed in the provided time system, with a fixed-width format.
+    /// The whole string must be consumed by the format.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Format, TimeSystem};
+    ///
+    /// let fmt = Format::new("YYYYMMDDHHMMSS").unwrap();
+    /// assert_eq!(
+    ///     Epoch::from_format("20220520175743", fmt, TimeSystem::TAI).unwrap(),
+    ///     Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43)
+    /// );
+    /// assert!(Epoch::from_format("2022052017574", fmt, TimeSystem::TAI).is_err());
+    /// ```
+    pub fn from_format(s: &str, format: Format, ts: TimeSystem) -> Result<Self, Errors> {
+        let (epoch, consumed) = format.parse_prefix(s.as_bytes(), ts)?;
+        if consumed != s.len() {
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        } else {
+            Ok(epoch)
+        }
+    }
+
+    /// Returns a displayable structure which formats this epoch with the provided fixed-width format,
+    /// in the provided time system. Each field is zero padded to its width.
+    #[must_use]
+    pub fn format<'a>(&self, format: Format<'a>, ts: TimeSystem) -> FormattedEpoch<'a> {
+        FormattedEpoch {
+            epoch: *self,
+            format,
+            ts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, EpochParser, Errors, Format, ParsingErrors, TimeSystem, TimeUnits};
+
+    #[test]
+    fn test_format_descriptor() {
+        assert!(Format::new("YYYYMMDDHHMMSS").is_ok());
+        assert!(Format::new("YYDDDHHMMSSmmm").is_ok());
+        assert!(Format::new("YYYY-DDDTHH:MM:SS.mmmuuunnn").is_ok());
+        assert_eq!(Format::new("YYYYMMDDHHMMSS").unwrap().len(), 14);
+        assert_eq!(Format::new("YYDDDHHMMSSmmm").unwrap().len(), 14);
+        assert_eq!(
+            Format::new("YYYYMMDDHHMMSS").unwrap().descriptor(),
+            "YYYYMMDDHHMMSS"
+        );
+        // Malformed tokens
+        assert_eq!(
+            Format::new("YYYMMDD"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert!(Format::new("YYYYMMDDHHMMSSmm").is_err());
+        // Incomplete or conflicting dates
+        assert!(Format::new("HHMMSS").is_err());
+        assert!(Format::new("YYYYMM").is_err());
+        assert!(Format::new("YYYYMMDDD").is_err());
+        // Second of day and GPS week
+        assert_eq!(Format::new("YYYY:DDD:SSSSS.sss").unwrap().len(), 18);
+        assert_eq!(Format::new("WWWW:SSSSSS.ssss").unwrap().len(), 16);
+        assert!(Format::new("WWWW").is_ok());
+        assert!(Format::new("YYYY:DDD:SSSSSHH").is_err());
+        assert!(Format::new("YYYYWWWW:SSSSSS").is_err());
+        assert!(Format::new("WWWW:SSSSSS HHMM").is_err());
+        assert!(Format::new("YYYY:DDD:SSSSSS").is_err());
+        assert!(Format::new("WWW:SSSSSS").is_err());
+        assert!(Format::new("YYYY:DDD:SSSSS.ssssssssss").is_err());
+        // Fraction of seconds provided twice, which would overflow the nanoseconds when parsing
+        for descriptor in [
+            "YYYYMMDDHHMMSS.sssssssss.sssssssss",
+            "YYYYMMDDHHMMSS.sss.mmm",
+            "YYYYMMDDHHMMSS.mmmuuummm",
+            "WWWW:SSSSSS.s.s",
+        ] {
+            assert_eq!(
+                Format::new(descriptor),
+                Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+                "{}",
+                descriptor
+            );
+        }
+        assert!(Format::new("YYYYMMDDHHMMSS.nnnuuummm").is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_format_gps_week_and_second_of_day() {
+        use crate::{GnssTimeScale, TimeUnits};
+        let fmt = Format::new("WWWW:SSSSSS.ssss").unwrap();
+        let epoch = Epoch::from_week_tow(
+            GnssTimeScale::GPST,
+            2210,
+            496_681.seconds() + 1.milliseconds(),
+        );
+        // The time system is ignored with a GPS week token
+        for ts in [TimeSystem::UTC, TimeSystem::TT] {
+            assert_eq!(format!("{}", epoch.format(fmt, ts)), "2210:496681.0010");
+            assert_eq!(Epoch::from_format("2210:496681.0010", fmt, ts), Ok(epoch));
+        }
+        // Full week numbers are never rolled over
+        let epoch = Epoch::from_week_tow(GnssTimeScale::GPST, 1023, 604_799.seconds());
+        assert_eq!(
+            format!("{}", epoch.format(fmt, TimeSystem::UTC)),
+            "1023:604799.0000"
+        );
+        assert_eq!(
+            Epoch::from_format("1024:000000.0000", fmt, TimeSystem::UTC),
+            Ok(epoch + 1.seconds())
+        );
+        assert_eq!(
+            Epoch::from_format("1024:604800.0000", fmt, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+
+        let fmt = Format::new("YYYY:DDD:SSSSS.ssssss").unwrap();
+        let epoch = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 123_456_000);
+        assert_eq!(
+            format!("{}", epoch.format(fmt, TimeSystem::UTC)),
+            "2022:140:64663.123456"
+        );
+        assert_eq!(
+            Epoch::from_format("2022:140:64663.123456", fmt, TimeSystem::UTC),
+            Ok(epoch)
+        );
+        let fmt = Format::new("YYYY:DDD:SSSSS").unwrap();
+        assert_eq!(
+            Epoch::from_format("2016:366:86400", fmt, TimeSystem::UTC),
+            Ok(Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0))
+        );
+        assert_eq!(
+            Epoch::from_format("2016:366:86401", fmt, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_format_roundtrip() {
+        let epoch = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 123_456_000);
+        for (descriptor, expected) in [
+            ("YYYYMMDDHHMMSS", "20220520175743"),
+            ("YYDDDHHMMSSmmm", "22140175743123"),
+            ("YYYY-DDDTHH:MM:SS.mmmuuu", "2022-140T17:57:43.123456"),
+            ("DD/MM/YY HHMM", "20/05/22 1757"),
+            // Non-ASCII literals are copied and matched as whole characters
+            ("YYYY·DDD→HH∶MM∶SS", "2022·140→17∶57∶43"),
+        ] {
+            let fmt = Format::new(descriptor).unwrap();
+            assert_eq!(format!("{}", epoch.format(fmt, TimeSystem::UTC)), expected);
+            let parsed = Epoch::from_format(expected, fmt, TimeSystem::UTC).unwrap();
+            // The parsed epoch is the original one truncated to the precision of the format
+            assert_eq!(
+                format!("{}", parsed.format(fmt, TimeSystem::UTC)),
+                expected,
+                "{}",
+                descriptor
+            );
+        }
+
+        // Other time systems
+        let fmt = Format::new("YYYYMMDDHHMMSS").unwrap();
+        let epoch = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
+        assert_eq!(
+            format!("{}", epoch.format(fmt, TimeSystem::TAI)),
+            "20220520175743"
+        );
+        assert_eq!(
+            format!("{}", epoch.format(fmt, TimeSystem::UTC)),
+            "20220520175706"
+        );
+        assert_eq!(
+            Epoch::from_format("20220520175743", fmt, TimeSystem::TT).unwrap(),
+            Epoch::maybe_from_gregorian(2022, 5, 20, 17, 57, 43, 0, TimeSystem::TT).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_format_parse_errors() {
+        let fmt = Format::new("YYDDDHHMMSS").unwrap();
+        // Short year pivot
+        assert_eq!(
+            Epoch::from_format("99365000000", fmt, TimeSystem::UTC).unwrap(),
+            Epoch::from_gregorian_utc_at_midnight(1999, 12, 31)
+        );
+        assert_eq!(
+            Epoch::from_format("00366000000", fmt, TimeSystem::UTC).unwrap(),
+            Epoch::from_gregorian_utc_at_midnight(2000, 12, 31)
+        );
+        // Day 366 of a non leap year
+        assert_eq!(
+            Epoch::from_format("01366000000", fmt, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            Epoch::from_format("01000000000", fmt, TimeSystem::UTC),
+            Err(Errors::Carry)
+        );
+        // Non digit and length mismatches
+        assert_eq!(
+            Epoch::from_format("0136500000a", fmt, TimeSystem::UTC),
+            Err(Errors::ParseError(ParsingErrors::ParseIntError))
+        );
+        assert!(Epoch::from_format("0136500000", fmt, TimeSystem::UTC).is_err());
+        assert!(Epoch::from_format("013650000000", fmt, TimeSystem::UTC).is_err());
+        // Literal mismatch
+        let fmt = Format::new("YYYY-MM-DD").unwrap();
+        assert!(Epoch::from_format("2022/05/20", fmt, TimeSystem::UTC).is_err());
+        let fmt = Format::new("YYYY→MM→DD").unwrap();
+        assert_eq!(fmt.len(), 14);
+        assert!(Epoch::from_format("2022→05→20", fmt, TimeSystem::UTC).is_ok());
+        // Same first byte as the arrow, but another character
+        assert!(Epoch::from_format("2022←05←20", fmt, TimeSystem::UTC).is_err());
+        assert!(Epoch::from_format("2022-05-20", fmt, TimeSystem::UTC).is_err());
+        // Leap second
+        let fmt = Format::new("YYYYMMDDHHMMSS").unwrap();
+        assert_eq!(
+            Epoch::from_format("20161231235960", fmt, TimeSystem::UTC).unwrap(),
+            Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0)
+        );
+    }
+
+    #[test]
+    fn test_epoch_parser() {
+        let parser = EpochParser::new(Format::new("YYYY-DDDTHH:MM:SS|").unwrap(), TimeSystem::TAI);
+        assert_eq!(parser.time_system(), TimeSystem::TAI);
+        assert_eq!(parser.format().len(), 18);
+        let start = Epoch::from_gregorian_tai_hms(2022, 12, 31, 23, 59, 58);
+
+        // Timestamps embedded in binary data
+        let stream = b"2022-365T23:59:58|2022-365T23:59:59|2023-001T00:00:00|\xff\x00";
+        assert_eq!(parser.parse(stream), Ok((start, 18)));
+        assert_eq!(parser.parse(&stream[18..]), Ok((start + 1.seconds(), 18)));
+        let mut epochs = parser.parse_all(stream);
+        for i in 0..3 {
+            assert_eq!(epochs.next(), Some(Ok(start + i.seconds())));
+            assert_eq!(epochs.consumed(), 18 * (i as usize + 1));
+        }
+        assert_eq!(epochs.next(), None);
+        assert_eq!(epochs.remainder(), b"\xff\x00");
+        assert_eq!(parser.parse_all(b"").next(), None);
+
+        // Stops at the first error, which is left in the remainder
+        let stream = b"2022-365T23:59:58|2022-365T23:59:5x|2023-001T00:00:00|";
+        let mut epochs = parser.parse_all(stream);
+        assert_eq!(epochs.next(), Some(Ok(start)));
+        assert_eq!(
+            epochs.next(),
+            Some(Err(Errors::ParseError(ParsingErrors::ParseIntError)))
+        );
+        assert_eq!(epochs.next(), None);
+        assert_eq!(epochs.remainder(), &stream[18..]);
+
+        // Missing separator
+        assert_eq!(
+            parser.parse(b"2022-365T23:59:58,"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        // Incomplete timestamp
+        assert_eq!(
+            parser.parse(b"2022-365T23:59"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+    }
+}
@@ -0,0 +1,668 @@
+use crate::duration::{Duration, Unit};
+use crate::epoch::TAI_GPST_OFFSET_S;
+use crate::{Epoch, Errors, SECONDS_GPS_TAI_OFFSET_I64};
+use core::convert::TryFrom;
+
+/// Number of GPS weeks between the GPS epoch and the Galileo System Time epoch (1999-08-22).
+const GST_GPS_WEEKS: i64 = 1024;
+/// Number of GPS weeks between the GPS epoch and the BeiDou Time epoch (2006-01-01), which also
+/// happens 14 seconds into that week since BDT started 14 seconds behind GPS time.
+const BDT_GPS_WEEKS: i64 = 1356;
+/// BeiDou Time is a constant 14 seconds behind GPS time
+const GPST_BDT_OFFSET_S: i64 = 14;
+/// Number of bits of the time of week count of a GPS Z-count
+const Z_COUNT_TOW_BITS: u32 = 19;
+/// Number of 1.5 second Z-counts in a GPS week
+const Z_COUNTS_PER_WEEK: u32 = 403_200;
+
+/// The time scales of the global navigation satellite systems, which are all counted in weeks and
+/// time of week (TOW) from their own reference epoch.
+///
+/// Each scale is a constant integer number of seconds away from TAI. The actual offset between
+/// the realization of two scales also includes a few nanoseconds which are broadcast by the
+/// satellites, cf. [`GnssTimeOffset`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GnssTimeScale {
+    /// GPS Time, starting at UTC midnight of January 5th to 6th 1980, 19 seconds behind TAI
+    GPST,
+    /// Galileo System Time, starting at GPS week 1024 (1999-08-22), aligned with GPS time
+    GST,
+    /// BeiDou Time, starting at UTC midnight of January 1st 2006, 33 seconds behind TAI
+    BDT,
+}
+
+impl GnssTimeScale {
+    /// Returns the start of week zero of this time scale.
+    #[must_use]
+    pub fn reference_epoch(&self) -> Epoch {
+        let gps_epoch = Epoch::from_tai_duration(Unit::Second * SECONDS_GPS_TAI_OFFSET_I64);
+        match self {
+            Self::GPST => gps_epoch,
+            Self::GST => gps_epoch + Unit::Day * (7 * GST_GPS_WEEKS),
+            Self::BDT => {
+                gps_epoch + Unit::Day * (7 * BDT_GPS_WEEKS) + Unit::Second * GPST_BDT_OFFSET_S
+            }
+        }
+    }
+
+    /// Returns the constant TAI - scale offset (19 seconds for GPST and GST, 33 seconds for BDT).
+    #[must_use]
+    pub fn tai_offset(&self) -> Duration {
+        match self {
+            Self::GPST | Self::GST => Unit::Second * TAI_GPST_OFFSET_S,
+            Self::BDT => Unit::Second * (TAI_GPST_OFFSET_S + GPST_BDT_OFFSET_S),
+        }
+    }
+
+    /// Returns the nominal offset of this scale with respect to the other one, i.e. `self - other`,
+    /// such that the reading of this scale is the reading of the other plus the returned duration.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{GnssTimeScale, TimeUnits};
+    ///
+    /// assert_eq!(GnssTimeScale::BDT.nominal_offset(GnssTimeScale::GPST), -14.seconds());
+    /// assert_eq!(GnssTimeScale::GST.nominal_offset(GnssTimeScale::GPST), 0.seconds());
+    /// ```
+    #[must_use]
+    pub fn nominal_offset(&self, other: Self) -> Duration {
+        other.tai_offset() - self.tai_offset()
+    }
+}
+
+/// The broadcast offset between two GNSS time scales, modeled as a first order polynomial on top of
+/// their nominal offset, i.e. `scale - reference_scale = nominal + a0 + a1 * (t - t_ref)`.
+///
+/// This is for example the GPS to Galileo time offset (GGTO) broadcast by the Galileo satellites,
+/// or the BDS to GPS offset broadcast by the BeiDou satellites.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, GnssTimeOffset, GnssTimeScale, TimeUnits};
+///
+/// // GGTO parameters (A0G, A1G) with their reference time (t0G, WN0G)
+/// let t0g = Epoch::from_week_tow(GnssTimeScale::GST, 1176, 345_600.seconds());
+/// let ggto = GnssTimeOffset::ggto(-2.9e-9, -4.4e-15, t0g);
+/// let offset = ggto.offset_at(t0g + 1.hours());
+/// // The offset is only resolved to the nanosecond
+/// assert!((offset.in_seconds() - (-2.9e-9 - 4.4e-15 * 3600.0)).abs() < 1e-9);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GnssTimeOffset {
+    /// The time scale whose offset is computed
+    pub scale: GnssTimeScale,
+    /// The time scale from which the offset is computed
+    pub reference_scale: GnssTimeScale,
+    /// Constant term of the polynomial, in seconds
+    pub a0: f64,
+    /// Rate of the polynomial, in seconds per second
+    pub a1: f64,
+    /// Reference epoch of the polynomial
+    pub reference: Epoch,
+}
+
+impl GnssTimeOffset {
+    /// Builds the GPS to Galileo time offset, i.e. GST - GPST, from its broadcast parameters
+    /// (`A0G` in seconds, `A1G` in seconds per second, and the reference epoch from `t0G` and `WN0G`).
+    #[must_use]
+    pub const fn ggto(a0: f64, a1: f64, reference: Epoch) -> Self {
+        Self {
+            scale: GnssTimeScale::GST,
+            reference_scale: GnssTimeScale::GPST,
+            a0,
+            a1,
+            reference,
+        }
+    }
+
+    /// Builds the BeiDou to GPS time offset, i.e. BDT - GPST without the nominal 14 seconds, from
+    /// its broadcast parameters (`A0GPS` in seconds, `A1GPS` in seconds per second, and the reference epoch).
+    #[must_use]
+    pub const fn bds_gps(a0: f64, a1: f64, reference: Epoch) -> Self {
+        Self {
+            scale: GnssTimeScale::BDT,
+            reference_scale: GnssTimeScale::GPST,
+            a0,
+            a1,
+            reference,
+        }
+    }
+
+    /// Returns the full offset `scale - reference_scale` at the provided epoch, including the nominal offset.
+    #[must_use]
+    pub fn offset_at(&self, epoch: Epoch) -> Duration {
+        let dt = (epoch - self.reference).in_seconds();
+        self.scale.nominal_offset(self.reference_scale) + Unit::Second * (self.a0 + self.a1 * dt)
+    }
+
+    /// Returns the offset `reference_scale - scale` at the provided epoch, i.e. the offset in the other direction.
+    #[must_use]
+    pub fn inverse_offset_at(&self, epoch: Epoch) -> Duration {
+        -self.offset_at(epoch)
+    }
+}
+
+impl Epoch {
+    /// Initialize an Epoch from the week number and time of week (TOW) of the provided GNSS time scale.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, GnssTimeScale, TimeUnits};
+    ///
+    /// assert_eq!(
+    ///     Epoch::from_week_tow(GnssTimeScale::GPST, 1024, 0.seconds()),
+    ///     Epoch::from_week_tow(GnssTimeScale::GST, 0, 0.seconds())
+    /// );
+    /// assert_eq!(
+    ///     Epoch::from_week_tow(GnssTimeScale::BDT, 0, 0.seconds()),
+    ///     Epoch::from_gregorian_utc_at_midnight(2006, 1, 1)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_week_tow(scale: GnssTimeScale, week: i32, tow: Duration) -> Self {
+        scale.reference_epoch() + Unit::Day * (7 * i64::from(week)) + tow
+    }
+
+    /// Returns the week number and time of week (TOW) of this epoch in the provided GNSS time scale.
+    /// The week number is negative before the reference epoch of that scale, and is never rolled over.
+    #[must_use]
+    pub fn as_week_tow(&self, scale: GnssTimeScale) -> (i32, Duration) {
+        let elapsed = (*self - scale.reference_epoch()).total_nanoseconds();
+        let week = (Unit::Day * 7).total_nanoseconds();
+        (
+            elapsed.div_euclid(week) as i32,
+            Duration::from_total_nanoseconds(elapsed.rem_euclid(week)),
+        )
+    }
+
+    /// Initialize an Epoch from the provided full GPS Z-count, i.e. the GPS week number (not rolled
+    /// over) in the upper bits, followed by 19 bits of time of week in units of 1.5 seconds.
+    ///
+    /// Returns `Errors::Carry` if the time of week count exceeds a week. To decode the 29 bit
+    /// Z-count broadcast by the satellites, whose week is modulo 1024, use
+    /// [`PackedWeekTow::GPS_Z_COUNT`] with a reference epoch instead.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, GnssTimeScale, TimeUnits};
+    ///
+    /// let epoch = Epoch::from_gps_zcount((2210 << 19) | 331_120).unwrap();
+    /// assert_eq!(
+    ///     epoch,
+    ///     Epoch::from_week_tow(GnssTimeScale::GPST, 2210, 496_680.seconds())
+    /// );
+    /// // Epochs between two Z-counts are truncated to the previous one
+    /// assert_eq!((epoch + 1.4.seconds()).as_gps_zcount(), Ok((2210 << 19) | 331_120));
+    /// ```
+    pub fn from_gps_zcount(z_count: u32) -> Result<Self, Errors> {
+        let count = z_count & ((1 << Z_COUNT_TOW_BITS) - 1);
+        if count >= Z_COUNTS_PER_WEEK {
+            return Err(Errors::Carry);
+        }
+        Ok(Self::from_week_tow(
+            GnssTimeScale::GPST,
+            (z_count >> Z_COUNT_TOW_BITS) as i32,
+            PackedWeekTow::GPS_Z_COUNT.tow_resolution * i64::from(count),
+        ))
+    }
+
+    /// Returns the full GPS Z-count of this epoch, cf. [`Epoch::from_gps_zcount`], where the time
+    /// of week is truncated to the start of its 1.5 second period.
+    ///
+    /// Returns `Errors::Overflow` if this epoch is before the GPS epoch, or if its week number does
+    /// not fit in the 13 upper bits (i.e. after the year 2137).
+    pub fn as_gps_zcount(&self) -> Result<u32, Errors> {
+        let (week, tow) = self.as_week_tow(GnssTimeScale::GPST);
+        if !(0..1 << (32 - Z_COUNT_TOW_BITS)).contains(&week) {
+            return Err(Errors::Overflow);
+        }
+        let count = tow.total_nanoseconds()
+            / PackedWeekTow::GPS_Z_COUNT
+                .tow_resolution
+                .total_nanoseconds();
+        Ok(((week as u32) << Z_COUNT_TOW_BITS) | count as u32)
+    }
+}
+
+/// Layout of a packed week number and time of week (TOW) field, as found in GNSS binary messages.
+///
+/// The field is `week_bits` bits of the week number modulo `2^week_bits`, followed by `tow_bits`
+/// bits of the number of `tow_resolution` elapsed in the week. The week ambiguity is resolved with
+/// a reference epoch (e.g. the current time from another source), picking the epoch closest to it.
+/// Besides the usual fields provided as constants, other layouts are built with
+/// [`PackedWeekTow::new`].
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, PackedWeekTow, TimeUnits};
+///
+/// let epoch = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 0);
+/// let z_count = PackedWeekTow::GPS_Z_COUNT.encode(epoch);
+/// // This is 496_681 seconds into the GPS week, but the Z-count has a resolution of 1.5 seconds
+/// assert_eq!(z_count & 0x7_FFFF, 331_120);
+/// let decoded = PackedWeekTow::GPS_Z_COUNT.decode(z_count, epoch + 10.days()).unwrap();
+/// assert_eq!(decoded, epoch - 1.seconds());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PackedWeekTow {
+    scale: GnssTimeScale,
+    week_bits: u8,
+    tow_bits: u8,
+    tow_resolution: Duration,
+}
+
+impl PackedWeekTow {
+    /// The 29 bit GPS Z-count: 10 bit week (modulo 1024) and 19 bit TOW in units of 1.5 seconds.
+    pub const GPS_Z_COUNT: Self = Self {
+        scale: GnssTimeScale::GPST,
+        week_bits: 10,
+        tow_bits: 19,
+        tow_resolution: Duration {
+            centuries: 0,
+            nanoseconds: 1_500_000_000,
+        },
+    };
+
+    /// The 17 bit truncated GPS TOW of the hand over word (HOW), in units of 6 seconds (i.e. four Z-counts).
+    pub const GPS_TRUNCATED_TOW: Self = Self {
+        scale: GnssTimeScale::GPST,
+        week_bits: 0,
+        tow_bits: 17,
+        tow_resolution: Duration {
+            centuries: 0,
+            nanoseconds: 6_000_000_000,
+        },
+    };
+
+    /// The GPS CNAV week and TOW: 13 bit week (modulo 8192) and 17 bit TOW in units of 6 seconds.
+    pub const GPS_CNAV: Self = Self {
+        scale: GnssTimeScale::GPST,
+        week_bits: 13,
+        tow_bits: 17,
+        tow_resolution: Duration {
+            centuries: 0,
+            nanoseconds: 6_000_000_000,
+        },
+    };
+
+    /// The Galileo I/NAV week and TOW: 12 bit week (modulo 4096) and 20 bit TOW in seconds.
+    pub const GALILEO: Self = Self {
+        scale: GnssTimeScale::GST,
+        week_bits: 12,
+        tow_bits: 20,
+        tow_resolution: Duration {
+            centuries: 0,
+            nanoseconds: 1_000_000_000,
+        },
+    };
+
+    /// The BeiDou week and seconds of week: 13 bit week (modulo 8192) and 20 bit SOW in seconds.
+    pub const BEIDOU: Self = Self {
+        scale: GnssTimeScale::BDT,
+        week_bits: 13,
+        tow_bits: 20,
+        tow_resolution: Duration {
+            centuries: 0,
+            nanoseconds: 1_000_000_000,
+        },
+    };
+
+    /// Builds the layout of a field in the provided time scale, made of `week_bits` bits of week
+    /// number (which may be zero if the field only contains the TOW) followed by `tow_bits` bits of
+    /// the number of `tow_resolution` elapsed in the week.
+    ///
+    /// Returns `Errors::Overflow` if the field does not fit in 64 bits, or if the count of a whole
+    /// week does not fit in `tow_bits` (including when the resolution is not positive).
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Errors, GnssTimeScale, PackedWeekTow, Unit};
+    ///
+    /// let field = PackedWeekTow::new(GnssTimeScale::GPST, 10, 19, Unit::Millisecond * 1_500);
+    /// assert_eq!(field, Ok(PackedWeekTow::GPS_Z_COUNT));
+    /// // A week is 604_800 seconds, which does not fit in 19 bits
+    /// assert_eq!(
+    ///     PackedWeekTow::new(GnssTimeScale::GPST, 10, 19, Unit::Second * 1),
+    ///     Err(Errors::Overflow)
+    /// );
+    /// ```
+    pub fn new(
+        scale: GnssTimeScale,
+        week_bits: u8,
+        tow_bits: u8,
+        tow_resolution: Duration,
+    ) -> Result<Self, Errors> {
+        let resolution_ns = tow_resolution.total_nanoseconds();
+        if u32::from(week_bits) + u32::from(tow_bits) > 64
+            || resolution_ns <= 0
+            || (Unit::Day * 7).total_nanoseconds() - 1 > i128::from(mask(tow_bits)) * resolution_ns
+        {
+            return Err(Errors::Overflow);
+        }
+        Ok(Self {
+            scale,
+            week_bits,
+            tow_bits,
+            tow_resolution,
+        })
+    }
+
+    /// Returns the time scale of the week and time of week.
+    #[must_use]
+    pub const fn scale(&self) -> GnssTimeScale {
+        self.scale
+    }
+
+    /// Returns the number of bits of the week number, which is zero if the field only contains the TOW.
+    #[must_use]
+    pub const fn week_bits(&self) -> u8 {
+        self.week_bits
+    }
+
+    /// Returns the number of bits of the time of week count.
+    #[must_use]
+    pub const fn tow_bits(&self) -> u8 {
+        self.tow_bits
+    }
+
+    /// Returns the duration of one unit of the time of week count.
+    #[must_use]
+    pub const fn tow_resolution(&self) -> Duration {
+        self.tow_resolution
+    }
+
+    /// Returns the total number of bits of this field, at most 64.
+    #[must_use]
+    pub const fn bits(&self) -> u8 {
+        self.week_bits + self.tow_bits
+    }
+
+    /// Encodes the provided epoch, truncating its time of week to the resolution of this field.
+    /// The epoch must be after the reference epoch of the time scale.
+    #[must_use]
+    pub fn encode(&self, epoch: Epoch) -> u64 {
+        let (week, tow) = epoch.as_week_tow(self.scale);
+        let count = (tow.total_nanoseconds() / self.tow_resolution.total_nanoseconds()) as u64;
+        // Masking the two's complement of the week is its remainder modulo 2^week_bits
+        let week = i64::from(week) as u64 & mask(self.week_bits);
+        week.checked_shl(u32::from(self.tow_bits)).unwrap_or(0) | count
+    }
+
+    /// Decodes the provided field, resolving the week ambiguity with the provided reference epoch.
+    ///
+    /// Returns `Errors::Overflow` if the raw value has more bits than this field (or if its week
+    /// cannot be represented), and `Errors::Carry` if the time of week count exceeds a week.
+    pub fn decode(&self, raw: u64, reference: Epoch) -> Result<Epoch, Errors> {
+        if raw & !mask(self.bits()) != 0 {
+            return Err(Errors::Overflow);
+        }
+        let count = raw & mask(self.tow_bits);
+        let tow = Duration::from_total_nanoseconds(
+            i128::from(count) * self.tow_resolution.total_nanoseconds(),
+        );
+        if tow >= Unit::Day * 7 {
+            return Err(Errors::Carry);
+        }
+        let modulus = 1_i128 << self.week_bits;
+        let week_mod = i128::from(raw.checked_shr(u32::from(self.tow_bits)).unwrap_or(0));
+        let (ref_week, _) = reference.as_week_tow(self.scale);
+        let ref_week = i128::from(ref_week);
+        // Latest week with this remainder up to the reference week, then check its neighbors
+        let week = ref_week - (ref_week - week_mod).rem_euclid(modulus);
+        let mut best: Option<Epoch> = None;
+        for candidate in [week, week - modulus, week + modulus] {
+            let candidate = match i32::try_from(candidate) {
+                Ok(candidate) => candidate,
+                Err(_) => continue,
+            };
+            let epoch = Epoch::from_week_tow(self.scale, candidate, tow);
+            if best.map_or(true, |best| {
+                (epoch - reference).abs() < (best - reference).abs()
+            }) {
+                best = Some(epoch);
+            }
+        }
+        best.ok_or(Errors::Overflow)
+    }
+
+    /// Encodes the provided epoch as big endian bytes, right aligned in the provided buffer.
+    /// Returns `Errors::Overflow` if the buffer is too small to hold this field.
+    pub fn encode_be(&self, epoch: Epoch, buf: &mut [u8]) -> Result<(), Errors> {
+        if buf.len() * 8 < usize::from(self.bits()) || buf.len() > 8 {
+            return Err(Errors::Overflow);
+        }
+        let raw = self.encode(epoch).to_be_bytes();
+        buf.copy_from_slice(&raw[8 - buf.len()..]);
+        Ok(())
+    }
+
+    /// Decodes the field from its big endian bytes, right aligned, cf. [`PackedWeekTow::decode`].
+    pub fn decode_be(&self, buf: &[u8], reference: Epoch) -> Result<Epoch, Errors> {
+        if buf.len() > 8 {
+            return Err(Errors::Overflow);
+        }
+        let raw = buf
+            .iter()
+            .fold(0_u64, |raw, byte| (raw << 8) | u64::from(*byte));
+        self.decode(raw, reference)
+    }
+}
+
+/// Returns the mask of the provided number of low bits, i.e. all the bits from 64 bits onward.
+fn mask(bits: u8) -> u64 {
+    1_u64
+        .checked_shl(u32::from(bits))
+        .map_or(u64::MAX, |bit| bit - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, Errors, GnssTimeOffset, GnssTimeScale, PackedWeekTow, TimeUnits, Unit};
+
+    #[test]
+    fn test_week_tow() {
+        // Cf. https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS
+        let gst0 = Epoch::from_week_tow(GnssTimeScale::GST, 0, 0.seconds());
+        assert_eq!(gst0, Epoch::from_gregorian_utc(1999, 8, 21, 23, 59, 47, 0));
+        assert_eq!(gst0.as_week_tow(GnssTimeScale::GPST), (1024, 0.seconds()));
+
+        let bdt0 = Epoch::from_week_tow(GnssTimeScale::BDT, 0, 0.seconds());
+        assert_eq!(bdt0, Epoch::from_gregorian_utc_at_midnight(2006, 1, 1));
+        assert_eq!(bdt0.as_week_tow(GnssTimeScale::GPST), (1356, 14.seconds()));
+        assert_eq!(bdt0.as_week_tow(GnssTimeScale::GST), (332, 14.seconds()));
+
+        let epoch = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 123_456_789);
+        for scale in [GnssTimeScale::GPST, GnssTimeScale::GST, GnssTimeScale::BDT] {
+            let (week, tow) = epoch.as_week_tow(scale);
+            assert!(tow < Unit::Day * 7);
+            assert_eq!(Epoch::from_week_tow(scale, week, tow), epoch);
+        }
+        // Friday 20 May 2022 at 17:58:01 GPST, which is 18 seconds ahead of UTC
+        assert_eq!(
+            epoch.as_week_tow(GnssTimeScale::GPST),
+            (
+                2210,
+                5.days() + 17.hours() + 58.minutes() + 1.seconds() + 123_456_789.nanoseconds()
+            )
+        );
+        let (bdt_week, bdt_tow) = epoch.as_week_tow(GnssTimeScale::BDT);
+        assert_eq!(bdt_week, 2210 - 1356);
+        assert_eq!(
+            bdt_tow,
+            5.days() + 17.hours() + 57.minutes() + 47.seconds() + 123_456_789.nanoseconds()
+        );
+
+        // Before the reference epoch
+        assert_eq!(
+            (bdt0 - 1.seconds()).as_week_tow(GnssTimeScale::BDT),
+            (-1, 7.days() - 1.seconds())
+        );
+    }
+
+    #[test]
+    fn test_gnss_offsets() {
+        assert_eq!(
+            GnssTimeScale::GPST.nominal_offset(GnssTimeScale::BDT),
+            14.seconds()
+        );
+        assert_eq!(
+            GnssTimeScale::BDT.nominal_offset(GnssTimeScale::GST),
+            -14.seconds()
+        );
+
+        let reference = Epoch::from_week_tow(GnssTimeScale::BDT, 800, 0.seconds());
+        let bds_gps = GnssTimeOffset::bds_gps(1e-8, 1e-12, reference);
+        let offset = bds_gps.offset_at(reference + 1000.seconds());
+        assert!((offset - (-14.seconds() + 11.nanoseconds())).abs() < 1.nanoseconds());
+        assert_eq!(
+            bds_gps.inverse_offset_at(reference),
+            -bds_gps.offset_at(reference)
+        );
+    }
+
+    #[test]
+    fn test_packed_week_tow() {
+        let epoch = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 123_456_789);
+        // GPS week 2210 is 162 modulo 1024, and 496_681 seconds of week are 331_120 Z-counts
+        let z_count = PackedWeekTow::GPS_Z_COUNT.encode(epoch);
+        assert_eq!(z_count, (162 << 19) | 331_120);
+        assert_eq!(PackedWeekTow::GPS_Z_COUNT.bits(), 29);
+
+        for field in [
+            PackedWeekTow::GPS_Z_COUNT,
+            PackedWeekTow::GPS_TRUNCATED_TOW,
+            PackedWeekTow::GPS_CNAV,
+            PackedWeekTow::GALILEO,
+            PackedWeekTow::BEIDOU,
+        ] {
+            let raw = field.encode(epoch);
+            assert!(raw < 1 << field.bits());
+            // The decoded epoch is the encoded one truncated to the resolution
+            let (_, tow) = epoch.as_week_tow(field.scale());
+            let truncated = epoch - (tow - tow.floor(field.tow_resolution()));
+            for offset in [-2.days(), 0.days(), 3.days() + 11.hours()] {
+                assert_eq!(
+                    field.decode(raw, epoch + offset),
+                    Ok(truncated),
+                    "{:?} {}",
+                    field,
+                    offset
+                );
+            }
+            let mut buf = [0_u8; 5];
+            field.encode_be(epoch, &mut buf).unwrap();
+            assert_eq!(field.decode_be(&buf, epoch), Ok(truncated));
+        }
+
+        // Week rollover: the reference is in the next roll over period
+        let reference = epoch + 1024 * 7.days() - 1.days();
+        assert_eq!(
+            PackedWeekTow::GPS_Z_COUNT.decode(z_count, reference),
+            Ok(PackedWeekTow::GPS_Z_COUNT.decode(z_count, epoch).unwrap() + 1024 * 7.days())
+        );
+
+        // Invalid fields
+        assert_eq!(
+            PackedWeekTow::GPS_Z_COUNT.decode(1 << 29, epoch),
+            Err(Errors::Overflow)
+        );
+        assert_eq!(
+            PackedWeekTow::GPS_Z_COUNT.decode(403_200, epoch),
+            Err(Errors::Carry)
+        );
+        let mut buf = [0_u8; 3];
+        assert_eq!(
+            PackedWeekTow::GPS_Z_COUNT.encode_be(epoch, &mut buf),
+            Err(Errors::Overflow)
+        );
+
+        // Custom layouts, up to 64 bits
+        for field in [
+            PackedWeekTow::GPS_Z_COUNT,
+            PackedWeekTow::GPS_TRUNCATED_TOW,
+            PackedWeekTow::GPS_CNAV,
+            PackedWeekTow::GALILEO,
+            PackedWeekTow::BEIDOU,
+        ] {
+            assert_eq!(
+                PackedWeekTow::new(
+                    field.scale(),
+                    field.week_bits(),
+                    field.tow_bits(),
+                    field.tow_resolution()
+                ),
+                Ok(field)
+            );
+        }
+        for (week_bits, tow_bits) in [(0, 64), (32, 32), (44, 20), (64, 0)] {
+            let field = PackedWeekTow::new(GnssTimeScale::GPST, week_bits, tow_bits, 1.seconds());
+            if tow_bits < 20 {
+                assert_eq!(field, Err(Errors::Overflow));
+                continue;
+            }
+            let field = field.unwrap();
+            assert_eq!(field.bits(), 64);
+            let truncated = epoch - 123_456_789.nanoseconds();
+            assert_eq!(field.decode(field.encode(epoch), epoch), Ok(truncated));
+            assert_eq!(field.decode(u64::MAX, epoch), Err(Errors::Carry));
+        }
+        // Without any week, the epoch is within half a week of the reference
+        let field = PackedWeekTow::new(GnssTimeScale::GPST, 0, 64, 1.seconds()).unwrap();
+        let raw = field.encode(epoch);
+        assert_eq!(raw, 496_681);
+        assert_eq!(
+            field.decode(raw, epoch + 3.days()),
+            Ok(epoch - 123_456_789.nanoseconds())
+        );
+        for (week_bits, tow_bits) in [(45, 20), (1, 64), (64, 1), (u8::MAX, u8::MAX)] {
+            assert_eq!(
+                PackedWeekTow::new(GnssTimeScale::GPST, week_bits, tow_bits, 1.seconds()),
+                Err(Errors::Overflow)
+            );
+        }
+        for resolution in [0.seconds(), -1.seconds()] {
+            assert_eq!(
+                PackedWeekTow::new(GnssTimeScale::GPST, 10, 19, resolution),
+                Err(Errors::Overflow)
+            );
+        }
+    }
+
+    #[test]
+    fn test_gps_zcount() {
+        let epoch = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 123_456_789);
+        let z_count = epoch.as_gps_zcount().unwrap();
+        assert_eq!(z_count, (2210 << 19) | 331_120);
+        // The Z-count is truncated, 1.123456789 seconds after the 331_120th Z-count
+        assert_eq!(
+            Epoch::from_gps_zcount(z_count),
+            Ok(epoch - 1.seconds() - 123_456_789.nanoseconds())
+        );
+        // Same as the broadcast Z-count, once its week is rolled over
+        assert_eq!(
+            u64::from(z_count) & ((1 << 29) - 1),
+            PackedWeekTow::GPS_Z_COUNT.encode(epoch)
+        );
+
+        let gps0 = GnssTimeScale::GPST.reference_epoch();
+        assert_eq!(Epoch::from_gps_zcount(0), Ok(gps0));
+        assert_eq!(gps0.as_gps_zcount(), Ok(0));
+        assert_eq!((gps0 + 1_499.milliseconds()).as_gps_zcount(), Ok(0));
+        assert_eq!((gps0 + 1.5.seconds()).as_gps_zcount(), Ok(1));
+        assert_eq!(
+            (gps0 + 7.days() - 1.nanoseconds()).as_gps_zcount(),
+            Ok(403_199)
+        );
+        assert_eq!((gps0 + 7.days()).as_gps_zcount(), Ok(1 << 19));
+
+        assert_eq!(Epoch::from_gps_zcount(403_200), Err(Errors::Carry));
+        assert_eq!(
+            (gps0 - 1.nanoseconds()).as_gps_zcount(),
+            Err(Errors::Overflow)
+        );
+        assert_eq!(
+            (gps0 + 8192 * 7.days()).as_gps_zcount(),
+            Err(Errors::Overflow)
+        );
+    }
+}
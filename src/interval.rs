@@ -0,0 +1,165 @@
+use super::{Duration, Epoch};
+
+/// A closed interval of time, from its start epoch to its end epoch, both included.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, Interval, TimeUnits};
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let pass = Interval::new(start + 10.minutes(), start + 22.minutes());
+/// assert_eq!(pass.duration(), 12.minutes());
+/// assert!(pass.contains(start + 22.minutes()));
+/// assert!(!pass.contains(start));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Interval {
+    start: Epoch,
+    end: Epoch,
+}
+
+impl Interval {
+    /// Builds a new interval between both epochs, which may be provided in any order.
+    #[must_use]
+    pub fn new(start: Epoch, end: Epoch) -> Self {
+        if end < start {
+            Self {
+                start: end,
+                end: start,
+            }
+        } else {
+            Self { start, end }
+        }
+    }
+
+    /// Returns the start epoch, i.e. the earliest of both epochs.
+    #[must_use]
+    pub const fn start(&self) -> Epoch {
+        self.start
+    }
+
+    /// Returns the end epoch, i.e. the latest of both epochs.
+    #[must_use]
+    pub const fn end(&self) -> Epoch {
+        self.end
+    }
+
+    /// Returns the duration of this interval, which is never negative.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// Returns true if the provided epoch is within this interval, bounds included.
+    #[must_use]
+    pub fn contains(&self, epoch: Epoch) -> bool {
+        self.start <= epoch && epoch <= self.end
+    }
+
+    /// Returns the intersection of both intervals, if they overlap (sharing a bound counts as overlapping).
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = if self.start > other.start {
+            self.start
+        } else {
+            other.start
+        };
+        let end = if self.end < other.end {
+            self.end
+        } else {
+            other.end
+        };
+        if start <= end {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Returns this interval clipped to the bounds of the other one. Unlike the
+    /// [intersection](Interval::intersection), this is always an interval within the other one: if
+    /// both intervals do not overlap, it is empty, at the bound of the other interval closest to
+    /// this one. This allows summing the durations of intervals within a period.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, Epoch, Interval, TimeUnits};
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// let day = Interval::new(start, start + 1.days());
+    /// let passes = [
+    ///     Interval::new(start - 1.hours(), start + 1.hours()),
+    ///     Interval::new(start + 12.hours(), start + 13.hours()),
+    ///     Interval::new(start + 2.days(), start + 3.days()),
+    /// ];
+    /// let contact: Duration = passes.iter().map(|pass| pass.clip(&day).duration()).sum();
+    /// assert_eq!(contact, 2.hours());
+    /// assert_eq!(passes[2].clip(&day), Interval::new(day.end(), day.end()));
+    /// ```
+    #[must_use]
+    pub fn clip(&self, other: &Self) -> Self {
+        let clamp = |epoch: Epoch| {
+            if epoch < other.start {
+                other.start
+            } else if epoch > other.end {
+                other.end
+            } else {
+                epoch
+            }
+        };
+        Self {
+            start: clamp(self.start),
+            end: clamp(self.end),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, Interval, TimeUnits};
+
+    #[test]
+    fn test_interval() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+        let interval = Interval::new(start + 1.hours(), start);
+        assert_eq!(interval.start(), start);
+        assert_eq!(interval.end(), start + 1.hours());
+        assert_eq!(interval.duration(), 1.hours());
+        assert!(interval.contains(start));
+        assert!(interval.contains(start + 1.hours()));
+        assert!(!interval.contains(start + 1.hours() + 1.nanoseconds()));
+
+        let other = Interval::new(start + 30.minutes(), start + 2.hours());
+        assert_eq!(
+            interval.intersection(&other),
+            Some(Interval::new(start + 30.minutes(), start + 1.hours()))
+        );
+        assert_eq!(interval.intersection(&other), other.intersection(&interval));
+        let touching = Interval::new(start + 1.hours(), start + 2.hours());
+        assert_eq!(
+            interval.intersection(&touching),
+            Some(Interval::new(start + 1.hours(), start + 1.hours()))
+        );
+        let disjoint = Interval::new(start + 2.hours(), start + 3.hours());
+        assert_eq!(interval.intersection(&disjoint), None);
+
+        assert_eq!(
+            interval.clip(&other),
+            Interval::new(start + 30.minutes(), start + 1.hours())
+        );
+        assert_eq!(interval.clip(&interval), interval);
+        assert_eq!(
+            other.clip(&Interval::new(start - 1.hours(), start + 3.hours())),
+            other
+        );
+        assert_eq!(
+            interval.clip(&disjoint),
+            Interval::new(start + 2.hours(), start + 2.hours())
+        );
+        assert_eq!(
+            disjoint.clip(&interval),
+            Interval::new(start + 1.hours(), start + 1.hours())
+        );
+        assert_eq!(interval.clip(&disjoint).duration(), 0.hours());
+    }
+}
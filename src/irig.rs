@@ -0,0 +1,322 @@
+use crate::epoch::{day_of_year, month_day_from_day_of_year};
+use crate::format::year_from_short_year;
+use crate::{Epoch, Errors, ParsingErrors, TimeSystem};
+
+/// Number of bits (i.e. of 10 ms elements) in an IRIG-B frame.
+pub const IRIG_B_FRAME_LEN: usize = 100;
+
+/// A single element of an IRIG-B frame, as determined by the pulse width of the carrier.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IrigBit {
+    /// A 2 ms pulse
+    Zero,
+    /// A 5 ms pulse
+    One,
+    /// An 8 ms pulse, used as the frame reference and position identifiers
+    Marker,
+}
+
+/// Bits of the BCD time-of-year fields of an IRIG-B frame, as (bit index, weight).
+/// The index bits which separate the digits are always zero.
+const SECONDS: [(usize, u32); 7] = [(1, 1), (2, 2), (3, 4), (4, 8), (6, 10), (7, 20), (8, 40)];
+const MINUTES: [(usize, u32); 7] = [
+    (10, 1),
+    (11, 2),
+    (12, 4),
+    (13, 8),
+    (15, 10),
+    (16, 20),
+    (17, 40),
+];
+const HOURS: [(usize, u32); 6] = [(20, 1), (21, 2), (22, 4), (23, 8), (25, 10), (26, 20)];
+const DAYS: [(usize, u32); 10] = [
+    (30, 1),
+    (31, 2),
+    (32, 4),
+    (33, 8),
+    (35, 10),
+    (36, 20),
+    (37, 40),
+    (38, 80),
+    (40, 100),
+    (41, 200),
+];
+const TENTHS: [(usize, u32); 4] = [(45, 1), (46, 2), (47, 4), (48, 8)];
+const YEARS: [(usize, u32); 8] = [
+    (50, 1),
+    (51, 2),
+    (52, 4),
+    (53, 8),
+    (55, 10),
+    (56, 20),
+    (57, 40),
+    (58, 80),
+];
+
+/// Returns true if the provided bit index is a marker: the reference bit and every tenth bit after 9.
+const fn is_marker(idx: usize) -> bool {
+    idx == 0 || idx % 10 == 9
+}
+
+/// An IRIG-B (IRIG Standard 200) time code frame, i.e. the time of year transmitted once per second.
+///
+/// The frame only carries a two digit year: years 50 to 99 map to 1950 to 1999 and 00 to 49 map to
+/// 2000 to 2049 when converting back to an Epoch. The control functions are not supported and
+/// encoded as zeros.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, IrigBFrame, TimeSystem};
+///
+/// let epoch = Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 300_000_000);
+/// let frame = IrigBFrame::from_epoch(epoch, TimeSystem::UTC);
+/// assert_eq!(frame.day_of_year, 140);
+/// assert_eq!(frame.seconds_of_day(), 64_663);
+///
+/// let decoded = IrigBFrame::from_bits(&frame.to_bits()).unwrap();
+/// assert_eq!(decoded, frame);
+/// assert_eq!(decoded.to_epoch(TimeSystem::UTC).unwrap(), epoch);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IrigBFrame {
+    /// Last two digits of the year
+    pub year: u8,
+    /// Day of the year, starting at 1
+    pub day_of_year: u16,
+    /// Hour of the day, from 0 to 23
+    pub hour: u8,
+    /// Minute of the hour, from 0 to 59
+    pub minute: u8,
+    /// Second of the minute, from 0 to 59 (or 60 during a leap second)
+    pub second: u8,
+    /// Tenths of seconds
+    pub tenths: u8,
+}
+
+impl IrigBFrame {
+    /// Builds the frame of the provided epoch, expressed in the provided time system (usually UTC).
+    /// The sub-second part of the epoch is truncated to the tenth of second, after rounding to the
+    /// millisecond to absorb the floating point error of the Gregorian conversion.
+    #[must_use]
+    pub fn from_epoch(epoch: Epoch, ts: TimeSystem) -> Self {
+        let (year, month, day, hour, minute, second, nanos) = epoch.as_gregorian(ts);
+        Self {
+            year: year.rem_euclid(100) as u8,
+            day_of_year: day_of_year(year, month, day),
+            hour,
+            minute,
+            second,
+            tenths: (((nanos + 500_000) / 1_000_000).min(999) / 100) as u8,
+        }
+    }
+
+    /// Returns the epoch of this frame in the provided time system, or an error if the fields are invalid.
+    pub fn to_epoch(&self, ts: TimeSystem) -> Result<Epoch, Errors> {
+        let year = year_from_short_year(self.year);
+        let (month, day) =
+            month_day_from_day_of_year(year, self.day_of_year).ok_or(Errors::Carry)?;
+        Epoch::maybe_from_gregorian(
+            year,
+            month,
+            day,
+            self.hour,
+            self.minute,
+            self.second,
+            u32::from(self.tenths) * 100_000_000,
+            ts,
+        )
+    }
+
+    /// Returns the straight binary seconds of day, as encoded in the last part of the frame.
+    #[must_use]
+    pub fn seconds_of_day(&self) -> u32 {
+        u32::from(self.hour) * 3600 + u32::from(self.minute) * 60 + u32::from(self.second)
+    }
+
+    /// Encodes this frame into its 100 elements, starting with the reference marker.
+    #[must_use]
+    pub fn to_bits(&self) -> [IrigBit; IRIG_B_FRAME_LEN] {
+        let mut bits = [IrigBit::Zero; IRIG_B_FRAME_LEN];
+        for (idx, bit) in bits.iter_mut().enumerate() {
+            if is_marker(idx) {
+                *bit = IrigBit::Marker;
+            }
+        }
+        encode_bcd(&mut bits, &SECONDS, u32::from(self.second));
+        encode_bcd(&mut bits, &MINUTES, u32::from(self.minute));
+        encode_bcd(&mut bits, &HOURS, u32::from(self.hour));
+        encode_bcd(&mut bits, &DAYS, u32::from(self.day_of_year));
+        encode_bcd(&mut bits, &TENTHS, u32::from(self.tenths));
+        encode_bcd(&mut bits, &YEARS, u32::from(self.year));
+        // Straight binary seconds: bits 80 to 88 then 90 to 97, least significant bit first
+        let sbs = self.seconds_of_day();
+        for n in 0..17 {
+            let idx = if n < 9 { 80 + n } else { 81 + n };
+            if (sbs >> n) & 1 == 1 {
+                bits[idx] = IrigBit::One;
+            }
+        }
+        bits
+    }
+
+    /// Decodes a frame from its 100 elements, starting with the reference marker.
+    ///
+    /// Returns a `ParseError(UnknownFormat)` if the markers are misplaced, and `Carry` if a BCD
+    /// digit is invalid or the straight binary seconds do not match the BCD time of day.
+    pub fn from_bits(bits: &[IrigBit; IRIG_B_FRAME_LEN]) -> Result<Self, Errors> {
+        for (idx, bit) in bits.iter().enumerate() {
+            if is_marker(idx) != (*bit == IrigBit::Marker) {
+                return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+            }
+        }
+        let me = Self {
+            second: decode_bcd(bits, &SECONDS)? as u8,
+            minute: decode_bcd(bits, &MINUTES)? as u8,
+            hour: decode_bcd(bits, &HOURS)? as u8,
+            day_of_year: decode_bcd(bits, &DAYS)? as u16,
+            tenths: decode_bcd(bits, &TENTHS)? as u8,
+            year: decode_bcd(bits, &YEARS)? as u8,
+        };
+        if me.hour > 23 || me.minute > 59 || me.second > 60 || me.day_of_year > 366 {
+            return Err(Errors::Carry);
+        }
+        let mut sbs = 0;
+        for n in 0..17 {
+            let idx = if n < 9 { 80 + n } else { 81 + n };
+            if bits[idx] == IrigBit::One {
+                sbs |= 1 << n;
+            }
+        }
+        if sbs != me.seconds_of_day() {
+            return Err(Errors::Carry);
+        }
+        Ok(me)
+    }
+}
+
+impl Epoch {
+    /// Returns the IRIG-B frame of this epoch in the provided time system, cf. [`IrigBFrame::from_epoch`].
+    #[must_use]
+    pub fn as_irig_b(&self, ts: TimeSystem) -> IrigBFrame {
+        IrigBFrame::from_epoch(*self, ts)
+    }
+}
+
+/// Returns the decimal scale (1, 10 or 100) of the BCD digit of the provided weight.
+const fn bcd_scale(weight: u32) -> u32 {
+    if weight >= 100 {
+        100
+    } else if weight >= 10 {
+        10
+    } else {
+        1
+    }
+}
+
+fn encode_bcd(bits: &mut [IrigBit; IRIG_B_FRAME_LEN], weights: &[(usize, u32)], value: u32) {
+    for (idx, weight) in weights {
+        let scale = bcd_scale(*weight);
+        if ((value / scale) % 10) & (weight / scale) != 0 {
+            bits[*idx] = IrigBit::One;
+        }
+    }
+}
+
+fn decode_bcd(bits: &[IrigBit; IRIG_B_FRAME_LEN], weights: &[(usize, u32)]) -> Result<u32, Errors> {
+    // Units, tens and hundreds
+    let mut digits = [0, 0, 0];
+    for (idx, weight) in weights {
+        if bits[*idx] == IrigBit::One {
+            let scale = bcd_scale(*weight);
+            digits[(scale / 10).min(2) as usize] += weight / scale;
+        }
+    }
+    if digits.iter().any(|digit| *digit > 9) {
+        return Err(Errors::Carry);
+    }
+    Ok(digits[0] + digits[1] * 10 + digits[2] * 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, Errors, IrigBFrame, IrigBit, ParsingErrors, TimeSystem, TimeUnits};
+
+    #[test]
+    fn test_irig_b_encoding() {
+        // Example from IRIG Standard 200: day 173, 21:18:42.75
+        let frame = IrigBFrame {
+            year: 3,
+            day_of_year: 173,
+            hour: 21,
+            minute: 18,
+            second: 42,
+            tenths: 7,
+        };
+        let bits = frame.to_bits();
+        // 42 s, 18 min, 21 h, day 173, 0.7 s, year 03, then SBS 76722 = 0b1_0010_1011_1011_0010
+        let ones = [
+            2, 8, 13, 15, 20, 26, 30, 31, 35, 36, 37, 40, 45, 46, 47, 50, 51, 81, 84, 85, 87, 88,
+            90, 92, 94, 97,
+        ];
+        for (idx, bit) in bits.iter().enumerate() {
+            assert_eq!(*bit == IrigBit::One, ones.contains(&idx), "bit {}", idx);
+        }
+        assert_eq!(
+            bits.iter().filter(|bit| **bit == IrigBit::Marker).count(),
+            11
+        );
+        assert_eq!(IrigBFrame::from_bits(&bits).unwrap(), frame);
+    }
+
+    #[test]
+    fn test_irig_b_roundtrip() {
+        let mut epoch = Epoch::from_gregorian_utc_at_midnight(1999, 12, 31);
+        let end = Epoch::from_gregorian_utc_at_midnight(2001, 1, 2);
+        while epoch < end {
+            let frame = epoch.as_irig_b(TimeSystem::UTC);
+            let decoded = IrigBFrame::from_bits(&frame.to_bits()).unwrap();
+            assert_eq!(decoded, frame);
+            assert_eq!(decoded.to_epoch(TimeSystem::UTC).unwrap(), epoch);
+            epoch += 7.hours() + 13.minutes() + 17.seconds() + 100.milliseconds();
+        }
+
+        // Sub-second truncation
+        let epoch = Epoch::from_gregorian_tai(2022, 5, 20, 0, 0, 1, 950_000_000);
+        let frame = epoch.as_irig_b(TimeSystem::TAI);
+        assert_eq!(frame.tenths, 9);
+        assert_eq!(
+            frame.to_epoch(TimeSystem::TAI).unwrap(),
+            Epoch::from_gregorian_tai(2022, 5, 20, 0, 0, 1, 900_000_000)
+        );
+    }
+
+    #[test]
+    fn test_irig_b_decoding_errors() {
+        let frame = Epoch::from_gregorian_utc_at_noon(2022, 5, 20).as_irig_b(TimeSystem::UTC);
+
+        let mut bits = frame.to_bits();
+        bits[29] = IrigBit::Zero;
+        assert_eq!(
+            IrigBFrame::from_bits(&bits),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+
+        // Invalid BCD digit: 8 + 2 seconds
+        let mut bits = frame.to_bits();
+        bits[2] = IrigBit::One;
+        bits[4] = IrigBit::One;
+        assert_eq!(IrigBFrame::from_bits(&bits), Err(Errors::Carry));
+
+        // Straight binary seconds mismatch
+        let mut bits = frame.to_bits();
+        bits[80] = IrigBit::One;
+        assert_eq!(IrigBFrame::from_bits(&bits), Err(Errors::Carry));
+
+        // Day 366 of a non leap year
+        let frame = IrigBFrame {
+            day_of_year: 366,
+            ..frame
+        };
+        assert_eq!(frame.to_epoch(TimeSystem::UTC), Err(Errors::Carry));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{Duration, Epoch};
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::ops::Deref;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+const UNINITIALIZED: u8 = 0;
+const INITIALIZING: u8 = 1;
+const READY: u8 = 2;
+
+/// An epoch which is computed on first access and cached afterward, usable as a `static` in `no_std`.
+///
+/// This avoids recomputing the Gregorian parsing or the time system conversions of commonly reused
+/// epochs (e.g. the mission start) at every call site. Initialization is lock-free: if several threads
+/// race on the first access, each computes the epoch and only one stores it. The initialization
+/// function must hence be deterministic, which is the case of all the Epoch initializers.
+///
+/// Prefer the [`lazy_epoch!`](crate::lazy_epoch) macro to declare such statics, and a plain `const`
+/// when the epoch can be built in a const context (e.g. with `Epoch::from_gregorian_tai_const`).
+///
+/// Only available on targets with compare-and-swap atomics, i.e. not on Cortex-M0 (`thumbv6m`)
+/// microcontrollers, where const epochs are the alternative.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, LazyEpoch};
+///
+/// static MISSION_START: LazyEpoch = LazyEpoch::new(|| Epoch::from_gregorian_utc_at_midnight(2022, 5, 20));
+///
+/// assert_eq!(*MISSION_START, Epoch::from_gregorian_utc_at_midnight(2022, 5, 20));
+/// assert_eq!(MISSION_START.get().as_gregorian_utc(), (2022, 5, 20, 0, 0, 0, 0));
+/// ```
+pub struct LazyEpoch {
+    state: AtomicU8,
+    value: UnsafeCell<Epoch>,
+    init: fn() -> Epoch,
+}
+
+// The value is only written once, by the thread which won the transition to INITIALIZING, and only
+// read after the READY state is observed with an acquire ordering.
+unsafe impl Sync for LazyEpoch {}
+
+impl LazyEpoch {
+    /// Builds a new lazy epoch which will be computed with the provided function on first access.
+    #[must_use]
+    pub const fn new(init: fn() -> Epoch) -> Self {
+        Self {
+            state: AtomicU8::new(UNINITIALIZED),
+            value: UnsafeCell::new(Epoch::from_tai_duration(Duration {
+                centuries: 0,
+                nanoseconds: 0,
+            })),
+            init,
+        }
+    }
+
+    /// Returns the epoch, computing it if this is the first access.
+    pub fn get(&self) -> Epoch {
+        *self.get_ref()
+    }
+
+    fn get_ref(&self) -> &Epoch {
+        if self.state.load(Ordering::Acquire) != READY {
+            let epoch = (self.init)();
+            if self
+                .state
+                .compare_exchange(
+                    UNINITIALIZED,
+                    INITIALIZING,
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                )
+                .is_ok()
+            {
+                unsafe { *self.value.get() = epoch };
+                self.state.store(READY, Ordering::Release);
+            } else {
+                // Another thread is storing the same value: wait for it to be done.
+                while self.state.load(Ordering::Acquire) != READY {
+                    core::hint::spin_loop();
+                }
+            }
+        }
+        unsafe { &*self.value.get() }
+    }
+
+    /// Returns true if the epoch has already been computed.
+    pub fn is_initialized(&self) -> bool {
+        self.state.load(Ordering::Acquire) == READY
+    }
+}
+
+impl Deref for LazyEpoch {
+    type Target = Epoch;
+
+    fn deref(&self) -> &Epoch {
+        self.get_ref()
+    }
+}
+
+impl fmt::Debug for LazyEpoch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LazyEpoch({:?})", self.get())
+    }
+}
+
+/// Declares one or more statics of lazily computed epochs, cf. [`LazyEpoch`].
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate hifitime;
+/// use hifitime::{Epoch, TimeUnits};
+///
+/// lazy_epoch! {
+///     /// Start of the mission
+///     pub static MISSION_START: Epoch = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+///     static FIRST_CONTACT: Epoch = *MISSION_START + 90.minutes();
+/// }
+///
+/// fn main() {
+///     assert_eq!(*FIRST_CONTACT - *MISSION_START, 90.minutes());
+/// }
+/// ```
+#[macro_export]
+macro_rules! lazy_epoch {
+    ($($(#[$attr:meta])* $vis:vis static $name:ident: Epoch = $init:expr;)+) => {
+        $(
+            $(#[$attr])*
+            $vis static $name: $crate::LazyEpoch = $crate::LazyEpoch::new({
+                fn init() -> $crate::Epoch {
+                    $init
+                }
+                init
+            });
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, LazyEpoch, TimeUnits};
+
+    lazy_epoch! {
+        static START: Epoch = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+        pub(crate) static END: Epoch = *START + 1.days();
+    }
+
+    #[test]
+    fn test_lazy_epoch() {
+        static UNUSED: LazyEpoch = LazyEpoch::new(|| Epoch::from_gregorian_tai_at_noon(2000, 1, 1));
+        assert!(!UNUSED.is_initialized());
+
+        assert!(!START.is_initialized());
+        assert_eq!(*END, Epoch::from_gregorian_utc_at_midnight(2022, 5, 21));
+        assert!(START.is_initialized());
+        assert_eq!(END.get() - START.get(), 1.days());
+        assert_eq!(*START, Epoch::from_gregorian_utc_at_midnight(2022, 5, 20));
+        assert!(!UNUSED.is_initialized());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_lazy_epoch_threads() {
+        static SHARED: LazyEpoch =
+            LazyEpoch::new(|| Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 1));
+        let handles: Vec<_> = (0..8)
+            .map(|_| std::thread::spawn(|| SHARED.get()))
+            .collect();
+        for handle in handles {
+            assert_eq!(
+                handle.join().unwrap(),
+                Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 1)
+            );
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::leap_seconds::{whole_seconds, with_active_leap_seconds};
+use crate::{Duration, Epoch, Unit};
+
+/// A cursor over the leap second table, which remembers the leap second interval of the last
+/// converted epoch so that converting ordered epochs to and from UTC does not search the table
+/// again for each of them, e.g. when streaming millions of timestamps.
+///
+/// The conversions are the same as [`Epoch::as_utc_duration`] and [`Epoch::from_utc_seconds`],
+/// from the active leap second table.
+/// Epochs in any order are supported, but each jump back in time walks the table backward.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, LeapCursor, TimeSeries, TimeUnits};
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2016, 12, 31);
+/// let mut cursor = LeapCursor::new();
+/// for epoch in TimeSeries::exclusive(start, start + 2.days(), 1.hours()) {
+///     assert_eq!(cursor.as_utc_duration(epoch), epoch.as_utc_duration());
+/// }
+/// assert_eq!(cursor.leap_seconds(start + 2.days()), 37);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LeapCursor {
+    /// Number of entries of the leap second table at or before the last epoch
+    index: usize,
+}
+
+impl LeapCursor {
+    /// Builds a cursor at the start of the leap second table.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { index: 0 }
+    }
+
+    /// Returns the accumulated number of leap seconds at the provided epoch, cf.
+    /// [`Epoch::get_num_leap_seconds`], moving the cursor to that epoch.
+    pub fn leap_seconds(&mut self, epoch: Epoch) -> i32 {
+        let seconds = whole_seconds(epoch.as_tai_duration());
+        with_active_leap_seconds(|entries| {
+            // The active table may have changed since the last call
+            self.index = self.index.min(entries.len());
+            while self.index < entries.len() && seconds >= entries[self.index].timestamp_utc_s {
+                self.index += 1;
+            }
+            while self.index > 0 && seconds < entries[self.index - 1].timestamp_utc_s {
+                self.index -= 1;
+            }
+            match self.index {
+                0 => 0,
+                idx => entries[idx - 1].delta_at,
+            }
+        })
+    }
+
+    /// Returns the provided epoch as a Duration past J1900 counted in UTC, cf. [`Epoch::as_utc_duration`].
+    pub fn as_utc_duration(&mut self, epoch: Epoch) -> Duration {
+        epoch.as_tai_duration() - Unit::Second * i64::from(self.leap_seconds(epoch))
+    }
+
+    /// Initialize an Epoch from the provided Duration past J1900 counted in UTC, cf. [`Epoch::from_utc_seconds`].
+    pub fn from_utc_duration(&mut self, duration: Duration) -> Epoch {
+        let epoch = Epoch::from_tai_duration(duration);
+        epoch + Unit::Second * i64::from(self.leap_seconds(epoch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, LeapCursor, TimeSeries, TimeUnits, Unit};
+
+    #[test]
+    fn test_leap_cursor() {
+        let start = Epoch::from_gregorian_tai_at_midnight(1971, 1, 1);
+        let end = Epoch::from_gregorian_tai_at_midnight(2030, 1, 1);
+        let mut cursor = LeapCursor::new();
+        for epoch in TimeSeries::inclusive(start, end, 17.days() + 3.hours()) {
+            assert_eq!(cursor.leap_seconds(epoch), epoch.get_num_leap_seconds());
+            let utc = cursor.as_utc_duration(epoch);
+            assert_eq!(utc, epoch.as_utc_duration());
+            assert_eq!(
+                cursor.from_utc_duration(utc),
+                Epoch::from_utc_seconds(utc.in_seconds())
+            );
+        }
+        assert_eq!(cursor.leap_seconds(end), 37);
+
+        // Jumping back in time
+        assert_eq!(cursor.leap_seconds(start), 0);
+        let epoch = Epoch::from_gregorian_tai_at_midnight(1999, 6, 1);
+        assert_eq!(cursor.leap_seconds(epoch), 32);
+        assert_eq!(cursor.leap_seconds(epoch - Unit::Day * 365), 31);
+
+        // Around a table entry
+        let entry = Epoch::from_tai_seconds(3_692_217_600.0);
+        assert_eq!(cursor.leap_seconds(entry - 1.microseconds()), 36);
+        assert_eq!(cursor.leap_seconds(entry), 37);
+        assert_eq!(LeapCursor::default(), LeapCursor::new());
+    }
+}
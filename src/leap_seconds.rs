@@ -0,0 +1,857 @@
+use crate::duration::NANOSECONDS_PER_DAY;
+use crate::epoch::{gregorian_from_days, LEAP_SECONDS};
+use crate::{Duration, Epoch, Interval, Unit};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock};
+
+/// An entry of a leap second table: the TAI - UTC offset which applies from a UTC instant onward.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LeapSecond {
+    /// Whole UTC seconds past 1900 January 01 at midnight from which this offset applies
+    pub timestamp_utc_s: i64,
+    /// TAI - UTC offset in whole seconds from that instant onward
+    pub delta_at: i32,
+}
+
+impl LeapSecond {
+    /// Builds the entry of the provided TAI - UTC offset in seconds, which applies from the provided
+    /// whole UTC seconds past 1900 January 01 at midnight onward.
+    #[must_use]
+    pub const fn new(timestamp_utc_s: i64, delta_at: i32) -> Self {
+        Self {
+            timestamp_utc_s,
+            delta_at,
+        }
+    }
+}
+
+/// A source of leap seconds, i.e. of the TAI - UTC offsets and the instants they apply from.
+///
+/// Implementors return the entries of their table sorted by increasing timestamp. Before the first
+/// entry, TAI - UTC is zero. This trait is implemented for the built-in table,
+/// [`LatestLeapSeconds`], and for slices, arrays and vectors of [`LeapSecond`], e.g. for a mission
+/// specific table or one updated after the release of hifitime.
+///
+/// The UTC conversions of [`Epoch`] consult the active provider, which is the built-in table unless
+/// another one is installed with [`set_leap_second_provider`], or accept a provider explicitly, e.g.
+/// [`Epoch::as_utc_duration_with`].
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, LatestLeapSeconds, LeapSecond, LeapSecondProvider};
+///
+/// // The built-in table with a (hypothetical) leap second at the end of 2030
+/// let mut table = [LeapSecond::new(0, 0); 29];
+/// table[..28].copy_from_slice(LatestLeapSeconds.entries());
+/// table[28] = LeapSecond::new(4_133_980_800, 38);
+///
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2031, 6, 1);
+/// assert_eq!(epoch.get_num_leap_seconds_with(&table), 38);
+/// assert_eq!(epoch.get_num_leap_seconds_with(&LatestLeapSeconds), 37);
+/// ```
+pub trait LeapSecondProvider {
+    /// Returns the entries of this leap second table, sorted by increasing timestamp.
+    fn entries(&self) -> &[LeapSecond];
+
+    /// Returns the epoch until which this table is known to be complete, or `None` if unknown.
+    /// After it, a leap second may have been announced which the table does not include, so the
+    /// UTC conversions assume that there is none.
+    fn valid_until(&self) -> Option<Epoch> {
+        None
+    }
+
+    /// Returns the epoch from which this table defines UTC, i.e. the start of its first entry, or
+    /// `None` if it is empty. Before it, the UTC conversions assume that TAI - UTC is zero.
+    fn valid_from(&self) -> Option<Epoch> {
+        self.entries().first().map(|leap| {
+            Epoch::from_tai_duration(
+                Unit::Second * (leap.timestamp_utc_s + i64::from(leap.delta_at)),
+            )
+        })
+    }
+
+    /// Returns a version of this table, as the 64 bit FNV-1a hash of its entries and of its
+    /// validity, such that services may log or compare the table they use.
+    fn version(&self) -> u64 {
+        let mut hash = FNV_OFFSET_BASIS;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash = (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME);
+            }
+        };
+        for leap in self.entries() {
+            write(&leap.timestamp_utc_s.to_le_bytes());
+            write(&leap.delta_at.to_le_bytes());
+        }
+        if let Some(valid_until) = self.valid_until() {
+            let (centuries, nanoseconds) = valid_until.to_tai_parts();
+            write(&centuries.to_le_bytes());
+            write(&nanoseconds.to_le_bytes());
+        }
+        hash
+    }
+}
+
+/// Whether the UTC conversions of an epoch are defined by a leap second table, or extrapolated
+/// before or after it, cf. [`Epoch::utc_validity`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UtcValidity {
+    /// Before the first entry of the table, where TAI - UTC is assumed to be zero. With the built-in
+    /// table, this is before 1972, when UTC was either not defined (before 1960) or steered with
+    /// fractional offsets and rates which are not modeled.
+    BeforeTable,
+    /// Within the table, where TAI - UTC is known.
+    Defined,
+    /// After the epoch until which the table is known to be complete, where a leap second may have
+    /// been announced since and TAI - UTC is assumed to be that of the last entry.
+    AfterTable,
+}
+
+/// Offset basis and prime of the 64 bit FNV-1a hash
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// The leap second table built into this version of hifitime, from the IETF `leap-seconds.list`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LatestLeapSeconds;
+
+/// The built-in leap second table, where the first entry is the initial offset of 10 seconds.
+const LATEST_LEAP_SECONDS: [LeapSecond; LEAP_SECONDS.len()] = {
+    let mut table = [LeapSecond::new(0, 0); LEAP_SECONDS.len()];
+    let mut i = 0;
+    while i < LEAP_SECONDS.len() {
+        table[i] = LeapSecond::new(LEAP_SECONDS[i], 10 + i as i32);
+        i += 1;
+    }
+    table
+};
+
+impl LeapSecondProvider for LatestLeapSeconds {
+    fn entries(&self) -> &[LeapSecond] {
+        &LATEST_LEAP_SECONDS
+    }
+
+    /// The expiration date of the built-in table, cf. [`leap_second_table_expiry`](crate::leap_second_table_expiry).
+    fn valid_until(&self) -> Option<Epoch> {
+        Some(crate::leap_second_table_expiry())
+    }
+}
+
+impl LeapSecondProvider for [LeapSecond] {
+    fn entries(&self) -> &[LeapSecond] {
+        self
+    }
+}
+
+impl<const N: usize> LeapSecondProvider for [LeapSecond; N] {
+    fn entries(&self) -> &[LeapSecond] {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl LeapSecondProvider for Vec<LeapSecond> {
+    fn entries(&self) -> &[LeapSecond] {
+        self
+    }
+}
+
+/// The leap second provider installed in place of the built-in table, if any.
+#[cfg(feature = "std")]
+static ACTIVE_PROVIDER: RwLock<Option<Box<dyn LeapSecondProvider + Send + Sync>>> =
+    RwLock::new(None);
+
+/// Installs the provided leap second table as the one consulted by all UTC conversions of this
+/// process, e.g. a table loaded at runtime which includes leap seconds announced after the release
+/// of hifitime, until it is replaced or [`reset_leap_second_provider`] is called.
+///
+/// Epochs are stored in TAI, so the epochs initialized from UTC before the table changes keep
+/// their TAI instant, and the same UTC reading may correspond to another epoch afterward.
+///
+/// # Example
+/// ```
+/// use hifitime::{
+///     reset_leap_second_provider, set_leap_second_provider, Epoch, LatestLeapSeconds, LeapSecond,
+///     LeapSecondProvider, TimeUnits,
+/// };
+///
+/// // A (hypothetical) leap second at the end of 2030
+/// let mut table = LatestLeapSeconds.entries().to_vec();
+/// table.push(LeapSecond::new(4_133_980_800, 38));
+/// set_leap_second_provider(table);
+///
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2031, 1, 1);
+/// assert_eq!(epoch.get_num_leap_seconds(), 38);
+/// assert_eq!(epoch.as_utc_duration(), 4_133_980_800_i64.seconds());
+/// assert_eq!(epoch - Epoch::from_gregorian_utc_at_midnight(2030, 12, 31), 1.days() + 1.seconds());
+///
+/// reset_leap_second_provider();
+/// assert_eq!(epoch.get_num_leap_seconds(), 37);
+/// ```
+#[cfg(feature = "std")]
+pub fn set_leap_second_provider<P: LeapSecondProvider + Send + Sync + 'static>(provider: P) {
+    *ACTIVE_PROVIDER
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Box::new(provider));
+}
+
+/// Restores the built-in leap second table, [`LatestLeapSeconds`], as the active one.
+#[cfg(feature = "std")]
+pub fn reset_leap_second_provider() {
+    *ACTIVE_PROVIDER
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Returns the epoch until which the active leap second table is known to be complete, cf.
+/// [`LeapSecondProvider::valid_until`], e.g. for long-running services to warn when they convert
+/// UTC epochs beyond it.
+///
+/// # Example
+/// ```
+/// use hifitime::{active_leap_seconds_valid_until, Epoch};
+///
+/// let valid_until = active_leap_seconds_valid_until().unwrap();
+/// assert_eq!(valid_until, Epoch::from_gregorian_utc_at_midnight(2026, 6, 28));
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+/// if epoch > valid_until {
+///     println!("{} is beyond the leap second table, which may be outdated", epoch);
+/// }
+/// ```
+#[must_use]
+pub fn active_leap_seconds_valid_until() -> Option<Epoch> {
+    with_active_provider(|provider| provider.valid_until())
+}
+
+/// Returns the epoch from which the active leap second table defines UTC, cf.
+/// [`LeapSecondProvider::valid_from`].
+#[must_use]
+pub fn active_leap_seconds_valid_from() -> Option<Epoch> {
+    with_active_provider(|provider| provider.valid_from())
+}
+
+/// Returns the version of the active leap second table, cf. [`LeapSecondProvider::version`].
+#[must_use]
+pub fn active_leap_seconds_version() -> u64 {
+    with_active_provider(|provider| provider.version())
+}
+
+/// Returns the piecewise constant TAI - UTC offset of the provided leap second table over the
+/// provided interval, as the offset in whole seconds at the start of the interval followed by each
+/// change of the offset within the interval, i.e. the epoch from which the new offset applies (the
+/// end of the leap second), e.g. to serialize it in RINEX headers or time correlation packets.
+///
+/// # Example
+/// ```
+/// use hifitime::{tai_utc_offset_history, Epoch, Interval, LatestLeapSeconds};
+///
+/// let interval = Interval::new(
+///     Epoch::from_gregorian_utc_at_midnight(2010, 1, 1),
+///     Epoch::from_gregorian_utc_at_midnight(2020, 1, 1),
+/// );
+/// let history: Vec<_> = tai_utc_offset_history(&LatestLeapSeconds, interval).collect();
+/// assert_eq!(
+///     history,
+///     [
+///         (interval.start(), 34),
+///         (Epoch::from_gregorian_utc_at_midnight(2012, 7, 1), 35),
+///         (Epoch::from_gregorian_utc_at_midnight(2015, 7, 1), 36),
+///         (Epoch::from_gregorian_utc_at_midnight(2017, 1, 1), 37),
+///     ]
+/// );
+/// ```
+pub fn tai_utc_offset_history<P: LeapSecondProvider + ?Sized>(
+    provider: &P,
+    interval: Interval,
+) -> impl Iterator<Item = (Epoch, i32)> + '_ {
+    let changes = provider.entries().iter().map(|leap| {
+        let tai = Unit::Second * (leap.timestamp_utc_s + i64::from(leap.delta_at));
+        (Epoch::from_tai_duration(tai), leap.delta_at)
+    });
+    let initial = changes
+        .clone()
+        .take_while(|(epoch, _)| *epoch <= interval.start())
+        .last()
+        .map_or(0, |(_, delta_at)| delta_at);
+    core::iter::once((interval.start(), initial)).chain(
+        changes.filter(move |(epoch, _)| interval.start() < *epoch && *epoch <= interval.end()),
+    )
+}
+
+/// Returns the TAI - UTC offset history of the active leap second table over the provided
+/// interval, cf. [`tai_utc_offset_history`].
+#[cfg(feature = "std")]
+#[must_use]
+pub fn active_tai_utc_offset_history(interval: Interval) -> Vec<(Epoch, i32)> {
+    with_active_provider(|provider| tai_utc_offset_history(provider, interval).collect())
+}
+
+/// Calls the provided function with the active leap second provider.
+fn with_active_provider<T>(f: impl FnOnce(&dyn LeapSecondProvider) -> T) -> T {
+    #[cfg(feature = "std")]
+    {
+        let active = ACTIVE_PROVIDER
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(provider) = active.as_ref() {
+            return f(provider.as_ref());
+        }
+    }
+    f(&LatestLeapSeconds)
+}
+
+/// Calls the provided function with the entries of the active leap second provider.
+pub(crate) fn with_active_leap_seconds<T>(f: impl FnOnce(&[LeapSecond]) -> T) -> T {
+    with_active_provider(|provider| f(provider.entries()))
+}
+
+/// Number of seconds in a century of 36525 days
+const SECONDS_PER_CENTURY: i64 = 3_155_760_000;
+
+/// Returns the whole seconds of the provided duration, rounded down, computed exactly from its
+/// centuries and nanoseconds such that it may be compared to the leap second timestamps.
+pub(crate) fn whole_seconds(duration: Duration) -> i64 {
+    let (centuries, nanoseconds) = duration.to_parts();
+    i64::from(centuries) * SECONDS_PER_CENTURY + (nanoseconds / 1_000_000_000) as i64
+}
+
+/// Returns the TAI - UTC offset in whole seconds of the last entry at or before the provided
+/// duration past 1900 January 01 at midnight, or zero before the first entry. The entries are
+/// sorted, so the lookup is a binary search.
+pub(crate) fn delta_at(entries: &[LeapSecond], duration: Duration) -> i32 {
+    let seconds = whole_seconds(duration);
+    match entries.partition_point(|leap| seconds >= leap.timestamp_utc_s) {
+        0 => 0,
+        idx => entries[idx - 1].delta_at,
+    }
+}
+
+impl Epoch {
+    /// Get the accumulated number of leap seconds up to this Epoch from the provided leap second
+    /// table, cf. [`Epoch::get_num_leap_seconds`].
+    #[must_use]
+    pub fn get_num_leap_seconds_with<P: LeapSecondProvider + ?Sized>(&self, provider: &P) -> i32 {
+        delta_at(provider.entries(), self.as_tai_duration())
+    }
+
+    /// Initialize an Epoch from the provided Duration past 1900 January 01 at midnight counted in
+    /// UTC, using the provided leap second table.
+    #[must_use]
+    pub fn from_utc_duration_with<P: LeapSecondProvider + ?Sized>(
+        duration: Duration,
+        provider: &P,
+    ) -> Self {
+        let e = Self::from_tai_duration(duration);
+        // TAI = UTC + leap_seconds
+        e + Unit::Second * i64::from(e.get_num_leap_seconds_with(provider))
+    }
+
+    /// Returns this time in a Duration past 1900 January 01 at midnight counted in UTC, using the
+    /// provided leap second table.
+    #[must_use]
+    pub fn as_utc_duration_with<P: LeapSecondProvider + ?Sized>(&self, provider: &P) -> Duration {
+        // UTC = TAI - leap_seconds
+        self.as_tai_duration() - Unit::Second * i64::from(self.get_num_leap_seconds_with(provider))
+    }
+
+    /// Returns the TAI - UTC offset at this epoch from the active leap second table, cf.
+    /// [`tai_utc_offset_at`](crate::tai_utc_offset_at), which changes at the end of each inserted
+    /// leap second.
+    #[must_use]
+    pub fn leap_seconds(&self) -> Duration {
+        crate::tai_utc_offset_at(*self)
+    }
+
+    /// Returns the start of the next leap second strictly after this epoch, i.e. 23:59:60 UTC for an
+    /// inserted leap second, from the active leap second table, or `None` if there is none.
+    ///
+    /// The first entry of the table, which sets the initial TAI - UTC offset of 1972, is not a leap
+    /// second.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2016, 5, 20);
+    /// let leap = epoch.next_leap_second().unwrap();
+    /// assert_eq!(leap, Epoch::from_gregorian_utc_at_midnight(2017, 1, 1) - 1.seconds());
+    /// assert!(leap.is_leap_second());
+    /// assert_eq!(leap.leap_seconds(), 36.seconds());
+    /// assert_eq!((leap + 1.seconds()).leap_seconds(), 37.seconds());
+    /// assert_eq!(leap.next_leap_second(), None);
+    /// assert_eq!(
+    ///     epoch.previous_leap_second(),
+    ///     Some(Epoch::from_gregorian_utc_at_midnight(2015, 7, 1) - 1.seconds())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn next_leap_second(&self) -> Option<Self> {
+        with_active_leap_seconds(|entries| {
+            leap_second_starts(entries).find(|(start, _)| start > self)
+        })
+        .map(|(start, _)| start)
+    }
+
+    /// Returns the start of the latest leap second at or before this epoch, from the active leap
+    /// second table, or `None` if there is none, cf. [`Epoch::next_leap_second`].
+    #[must_use]
+    pub fn previous_leap_second(&self) -> Option<Self> {
+        with_active_leap_seconds(|entries| {
+            leap_second_starts(entries)
+                .take_while(|(start, _)| start <= self)
+                .last()
+        })
+        .map(|(start, _)| start)
+    }
+
+    /// Returns whether the UTC conversions of this epoch are defined by the provided leap second
+    /// table, or extrapolated before or after it, cf. [`UtcValidity`]. A table without a known
+    /// bound, e.g. a [`LeapSecondProvider::valid_until`] of `None`, is taken as valid on that side.
+    #[must_use]
+    pub fn utc_validity_with<P: LeapSecondProvider + ?Sized>(&self, provider: &P) -> UtcValidity {
+        if provider.valid_from().is_some_and(|start| *self < start) {
+            UtcValidity::BeforeTable
+        } else if provider.valid_until().is_some_and(|end| *self > end) {
+            UtcValidity::AfterTable
+        } else {
+            UtcValidity::Defined
+        }
+    }
+
+    /// Returns whether the UTC conversions of this epoch are defined by the active leap second
+    /// table, or extrapolated before or after it, cf. [`UtcValidity`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, UtcValidity};
+    ///
+    /// let e = Epoch::from_gregorian_utc_at_midnight(1965, 1, 1);
+    /// assert_eq!(e.utc_validity(), UtcValidity::BeforeTable);
+    /// assert!(!e.is_utc_well_defined());
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// assert_eq!(e.utc_validity(), UtcValidity::Defined);
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+    /// assert_eq!(e.utc_validity(), UtcValidity::AfterTable);
+    /// ```
+    #[must_use]
+    pub fn utc_validity(&self) -> UtcValidity {
+        with_active_provider(|provider| self.utc_validity_with(provider))
+    }
+
+    /// Returns whether the UTC conversions of this epoch are defined by the active leap second
+    /// table, i.e. from 1972 until the expiry of the table with the built-in one, instead of
+    /// extrapolated, cf. [`Epoch::utc_validity`].
+    #[must_use]
+    pub fn is_utc_well_defined(&self) -> bool {
+        self.utc_validity() == UtcValidity::Defined
+    }
+
+    /// Returns whether this epoch is within an inserted leap second, i.e. at 23:59:60 UTC, from the
+    /// active leap second table.
+    #[must_use]
+    pub fn is_leap_second(&self) -> bool {
+        with_active_leap_seconds(|entries| {
+            leap_second_starts(entries)
+                .any(|(start, length)| start <= *self && *self < start + length)
+        })
+    }
+
+    /// Returns the Gregorian date and time of this epoch in UTC from the active leap second table,
+    /// where the second is 60 during an inserted leap second, along with whether this epoch is
+    /// within that leap second, cf. [`UtcParts`].
+    ///
+    /// A duration counted in UTC, e.g. [`Epoch::as_utc_duration`], does not count the leap seconds,
+    /// so an inserted leap second and the second which follows it share the same UTC readings. These
+    /// parts tell them apart, and are computed exactly.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let midnight = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    /// let leap = (midnight - 500.milliseconds()).to_utc_parts_checked();
+    /// assert!(leap.leap_second);
+    /// assert_eq!(
+    ///     (leap.year, leap.month, leap.day, leap.hour, leap.minute, leap.second, leap.nanos),
+    ///     (2016, 12, 31, 23, 59, 60, 500_000_000)
+    /// );
+    /// assert_eq!(format!("{}", leap), "2016-12-31T23:59:60.500000000 UTC");
+    /// let next = (midnight + 500.milliseconds()).to_utc_parts_checked();
+    /// assert!(!next.leap_second);
+    /// assert_eq!(format!("{}", next), "2017-01-01T00:00:00.500000000 UTC");
+    /// ```
+    #[must_use]
+    pub fn to_utc_parts_checked(&self) -> UtcParts {
+        let leap_start = with_active_leap_seconds(|entries| {
+            leap_second_starts(entries)
+                .find(|(start, length)| *start <= *self && *self < *start + *length)
+                .map(|(start, _)| start)
+        });
+        match leap_start {
+            // The UTC reading at the start of the leap second is midnight, so the leap second is
+            // the last second of the previous day
+            Some(start) => {
+                let (days, _) = utc_days(start);
+                let (year, month, day) = gregorian_from_days(days - 1);
+                let (_, nanos) = (*self - start).to_parts();
+                UtcParts {
+                    year,
+                    month,
+                    day,
+                    hour: 23,
+                    minute: 59,
+                    second: 60,
+                    nanos: nanos as u32,
+                    leap_second: true,
+                }
+            }
+            None => {
+                let (days, nanoseconds_of_day) = utc_days(*self);
+                let (year, month, day) = gregorian_from_days(days);
+                let seconds = nanoseconds_of_day / 1_000_000_000;
+                UtcParts {
+                    year,
+                    month,
+                    day,
+                    hour: (seconds / 3_600) as u8,
+                    minute: (seconds / 60 % 60) as u8,
+                    second: (seconds % 60) as u8,
+                    nanos: (nanoseconds_of_day % 1_000_000_000) as u32,
+                    leap_second: false,
+                }
+            }
+        }
+    }
+}
+
+/// Returns the days since 1900 January 01 of the provided epoch in UTC, and the nanoseconds into
+/// that day, computed exactly from the offset at that epoch, cf. [`crate::tai_utc_offset_at`].
+fn utc_days(epoch: Epoch) -> (i64, u64) {
+    let utc = epoch.as_tai_duration() - crate::tai_utc_offset_at(epoch);
+    let (centuries, nanoseconds) = utc.to_parts();
+    (
+        i64::from(centuries) * 36_525 + (nanoseconds / NANOSECONDS_PER_DAY) as i64,
+        nanoseconds % NANOSECONDS_PER_DAY,
+    )
+}
+
+/// The Gregorian date and time of an epoch in UTC, where the second is 60 during an inserted leap
+/// second, cf. [`Epoch::to_utc_parts_checked`]. It is printed in ISO 8601 with the UTC suffix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UtcParts {
+    /// Full year
+    pub year: i32,
+    /// Month of the year, from 1 to 12
+    pub month: u8,
+    /// Day of the month, starting at 1
+    pub day: u8,
+    /// Hour of the day, from 0 to 23
+    pub hour: u8,
+    /// Minute of the hour, from 0 to 59
+    pub minute: u8,
+    /// The second of the minute, which is 60 during an inserted leap second
+    pub second: u8,
+    /// Nanoseconds within the second
+    pub nanos: u32,
+    /// Whether this instant is within an inserted leap second, whose UTC readings since 1900 are
+    /// shared with the second which follows it
+    pub leap_second: bool,
+}
+
+impl fmt::Display for UtcParts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.nanos != 0 {
+            write!(f, ".{:09}", self.nanos)?;
+        }
+        write!(f, " UTC")
+    }
+}
+
+/// Returns the start of each leap second of the provided table and its length, which is negative
+/// for a removed leap second.
+fn leap_second_starts(entries: &[LeapSecond]) -> impl Iterator<Item = (Epoch, Duration)> + '_ {
+    entries.windows(2).map(|leaps| {
+        // The leap second starts when the UTC reading reaches the entry with the previous offset
+        let utc = Unit::Second * leaps[1].timestamp_utc_s;
+        (
+            Epoch::from_tai_duration(utc + Unit::Second * i64::from(leaps[0].delta_at)),
+            Unit::Second * i64::from(leaps[1].delta_at - leaps[0].delta_at),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        active_leap_seconds_valid_from, active_leap_seconds_valid_until,
+        active_leap_seconds_version, Epoch, LatestLeapSeconds, LeapSecond, LeapSecondProvider,
+        TimeSeries, TimeUnits, Unit, UtcValidity,
+    };
+
+    #[test]
+    fn test_latest_leap_seconds() {
+        let entries = LatestLeapSeconds.entries();
+        assert_eq!(entries.len(), 28);
+        assert_eq!(entries[0], LeapSecond::new(2_272_060_800, 10));
+        assert_eq!(entries[27], LeapSecond::new(3_692_217_600, 37));
+
+        let start = Epoch::from_gregorian_tai_at_midnight(1971, 1, 1);
+        let end = Epoch::from_gregorian_tai_at_midnight(2030, 1, 1);
+        for epoch in TimeSeries::inclusive(start, end, 17.days() + 3.hours()) {
+            assert_eq!(
+                epoch.get_num_leap_seconds_with(&LatestLeapSeconds),
+                epoch.get_num_leap_seconds()
+            );
+            let utc = epoch.as_utc_duration_with(&LatestLeapSeconds);
+            assert_eq!(utc, epoch.as_utc_duration());
+            assert_eq!(
+                Epoch::from_utc_duration_with(utc, &LatestLeapSeconds),
+                Epoch::from_utc_seconds(utc.in_seconds())
+            );
+        }
+    }
+
+    #[test]
+    fn test_table_validity() {
+        assert_eq!(
+            LatestLeapSeconds.valid_until(),
+            Some(Epoch::from_gregorian_utc_at_midnight(2026, 6, 28))
+        );
+        assert_eq!(LatestLeapSeconds.entries().valid_until(), None);
+        assert_eq!(active_leap_seconds_version(), LatestLeapSeconds.version());
+        assert_eq!(
+            active_leap_seconds_valid_until(),
+            LatestLeapSeconds.valid_until()
+        );
+
+        // The version depends on the entries and the validity only
+        let entries = LatestLeapSeconds.entries();
+        let mut table = [LeapSecond::new(0, 0); 29];
+        table[..28].copy_from_slice(entries);
+        assert_eq!(table[..28].version(), entries.version());
+        assert_ne!(table[..28].version(), LatestLeapSeconds.version());
+        table[28] = LeapSecond::new(4_133_980_800, 38);
+        assert_ne!(table.version(), entries.version());
+        assert_eq!(([] as [LeapSecond; 0]).version(), 0xcbf2_9ce4_8422_2325);
+    }
+
+    #[test]
+    fn test_utc_validity() {
+        let start = Epoch::from_gregorian_utc_at_midnight(1972, 1, 1);
+        assert_eq!(LatestLeapSeconds.valid_from(), Some(start));
+        assert_eq!(active_leap_seconds_valid_from(), Some(start));
+        assert_eq!(([] as [LeapSecond; 0]).valid_from(), None);
+        assert_eq!(
+            (start - 1.nanoseconds()).utc_validity(),
+            UtcValidity::BeforeTable
+        );
+        assert_eq!(start.utc_validity(), UtcValidity::Defined);
+        assert!(start.is_utc_well_defined());
+        let end = LatestLeapSeconds.valid_until().unwrap();
+        assert!(end.is_utc_well_defined());
+        assert_eq!(
+            (end + 1.nanoseconds()).utc_validity(),
+            UtcValidity::AfterTable
+        );
+        assert!(!(end + 1.days()).is_utc_well_defined());
+
+        // A table without an expiry is taken as valid after its last entry
+        let table = LatestLeapSeconds.entries();
+        assert_eq!(
+            (end + 1.days()).utc_validity_with(table),
+            UtcValidity::Defined
+        );
+        assert_eq!(
+            (start - 1.days()).utc_validity_with(table),
+            UtcValidity::BeforeTable
+        );
+        assert_eq!(
+            (start - 1.days()).utc_validity_with(&([] as [LeapSecond; 0])),
+            UtcValidity::Defined
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_tai_utc_offset_history() {
+        use crate::{
+            active_tai_utc_offset_history, tai_utc_offset_at, tai_utc_offset_history, Interval,
+        };
+
+        let start = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1);
+        let end = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+        let history = active_tai_utc_offset_history(Interval::new(start, end));
+        assert_eq!(history.len(), 29);
+        assert_eq!(history[0], (start, 0));
+        assert_eq!(
+            history[1],
+            (Epoch::from_gregorian_utc_at_midnight(1972, 1, 1), 10)
+        );
+        // Each offset applies until the next change, as in tai_utc_offset_at
+        for pair in history.windows(2) {
+            let ((from, offset), (until, _)) = (pair[0], pair[1]);
+            assert_eq!(tai_utc_offset_at(from), i64::from(offset).seconds());
+            assert_eq!(
+                tai_utc_offset_at(until - 1.nanoseconds()),
+                i64::from(offset).seconds()
+            );
+        }
+
+        // Starting exactly at a change, and within a constant offset
+        let leap = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+        let history: Vec<_> =
+            tai_utc_offset_history(&LatestLeapSeconds, Interval::new(leap, end)).collect();
+        assert_eq!(history, [(leap, 37)]);
+        let history: Vec<_> = tai_utc_offset_history(
+            &LatestLeapSeconds,
+            Interval::new(leap - 1.days(), leap - 1.nanoseconds()),
+        )
+        .collect();
+        assert_eq!(history, [(leap - 1.days(), 36)]);
+        let history: Vec<_> =
+            tai_utc_offset_history(&LatestLeapSeconds, Interval::new(leap - 1.days(), leap))
+                .collect();
+        assert_eq!(history, [(leap - 1.days(), 36), (leap, 37)]);
+    }
+
+    #[test]
+    fn test_exact_leap_instants() {
+        // A nanosecond before a leap instant is indistinguishable from it as f64 seconds
+        let instant = Epoch::from_tai_duration(Unit::Second * 3_692_217_600_i64);
+        let before = instant - 1.nanoseconds();
+        assert_eq!(before.as_tai_seconds(), instant.as_tai_seconds());
+        assert_eq!(instant.get_num_leap_seconds(), 37);
+        assert_eq!(before.get_num_leap_seconds(), 36);
+        assert_eq!(before.get_num_leap_seconds_with(&LatestLeapSeconds), 36);
+
+        // Exact before 1900 too, where the duration has negative centuries
+        let table = [LeapSecond::new(-3_155_760_000, 1)];
+        let century = Epoch::from_tai_duration(Unit::Second * -3_155_760_000_i64);
+        assert_eq!(century.get_num_leap_seconds_with(&table), 1);
+        assert_eq!(
+            (century - 1.nanoseconds()).get_num_leap_seconds_with(&table),
+            0
+        );
+        assert_eq!(
+            (century + 1.nanoseconds()).get_num_leap_seconds_with(&table),
+            1
+        );
+    }
+
+    #[test]
+    fn test_leap_second_introspection() {
+        let leap = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1) - 1.seconds();
+        assert!(leap.is_leap_second());
+        assert!((leap + 999_999_999.nanoseconds()).is_leap_second());
+        assert!(!(leap + 1.seconds()).is_leap_second());
+        assert!(!(leap - 1.nanoseconds()).is_leap_second());
+        assert_eq!(leap.leap_seconds(), 36.seconds());
+        assert_eq!((leap + 1.seconds()).leap_seconds(), 37.seconds());
+
+        assert_eq!(leap.previous_leap_second(), Some(leap));
+        assert_eq!((leap - 1.nanoseconds()).next_leap_second(), Some(leap));
+        assert_eq!(leap.next_leap_second(), None);
+        let previous = Epoch::from_gregorian_utc_at_midnight(2015, 7, 1) - 1.seconds();
+        assert_eq!(
+            (leap - 1.nanoseconds()).previous_leap_second(),
+            Some(previous)
+        );
+        assert_eq!(previous.leap_seconds(), 35.seconds());
+
+        // The initial offset of 1972 is not a leap second
+        let start = Epoch::from_gregorian_utc_at_midnight(1972, 1, 1);
+        assert_eq!(start.leap_seconds(), 10.seconds());
+        assert!(!(start - 1.seconds()).is_leap_second());
+        assert_eq!(start.previous_leap_second(), None);
+        assert_eq!(
+            Epoch::from_gregorian_utc_at_midnight(1960, 1, 1).next_leap_second(),
+            Some(Epoch::from_gregorian_utc_at_midnight(1972, 7, 1) - 1.seconds())
+        );
+        let count = TimeSeries::inclusive(start, leap + 1.days(), 1.days())
+            .filter(|epoch| epoch.next_leap_second() != (*epoch - 1.days()).next_leap_second())
+            .count();
+        assert_eq!(count, 27);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_utc_parts_checked() {
+        let midnight = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+        let labels: Vec<String> = TimeSeries::exclusive(
+            midnight - 2.seconds(),
+            midnight + 1.seconds(),
+            500.milliseconds(),
+        )
+        .map(|epoch| format!("{}", epoch.to_utc_parts_checked()))
+        .collect();
+        assert_eq!(
+            labels,
+            [
+                "2016-12-31T23:59:59 UTC",
+                "2016-12-31T23:59:59.500000000 UTC",
+                "2016-12-31T23:59:60 UTC",
+                "2016-12-31T23:59:60.500000000 UTC",
+                "2017-01-01T00:00:00 UTC",
+                "2017-01-01T00:00:00.500000000 UTC",
+            ]
+        );
+        let last = (midnight - 1.nanoseconds()).to_utc_parts_checked();
+        assert!(last.leap_second);
+        assert_eq!((last.second, last.nanos), (60, 999_999_999));
+        assert!(!midnight.to_utc_parts_checked().leap_second);
+        assert_eq!(
+            format!("{}", (midnight + 5.nanoseconds()).to_utc_parts_checked()),
+            "2017-01-01T00:00:00.000000005 UTC"
+        );
+
+        // Outside of leap seconds, these are the usual Gregorian fields, including before 1900
+        for epoch in [
+            Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 250_000_000),
+            Epoch::from_gregorian_utc_hms(1960, 2, 29, 1, 2, 3),
+            Epoch::from_gregorian_utc_hms(1700, 3, 1, 12, 0, 0),
+        ] {
+            let parts = epoch.to_utc_parts_checked();
+            assert!(!parts.leap_second);
+            assert_eq!(
+                (
+                    parts.year,
+                    parts.month,
+                    parts.day,
+                    parts.hour,
+                    parts.minute,
+                    parts.second,
+                    parts.nanos
+                ),
+                epoch.as_gregorian_utc()
+            );
+        }
+    }
+
+    #[test]
+    fn test_custom_leap_seconds() {
+        // A mission table which only knows of the last two leap seconds
+        let table = [
+            LeapSecond::new(3_644_697_600, 36),
+            LeapSecond::new(3_692_217_600, 37),
+        ];
+        let before = Epoch::from_gregorian_utc_at_midnight(2015, 1, 1);
+        assert_eq!(before.get_num_leap_seconds(), 35);
+        assert_eq!(before.get_num_leap_seconds_with(&table), 0);
+        let utc = Unit::Second * 3_692_217_600_i64;
+        let epoch = Epoch::from_utc_duration_with(utc, &table);
+        assert_eq!(epoch, Epoch::from_gregorian_utc_at_midnight(2017, 1, 1));
+        assert_eq!(epoch.as_utc_duration_with(&table), utc);
+        assert_eq!(epoch.as_utc_duration_with(&table[..1]), utc + 1.seconds());
+        // Empty tables have no leap seconds
+        assert_eq!(
+            epoch.as_utc_duration_with(&([] as [LeapSecond; 0])),
+            epoch.as_tai_duration()
+        );
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{
+    set_leap_second_provider, Epoch, Errors, LeapSecond, LeapSecondProvider, ParsingErrors, Unit,
+};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A leap second table loaded at runtime from the IETF/IERS `leap-seconds.list` file, e.g. as
+/// distributed with the IANA time zone database or by the IERS.
+///
+/// Besides the leap second lines (the NTP timestamp, i.e. UTC seconds past 1900 January 01 at
+/// midnight, and the TAI - UTC offset), the file must include its last update (`#$` line), its
+/// expiration date (`#@` line) and the SHA-1 hash of its contents (`#h` line), which is verified.
+/// Returns `Errors::ParseError(ParsingErrors::Checksum)` if the hash does not match, and
+/// `Errors::ParseError(ParsingErrors::UnknownFormat)` if a line cannot be parsed, if the leap
+/// seconds are not sorted, or if the file expires before its last leap second.
+///
+/// The table is a [`LeapSecondProvider`], which can be installed as the active leap second source
+/// with [`LeapSecondsFile::install`].
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, LeapSecondsFile};
+/// use std::str::FromStr;
+///
+/// let file = LeapSecondsFile::from_str(
+///     "#$ 3676924800
+/// #@ 3707596800
+/// 2272060800 10 # 1 Jan 1972
+/// 3692217600 37 # 1 Jan 2017
+/// #h 45bb6c8d ab5e2e7c ad894fb4 ac437e11 dc9399a8",
+/// )
+/// .unwrap();
+/// assert_eq!(file.expiry(), Epoch::from_gregorian_utc_at_midnight(2017, 6, 28));
+/// assert!(file.is_expired(Epoch::from_gregorian_utc_at_midnight(2022, 5, 20)));
+/// assert_eq!(
+///     Epoch::from_gregorian_utc_at_midnight(2017, 1, 1).get_num_leap_seconds_with(&file),
+///     37
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeapSecondsFile {
+    entries: Vec<LeapSecond>,
+    last_update: Epoch,
+    expiry: Epoch,
+    hash: [u32; 5],
+}
+
+impl LeapSecondsFile {
+    /// Loads the `leap-seconds.list` file at the provided path.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Errors> {
+        Self::from_reader(File::open(path).map_err(|_| Errors::Io)?)
+    }
+
+    /// Loads a `leap-seconds.list` file from the provided reader.
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, Errors> {
+        let mut contents = String::new();
+        reader
+            .read_to_string(&mut contents)
+            .map_err(|_| Errors::Io)?;
+        Self::from_str(&contents)
+    }
+
+    /// Returns the date of the last update of this file.
+    #[must_use]
+    pub const fn last_update(&self) -> Epoch {
+        self.last_update
+    }
+
+    /// Returns the expiration date of this file, after which a leap second may have been announced
+    /// which it does not include.
+    #[must_use]
+    pub const fn expiry(&self) -> Epoch {
+        self.expiry
+    }
+
+    /// Returns the SHA-1 hash of this file, as published in its `#h` line, which identifies its
+    /// version.
+    #[must_use]
+    pub const fn hash(&self) -> [u32; 5] {
+        self.hash
+    }
+
+    /// Returns whether this file has expired at the provided epoch, e.g. `Epoch::now()`.
+    #[must_use]
+    pub fn is_expired(&self, epoch: Epoch) -> bool {
+        epoch > self.expiry
+    }
+
+    /// Installs this table as the active leap second source, cf. [`set_leap_second_provider`].
+    pub fn install(self) {
+        set_leap_second_provider(self);
+    }
+}
+
+impl LeapSecondProvider for LeapSecondsFile {
+    fn entries(&self) -> &[LeapSecond] {
+        &self.entries
+    }
+
+    fn valid_until(&self) -> Option<Epoch> {
+        Some(self.expiry)
+    }
+}
+
+impl FromStr for LeapSecondsFile {
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Errors> {
+        let unknown = Errors::ParseError(ParsingErrors::UnknownFormat);
+        let mut entries: Vec<LeapSecond> = Vec::new();
+        let mut last_update = None;
+        let mut expiry = None;
+        let mut hash = None;
+        // The hash covers the last update, the expiration date and the leap second lines, without
+        // whitespace nor comments
+        let mut hashed = String::new();
+        for line in s.lines() {
+            if let Some(value) = line.strip_prefix("#$") {
+                let value = value.trim();
+                last_update = Some(value.parse::<i64>().map_err(|_| unknown)?);
+                hashed.push_str(value);
+            } else if let Some(value) = line.strip_prefix("#@") {
+                let value = value.trim();
+                expiry = Some(value.parse::<i64>().map_err(|_| unknown)?);
+                hashed.push_str(value);
+            } else if let Some(value) = line.strip_prefix("#h") {
+                let words = value
+                    .split_whitespace()
+                    .map(|word| u32::from_str_radix(word, 16))
+                    .collect::<Result<Vec<u32>, _>>()
+                    .map_err(|_| unknown)?;
+                hash = Some(words);
+            } else if !line.starts_with('#') {
+                let data = line.split('#').next().unwrap_or_default();
+                let tokens: Vec<&str> = data.split_whitespace().collect();
+                match tokens.as_slice() {
+                    [] => continue,
+                    [timestamp, delta_at] => {
+                        let timestamp = timestamp.parse::<i64>().map_err(|_| unknown)?;
+                        let delta_at = delta_at.parse::<i32>().map_err(|_| unknown)?;
+                        if entries
+                            .last()
+                            .is_some_and(|prev| prev.timestamp_utc_s >= timestamp)
+                        {
+                            return Err(unknown);
+                        }
+                        entries.push(LeapSecond::new(timestamp, delta_at));
+                    }
+                    _ => return Err(unknown),
+                }
+                hashed.extend(tokens);
+            }
+        }
+
+        let (last_update, expiry, words) = match (last_update, expiry, hash) {
+            (Some(last_update), Some(expiry), Some(words)) => (last_update, expiry, words),
+            _ => return Err(unknown),
+        };
+        let hash = sha1(hashed.as_bytes());
+        if words != hash {
+            return Err(Errors::ParseError(ParsingErrors::Checksum));
+        }
+        if entries
+            .last()
+            .is_some_and(|last| last.timestamp_utc_s >= expiry)
+        {
+            return Err(unknown);
+        }
+
+        let last_update = Epoch::from_utc_duration_with(Unit::Second * last_update, &entries);
+        let expiry = Epoch::from_utc_duration_with(Unit::Second * expiry, &entries);
+        Ok(Self {
+            entries,
+            last_update,
+            expiry,
+            hash,
+        })
+    }
+}
+
+/// Returns the SHA-1 digest (FIPS 180-4) of the provided data, as the five words in which the
+/// `leap-seconds.list` files publish it.
+fn sha1(data: &[u8]) -> [u32; 5] {
+    let mut state: [u32; 5] = [
+        0x6745_2301,
+        0xEFCD_AB89,
+        0x98BA_DCFE,
+        0x1032_5476,
+        0xC3D2_E1F0,
+    ];
+    // Padding with a one bit, zeros, and the length in bits, to a multiple of 64 bytes
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&(data.len() as u64 * 8).to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut w = [0_u32; 80];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (i, wi) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ED9_EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1B_BCDC),
+                _ => (b ^ c ^ d, 0xCA62_C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*wi);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (s, v) in state.iter_mut().zip([a, b, c, d, e]) {
+            *s = s.wrapping_add(v);
+        }
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sha1;
+    use crate::{
+        Epoch, Errors, LatestLeapSeconds, LeapSecondProvider, LeapSecondsFile, ParsingErrors,
+        TimeUnits,
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn test_sha1() {
+        assert_eq!(
+            sha1(b""),
+            [0xda39a3ee, 0x5e6b4b0d, 0x3255bfef, 0x95601890, 0xafd80709]
+        );
+        assert_eq!(
+            sha1(b"abc"),
+            [0xa9993e36, 0x4706816a, 0xba3e2571, 0x7850c26c, 0x9cd0d89d]
+        );
+        // Two blocks
+        assert_eq!(
+            sha1(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            [0x84983e44, 0x1c3bd26e, 0xbaae4aa1, 0xf95129e5, 0xe54670f1]
+        );
+    }
+
+    #[test]
+    fn test_leap_seconds_file() {
+        let file = LeapSecondsFile::from_str(LEAP_SECONDS_LIST).unwrap();
+        assert_eq!(file.entries(), LatestLeapSeconds.entries());
+        assert_eq!(
+            file.last_update(),
+            Epoch::from_gregorian_utc_at_midnight(2024, 7, 8)
+        );
+        assert_eq!(
+            file.expiry(),
+            Epoch::from_gregorian_utc_at_midnight(2025, 6, 28)
+        );
+        assert!(!file.is_expired(file.expiry()));
+        assert!(file.is_expired(file.expiry() + 1.nanoseconds()));
+        assert_eq!(file.valid_until(), Some(file.expiry()));
+        assert_eq!(
+            file.hash(),
+            [0x13882f8c, 0x91f99670, 0x2a4941e1, 0x69c2d4ec, 0x99005181]
+        );
+        // Same entries as the built-in table, but another expiration date
+        assert_ne!(file.version(), LatestLeapSeconds.version());
+
+        let reader = LeapSecondsFile::from_reader(LEAP_SECONDS_LIST.as_bytes()).unwrap();
+        assert_eq!(reader, file);
+
+        // Any change to the data is detected
+        let tampered = LEAP_SECONDS_LIST.replace("3692217600\t37", "3692217600\t38");
+        assert_eq!(
+            LeapSecondsFile::from_str(&tampered),
+            Err(Errors::ParseError(ParsingErrors::Checksum))
+        );
+        // But not to the comments nor whitespace
+        let commented = LEAP_SECONDS_LIST.replace("# 1 Jan 2017", "# 1 January 2017   ");
+        assert_eq!(LeapSecondsFile::from_str(&commented), Ok(file));
+
+        // Missing expiration date or hash
+        let no_expiry = LEAP_SECONDS_LIST.replace("#@", "#");
+        assert_eq!(
+            LeapSecondsFile::from_str(&no_expiry),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        let no_hash = LEAP_SECONDS_LIST.replace("#h", "#");
+        assert_eq!(
+            LeapSecondsFile::from_str(&no_hash),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            LeapSecondsFile::from_path("/does/not/exist/leap-seconds.list"),
+            Err(Errors::Io)
+        );
+    }
+
+    /// A `leap-seconds.list` with the leap seconds up to 2017, without the header comments.
+    const LEAP_SECONDS_LIST: &str = "#	Updated through IERS Bulletin C 68
+#	File expires on:  28 June 2025
+#
+#$	 3929385600
+#
+#@	3960057600
+#
+2272060800	10	# 1 Jan 1972
+2287785600	11	# 1 Jul 1972
+2303683200	12	# 1 Jan 1973
+2335219200	13	# 1 Jan 1974
+2366755200	14	# 1 Jan 1975
+2398291200	15	# 1 Jan 1976
+2429913600	16	# 1 Jan 1977
+2461449600	17	# 1 Jan 1978
+2492985600	18	# 1 Jan 1979
+2524521600	19	# 1 Jan 1980
+2571782400	20	# 1 Jul 1981
+2603318400	21	# 1 Jul 1982
+2634854400	22	# 1 Jul 1983
+2698012800	23	# 1 Jul 1985
+2776982400	24	# 1 Jan 1988
+2840140800	25	# 1 Jan 1990
+2871676800	26	# 1 Jan 1991
+2918937600	27	# 1 Jul 1992
+2950473600	28	# 1 Jul 1993
+2982009600	29	# 1 Jul 1994
+3029443200	30	# 1 Jan 1996
+3076704000	31	# 1 Jul 1997
+3124137600	32	# 1 Jan 1999
+3345062400	33	# 1 Jan 2006
+3439756800	34	# 1 Jan 2009
+3550089600	35	# 1 Jul 2012
+3644697600	36	# 1 Jul 2015
+3692217600	37	# 1 Jan 2017
+#
+#	the hash
+#
+#h	13882f8c 91f99670 2a4941e1 69c2d4ec 99005181
+";
+}
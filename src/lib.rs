@@ -20,6 +20,27 @@ pub const MJD_OFFSET: f64 = 2_400_000.5;
 pub const JDE_OFFSET_DAYS: f64 = J1900_OFFSET + MJD_OFFSET;
 /// The JDE offset in seconds
 pub const JDE_OFFSET_SECONDS: f64 = JDE_OFFSET_DAYS * SECONDS_PER_DAY;
+/// `J1900_OFFSET_DURATION` is the exact duration of `J1900_OFFSET`, i.e. 15,020 days.
+pub const J1900_OFFSET_DURATION: Duration = Duration {
+    centuries: 0,
+    nanoseconds: 1_297_728_000_000_000_000,
+};
+/// `J2000_OFFSET_DURATION` is the exact duration of `J2000_OFFSET`, i.e. 51,544.5 days.
+pub const J2000_OFFSET_DURATION: Duration = Duration {
+    centuries: 1,
+    nanoseconds: 1_297_684_800_000_000_000,
+};
+/// `MJD_OFFSET_DURATION` is the exact duration of `MJD_OFFSET`, i.e. 2,400,000.5 days.
+pub const MJD_OFFSET_DURATION: Duration = Duration {
+    centuries: 65,
+    nanoseconds: 2_235_643_200_000_000_000,
+};
+/// `JDE_OFFSET_DURATION` is the exact duration of the JDE offset, i.e. 2,415,020.5 days, whose
+/// seconds are also `JDE_OFFSET_SECONDS`.
+pub const JDE_OFFSET_DURATION: Duration = Duration {
+    centuries: 66,
+    nanoseconds: 377_611_200_000_000_000,
+};
 /// `DAYS_PER_YEAR` corresponds to the number of days per year in the Julian calendar.
 pub const DAYS_PER_YEAR: f64 = 365.25;
 /// `DAYS_PER_CENTURY` corresponds to the number of days per centuy in the Julian calendar.
@@ -49,6 +70,18 @@ pub const SECONDS_GPS_TAI_OFFSET_I64: i64 =
 /// `DAYS_GPS_TAI_OFFSET` is the number of days from the TAI epoch to the GPS
 /// epoch (UTC midnight of January 6th 1980; cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#GPS_Time_.28GPST.29>)
 pub const DAYS_GPS_TAI_OFFSET: f64 = SECONDS_GPS_TAI_OFFSET / SECONDS_PER_DAY;
+/// `SECONDS_GST_TAI_OFFSET` is the number of seconds from the TAI epoch to the Galileo System
+/// Time epoch, i.e. GPS week 1024 (midnight of August 22nd 1999 in GST, which is aligned with GPS
+/// time; cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#Galileo_System_Time_.28GST.29>)
+pub const SECONDS_GST_TAI_OFFSET: f64 = SECONDS_GPS_TAI_OFFSET + 1024.0 * 7.0 * SECONDS_PER_DAY;
+pub const SECONDS_GST_TAI_OFFSET_I64: i64 =
+    SECONDS_GPS_TAI_OFFSET_I64 + 1024 * 7 * SECONDS_PER_DAY_I64;
+/// `DAYS_GST_TAI_OFFSET` is the number of days from the TAI epoch to the Galileo System Time epoch
+pub const DAYS_GST_TAI_OFFSET: f64 = SECONDS_GST_TAI_OFFSET / SECONDS_PER_DAY;
+
+/// `SECONDS_LORAN_TAI_OFFSET_I64` is the number of seconds from the TAI epoch to the LORAN epoch
+/// (midnight of January 1st 1958 in LORAN time, which is 10 seconds behind TAI)
+pub const SECONDS_LORAN_TAI_OFFSET_I64: i64 = 21_184 * SECONDS_PER_DAY_I64 + 10;
 
 /// The UNIX reference epoch of 1970-01-01.
 pub const UNIX_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
@@ -56,6 +89,68 @@ pub const UNIX_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
     nanoseconds: 2_208_988_800_000_000_000,
 });
 
+/// The original TAI reference epoch of 1958-01-01 at midnight TAI, when TAI was aligned with UT2.
+pub const TAI58_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 0,
+    nanoseconds: 1_830_297_600_000_000_000,
+});
+
+/// The J1950 reference epoch, i.e. the Julian epoch 1950.0 of 1950-01-01 at midnight TT.
+pub const J1950_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 0,
+    nanoseconds: 1_577_836_767_816_000_000,
+});
+
+/// Emits a trace event with the intermediate values of a time system conversion when the
+/// `trace-conversions` feature is enabled, and compiles to nothing otherwise.
+macro_rules! trace_conversion {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "trace-conversions")]
+        tracing::trace!(target: "hifitime::conversions", $($arg)+);
+    };
+}
+
+/// Implements a binary operator over references of its operands, by copying the referenced values,
+/// so that generic code with operator bounds over references works with hifitime types.
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<'a> $imp<$u> for &'a $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, other: $u) -> Self::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<'a> $imp<&'a $u> for $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, other: &'a $u) -> Self::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<'a, 'b> $imp<&'a $u> for &'b $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, other: &'a $u) -> Self::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+    };
+}
+
+/// Implements an assignment operator with a referenced right hand side, by copying it.
+macro_rules! forward_ref_op_assign {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<'a> $imp<&'a $u> for $t {
+            fn $method(&mut self, other: &'a $u) {
+                $imp::$method(self, *other);
+            }
+        }
+    };
+}
+
 mod epoch;
 
 pub use epoch::*;
@@ -64,15 +159,143 @@ mod duration;
 
 pub use duration::*;
 
+mod leap_seconds;
+pub use leap_seconds::*;
+
+mod smear;
+pub use smear::*;
+
+#[cfg(feature = "std")]
+mod leap_seconds_file;
+#[cfg(feature = "std")]
+pub use leap_seconds_file::*;
+
 mod timeseries;
 pub use timeseries::*;
 
+mod interval;
+pub use interval::*;
+
+#[cfg(feature = "std")]
+mod windows;
+#[cfg(feature = "std")]
+pub use windows::*;
+
+#[cfg(feature = "std")]
+mod blackout;
+#[cfg(feature = "std")]
+pub use blackout::*;
+
+mod monotonic;
+pub use monotonic::*;
+
+mod format;
+pub use format::*;
+
+mod irig;
+pub use irig::*;
+
+mod gnss;
+pub use gnss::*;
+
+// Lazy epochs need compare-and-swap atomics, which some targets (e.g. Cortex-M0) lack: use const
+// epochs there, cf. `Epoch::from_gregorian_tai_const`.
+#[cfg(target_has_atomic = "8")]
+mod lazy;
+#[cfg(target_has_atomic = "8")]
+pub use lazy::*;
+
+mod dtg;
+pub use dtg::*;
+
+mod bipm;
+pub use bipm::*;
+
+mod ut1;
+pub use ut1::*;
+
+mod sidereal;
+
+mod tdb;
+
+mod replay;
+pub use replay::*;
+
+mod tle;
+pub use tle::*;
+
+mod sinex;
+pub use sinex::*;
+
+mod tagged;
+pub use tagged::*;
+
+mod ticker;
+pub use ticker::*;
+
+mod assertions;
+pub use assertions::*;
+
+mod leap_cursor;
+pub use leap_cursor::*;
+
+mod calendar;
+pub use calendar::*;
+
+mod typed;
+pub use typed::*;
+
+mod met;
+pub use met::*;
+
+#[cfg(feature = "std")]
+mod custom_scale;
+#[cfg(feature = "std")]
+pub use custom_scale::*;
+
+#[cfg(feature = "std")]
+mod countdown;
+#[cfg(feature = "std")]
+pub use countdown::*;
+
+pub mod search;
+
+pub mod math;
+
+pub mod sofa;
+
+#[cfg(feature = "libc")]
+mod posix;
+#[cfg(feature = "libc")]
+pub use posix::*;
+
+#[cfg(feature = "os-local-time")]
+mod local;
+#[cfg(feature = "os-local-time")]
+pub use local::*;
+
+#[cfg(feature = "tokio")]
+pub mod tokio_time;
+
+#[cfg(feature = "long-horizon")]
+mod epoch128;
+#[cfg(feature = "long-horizon")]
+pub use epoch128::*;
+
+mod literal;
+
+#[cfg(feature = "test_clock")]
+pub mod test_clock;
+
 pub mod prelude {
     pub use {Duration, Epoch, Freq, Frequencies, TimeSeries, TimeUnits, Unit};
 }
 
 extern crate num_traits;
 
+#[cfg(feature = "arbitrary")]
+extern crate arbitrary;
+
 #[cfg(feature = "std")]
 extern crate serde;
 
@@ -84,10 +307,18 @@ use core::fmt;
 use core::num::ParseIntError;
 use core::str::FromStr;
 
+#[cfg(feature = "tokio")]
+extern crate futures_core;
+#[cfg(feature = "libc")]
+extern crate libc;
 #[cfg(feature = "std")]
 extern crate regex;
 #[cfg(feature = "std")]
 extern crate serde_derive;
+#[cfg(feature = "tokio")]
+extern crate tokio;
+#[cfg(feature = "trace-conversions")]
+extern crate tracing;
 #[cfg(feature = "std")]
 use std::error::Error;
 
@@ -107,6 +338,9 @@ pub enum Errors {
     Overflow,
     /// Raised if the initialization from system time failed
     SystemTimeError,
+    /// Raised if reading a file failed, e.g. a leap second file. This variant exists regardless of
+    /// the crate features, but it is only returned by the file readers of the `std` feature.
+    Io,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -117,6 +351,8 @@ pub enum ParsingErrors {
     UnknownFormat,
     UnknownUnit,
     UnsupportedTimeSystem,
+    /// The checksum embedded in the parsed data does not match it
+    Checksum,
 }
 
 impl fmt::Display for Errors {
@@ -132,6 +368,7 @@ impl fmt::Display for Errors {
                 "overflow occured when trying to convert Duration information"
             ),
             Self::SystemTimeError => write!(f, "std::time::SystemTime returned an error"),
+            Self::Io => write!(f, "failed to read a file"),
         }
     }
 }
@@ -158,6 +395,15 @@ pub enum TimeSystem {
     TDB,
     /// Universal Coordinated Time
     UTC,
+    /// Geocentric Coordinate Time (TCG), which runs faster than TT by the defining rate
+    /// L_G = 6.969290134e-10, and coincides with TT on 1977 January 01 at midnight TAI
+    TCG,
+    /// Barycentric Coordinate Time (TCB), which runs faster than TDB by the defining rate
+    /// L_B = 1.550519768e-8, with TDB = TCB - 65.5 µs on 1977 January 01 at midnight TAI
+    TCB,
+    /// LORAN-C system time, counted since January 1st 1958 without leap seconds and a constant
+    /// 10 seconds behind TAI (i.e. 9 seconds ahead of GPS time), as used by eLoran timing receivers
+    LORAN,
 }
 
 impl FromStr for TimeSystem {
@@ -174,6 +420,12 @@ impl FromStr for TimeSystem {
             Ok(TimeSystem::TDB)
         } else if val == "ET" {
             Ok(TimeSystem::ET)
+        } else if val == "TCG" {
+            Ok(TimeSystem::TCG)
+        } else if val == "TCB" {
+            Ok(TimeSystem::TCB)
+        } else if val == "LORAN" {
+            Ok(TimeSystem::LORAN)
         } else {
             Err(Errors::ParseError(ParsingErrors::TimeSystem))
         }
@@ -0,0 +1,601 @@
+use crate::duration::Duration;
+use crate::epoch::{
+    days_from_gregorian, days_in_month, JANUARY_YEARS, JULY_YEARS, LEAP_SECONDS, TT_OFFSET_MS,
+};
+use crate::{Epoch, Errors, ParsingErrors, TimeSystem};
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Parses the `len` ASCII digits starting at `start`, if they all are digits.
+const fn digits(bytes: &[u8], start: usize, len: usize) -> Option<u32> {
+    if start + len > bytes.len() {
+        return None;
+    }
+    let mut value = 0;
+    let mut idx = start;
+    while idx < start + len {
+        if !bytes[idx].is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (bytes[idx] - b'0') as u32;
+        idx += 1;
+    }
+    Some(value)
+}
+
+/// Returns true if the bytes from `start` onward are exactly the provided ones.
+const fn ends_with_at(bytes: &[u8], start: usize, expected: &[u8]) -> bool {
+    if bytes.len() != start + expected.len() {
+        return false;
+    }
+    let mut idx = 0;
+    while idx < expected.len() {
+        if bytes[start + idx] != expected[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+/// Parses the run of ASCII digits starting at `start`, returning its value and the index past it.
+const fn digit_run(bytes: &[u8], start: usize) -> Option<(i128, usize)> {
+    let mut value: i128 = 0;
+    let mut idx = start;
+    // At most 20 digits so that the value always fits
+    while idx < bytes.len() && idx - start < 20 && bytes[idx].is_ascii_digit() {
+        value = value * 10 + (bytes[idx] - b'0') as i128;
+        idx += 1;
+    }
+    if idx == start {
+        None
+    } else {
+        Some((value, idx))
+    }
+}
+
+const fn contains(years: &[i32], year: i32) -> bool {
+    let mut idx = 0;
+    while idx < years.len() {
+        if years[idx] == year {
+            return true;
+        }
+        idx += 1;
+    }
+    false
+}
+
+/// Same checks as `is_gregorian_valid`, usable in a const context.
+const fn is_valid(
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanos: u32,
+) -> bool {
+    if month == 0 || month > 12 || day == 0 || hour > 24 || minute > 59 {
+        return false;
+    }
+    if hour == 24 {
+        // The end of the day in ISO 8601, i.e. midnight of the next day
+        return minute == 0 && second == 0 && nanos == 0;
+    }
+    let days_in_month = days_in_month(year, month as u8) as u32;
+    if day > days_in_month {
+        return false;
+    }
+    let leap_second_allowed = day == days_in_month
+        && hour == 23
+        && minute == 59
+        && ((month == 6 && contains(&JULY_YEARS, year))
+            || (month == 12 && contains(&JANUARY_YEARS, year + 1)));
+    second < 60 || (second == 60 && leap_second_allowed)
+}
+
+impl Duration {
+    /// Parses a duration in the fixed width format of [`Duration::aligned`], i.e. `[-][D ]H:MM:SS[.s]`
+    /// where the number of days and hours may have any number of digits and the seconds up to nine
+    /// decimals, e.g. `001 02:03:04.567` or `26:03:04.5`. This is usable in a const context, cf. the
+    /// [`duration!`](crate::duration) macro.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, TimeUnits, Unit};
+    ///
+    /// let duration = 1.days() + 2.hours() + 3.minutes() + 4.seconds() + 567.milliseconds();
+    /// assert_eq!(Duration::from_aligned_str("001 02:03:04.567"), Ok(duration));
+    /// assert_eq!(Duration::from_aligned_str("26:03:04.567"), Ok(duration));
+    /// assert_eq!(
+    ///     Duration::from_aligned_str(&format!("{}", duration.aligned(Unit::Day, 9))),
+    ///     Ok(duration)
+    /// );
+    /// assert!(Duration::from_aligned_str("02:60:00").is_err());
+    /// ```
+    pub const fn from_aligned_str(s: &str) -> Result<Self, Errors> {
+        let bytes = s.as_bytes();
+        let negative = !bytes.is_empty() && bytes[0] == b'-';
+        let mut idx = if negative { 1 } else { 0 };
+        let mut days = 0;
+        if let Some((value, next)) = digit_run(bytes, idx) {
+            if next < bytes.len() && bytes[next] == b' ' {
+                days = value;
+                idx = next + 1;
+            }
+        }
+        let hours = match digit_run(bytes, idx) {
+            Some((value, next)) if next < bytes.len() && bytes[next] == b':' => {
+                idx = next + 1;
+                value
+            }
+            _ => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+        };
+        if idx + 5 > bytes.len() || bytes[idx + 2] != b':' {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+        let (minutes, seconds) = match (digits(bytes, idx, 2), digits(bytes, idx + 3, 2)) {
+            (Some(minutes), Some(seconds)) => (minutes as i128, seconds as i128),
+            _ => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+        };
+        if minutes > 59 || seconds > 59 {
+            return Err(Errors::Carry);
+        }
+        idx += 5;
+        let mut nanos: i128 = 0;
+        if idx < bytes.len() && bytes[idx] == b'.' {
+            idx += 1;
+            let mut scale = 100_000_000;
+            while idx < bytes.len() && scale > 0 && bytes[idx].is_ascii_digit() {
+                nanos += (bytes[idx] - b'0') as i128 * scale;
+                scale /= 10;
+                idx += 1;
+            }
+            if scale == 100_000_000 {
+                return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+            }
+        }
+        if idx != bytes.len() {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+        let total =
+            (((days * 24 + hours) * 60 + minutes) * 60 + seconds) * NANOSECONDS_PER_SECOND + nanos;
+        Self::try_from_total_nanoseconds(if negative { -total } else { total })
+    }
+}
+
+impl Epoch {
+    /// Parses a Gregorian date time string in a const context, cf. the [`epoch!`](crate::epoch) macro
+    /// which validates epoch literals at compile time.
+    ///
+    /// The format is `YYYY-MM-DDTHH:MM:SS`, where the `T` may be a space, optionally followed by up to
+    /// nine decimals of seconds, and optionally followed by the time system, with or without a space.
+    /// The time system defaults to UTC, and may be UTC, TAI or TT (ET and TDB require trigonometric
+    /// functions which are not available in a const context). The year must be at least 1900.
+    /// The result is identical to that of `Epoch::from_gregorian_str` with the std feature.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// const LAUNCH: Epoch = match Epoch::from_gregorian_str_const("2022-05-20T17:57:43.5 UTC") {
+    ///     Ok(epoch) => epoch,
+    ///     Err(_) => panic!("invalid launch epoch"),
+    /// };
+    /// assert_eq!(LAUNCH, Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 500_000_000));
+    /// assert!(Epoch::from_gregorian_str_const("2022-05-20T17:57:43 TDB").is_err());
+    /// ```
+    pub const fn from_gregorian_str_const(s: &str) -> Result<Self, Errors> {
+        let bytes = s.as_bytes();
+        if bytes.len() < 19
+            || bytes[4] != b'-'
+            || bytes[7] != b'-'
+            || (bytes[10] != b'T' && bytes[10] != b' ')
+            || bytes[13] != b':'
+            || bytes[16] != b':'
+        {
+            return Err(Errors::ParseError(ParsingErrors::ISO8601));
+        }
+        let fields = [
+            digits(bytes, 0, 4),
+            digits(bytes, 5, 2),
+            digits(bytes, 8, 2),
+            digits(bytes, 11, 2),
+            digits(bytes, 14, 2),
+            digits(bytes, 17, 2),
+        ];
+        let (year, month, day, hour, minute, second) = match fields {
+            [Some(y), Some(mo), Some(d), Some(h), Some(mi), Some(s)] => (y as i32, mo, d, h, mi, s),
+            _ => return Err(Errors::ParseError(ParsingErrors::ParseIntError)),
+        };
+
+        // Decimals of the seconds
+        let mut idx = 19;
+        let mut nanos = 0;
+        if idx < bytes.len() && bytes[idx] == b'.' {
+            idx += 1;
+            let mut scale = 100_000_000;
+            while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                if scale == 0 {
+                    return Err(Errors::ParseError(ParsingErrors::ISO8601));
+                }
+                nanos += (bytes[idx] - b'0') as u32 * scale;
+                scale /= 10;
+                idx += 1;
+            }
+            if scale == 100_000_000 {
+                return Err(Errors::ParseError(ParsingErrors::ISO8601));
+            }
+        }
+
+        // Time system
+        if idx < bytes.len() && bytes[idx] == b' ' {
+            idx += 1;
+        }
+        let ts = if idx == bytes.len() || ends_with_at(bytes, idx, b"UTC") {
+            TimeSystem::UTC
+        } else if ends_with_at(bytes, idx, b"TAI") {
+            TimeSystem::TAI
+        } else if ends_with_at(bytes, idx, b"TT") {
+            TimeSystem::TT
+        } else if ends_with_at(bytes, idx, b"ET") || ends_with_at(bytes, idx, b"TDB") {
+            return Err(Errors::ParseError(ParsingErrors::UnsupportedTimeSystem));
+        } else {
+            return Err(Errors::ParseError(ParsingErrors::TimeSystem));
+        };
+
+        if year < 1900 || !is_valid(year, month, day, hour, minute, second, nanos) {
+            return Err(Errors::Carry);
+        }
+
+        // Days since 1900 January 01 at midnight
+        let days = days_from_gregorian(year, month as u8, day as u8);
+        let mut seconds = days * 86_400 + hour as i64 * 3_600 + minute as i64 * 60 + second as i64;
+        if second == 60 {
+            // As in `maybe_from_gregorian`, a leap second shares its seconds with the next second.
+            seconds -= 1;
+        }
+
+        let mut total_ns = seconds as i128 * NANOSECONDS_PER_SECOND + nanos as i128;
+        match ts {
+            TimeSystem::UTC => {
+                let mut cnt = 0;
+                let mut leap = 0;
+                while leap < LEAP_SECONDS.len() && seconds >= LEAP_SECONDS[leap] {
+                    cnt = if cnt == 0 { 10 } else { cnt + 1 };
+                    leap += 1;
+                }
+                total_ns += cnt as i128 * NANOSECONDS_PER_SECOND;
+            }
+            TimeSystem::TT => total_ns -= TT_OFFSET_MS as i128 * 1_000_000,
+            _ => {}
+        }
+
+        match Duration::try_from_total_nanoseconds(total_ns) {
+            Ok(duration) => Ok(Self::from_tai_duration(duration)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Builds an Epoch from a Gregorian date time string literal, parsed and validated at compile time,
+/// cf. [`Epoch::from_gregorian_str_const`] for the supported format. An invalid literal is a
+/// compilation error, and a valid one costs nothing at runtime.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate hifitime;
+/// use hifitime::{Epoch, TimeUnits};
+///
+/// const LAUNCH: Epoch = epoch!("2022-05-20T17:57:43 UTC");
+///
+/// fn main() {
+///     assert_eq!(LAUNCH, Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43));
+///     assert_eq!(epoch!("2022-05-20 17:57:44.25") - LAUNCH, 1250.milliseconds());
+/// }
+/// ```
+///
+/// Invalid literals do not compile:
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate hifitime;
+///
+/// fn main() {
+///     let _ = epoch!("2022-02-30T00:00:00 UTC");
+/// }
+/// ```
+#[macro_export]
+macro_rules! epoch {
+    ($s:literal) => {{
+        const EPOCH: $crate::Epoch = match $crate::Epoch::from_gregorian_str_const($s) {
+            Ok(epoch) => epoch,
+            Err(_) => panic!(concat!("invalid epoch literal: ", $s)),
+        };
+        EPOCH
+    }};
+}
+
+/// Builds a Duration from a literal, computed and validated at compile time: either a list of
+/// integer values with their units (with the same unit names as `Duration::from_str`), or a string
+/// in the fixed width format of [`Duration::from_aligned_str`].
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate hifitime;
+/// use hifitime::{Duration, TimeUnits};
+///
+/// const PASS: Duration = duration!(1 d 2 h 30 min);
+/// const SLEW: Duration = duration!("02:30:00.5");
+///
+/// fn main() {
+///     assert_eq!(PASS, 1.days() + 2.hours() + 30.minutes());
+///     assert_eq!(SLEW, 2.hours() + 30.minutes() + 500.milliseconds());
+///     assert_eq!(duration!(-3 s 250 ms), -2750.milliseconds());
+/// }
+/// ```
+///
+/// Invalid literals do not compile:
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate hifitime;
+///
+/// fn main() {
+///     let _ = duration!("02:60:00");
+/// }
+/// ```
+#[macro_export]
+macro_rules! duration {
+    ($s:literal) => {{
+        const DURATION: $crate::Duration = match $crate::Duration::from_aligned_str($s) {
+            Ok(duration) => duration,
+            Err(_) => panic!(concat!("invalid duration literal: ", $s)),
+        };
+        DURATION
+    }};
+    ($($value:literal $unit:ident)+) => {{
+        const DURATION: $crate::Duration = match $crate::Duration::try_from_total_nanoseconds(
+            0 $(+ ($value as i128) * $crate::__duration_unit_nanoseconds!($unit))+
+        ) {
+            Ok(duration) => duration,
+            Err(_) => panic!("duration literal overflow"),
+        };
+        DURATION
+    }};
+}
+
+/// Returns the number of nanoseconds of the unit names of the `duration!` macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __duration_unit_nanoseconds {
+    (d) => {
+        86_400_000_000_000_i128
+    };
+    (day) => {
+        86_400_000_000_000_i128
+    };
+    (days) => {
+        86_400_000_000_000_i128
+    };
+    (h) => {
+        3_600_000_000_000_i128
+    };
+    (hour) => {
+        3_600_000_000_000_i128
+    };
+    (hours) => {
+        3_600_000_000_000_i128
+    };
+    (min) => {
+        60_000_000_000_i128
+    };
+    (mins) => {
+        60_000_000_000_i128
+    };
+    (minute) => {
+        60_000_000_000_i128
+    };
+    (minutes) => {
+        60_000_000_000_i128
+    };
+    (s) => {
+        1_000_000_000_i128
+    };
+    (second) => {
+        1_000_000_000_i128
+    };
+    (seconds) => {
+        1_000_000_000_i128
+    };
+    (ms) => {
+        1_000_000_i128
+    };
+    (millisecond) => {
+        1_000_000_i128
+    };
+    (milliseconds) => {
+        1_000_000_i128
+    };
+    (us) => {
+        1_000_i128
+    };
+    (microsecond) => {
+        1_000_i128
+    };
+    (microseconds) => {
+        1_000_i128
+    };
+    (ns) => {
+        1_i128
+    };
+    (nanosecond) => {
+        1_i128
+    };
+    (nanoseconds) => {
+        1_i128
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Duration, Epoch, Errors, ParsingErrors, TimeSystem, TimeUnits};
+
+    #[test]
+    fn test_epoch_literal() {
+        const LEAP: Epoch = epoch!("2016-12-31T23:59:60 UTC");
+        assert_eq!(LEAP, Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0));
+        assert_eq!(
+            epoch!("2017-01-01T00:00:00.000000001"),
+            Epoch::from_gregorian_utc(2017, 1, 1, 0, 0, 0, 1)
+        );
+        assert_eq!(
+            epoch!("1900-01-01T00:00:00 TAI"),
+            Epoch::from_gregorian_tai_at_midnight(1900, 1, 1)
+        );
+        assert_eq!(
+            epoch!("2000-02-29 12:00:00TT"),
+            Epoch::maybe_from_gregorian(2000, 2, 29, 12, 0, 0, 0, TimeSystem::TT).unwrap()
+        );
+        // Every leap second transition matches the runtime conversion
+        for year in 1971..2023 {
+            for (month, day) in [(6, 30), (7, 1), (12, 31), (1, 1)] {
+                assert_eq!(
+                    Epoch::from_gregorian_str_const(
+                        core::str::from_utf8(&[
+                            b'0' + (year / 1000) as u8,
+                            b'0' + (year / 100 % 10) as u8,
+                            b'0' + (year / 10 % 10) as u8,
+                            b'0' + (year % 10) as u8,
+                            b'-',
+                            b'0' + month / 10,
+                            b'0' + month % 10,
+                            b'-',
+                            b'0' + day / 10,
+                            b'0' + day % 10,
+                            b'T',
+                            b'0',
+                            b'0',
+                            b':',
+                            b'0',
+                            b'0',
+                            b':',
+                            b'0',
+                            b'1',
+                        ])
+                        .unwrap()
+                    ),
+                    Ok(Epoch::from_gregorian_utc(year, month, day, 0, 0, 1, 0))
+                );
+            }
+        }
+
+        let parse = Epoch::from_gregorian_str_const;
+        assert_eq!(
+            parse("2022-05-20T17:57"),
+            Err(Errors::ParseError(ParsingErrors::ISO8601))
+        );
+        assert_eq!(
+            parse("2022-05-20T17:57:43."),
+            Err(Errors::ParseError(ParsingErrors::ISO8601))
+        );
+        assert_eq!(
+            parse("2022-05-20T17:57:43.1234567891"),
+            Err(Errors::ParseError(ParsingErrors::ISO8601))
+        );
+        assert_eq!(
+            parse("2022-05-2OT17:57:43"),
+            Err(Errors::ParseError(ParsingErrors::ParseIntError))
+        );
+        assert_eq!(
+            parse("2022-05-20T17:57:43 GPS"),
+            Err(Errors::ParseError(ParsingErrors::TimeSystem))
+        );
+        assert_eq!(
+            parse("2022-05-20T17:57:43 ET"),
+            Err(Errors::ParseError(ParsingErrors::UnsupportedTimeSystem))
+        );
+        assert_eq!(parse("2017-12-31T23:59:60 UTC"), Err(Errors::Carry));
+        assert_eq!(parse("2022-05-20T24:00:01 UTC"), Err(Errors::Carry));
+        assert_eq!(parse("2022-05-20T24:00:00.1 UTC"), Err(Errors::Carry));
+        assert_eq!(
+            parse("2016-12-31T24:00:00 UTC"),
+            Ok(Epoch::from_gregorian_utc_at_midnight(2017, 1, 1))
+        );
+        assert_eq!(parse("1899-12-31T00:00:00 UTC"), Err(Errors::Carry));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_epoch_literal_matches_runtime() {
+        for s in [
+            "2022-05-20T17:57:43.123456789 UTC",
+            "2022-05-20 17:57:43 TAI",
+            "1985-07-01T00:00:00 TT",
+            "2012-06-30T23:59:60 UTC",
+            "1972-01-01T00:00:00",
+        ] {
+            assert_eq!(
+                Epoch::from_gregorian_str_const(s),
+                Epoch::from_gregorian_str(s),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_duration_literal() {
+        const PASS: Duration = duration!(1 days 2 hours 30 minutes 15 s 1 ms 2 us 3 ns);
+        assert_eq!(
+            PASS,
+            1.days()
+                + 2.hours()
+                + 30.minutes()
+                + 15.seconds()
+                + 1.milliseconds()
+                + 2.microseconds()
+                + 3.nanoseconds()
+        );
+        assert_eq!(duration!(-1 h), -1.hours());
+        assert_eq!(duration!(36525 d), Duration::from_parts(1, 0));
+        assert_eq!(duration!("-000 00:00:00.000000001"), -1.nanoseconds());
+        assert_eq!(duration!("123:00:00"), 123.hours());
+
+        let parse = Duration::from_aligned_str;
+        assert_eq!(parse("1 00:00:00"), Ok(1.days()));
+        assert_eq!(
+            parse("00:00:59.999999999"),
+            Ok(60.seconds() - 1.nanoseconds())
+        );
+        assert_eq!(
+            parse("00:00:00.1234567891"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            parse("00:00:00."),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            parse("00:00"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            parse("1 00:0:00"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            parse("1  00:00:00"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            parse(""),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(parse("00:00:60"), Err(Errors::Carry));
+        assert_eq!(
+            parse("99999999999999999999 00:00:00"),
+            Err(Errors::Overflow)
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use crate::duration::{Duration, Unit};
+use crate::{Epoch, Errors, TimeSystem};
+use core::convert::TryFrom;
+use core::fmt;
+
+/// An epoch with the UTC offset of the local time zone of the operating system at that epoch, as
+/// returned by [`Epoch::now_local`] and [`Epoch::to_local`]. Its display is the local wall clock
+/// time in the ISO8601 format with the offset made explicit, e.g. `2022-05-20T19:57:43+02:00`.
+///
+/// The offset is only used for display: the epoch is unaffected by it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LocalTime {
+    /// The epoch
+    pub epoch: Epoch,
+    /// The offset of the local wall clock time from UTC, positive east of Greenwich
+    pub utc_offset: Duration,
+}
+
+impl LocalTime {
+    /// Returns the local wall clock time as (year, month, day, hour, minute, second, nanoseconds).
+    #[must_use]
+    pub fn as_gregorian(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        (self.epoch + self.utc_offset).as_gregorian(TimeSystem::UTC)
+    }
+}
+
+impl fmt::Display for LocalTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (y, mm, dd, hh, min, s, nanos) = self.as_gregorian();
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            y, mm, dd, hh, min, s
+        )?;
+        if nanos != 0 {
+            write!(f, ".{:09}", nanos)?;
+        }
+        let offset_minutes = self.utc_offset.total_nanoseconds() / 60_000_000_000;
+        write!(
+            f,
+            "{}{:02}:{:02}",
+            if offset_minutes < 0 { '-' } else { '+' },
+            offset_minutes.abs() / 60,
+            offset_minutes.abs() % 60
+        )
+    }
+}
+
+/// Returns the UTC offset of the local time zone of the operating system at the provided UNIX time.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+fn os_utc_offset(unix_seconds: i64) -> Result<Duration, Errors> {
+    #[allow(clippy::useless_conversion)] // `time_t` is not an `i64` on all platforms
+    let time = libc::time_t::try_from(unix_seconds).map_err(|_| Errors::Overflow)?;
+    // SAFETY: `tm` is plain old data, for which all zeros is a valid value.
+    let mut tm: libc::tm = unsafe { core::mem::zeroed() };
+    // SAFETY: both pointers are valid for the duration of the call, and `localtime_r` is thread safe.
+    if unsafe { libc::localtime_r(&time, &mut tm) }.is_null() {
+        return Err(Errors::SystemTimeError);
+    }
+    #[allow(clippy::useless_conversion)]
+    Ok(Unit::Second * i64::from(tm.tm_gmtoff))
+}
+
+/// The local time zone is not supported on this platform.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+)))]
+fn os_utc_offset(_unix_seconds: i64) -> Result<Duration, Errors> {
+    Err(Errors::SystemTimeError)
+}
+
+impl Epoch {
+    /// Returns the UTC offset of the local time zone of the operating system at this epoch,
+    /// including daylight saving time, e.g. two hours in Paris in summer.
+    ///
+    /// This queries `localtime_r` and is supported on Linux, Android, macOS, iOS and the BSDs:
+    /// other platforms return a SystemTimeError.
+    pub fn local_utc_offset(&self) -> Result<Duration, Errors> {
+        let unix_seconds = self
+            .as_unix_duration()
+            .total_nanoseconds()
+            .div_euclid(1_000_000_000);
+        os_utc_offset(i64::try_from(unix_seconds).map_err(|_| Errors::Overflow)?)
+    }
+
+    /// Returns this epoch labeled with the UTC offset of the local time zone of the operating system.
+    pub fn to_local(&self) -> Result<LocalTime, Errors> {
+        Ok(LocalTime {
+            epoch: *self,
+            utc_offset: self.local_utc_offset()?,
+        })
+    }
+
+    /// Returns the current epoch labeled with the UTC offset of the local time zone of the operating system.
+    pub fn now_local() -> Result<LocalTime, Errors> {
+        Self::now()?.to_local()
+    }
+
+    /// Attempts to build an Epoch from the provided Gregorian date and wall clock time in the local
+    /// time zone of the operating system.
+    ///
+    /// Wall clock times repeated when daylight saving time ends resolve to one of both epochs, and
+    /// wall clock times skipped when it starts resolve to an epoch one gap away from the wall clock.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_gregorian_local(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Result<Self, Errors> {
+        let wall_clock =
+            Self::maybe_from_gregorian_utc(year, month, day, hour, minute, second, nanos)?;
+        // The offset at the wall clock read as UTC is off by at most one offset change.
+        let guess = wall_clock - wall_clock.local_utc_offset()?;
+        Ok(wall_clock - guess.local_utc_offset()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, LocalTime, TimeUnits};
+
+    #[test]
+    fn test_local_time_display() {
+        let epoch = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+        let local = LocalTime {
+            epoch,
+            utc_offset: 2.hours(),
+        };
+        assert_eq!(format!("{}", local), "2022-05-20T19:57:43+02:00");
+        assert_eq!(local.as_gregorian(), (2022, 5, 20, 19, 57, 43, 0));
+        let local = LocalTime {
+            epoch: epoch + 500.milliseconds(),
+            utc_offset: -(9.hours() + 30.minutes()),
+        };
+        assert_eq!(format!("{}", local), "2022-05-20T08:27:43.500000000-09:30");
+    }
+
+    #[test]
+    fn test_local_roundtrip() {
+        let now = Epoch::now_local().unwrap();
+        // UTC offsets range from -12 to +14 hours
+        assert!(now.utc_offset.abs() <= 14.hours());
+        let (y, mm, dd, hh, min, s, nanos) = now.as_gregorian();
+        let epoch = Epoch::from_gregorian_local(y, mm, dd, hh, min, s, nanos).unwrap();
+        assert!((epoch - now.epoch).abs() < 1.microseconds());
+    }
+}
@@ -0,0 +1,174 @@
+//! Floor (Euclidean) division helpers used throughout the time computations, e.g. to split a
+//! number of seconds into days and seconds of day. Unlike the `/` and `%` operators, the remainder
+//! is never negative, so negative values (before a reference epoch) are split consistently.
+
+/// The floating point functions used by the time computations, e.g. the sine of the TDB correction,
+/// which are provided by `std`, or by the pure Rust `libm` on `no_std` targets with the `libm`
+/// feature.
+#[cfg(any(feature = "std", not(feature = "libm")))]
+mod float {
+    pub fn sin(x: f64) -> f64 {
+        x.sin()
+    }
+
+    pub fn trunc(x: f64) -> f64 {
+        x.trunc()
+    }
+
+    pub fn floor(x: f64) -> f64 {
+        x.floor()
+    }
+
+    pub fn abs(x: f64) -> f64 {
+        x.abs()
+    }
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        x.powi(n)
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+mod float {
+    use num_traits::float::Float;
+
+    pub fn sin(x: f64) -> f64 {
+        Float::sin(x)
+    }
+
+    pub fn trunc(x: f64) -> f64 {
+        Float::trunc(x)
+    }
+
+    pub fn floor(x: f64) -> f64 {
+        Float::floor(x)
+    }
+
+    pub fn abs(x: f64) -> f64 {
+        Float::abs(x)
+    }
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        Float::powi(x, n)
+    }
+}
+
+pub(crate) use self::float::{abs, floor, powi, sin, trunc};
+
+/// Returns the Euclidean quotient (as an `i32`) and remainder of `lhs` by `rhs`.
+///
+/// # Example
+/// ```
+/// use hifitime::math::div_rem_f64;
+///
+/// assert_eq!(div_rem_f64(25.0, 6.0), (4, 1.0));
+/// assert_eq!(div_rem_f64(-25.0, 6.0), (-5, 5.0));
+/// ```
+pub fn div_rem_f64(lhs: f64, rhs: f64) -> (i32, f64) {
+    ((div_euclid_f64(lhs, rhs) as i32), rem_euclid_f64(lhs, rhs))
+}
+
+/// Returns the Euclidean quotient of `lhs` by `rhs`, i.e. the quotient rounded such that the
+/// remainder is not negative.
+pub fn div_euclid_f64(lhs: f64, rhs: f64) -> f64 {
+    let q = trunc(lhs / rhs);
+    if lhs % rhs < 0.0 {
+        return if rhs > 0.0 { q - 1.0 } else { q + 1.0 };
+    }
+    q
+}
+
+/// Returns the non negative remainder of `lhs` by `rhs`, in `[0, |rhs|)`.
+pub fn rem_euclid_f64(lhs: f64, rhs: f64) -> f64 {
+    let r = lhs % rhs;
+    if r < 0.0 {
+        r + abs(rhs)
+    } else {
+        r
+    }
+}
+
+/// Returns the Euclidean quotient and remainder of `lhs` by `rhs`.
+///
+/// # Example
+/// ```
+/// use hifitime::math::div_rem_i128;
+///
+/// assert_eq!(div_rem_i128(25, 6), (4, 1));
+/// assert_eq!(div_rem_i128(-25, 6), (-5, 5));
+/// ```
+///
+/// # Panics
+/// If `rhs` is zero, or on overflow of `i128::MIN / -1`.
+pub const fn div_rem_i128(lhs: i128, rhs: i128) -> (i128, i128) {
+    (lhs.div_euclid(rhs), lhs.rem_euclid(rhs))
+}
+
+/// Returns the Euclidean quotient of `lhs` by `rhs`, i.e. the quotient rounded such that the
+/// remainder is not negative.
+///
+/// # Panics
+/// If `rhs` is zero, or on overflow of `i128::MIN / -1`.
+pub const fn div_euclid_i128(lhs: i128, rhs: i128) -> i128 {
+    lhs.div_euclid(rhs)
+}
+
+/// Returns the non negative remainder of `lhs` by `rhs`, in `[0, |rhs|)`.
+///
+/// # Panics
+/// If `rhs` is zero, or on overflow of `i128::MIN % -1`.
+pub const fn rem_euclid_i128(lhs: i128, rhs: i128) -> i128 {
+    lhs.rem_euclid(rhs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_div_rem_f64() {
+        assert_eq!(div_rem_f64(24.0, 6.0), (4, 0.0));
+        assert_eq!(div_rem_f64(25.0, 6.0), (4, 1.0));
+        assert_eq!(div_rem_f64(6.0, 6.0), (1, 0.0));
+        assert_eq!(div_rem_f64(5.0, 6.0), (0, 5.0));
+        assert_eq!(div_rem_f64(3540.0, 3600.0), (0, 3540.0));
+        assert_eq!(div_rem_f64(3540.0, 60.0), (59, 0.0));
+        assert_eq!(div_rem_f64(24.0, -6.0), (-4, 0.0));
+        assert_eq!(div_rem_f64(-24.0, 6.0), (-4, 0.0));
+        assert_eq!(div_rem_f64(-24.0, -6.0), (4, 0.0));
+        assert_eq!(div_rem_f64(-25.0, 6.0), (-5, 5.0));
+        assert_eq!(div_rem_f64(-25.0, -6.0), (5, 5.0));
+        assert_eq!(div_euclid_f64(-0.5, 1.0), -1.0);
+        assert_eq!(rem_euclid_f64(-0.5, 1.0), 0.5);
+        assert_eq!(rem_euclid_f64(7.5, -2.0), 1.5);
+    }
+
+    #[test]
+    fn test_div_rem_i128() {
+        assert_eq!(div_rem_i128(24, 6), (4, 0));
+        assert_eq!(div_rem_i128(25, 6), (4, 1));
+        assert_eq!(div_rem_i128(-25, 6), (-5, 5));
+        assert_eq!(div_rem_i128(25, -6), (-4, 1));
+        assert_eq!(div_rem_i128(-25, -6), (5, 5));
+        assert_eq!(div_euclid_i128(-1, 86_400), -1);
+        assert_eq!(rem_euclid_i128(-1, 86_400), 86_399);
+        // The integer and floating point versions agree
+        for (lhs, rhs) in [(-7, 3), (7, -3), (-7, -3), (7, 3)] {
+            let (q, r) = div_rem_f64(lhs as f64, rhs as f64);
+            assert_eq!(div_rem_i128(lhs, rhs), (i128::from(q), r as i128));
+        }
+    }
+
+    #[test]
+    fn test_float() {
+        use core::f64::consts::PI;
+        // These hold whether the functions are provided by std or by libm
+        assert_eq!(sin(0.0), 0.0);
+        assert!(abs(sin(PI / 6.0) - 0.5) < 1e-15);
+        assert!(abs(sin(-PI / 2.0) + 1.0) < 1e-15);
+        assert_eq!(trunc(-2.7), -2.0);
+        assert_eq!(floor(-2.7), -3.0);
+        assert_eq!(abs(-2.7), 2.7);
+        assert_eq!(powi(10.0, 3), 1_000.0);
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{Duration, Epoch, Errors, ParsingErrors};
+use core::fmt;
+use core::str::FromStr;
+
+const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Parses one or more ASCII digits
+fn digits(s: &str) -> Result<u64, Errors> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Errors::ParseError(ParsingErrors::ParseIntError));
+    }
+    s.parse()
+        .map_err(|_| Errors::ParseError(ParsingErrors::ParseIntError))
+}
+
+/// A mission elapsed time (MET), i.e. the duration since liftoff, formatted as `T+HH:MM:SS.s`
+/// after liftoff or `T-HH:MM:SS.s` during the countdown.
+///
+/// The hours are not wrapped into days, and the fraction of seconds is only written if non zero,
+/// without trailing zeros.
+///
+/// # Example
+/// ```
+/// use hifitime::{Met, TimeUnits};
+/// use std::str::FromStr;
+///
+/// assert_eq!(format!("{}", Met(2.minutes() + 31.5.seconds())), "T+00:02:31.5");
+/// assert_eq!(format!("{}", Met(-(10.seconds()))), "T-00:00:10");
+/// assert_eq!(Met::from_str("T-00:00:10").unwrap(), Met(-(10.seconds())));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Met(pub Duration);
+
+impl fmt::Display for Met {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nanos = self.0.total_nanoseconds();
+        let sign = if nanos < 0 { '-' } else { '+' };
+        let nanos = nanos.unsigned_abs();
+        let (secs, mut fraction) = (
+            nanos / NANOSECONDS_PER_SECOND,
+            nanos % NANOSECONDS_PER_SECOND,
+        );
+        write!(
+            f,
+            "T{}{:02}:{:02}:{:02}",
+            sign,
+            secs / 3_600,
+            secs / 60 % 60,
+            secs % 60
+        )?;
+        if fraction > 0 {
+            let mut width = 9;
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                width -= 1;
+            }
+            write!(f, ".{:0width$}", fraction, width = width)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Met {
+    type Err = Errors;
+
+    /// Parses `T+HH:MM:SS` or `T-HH:MM:SS`, with an optional fraction of seconds of at most nine
+    /// digits. The hours may have any number of digits, and the minutes and seconds must be below 60.
+    /// Returns `Errors::Overflow` if the time is beyond the range of a duration.
+    fn from_str(s: &str) -> Result<Self, Errors> {
+        let s = s.trim();
+        let (negative, hms) = if let Some(hms) = s.strip_prefix("T+") {
+            (false, hms)
+        } else if let Some(hms) = s.strip_prefix("T-") {
+            (true, hms)
+        } else {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        };
+        let mut fields = hms.split(':');
+        let (hours, minutes, seconds) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(hours), Some(minutes), Some(seconds)) if fields.next().is_none() => {
+                (hours, minutes, seconds)
+            }
+            _ => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+        };
+        let (seconds, fraction) = match seconds.split_once('.') {
+            Some((seconds, fraction)) => (seconds, Some(fraction)),
+            None => (seconds, None),
+        };
+        if minutes.len() != 2 || seconds.len() != 2 {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+        let (hours, minutes, seconds) = (digits(hours)?, digits(minutes)?, digits(seconds)?);
+        if minutes >= 60 || seconds >= 60 {
+            return Err(Errors::Carry);
+        }
+        let nanos = match fraction {
+            Some(fraction) if fraction.len() <= 9 => {
+                digits(fraction)? * 10_u64.pow(9 - fraction.len() as u32)
+            }
+            Some(_) => return Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+            None => 0,
+        };
+        // Summed in nanoseconds to reject the hours beyond the range of a duration
+        let nanoseconds = i128::from(hours)
+            .checked_mul(3_600 * NANOSECONDS_PER_SECOND as i128)
+            .and_then(|ns| {
+                ns.checked_add(i128::from(minutes * 60 + seconds) * NANOSECONDS_PER_SECOND as i128)
+            })
+            .and_then(|ns| ns.checked_add(i128::from(nanos)))
+            .ok_or(Errors::Overflow)?;
+        Duration::try_from_total_nanoseconds(if negative { -nanoseconds } else { nanoseconds })
+            .map(Self)
+    }
+}
+
+/// The mission elapsed time (MET) of a mission, anchored at its liftoff epoch, which converts
+/// epochs to MET and back.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, MissionTime, TimeUnits};
+///
+/// let liftoff = Epoch::from_gregorian_utc_hms(2022, 11, 16, 6, 47, 44);
+/// let mission = MissionTime::new(liftoff);
+/// let meco = liftoff + 8.minutes() + 2.5.seconds();
+/// assert_eq!(format!("{}", mission.met(meco)), "T+00:08:02.5");
+/// assert_eq!(mission.parse("T+00:08:02.5").unwrap(), meco);
+/// assert_eq!(mission.parse("T-00:00:10").unwrap(), liftoff - 10.seconds());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MissionTime {
+    liftoff: Epoch,
+}
+
+impl MissionTime {
+    /// Builds the mission elapsed time of a mission which lifts off at the provided epoch.
+    #[must_use]
+    pub const fn new(liftoff: Epoch) -> Self {
+        Self { liftoff }
+    }
+
+    /// Returns the liftoff epoch, i.e. T+0.
+    #[must_use]
+    pub const fn liftoff(&self) -> Epoch {
+        self.liftoff
+    }
+
+    /// Returns the mission elapsed time of the provided epoch, negative before liftoff.
+    #[must_use]
+    pub fn met(&self, epoch: Epoch) -> Met {
+        Met(epoch.since(self.liftoff))
+    }
+
+    /// Returns the epoch of the provided mission elapsed time.
+    #[must_use]
+    pub fn epoch(&self, met: Met) -> Epoch {
+        self.liftoff + met.0
+    }
+
+    /// Parses a mission elapsed time, e.g. `T+00:02:31.5`, into its epoch, cf. [`Met::from_str`].
+    pub fn parse(&self, s: &str) -> Result<Epoch, Errors> {
+        Met::from_str(s).map(|met| self.epoch(met))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Met;
+    use crate::{Duration, Epoch, Errors, MissionTime, ParsingErrors, TimeUnits};
+    use core::str::FromStr;
+
+    #[test]
+    fn test_mission_time() {
+        let liftoff = Epoch::from_gregorian_tai_hms(2022, 11, 16, 6, 47, 44);
+        let mission = MissionTime::new(liftoff);
+        assert_eq!(mission.liftoff(), liftoff);
+        let epoch = liftoff + 2.minutes() + 31.5.seconds();
+        assert_eq!(mission.met(epoch), Met(151.5.seconds()));
+        assert_eq!(mission.epoch(mission.met(epoch)), epoch);
+        assert_eq!(mission.met(liftoff - 10.seconds()), Met(-(10.seconds())));
+        assert!(mission.met(liftoff - 1.seconds()) < mission.met(liftoff));
+
+        for (s, met) in [
+            ("T+00:02:31.5", Met(151.5.seconds())),
+            ("T-00:00:10", Met(-(10.seconds()))),
+            ("T+00:00:00", Met(0.seconds())),
+            (
+                "T+123:04:05.000000001",
+                Met(123.hours() + 245.seconds() + 1.nanoseconds()),
+            ),
+            ("T-01:00:00.25", Met(-(1.hours() + 250.milliseconds()))),
+        ] {
+            assert_eq!(Met::from_str(s), Ok(met));
+            assert_eq!(mission.parse(s), Ok(mission.epoch(met)));
+        }
+        assert_eq!(Met::from_str(" T+1:00:00 "), Ok(Met(1.hours())));
+        assert_eq!(Met::from_str("T-00:00:00"), Ok(Met(0.seconds())));
+
+        for s in [
+            "00:00:10",
+            "T00:00:10",
+            "T+00:10",
+            "T+00:00:00:10",
+            "T+0:0:10",
+            "T+00:00:10.1234567890",
+        ] {
+            assert_eq!(
+                Met::from_str(s),
+                Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            Met::from_str("T+00:0a:10"),
+            Err(Errors::ParseError(ParsingErrors::ParseIntError))
+        );
+        assert_eq!(
+            Met::from_str("T+00:00:10."),
+            Err(Errors::ParseError(ParsingErrors::ParseIntError))
+        );
+        assert_eq!(Met::from_str("T+00:60:00"), Err(Errors::Carry));
+
+        // Beyond the range of an i64 of nanoseconds
+        assert_eq!(
+            Met::from_str("T+3000000:00:00"),
+            Ok(Met(Duration::from_total_nanoseconds(
+                3_000_000 * 3_600 * 1_000_000_000
+            )))
+        );
+        assert_eq!(
+            Met::from_str("T-3000000:00:00"),
+            Ok(Met(Duration::from_total_nanoseconds(
+                -3_000_000 * 3_600 * 1_000_000_000
+            )))
+        );
+        // Beyond the range of a duration, i.e. about 28.7 billion hours
+        for s in [
+            "T+30000000000:00:00",
+            "T-30000000000:00:00",
+            "T+18446744073709551615:00:00",
+        ] {
+            assert_eq!(Met::from_str(s), Err(Errors::Overflow), "{}", s);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_met_format() {
+        for (met, s) in [
+            (Met(151.5.seconds()), "T+00:02:31.5"),
+            (Met(-(10.seconds())), "T-00:00:10"),
+            (Met(0.seconds()), "T+00:00:00"),
+            (
+                Met(123.hours() + 245.seconds() + 1.nanoseconds()),
+                "T+123:04:05.000000001",
+            ),
+            (Met(-(1.hours() + 250.milliseconds())), "T-01:00:00.25"),
+        ] {
+            assert_eq!(format!("{}", met), s);
+            assert_eq!(Met::from_str(s), Ok(met));
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use super::{Duration, Epoch};
+
+/// An anomaly detected by a [`MonotonicChecker`] in a stream of epochs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MonotonicEvent {
+    /// The current epoch is strictly before the previous one, by the provided duration.
+    Regression {
+        previous: Epoch,
+        current: Epoch,
+        by: Duration,
+    },
+    /// The current epoch is identical to the previous one.
+    Repeat { epoch: Epoch },
+    /// The time elapsed between the previous and the current epochs is larger than the maximum gap.
+    Gap {
+        previous: Epoch,
+        current: Epoch,
+        gap: Duration,
+    },
+}
+
+/// Checks that a stream of epochs is strictly increasing, and flags regressions, repeats and gaps.
+///
+/// All comparisons are exact: no floating point arithmetic is involved.
+/// The reference epoch is only updated with samples which are _not_ regressions, such that a
+/// single corrupted timestamp does not cause the following valid samples to be flagged.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, MonotonicChecker, MonotonicEvent, TimeUnits};
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let mut checker = MonotonicChecker::new(2.seconds());
+/// assert_eq!(checker.check(start), None);
+/// assert_eq!(checker.check(start + 1.seconds()), None);
+/// assert_eq!(
+///     checker.check(start + 1.seconds()),
+///     Some(MonotonicEvent::Repeat { epoch: start + 1.seconds() })
+/// );
+/// assert_eq!(
+///     checker.check(start + 10.seconds()),
+///     Some(MonotonicEvent::Gap {
+///         previous: start + 1.seconds(),
+///         current: start + 10.seconds(),
+///         gap: 9.seconds()
+///     })
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MonotonicChecker {
+    max_gap: Option<Duration>,
+    previous: Option<Epoch>,
+}
+
+impl MonotonicChecker {
+    /// Builds a new checker which flags any gap strictly larger than the provided duration.
+    #[must_use]
+    pub const fn new(max_gap: Duration) -> Self {
+        Self {
+            max_gap: Some(max_gap),
+            previous: None,
+        }
+    }
+
+    /// Builds a new checker which only flags regressions and repeats.
+    #[must_use]
+    pub const fn without_gap_check() -> Self {
+        Self {
+            max_gap: None,
+            previous: None,
+        }
+    }
+
+    /// Returns the latest epoch accepted by this checker, if any.
+    #[must_use]
+    pub const fn previous(&self) -> Option<Epoch> {
+        self.previous
+    }
+
+    /// Forgets the previous epoch, e.g. when the underlying stream is restarted.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+
+    /// Checks the provided epoch against the previous one, and returns the anomaly if any.
+    pub fn check(&mut self, epoch: Epoch) -> Option<MonotonicEvent> {
+        let previous = match self.previous {
+            Some(previous) => previous,
+            None => {
+                self.previous = Some(epoch);
+                return None;
+            }
+        };
+
+        if epoch < previous {
+            return Some(MonotonicEvent::Regression {
+                previous,
+                current: epoch,
+                by: previous - epoch,
+            });
+        }
+
+        self.previous = Some(epoch);
+
+        if epoch == previous {
+            return Some(MonotonicEvent::Repeat { epoch });
+        }
+
+        match self.max_gap {
+            Some(max_gap) if epoch - previous > max_gap => Some(MonotonicEvent::Gap {
+                previous,
+                current: epoch,
+                gap: epoch - previous,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Consumes this checker and returns an iterator over the anomalies of the provided epochs,
+    /// along with the index of the sample which triggered them.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, MonotonicChecker, MonotonicEvent, TimeUnits};
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// let samples = [start, start + 1.seconds(), start, start + 2.seconds()];
+    /// let events: Vec<(usize, MonotonicEvent)> =
+    ///     MonotonicChecker::without_gap_check().events(samples).collect();
+    /// assert_eq!(events.len(), 1);
+    /// assert_eq!(events[0].0, 2);
+    /// ```
+    pub fn events<I: IntoIterator<Item = Epoch>>(self, epochs: I) -> MonotonicEvents<I::IntoIter> {
+        MonotonicEvents {
+            checker: self,
+            epochs: epochs.into_iter(),
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over the anomalies found by a [`MonotonicChecker`], cf. [`MonotonicChecker::events`].
+#[derive(Clone, Debug)]
+pub struct MonotonicEvents<I> {
+    checker: MonotonicChecker,
+    epochs: I,
+    index: usize,
+}
+
+impl<I: Iterator<Item = Epoch>> Iterator for MonotonicEvents<I> {
+    type Item = (usize, MonotonicEvent);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for epoch in self.epochs.by_ref() {
+            let index = self.index;
+            self.index += 1;
+            if let Some(event) = self.checker.check(epoch) {
+                return Some((index, event));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, MonotonicChecker, MonotonicEvent, TimeSeries, TimeUnits, Unit};
+
+    #[test]
+    fn test_monotonic_checker() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let end = Epoch::from_gregorian_utc_at_noon(2017, 1, 14);
+
+        // A regular time series has no anomaly
+        let series = TimeSeries::inclusive(start, end, Unit::Hour * 2);
+        assert_eq!(MonotonicChecker::new(2.hours()).events(series).count(), 0);
+        let series = TimeSeries::inclusive(start, end, Unit::Hour * 2);
+        assert_eq!(
+            MonotonicChecker::new(1.hours()).events(series).count(),
+            6,
+            "every step should be flagged as a gap"
+        );
+
+        let mut checker = MonotonicChecker::new(1.minutes());
+        assert_eq!(checker.check(start), None);
+        assert_eq!(checker.previous(), Some(start));
+        assert_eq!(checker.check(start + 1.minutes()), None);
+        assert_eq!(
+            checker.check(start + 30.seconds()),
+            Some(MonotonicEvent::Regression {
+                previous: start + 1.minutes(),
+                current: start + 30.seconds(),
+                by: 30.seconds()
+            })
+        );
+        // The regression did not update the reference epoch
+        assert_eq!(checker.previous(), Some(start + 1.minutes()));
+        assert_eq!(checker.check(start + 2.minutes()), None);
+        assert_eq!(
+            checker.check(start + 2.minutes()),
+            Some(MonotonicEvent::Repeat {
+                epoch: start + 2.minutes()
+            })
+        );
+        // A single nanosecond beyond the maximum gap is flagged
+        assert_eq!(
+            checker.check(start + 3.minutes() + 1.nanoseconds()),
+            Some(MonotonicEvent::Gap {
+                previous: start + 2.minutes(),
+                current: start + 3.minutes() + 1.nanoseconds(),
+                gap: 1.minutes() + 1.nanoseconds()
+            })
+        );
+
+        checker.reset();
+        assert_eq!(checker.previous(), None);
+        assert_eq!(checker.check(start), None);
+
+        let mut checker = MonotonicChecker::without_gap_check();
+        assert_eq!(checker.check(start), None);
+        assert_eq!(checker.check(end), None);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::duration::Duration;
+use crate::{Epoch, Errors, UNIX_REF_EPOCH};
+use core::convert::TryFrom;
+
+/// Conversions to and from the POSIX `timespec` and `timeval` structures, e.g. as returned by
+/// `clock_gettime` or as the kernel timestamps of sockets (`SO_TIMESTAMP`, `SO_TIMESTAMPING`).
+///
+/// These structures hold UNIX time, i.e. the UTC seconds since 1970 January 01 at midnight, where
+/// every day lasts exactly 86400 seconds. Hence, the leap seconds are not representable: a leap
+/// second is reported with the same UNIX time as the first second of the next day (or smeared
+/// over the surrounding hours by some NTP servers), and converting such a structure to an Epoch
+/// yields the epoch of the first second of the next day.
+impl Epoch {
+    /// Initialize an Epoch from the provided POSIX `timespec`, exactly.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate libc;
+    /// extern crate hifitime;
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let ts = libc::timespec {
+    ///     tv_sec: 1_653_069_463,
+    ///     tv_nsec: 123_456_789,
+    /// };
+    /// let epoch = Epoch::from_timespec(ts);
+    /// assert_eq!(
+    ///     epoch,
+    ///     Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43) + 123_456_789.nanoseconds()
+    /// );
+    /// let back = epoch.as_timespec().unwrap();
+    /// assert_eq!((back.tv_sec, back.tv_nsec), (ts.tv_sec, ts.tv_nsec));
+    /// ```
+    #[must_use]
+    pub fn from_timespec(ts: libc::timespec) -> Self {
+        Self::from_unix_duration(Duration::from_total_nanoseconds(
+            i128::from(ts.tv_sec) * 1_000_000_000 + i128::from(ts.tv_nsec),
+        ))
+    }
+
+    /// Returns this epoch as a POSIX `timespec`, with a nanosecond field in `[0, 1e9)`.
+    /// Returns an Overflow error if the seconds do not fit in the `time_t` of this platform.
+    pub fn as_timespec(&self) -> Result<libc::timespec, Errors> {
+        let (seconds, nanoseconds) = self.unix_seconds_nanoseconds();
+        Ok(libc::timespec {
+            tv_sec: libc::time_t::try_from(seconds).map_err(|_| Errors::Overflow)?,
+            tv_nsec: nanoseconds as libc::c_long,
+        })
+    }
+
+    /// Initialize an Epoch from the provided POSIX `timeval`, exactly.
+    #[must_use]
+    pub fn from_timeval(tv: libc::timeval) -> Self {
+        Self::from_unix_duration(Duration::from_total_nanoseconds(
+            i128::from(tv.tv_sec) * 1_000_000_000 + i128::from(tv.tv_usec) * 1_000,
+        ))
+    }
+
+    /// Returns this epoch as a POSIX `timeval`, with a microsecond field in `[0, 1e6)`: the
+    /// nanoseconds are truncated toward the past.
+    /// Returns an Overflow error if the seconds do not fit in the `time_t` of this platform.
+    pub fn as_timeval(&self) -> Result<libc::timeval, Errors> {
+        let (seconds, nanoseconds) = self.unix_seconds_nanoseconds();
+        Ok(libc::timeval {
+            tv_sec: libc::time_t::try_from(seconds).map_err(|_| Errors::Overflow)?,
+            tv_usec: (nanoseconds / 1_000) as libc::suseconds_t,
+        })
+    }
+
+    /// Initialize an Epoch from the provided reading of a PTP hardware clock (PHC), e.g. from
+    /// `clock_gettime` on `/dev/ptpN` or the raw hardware timestamp of `SO_TIMESTAMPING`, in the
+    /// provided time scale of that clock.
+    ///
+    /// # Example
+    /// ```
+    /// extern crate libc;
+    /// extern crate hifitime;
+    /// use hifitime::{Epoch, PhcTimeScale};
+    ///
+    /// // PHCs count TAI seconds, 37 seconds ahead of the UNIX time since 2017
+    /// let ts = libc::timespec {
+    ///     tv_sec: 1_653_069_463 + 37,
+    ///     tv_nsec: 0,
+    /// };
+    /// assert_eq!(
+    ///     Epoch::from_phc_timespec(ts, PhcTimeScale::Tai),
+    ///     Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_phc_timespec(ts: libc::timespec, scale: PhcTimeScale) -> Self {
+        let duration = Duration::from_total_nanoseconds(
+            i128::from(ts.tv_sec) * 1_000_000_000 + i128::from(ts.tv_nsec),
+        );
+        match scale {
+            PhcTimeScale::Tai => UNIX_REF_EPOCH + duration,
+            PhcTimeScale::Utc => Self::from_unix_duration(duration),
+        }
+    }
+
+    /// Returns this epoch as a reading of a PTP hardware clock (PHC) in the provided time scale,
+    /// e.g. to set the clock with `clock_settime`.
+    /// Returns an Overflow error if the seconds do not fit in the `time_t` of this platform.
+    pub fn as_phc_timespec(&self, scale: PhcTimeScale) -> Result<libc::timespec, Errors> {
+        let (seconds, nanoseconds) = match scale {
+            PhcTimeScale::Tai => split_seconds(*self - UNIX_REF_EPOCH),
+            PhcTimeScale::Utc => split_seconds(self.as_unix_duration()),
+        };
+        Ok(libc::timespec {
+            tv_sec: libc::time_t::try_from(seconds).map_err(|_| Errors::Overflow)?,
+            tv_nsec: nanoseconds as libc::c_long,
+        })
+    }
+
+    /// Returns the whole UNIX seconds and the nanoseconds within that second.
+    fn unix_seconds_nanoseconds(&self) -> (i128, i128) {
+        split_seconds(self.as_unix_duration())
+    }
+}
+
+/// Splits a duration into whole seconds and the nanoseconds within that second.
+fn split_seconds(duration: Duration) -> (i128, i128) {
+    let nanoseconds = duration.total_nanoseconds();
+    (
+        nanoseconds.div_euclid(1_000_000_000),
+        nanoseconds.rem_euclid(1_000_000_000),
+    )
+}
+
+/// The time scale of a PTP hardware clock (PHC).
+///
+/// PTP uses the TAI seconds since 1970 January 01 at midnight TAI by default, so the PHC of a
+/// network interface synchronized by `ptp4l` is ahead of the system clock (`CLOCK_REALTIME`) by
+/// the number of leap seconds, e.g. 37 seconds since 2017. Mixing both scales is a classic source
+/// of bugs: the scale must be explicit when converting a PHC reading.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PhcTimeScale {
+    /// The PTP time scale, i.e. TAI seconds since 1970 January 01 at midnight TAI.
+    Tai,
+    /// The arbitrary (ARB) time scale of a PHC set to the UNIX time, like the system clock.
+    Utc,
+}
+
+/// The timestamps reported by the Linux kernel with `SO_TIMESTAMPING`, i.e. the three `timespec`
+/// of a `SCM_TIMESTAMPING` control message, where zero timespecs are absent timestamps.
+///
+/// # Example
+/// ```
+/// extern crate libc;
+/// extern crate hifitime;
+/// use hifitime::{Epoch, KernelTimestamps, PhcTimeScale};
+///
+/// let zero = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+/// let hardware = libc::timespec { tv_sec: 1_653_069_463 + 37, tv_nsec: 0 };
+/// let stamps = KernelTimestamps::from_scm_timestamping(&[zero, zero, hardware], PhcTimeScale::Tai);
+/// assert_eq!(stamps.software, None);
+/// assert_eq!(stamps.hardware, Some(Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KernelTimestamps {
+    /// The software timestamp, from the system clock (UNIX time)
+    pub software: Option<Epoch>,
+    /// The raw hardware timestamp, from the PHC of the network interface
+    pub hardware: Option<Epoch>,
+}
+
+impl KernelTimestamps {
+    /// Converts the three `timespec` of a `SCM_TIMESTAMPING` control message: the first one is the
+    /// software timestamp in UNIX time, the second one is deprecated and ignored, and the third one
+    /// is the raw hardware timestamp in the provided time scale of the PHC.
+    #[must_use]
+    pub fn from_scm_timestamping(ts: &[libc::timespec; 3], phc_scale: PhcTimeScale) -> Self {
+        let is_set = |ts: &libc::timespec| ts.tv_sec != 0 || ts.tv_nsec != 0;
+        Self {
+            software: Some(ts[0]).filter(is_set).map(Epoch::from_timespec),
+            hardware: Some(ts[2])
+                .filter(is_set)
+                .map(|ts| Epoch::from_phc_timespec(ts, phc_scale)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Duration, Epoch, TimeUnits};
+
+    #[test]
+    fn test_timespec() {
+        let epoch =
+            Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43) + 123_456_789.nanoseconds();
+        let ts = epoch.as_timespec().unwrap();
+        assert_eq!((ts.tv_sec, ts.tv_nsec), (1_653_069_463, 123_456_789));
+        assert_eq!(Epoch::from_timespec(ts), epoch);
+
+        // Before 1970, the nanoseconds remain positive
+        let epoch = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1) - 250.milliseconds();
+        let ts = epoch.as_timespec().unwrap();
+        assert_eq!((ts.tv_sec, ts.tv_nsec), (-1, 750_000_000));
+        assert_eq!(Epoch::from_timespec(ts), epoch);
+
+        // Beyond the 292 years of an i64 of nanoseconds, in UNIX time, i.e. without leap seconds
+        for tv_sec in [20_000_000_000, -20_000_000_000] {
+            let ts = libc::timespec {
+                tv_sec,
+                tv_nsec: 123_456_789,
+            };
+            let epoch = Epoch::from_timespec(ts);
+            assert_eq!(
+                epoch.as_utc_duration()
+                    - Epoch::from_gregorian_utc_at_midnight(1970, 1, 1).as_utc_duration(),
+                Duration::from_total_nanoseconds(i128::from(tv_sec) * 1_000_000_000 + 123_456_789)
+            );
+            let back = epoch.as_timespec().unwrap();
+            assert_eq!((back.tv_sec, back.tv_nsec), (ts.tv_sec, ts.tv_nsec));
+        }
+    }
+
+    #[test]
+    fn test_timeval() {
+        let epoch =
+            Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43) + 123_456_789.nanoseconds();
+        let tv = epoch.as_timeval().unwrap();
+        assert_eq!((tv.tv_sec, tv.tv_usec), (1_653_069_463, 123_456));
+        assert_eq!(Epoch::from_timeval(tv), epoch - 789.nanoseconds());
+
+        let epoch = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1) - 1.nanoseconds();
+        let tv = epoch.as_timeval().unwrap();
+        assert_eq!((tv.tv_sec, tv.tv_usec), (-1, 999_999));
+
+        // Beyond the 292 years of an i64 of nanoseconds
+        let tv = libc::timeval {
+            tv_sec: 20_000_000_000,
+            tv_usec: 123_456,
+        };
+        let epoch = Epoch::from_timeval(tv);
+        assert_eq!(
+            epoch.as_utc_duration()
+                - Epoch::from_gregorian_utc_at_midnight(1970, 1, 1).as_utc_duration(),
+            Duration::from_total_nanoseconds(20_000_000_000_123_456_000)
+        );
+        let back = epoch.as_timeval().unwrap();
+        assert_eq!((back.tv_sec, back.tv_usec), (tv.tv_sec, tv.tv_usec));
+    }
+
+    #[test]
+    fn test_phc_timespec() {
+        use crate::{KernelTimestamps, PhcTimeScale};
+        let epoch =
+            Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43) + 123_456_789.nanoseconds();
+        let tai = epoch.as_phc_timespec(PhcTimeScale::Tai).unwrap();
+        let utc = epoch.as_phc_timespec(PhcTimeScale::Utc).unwrap();
+        assert_eq!((tai.tv_sec, tai.tv_nsec), (1_653_069_463 + 37, 123_456_789));
+        assert_eq!((utc.tv_sec, utc.tv_nsec), (1_653_069_463, 123_456_789));
+        assert_eq!(Epoch::from_phc_timespec(tai, PhcTimeScale::Tai), epoch);
+        assert_eq!(Epoch::from_phc_timespec(utc, PhcTimeScale::Utc), epoch);
+        // The PTP epoch is 1970 January 01 at midnight TAI
+        let zero = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        assert_eq!(
+            Epoch::from_phc_timespec(zero, PhcTimeScale::Tai),
+            Epoch::from_gregorian_tai_at_midnight(1970, 1, 1)
+        );
+
+        let stamps = KernelTimestamps::from_scm_timestamping(&[utc, zero, tai], PhcTimeScale::Tai);
+        assert_eq!(stamps.software, Some(epoch));
+        assert_eq!(stamps.hardware, Some(epoch));
+        let stamps = KernelTimestamps::from_scm_timestamping(&[utc, zero, zero], PhcTimeScale::Tai);
+        assert_eq!(stamps.hardware, None);
+
+        // Beyond the 292 years of an i64 of nanoseconds
+        let far = libc::timespec {
+            tv_sec: 20_000_000_000,
+            tv_nsec: 1,
+        };
+        let epoch = Epoch::from_phc_timespec(far, PhcTimeScale::Tai);
+        assert_eq!(
+            epoch - Epoch::from_gregorian_tai_at_midnight(1970, 1, 1),
+            Duration::from_total_nanoseconds(20_000_000_000_000_000_001)
+        );
+        let back = epoch.as_phc_timespec(PhcTimeScale::Tai).unwrap();
+        assert_eq!((back.tv_sec, back.tv_nsec), (far.tv_sec, far.tv_nsec));
+        let epoch = Epoch::from_phc_timespec(far, PhcTimeScale::Utc);
+        let back = epoch.as_phc_timespec(PhcTimeScale::Utc).unwrap();
+        assert_eq!((back.tv_sec, back.tv_nsec), (far.tv_sec, far.tv_nsec));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::Epoch;
+
+/// A clock mapping wall clock (real) epochs to simulated epochs running at a scaled rate, e.g. to
+/// replay telemetry ten times faster than it was recorded, with pause and seek support.
+///
+/// While running, the simulated epoch is `sim_start + (real - real_start) * rate`. Pausing, seeking or
+/// changing the rate rebases both starts on the provided real epoch, so the simulated time is
+/// continuous (except for seeks).
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, ReplayClock, TimeUnits};
+///
+/// let real_start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let sim_start = Epoch::from_gregorian_utc_at_noon(2015, 2, 7);
+/// let mut clock = ReplayClock::new(real_start, sim_start, 10.0);
+/// assert_eq!(clock.sim_time(real_start + 1.minutes()), sim_start + 10.minutes());
+/// assert_eq!(clock.real_time(sim_start + 10.minutes()), Some(real_start + 1.minutes()));
+///
+/// // Pause for a minute, and resume
+/// clock.pause(real_start + 1.minutes());
+/// assert_eq!(clock.sim_time(real_start + 2.minutes()), sim_start + 10.minutes());
+/// clock.resume(real_start + 2.minutes());
+/// assert_eq!(clock.sim_time(real_start + 3.minutes()), sim_start + 20.minutes());
+///
+/// // Jump back to the start of the replay
+/// clock.seek(real_start + 3.minutes(), sim_start);
+/// assert_eq!(clock.sim_time(real_start + 4.minutes()), sim_start + 10.minutes());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReplayClock {
+    real_start: Epoch,
+    sim_start: Epoch,
+    rate: f64,
+    paused: bool,
+}
+
+impl ReplayClock {
+    /// Builds a running replay clock where the real epoch `real_start` maps to the simulated epoch
+    /// `sim_start`, and the simulated time elapses `rate` times as fast as real time.
+    #[must_use]
+    pub const fn new(real_start: Epoch, sim_start: Epoch, rate: f64) -> Self {
+        Self {
+            real_start,
+            sim_start,
+            rate,
+            paused: false,
+        }
+    }
+
+    /// Returns the real epoch from which the simulated time is counted, i.e. the epoch of the
+    /// last pause, resume, seek or rate change (or the initial one).
+    #[must_use]
+    pub const fn real_start(&self) -> Epoch {
+        self.real_start
+    }
+
+    /// Returns the simulated epoch corresponding to [`ReplayClock::real_start`].
+    #[must_use]
+    pub const fn sim_start(&self) -> Epoch {
+        self.sim_start
+    }
+
+    /// Returns the number of simulated seconds per real second, negative for reverse playback.
+    #[must_use]
+    pub const fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Returns whether the simulated time is frozen.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns the simulated epoch corresponding to the provided real epoch.
+    #[must_use]
+    pub fn sim_time(&self, real: Epoch) -> Epoch {
+        if self.paused {
+            self.sim_start
+        } else {
+            self.sim_start + (real - self.real_start) * self.rate
+        }
+    }
+
+    /// Returns the real epoch at which the provided simulated epoch is reached, or None if the
+    /// clock is paused or its rate is zero.
+    #[must_use]
+    pub fn real_time(&self, sim: Epoch) -> Option<Epoch> {
+        if self.paused || self.rate == 0.0 {
+            None
+        } else {
+            Some(self.real_start + (sim - self.sim_start) * (1.0 / self.rate))
+        }
+    }
+
+    /// Returns the simulated epoch corresponding to the current system time.
+    #[cfg(feature = "std")]
+    pub fn sim_now(&self) -> Result<Epoch, crate::Errors> {
+        Ok(self.sim_time(Epoch::now()?))
+    }
+
+    /// Freezes the simulated time at its value at the provided real epoch.
+    pub fn pause(&mut self, real: Epoch) {
+        self.rebase(real);
+        self.paused = true;
+    }
+
+    /// Resumes the simulated time from its frozen value at the provided real epoch.
+    pub fn resume(&mut self, real: Epoch) {
+        self.rebase(real);
+        self.paused = false;
+    }
+
+    /// Jumps to the provided simulated epoch at the provided real epoch, keeping the paused state.
+    pub fn seek(&mut self, real: Epoch, sim: Epoch) {
+        self.real_start = real;
+        self.sim_start = sim;
+    }
+
+    /// Changes the rate of the clock from the provided real epoch onward.
+    pub fn set_rate(&mut self, real: Epoch, rate: f64) {
+        self.rebase(real);
+        self.rate = rate;
+    }
+
+    fn rebase(&mut self, real: Epoch) {
+        self.sim_start = self.sim_time(real);
+        self.real_start = real;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, ReplayClock, TimeUnits};
+
+    #[test]
+    fn test_replay_clock() {
+        let real_start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+        let sim_start = Epoch::from_gregorian_tai_at_noon(2000, 1, 1);
+        let mut clock = ReplayClock::new(real_start, sim_start, 1.0);
+        assert_eq!(
+            clock.sim_time(real_start - 1.hours()),
+            sim_start - 1.hours()
+        );
+        assert_eq!(
+            clock.sim_time(real_start + 1.nanoseconds()),
+            sim_start + 1.nanoseconds()
+        );
+
+        // Slow motion and reverse playback
+        clock.set_rate(real_start + 1.hours(), 0.5);
+        assert_eq!(
+            clock.sim_time(real_start + 3.hours()),
+            sim_start + 2.hours()
+        );
+        assert_eq!(
+            clock.real_time(sim_start + 2.hours()),
+            Some(real_start + 3.hours())
+        );
+        clock.set_rate(real_start + 3.hours(), -2.0);
+        assert_eq!(clock.rate(), -2.0);
+        assert_eq!(clock.sim_time(real_start + 4.hours()), sim_start);
+        assert_eq!(clock.real_time(sim_start), Some(real_start + 4.hours()));
+
+        // Paused clocks never reach other simulated epochs
+        clock.pause(real_start + 4.hours());
+        assert!(clock.is_paused());
+        assert_eq!(clock.sim_time(real_start + 1.days()), sim_start);
+        assert_eq!(clock.real_time(sim_start), None);
+        clock.seek(real_start + 5.hours(), sim_start + 1.days());
+        assert!(clock.is_paused());
+        assert_eq!(clock.sim_time(real_start + 6.hours()), sim_start + 1.days());
+        clock.resume(real_start + 6.hours());
+        assert_eq!(clock.real_start(), real_start + 6.hours());
+        assert_eq!(clock.sim_start(), sim_start + 1.days());
+        assert_eq!(
+            clock.sim_time(real_start + 7.hours()),
+            sim_start + 1.days() - 2.hours()
+        );
+
+        clock.set_rate(real_start + 7.hours(), 0.0);
+        assert_eq!(clock.real_time(sim_start), None);
+    }
+}
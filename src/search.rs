@@ -0,0 +1,156 @@
+//! Searches and interpolations over chronologically sorted slices of epochs, as the first step of
+//! any ephemeris or clock product interpolator. All of the comparisons are exact.
+
+use crate::Epoch;
+
+/// The pair of indices of a sorted slice of epochs surrounding a target epoch, and the weight of the
+/// upper index in a linear interpolation, i.e. zero at the lower epoch and one at the upper epoch.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bracket {
+    /// Index of the last epoch at or before the target
+    pub lower: usize,
+    /// Index of the first epoch at or after the target
+    pub upper: usize,
+    /// Weight of the upper epoch, between zero and one (zero if the target is one of the epochs)
+    pub weight: f64,
+}
+
+/// Returns the bracket of the target epoch in the provided sorted epochs, or None if the target is
+/// outside of the epochs. If the target is one of the epochs, both indices are that of the epoch
+/// and the weight is zero.
+///
+/// # Example
+/// ```
+/// use hifitime::search::{bracket, Bracket};
+/// use hifitime::{Epoch, TimeUnits};
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let epochs = [start, start + 1.minutes(), start + 2.minutes()];
+/// assert_eq!(
+///     bracket(&epochs, start + 90.seconds()),
+///     Some(Bracket { lower: 1, upper: 2, weight: 0.5 })
+/// );
+/// assert_eq!(
+///     bracket(&epochs, start),
+///     Some(Bracket { lower: 0, upper: 0, weight: 0.0 })
+/// );
+/// assert_eq!(bracket(&epochs, start - 1.seconds()), None);
+/// ```
+#[must_use]
+pub fn bracket(epochs: &[Epoch], target: Epoch) -> Option<Bracket> {
+    // Index of the first epoch after the target
+    let upper = epochs.partition_point(|epoch| *epoch <= target);
+    if upper == 0 {
+        return None;
+    }
+    let lower = upper - 1;
+    if epochs[lower] == target {
+        Some(Bracket {
+            lower,
+            upper: lower,
+            weight: 0.0,
+        })
+    } else if upper == epochs.len() {
+        None
+    } else {
+        let span = (epochs[upper] - epochs[lower]).total_nanoseconds();
+        let elapsed = (target - epochs[lower]).total_nanoseconds();
+        Some(Bracket {
+            lower,
+            upper,
+            weight: elapsed as f64 / span as f64,
+        })
+    }
+}
+
+/// Linearly interpolates the values sampled at the provided sorted epochs at the target epoch.
+/// Returns None if the target is outside of the epochs, or if the slices do not have the same length.
+///
+/// # Example
+/// ```
+/// use hifitime::search::linear_interp;
+/// use hifitime::{Epoch, TimeUnits};
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let times = [start, start + 1.minutes(), start + 2.minutes()];
+/// let clock_bias = [1.0e-6, 2.0e-6, 4.0e-6];
+/// let bias = linear_interp(&times, &clock_bias, start + 75.seconds()).unwrap();
+/// assert!((bias - 2.5e-6).abs() < 1e-15);
+/// ```
+#[must_use]
+pub fn linear_interp(times: &[Epoch], values: &[f64], target: Epoch) -> Option<f64> {
+    if times.len() != values.len() {
+        return None;
+    }
+    let Bracket {
+        lower,
+        upper,
+        weight,
+    } = bracket(times, target)?;
+    Some(values[lower] + (values[upper] - values[lower]) * weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bracket, linear_interp, Bracket};
+    use crate::{Epoch, TimeUnits};
+
+    #[test]
+    fn test_bracket() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+        let epochs = [start, start + 1.minutes(), start + 3.minutes()];
+        assert_eq!(bracket(&[], start), None);
+        assert_eq!(
+            bracket(&epochs[..1], start),
+            Some(Bracket {
+                lower: 0,
+                upper: 0,
+                weight: 0.0
+            })
+        );
+        assert_eq!(bracket(&epochs[..1], start + 1.nanoseconds()), None);
+        assert_eq!(
+            bracket(&epochs, start + 1.nanoseconds()),
+            Some(Bracket {
+                lower: 0,
+                upper: 1,
+                weight: 1.0 / 60e9
+            })
+        );
+        assert_eq!(
+            bracket(&epochs, start + 3.minutes() - 1.nanoseconds()).map(|b| (b.lower, b.upper)),
+            Some((1, 2))
+        );
+        assert_eq!(
+            bracket(&epochs, start + 3.minutes()),
+            Some(Bracket {
+                lower: 2,
+                upper: 2,
+                weight: 0.0
+            })
+        );
+        assert_eq!(
+            bracket(&epochs, start + 3.minutes() + 1.nanoseconds()),
+            None
+        );
+
+        // Repeated epochs bracket to the last of them
+        let repeated = [start, start + 1.minutes(), start + 1.minutes()];
+        assert_eq!(
+            bracket(&repeated, start + 1.minutes()).map(|b| b.lower),
+            Some(2)
+        );
+
+        let values = [0.0, 60.0, 180.0];
+        assert_eq!(
+            linear_interp(&epochs, &values, start + 2.minutes()),
+            Some(120.0)
+        );
+        assert_eq!(
+            linear_interp(&epochs, &values, start + 3.minutes()),
+            Some(180.0)
+        );
+        assert_eq!(linear_interp(&epochs, &values[..2], start), None);
+        assert_eq!(linear_interp(&epochs, &values, start - 1.minutes()), None);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::duration::{Duration, Unit};
+use crate::math::{rem_euclid_f64, sin};
+use crate::{Epoch, Ut1Provider, J1900_OFFSET_DURATION, J2000_OFFSET_DURATION};
+use core::f64::consts::{FRAC_PI_2, TAU};
+
+/// Arc seconds to radians
+const ARCSEC_TO_RAD: f64 = TAU / 1_296_000.0;
+/// Degrees to radians
+const DEG_TO_RAD: f64 = TAU / 360.0;
+
+impl Epoch {
+    /// Returns the Julian centuries of TT past J2000.
+    fn tt_centuries_since_j2000(&self) -> f64 {
+        (self.as_tt_duration() - (J2000_OFFSET_DURATION - J1900_OFFSET_DURATION))
+            .in_unit(Unit::Century)
+    }
+
+    #[must_use]
+    /// Returns the Earth rotation angle (IAU 2000) in radians, between 0 and 2π, using the UT1 of
+    /// the provided DUT1 provider (cf. [`Ut1Provider`] and [`Epoch::earth_rotation_angle`]).
+    pub fn as_era<P: Ut1Provider>(&self, provider: &P) -> f64 {
+        self.earth_rotation_angle(provider.ut1_utc(*self))
+    }
+
+    #[must_use]
+    /// Returns the Greenwich mean sidereal time (IAU 2006) in radians, between 0 and 2π, using the
+    /// UT1 of the provided DUT1 provider for the Earth rotation angle, and TT for the precession.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let dut1 = |_| -(110.milliseconds());
+    /// let epoch = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+    /// let gmst = epoch.as_gmst(&dut1);
+    /// assert!((0.0..core::f64::consts::TAU).contains(&gmst));
+    /// // GMST advances by one turn per sidereal day, slightly shorter than a solar day
+    /// let sidereal_day = 86_164.0905.seconds();
+    /// assert!(((epoch + sidereal_day).as_gmst(&dut1) - gmst).abs() < 1e-6);
+    /// ```
+    pub fn as_gmst<P: Ut1Provider>(&self, provider: &P) -> f64 {
+        let t = self.tt_centuries_since_j2000();
+        let precession = 0.014_506
+            + (4_612.156_534
+                + (1.391_581_7 + (-0.000_000_44 + (-0.000_029_956 - 0.000_000_036_8 * t) * t) * t)
+                    * t)
+                * t;
+        rem_euclid_f64(self.as_era(provider) + precession * ARCSEC_TO_RAD, TAU)
+    }
+
+    #[must_use]
+    /// Returns the Greenwich apparent sidereal time in radians, between 0 and 2π, i.e. the
+    /// [`as_gmst`](Self::as_gmst) corrected by the equation of the equinoxes.
+    ///
+    /// The nutation in longitude is computed from its four largest terms (IAU 1980), and the
+    /// equation of the equinoxes includes the complementary terms of IAU 1994, which is accurate to
+    /// a few milliarcseconds with respect to the full IAU 2006/2000A model.
+    pub fn as_gast<P: Ut1Provider>(&self, provider: &P) -> f64 {
+        let t = self.tt_centuries_since_j2000();
+        // Longitude of the ascending node of the Moon, mean longitudes of the Sun and of the Moon
+        let omega = (125.044_52 - 1_934.136_261 * t) * DEG_TO_RAD;
+        let sun = (280.466_5 + 36_000.769_8 * t) * DEG_TO_RAD;
+        let moon = (218.316_5 + 481_267.881_3 * t) * DEG_TO_RAD;
+        let nutation_longitude =
+            -17.20 * sin(omega) - 1.32 * sin(2.0 * sun) - 0.23 * sin(2.0 * moon)
+                + 0.21 * sin(2.0 * omega);
+        // Mean obliquity of the ecliptic
+        let obliquity = (84_381.448 - 46.815_0 * t) * ARCSEC_TO_RAD;
+        let equation_of_equinoxes = nutation_longitude * sin(obliquity + FRAC_PI_2)
+            + 0.002_64 * sin(omega)
+            + 0.000_063 * sin(2.0 * omega);
+        rem_euclid_f64(
+            self.as_gmst(provider) + equation_of_equinoxes * ARCSEC_TO_RAD,
+            TAU,
+        )
+    }
+
+    #[must_use]
+    /// Returns the Greenwich mean sidereal time as a time of day, between 0 and 24 sidereal hours,
+    /// cf. [`as_gmst`](Self::as_gmst).
+    pub fn as_gmst_duration<P: Ut1Provider>(&self, provider: &P) -> Duration {
+        Unit::Day * (self.as_gmst(provider) / TAU)
+    }
+
+    #[must_use]
+    /// Returns the Greenwich apparent sidereal time as a time of day, between 0 and 24 sidereal
+    /// hours, cf. [`as_gast`](Self::as_gast).
+    pub fn as_gast_duration<P: Ut1Provider>(&self, provider: &P) -> Duration {
+        Unit::Day * (self.as_gast(provider) / TAU)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, TimeUnits};
+    use core::f64::consts::TAU;
+
+    #[test]
+    fn test_sidereal_time() {
+        // Reference values of the SOFA test suite, with UT1 = UTC for the Earth rotation angle
+        let dut1 = |_| 0.seconds();
+        let era = Epoch::from_mjd_utc(54_388.0).as_era(&dut1);
+        assert!((era - 0.402_283_724_002_815_8).abs() < 1e-12, "{}", era);
+        let late = |_| -(110.milliseconds());
+        let epoch = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43);
+        assert_eq!(
+            epoch.as_era(&late),
+            epoch.earth_rotation_angle(-(110.milliseconds()))
+        );
+
+        // SOFA uses the same two-part Julian date for UT1 and TT, i.e. UT1 = TT = MJD 53736, which
+        // is just before the leap second of 2006 in UTC, when TAI - UTC was 32 seconds
+        let epoch = Epoch::from_mjd_tai(53_736.0) - 32_184.milliseconds();
+        let ut1_is_tt = |_| 64_184.milliseconds();
+        let gmst = epoch.as_gmst(&ut1_is_tt);
+        assert!((gmst - 1.754_174_971_870_091_2).abs() < 1e-12, "{}", gmst);
+        // Simplified nutation model against the full IAU 2006/2000A model
+        let gast = epoch.as_gast(&ut1_is_tt);
+        assert!((gast - 1.754_166_137_675_019_2).abs() < 1e-7, "{}", gast);
+
+        // Sidereal time of day
+        let gmst_tod = epoch.as_gmst_duration(&ut1_is_tt);
+        assert!((gmst_tod.in_seconds() - gmst / TAU * 86_400.0).abs() < 1e-6);
+        assert!(gmst_tod < 1.days() && gmst_tod > 0.days());
+        assert!(epoch.as_gast_duration(&ut1_is_tt) < gmst_tod);
+
+        // Before J2000, the angles are still within a turn
+        let epoch = Epoch::from_gregorian_utc_at_midnight(1980, 1, 6);
+        for angle in [
+            epoch.as_era(&dut1),
+            epoch.as_gmst(&dut1),
+            epoch.as_gast(&dut1),
+        ] {
+            assert!((0.0..TAU).contains(&angle), "{}", angle);
+        }
+        // One sidereal day later, the GMST is the same
+        let sidereal_day = 86_164.090_5.seconds();
+        assert!(((epoch + sidereal_day).as_gmst(&dut1) - epoch.as_gmst(&dut1)).abs() < 1e-6);
+    }
+}
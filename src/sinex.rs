@@ -0,0 +1,294 @@
+use crate::duration::Unit;
+use crate::epoch::{days_in_year, month_day_from_day_of_year};
+use crate::{Epoch, Errors, ParsingErrors, TimeSystem};
+use core::fmt;
+
+/// Number of seconds in a day, which bounds the seconds of day field.
+const SECONDS_PER_DAY: u32 = 86_400;
+
+/// The epoch of a Solution INdependent EXchange (SINEX) file, i.e. a year, a day of year and a
+/// second of day, written as the 12 character field `YY:DDD:SSSSS`.
+///
+/// As per the SINEX convention, two digit years 00 to 50 map to 2000 to 2050, and 51 to 99 map to
+/// 1951 to 1999. The field `00:000:00000` stands for an unknown or open epoch (e.g. the end of
+/// validity of a station position), so it is parsed as `None`, and [`SinexEpoch::UNKNOWN`] should
+/// be written for a `None` epoch.
+///
+/// The SINEX format does not carry its time system, so it is provided on conversion to and from
+/// an [`Epoch`].
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, SinexEpoch, TimeSystem};
+///
+/// let sinex_epoch = SinexEpoch::parse("22:140:64663").unwrap().unwrap();
+/// assert_eq!(sinex_epoch.year(), 2022);
+/// assert_eq!(sinex_epoch.day_of_year(), 140);
+/// assert_eq!(format!("{}", sinex_epoch), "22:140:64663");
+/// let epoch = sinex_epoch.to_epoch(TimeSystem::UTC);
+/// assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43));
+/// assert_eq!(SinexEpoch::from_epoch(epoch, TimeSystem::UTC), Ok(sinex_epoch));
+///
+/// assert_eq!(SinexEpoch::parse_epoch("00:000:00000", TimeSystem::UTC), Ok(None));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SinexEpoch {
+    year: i32,
+    day_of_year: u16,
+    second_of_day: u32,
+}
+
+impl SinexEpoch {
+    /// The field of an unknown or open epoch.
+    pub const UNKNOWN: &'static str = "00:000:00000";
+
+    /// Builds a SINEX epoch from its full year (1951 to 2050), its day of year (starting at 1) and
+    /// its second of day. Returns an error if any of these is out of range.
+    pub fn new(year: i32, day_of_year: u16, second_of_day: u32) -> Result<Self, Errors> {
+        let days_in_year = days_in_year(year);
+        if !(1951..=2050).contains(&year)
+            || day_of_year == 0
+            || day_of_year > days_in_year
+            || second_of_day >= SECONDS_PER_DAY
+        {
+            Err(Errors::Carry)
+        } else {
+            Ok(Self {
+                year,
+                day_of_year,
+                second_of_day,
+            })
+        }
+    }
+
+    /// Parses the 12 character SINEX epoch field `YY:DDD:SSSSS`, leading and trailing spaces
+    /// allowed. Returns `None` for the unknown epoch `00:000:00000`.
+    pub fn parse(s: &str) -> Result<Option<Self>, Errors> {
+        let s = s.trim().as_bytes();
+        if s.len() != 12 || s[2] != b':' || s[6] != b':' {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+        let digits = |bytes: &[u8]| -> Result<u32, Errors> {
+            bytes.iter().try_fold(0_u32, |acc, c| {
+                if c.is_ascii_digit() {
+                    Ok(acc * 10 + u32::from(c - b'0'))
+                } else {
+                    Err(Errors::ParseError(ParsingErrors::ParseIntError))
+                }
+            })
+        };
+        let short_year = digits(&s[..2])? as i32;
+        let day_of_year = digits(&s[3..6])? as u16;
+        let second_of_day = digits(&s[7..])?;
+        if short_year == 0 && day_of_year == 0 && second_of_day == 0 {
+            return Ok(None);
+        }
+        let year = if short_year <= 50 {
+            2000 + short_year
+        } else {
+            1900 + short_year
+        };
+        Self::new(year, day_of_year, second_of_day).map(Some)
+    }
+
+    /// Parses the SINEX epoch field `YY:DDD:SSSSS` into the epoch in the provided time system, or
+    /// `None` for the unknown epoch `00:000:00000`.
+    pub fn parse_epoch(s: &str, ts: TimeSystem) -> Result<Option<Epoch>, Errors> {
+        Ok(Self::parse(s)?.map(|sinex_epoch| sinex_epoch.to_epoch(ts)))
+    }
+
+    /// Builds the SINEX epoch of the provided epoch in the provided time system, rounded to the
+    /// closest second. Returns an error if its year is not within 1951 and 2050 (or if it rounds up
+    /// out of 2050).
+    pub fn from_epoch(epoch: Epoch, ts: TimeSystem) -> Result<Self, Errors> {
+        let (mut year, month, day, sod) = epoch.as_ymd_sod(ts);
+        let mut day_of_year = crate::epoch::day_of_year(year, month, day);
+        let mut second_of_day = (sod + 0.5) as u32;
+        if second_of_day >= SECONDS_PER_DAY {
+            // Rounded up to the first instant of the next day
+            second_of_day = 0;
+            day_of_year += 1;
+            let days_in_year = days_in_year(year);
+            if day_of_year > days_in_year {
+                year += 1;
+                day_of_year = 1;
+            }
+        }
+        Self::new(year, day_of_year, second_of_day)
+    }
+
+    /// Returns the epoch of this SINEX epoch in the provided time system.
+    #[must_use]
+    pub fn to_epoch(&self, ts: TimeSystem) -> Epoch {
+        let (month, day) = month_day_from_day_of_year(self.year, self.day_of_year)
+            .expect("day of year checked on initialization");
+        Epoch::from_ymd_sod(self.year, month, day, 0.0, ts)
+            + Unit::Second * i64::from(self.second_of_day)
+    }
+
+    /// Returns the full year, from 1951 to 2050.
+    #[must_use]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the day of year, starting at 1 on January 1st.
+    #[must_use]
+    pub const fn day_of_year(&self) -> u16 {
+        self.day_of_year
+    }
+
+    /// Returns the second of day, from 0 to 86399.
+    #[must_use]
+    pub const fn second_of_day(&self) -> u32 {
+        self.second_of_day
+    }
+}
+
+impl fmt::Display for SinexEpoch {
+    /// Prints the 12 character SINEX epoch field
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:03}:{:05}",
+            self.year % 100,
+            self.day_of_year,
+            self.second_of_day
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, Errors, ParsingErrors, SinexEpoch, TimeSystem, TimeUnits};
+
+    #[test]
+    fn test_sinex_epoch() {
+        let sinex_epoch = SinexEpoch::parse("20:366:43200").unwrap().unwrap();
+        assert_eq!(sinex_epoch.year(), 2020);
+        assert_eq!(sinex_epoch.day_of_year(), 366);
+        assert_eq!(sinex_epoch.second_of_day(), 43_200);
+        let epoch = sinex_epoch.to_epoch(TimeSystem::UTC);
+        assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2020, 12, 31, 12, 0, 0));
+        assert_eq!(
+            SinexEpoch::from_epoch(epoch, TimeSystem::UTC),
+            Ok(sinex_epoch)
+        );
+        // Same field in another time system
+        assert_eq!(
+            sinex_epoch.to_epoch(TimeSystem::TAI),
+            Epoch::from_gregorian_tai_hms(2020, 12, 31, 12, 0, 0)
+        );
+        assert_eq!(
+            SinexEpoch::parse_epoch(" 20:366:43200 ", TimeSystem::TT),
+            Ok(Some(Epoch::from_ymd_sod(
+                2020,
+                12,
+                31,
+                43_200.0,
+                TimeSystem::TT
+            )))
+        );
+
+        // ET and TDB, about a minute ahead of UTC
+        for ts in [TimeSystem::ET, TimeSystem::TDB] {
+            let epoch = sinex_epoch.to_epoch(ts);
+            assert!(
+                (epoch - Epoch::from_gregorian_utc(2020, 12, 31, 11, 58, 50, 815_065_000)).abs()
+                    < 2.milliseconds()
+            );
+            assert_eq!(SinexEpoch::from_epoch(epoch, ts), Ok(sinex_epoch));
+        }
+
+        // Unknown epoch
+        assert_eq!(SinexEpoch::parse(SinexEpoch::UNKNOWN), Ok(None));
+        assert_eq!(
+            SinexEpoch::parse_epoch("00:000:00000", TimeSystem::UTC),
+            Ok(None)
+        );
+
+        // Year pivot
+        assert_eq!(
+            SinexEpoch::parse("50:001:00000").unwrap().unwrap().year(),
+            2050
+        );
+        assert_eq!(
+            SinexEpoch::parse("51:001:00000").unwrap().unwrap().year(),
+            1951
+        );
+        assert_eq!(
+            SinexEpoch::parse("00:001:00000").unwrap().unwrap().year(),
+            2000
+        );
+
+        // Rounding to the closest second, and into the next year
+        assert_eq!(
+            SinexEpoch::from_epoch(epoch + 499.milliseconds(), TimeSystem::UTC),
+            Ok(sinex_epoch)
+        );
+        assert_eq!(
+            SinexEpoch::from_epoch(epoch - 500.milliseconds(), TimeSystem::UTC),
+            Ok(sinex_epoch)
+        );
+        let new_year = Epoch::from_gregorian_utc_at_midnight(2021, 1, 1);
+        assert_eq!(
+            SinexEpoch::from_epoch(new_year - 1.milliseconds(), TimeSystem::UTC),
+            SinexEpoch::new(2021, 1, 0)
+        );
+        assert_eq!(
+            SinexEpoch::from_epoch(new_year - 1.seconds(), TimeSystem::UTC),
+            SinexEpoch::new(2020, 366, 86_399)
+        );
+
+        // Out of the SINEX range of years
+        for (year, month, day) in [(1950, 12, 31), (2051, 1, 1)] {
+            assert_eq!(
+                SinexEpoch::from_epoch(
+                    Epoch::from_gregorian_utc_at_noon(year, month, day),
+                    TimeSystem::UTC
+                ),
+                Err(Errors::Carry)
+            );
+        }
+        assert_eq!(
+            SinexEpoch::from_epoch(
+                Epoch::from_gregorian_utc_at_midnight(2051, 1, 1) - 1.milliseconds(),
+                TimeSystem::UTC
+            ),
+            Err(Errors::Carry)
+        );
+
+        for s in ["20:366:4320", "20-366-43200", "2020:366:43200"] {
+            assert_eq!(
+                SinexEpoch::parse(s),
+                Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            SinexEpoch::parse("20:36a:43200"),
+            Err(Errors::ParseError(ParsingErrors::ParseIntError))
+        );
+        assert_eq!(SinexEpoch::parse("21:366:00000"), Err(Errors::Carry));
+        assert_eq!(SinexEpoch::parse("21:000:00000"), Err(Errors::Carry));
+        assert_eq!(SinexEpoch::parse("00:000:00001"), Err(Errors::Carry));
+        assert_eq!(SinexEpoch::parse("21:001:86400"), Err(Errors::Carry));
+        assert_eq!(SinexEpoch::new(1950, 1, 0), Err(Errors::Carry));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sinex_epoch_display() {
+        for field in ["20:366:43200", "51:001:00000", "05:032:00007"] {
+            assert_eq!(
+                format!("{}", SinexEpoch::parse(field).unwrap().unwrap()),
+                field
+            );
+        }
+        assert_eq!(
+            format!("{}", SinexEpoch::new(2000, 1, 5).unwrap()),
+            "00:001:00005"
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use crate::leap_seconds::with_active_leap_seconds;
+use crate::{Duration, Epoch, Unit};
+
+/// A leap second smear, which spreads each leap second linearly over a window around it instead of
+/// inserting (or removing) a whole second at once, as done by the NTP servers of cloud providers.
+///
+/// The window is defined by the UTC readings before and after the leap second instant (midnight
+/// UTC at the end of the day of the leap second). During the window, the smeared UTC clock runs
+/// slightly slower (or faster) than TAI, so that it is continuous and always increasing, and it
+/// matches UTC outside of the window. The leap seconds are those of the active leap second table,
+/// except for its first entry which sets the initial TAI - UTC offset of 1972.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, LeapSmear, TimeUnits};
+///
+/// let smear = LeapSmear::NOON_TO_NOON;
+/// let noon = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+/// // Half way through the smear, half of the leap second has been applied
+/// let midpoint = noon + 12.hours() + 500.milliseconds();
+/// assert_eq!(
+///     midpoint.as_utc_smeared_duration(smear),
+///     midpoint.as_utc_duration() + 500.milliseconds()
+/// );
+/// assert_eq!(Epoch::from_utc_smeared_duration(midpoint.as_utc_smeared_duration(smear), smear), midpoint);
+/// // Smeared UTC matches UTC outside of the window
+/// assert_eq!(noon.as_utc_smeared_duration(smear), noon.as_utc_duration());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LeapSmear {
+    before: Duration,
+    after: Duration,
+}
+
+impl LeapSmear {
+    /// The 24 hour linear smear from noon to noon UTC around the leap second, used by the public NTP
+    /// servers of Google and by the Amazon Time Sync Service.
+    pub const NOON_TO_NOON: Self = Self {
+        before: Duration {
+            centuries: 0,
+            nanoseconds: 43_200_000_000_000,
+        },
+        after: Duration {
+            centuries: 0,
+            nanoseconds: 43_200_000_000_000,
+        },
+    };
+
+    /// Builds a linear smear starting at the UTC reading `before` the leap second instant and ending
+    /// at the UTC reading `after` it. Negative durations are taken as zero, and an empty window holds
+    /// the clock at the leap second instant during the leap second.
+    #[must_use]
+    pub fn linear(before: Duration, after: Duration) -> Self {
+        let zero = Unit::Second * 0;
+        Self {
+            before: if before.signum() < 0 { zero } else { before },
+            after: if after.signum() < 0 { zero } else { after },
+        }
+    }
+
+    /// Returns the duration of the window before the leap second instant, in UTC.
+    #[must_use]
+    pub const fn before(&self) -> Duration {
+        self.before
+    }
+
+    /// Returns the duration of the window after the leap second instant, in UTC.
+    #[must_use]
+    pub const fn after(&self) -> Duration {
+        self.after
+    }
+
+    /// Returns the smeared TAI - UTC offset at the provided TAI duration, in nanoseconds, or `None`
+    /// if this instant is outside of all smear windows.
+    fn offset_at_tai(&self, tai: Duration) -> Option<i128> {
+        let tai = tai.total_nanoseconds();
+        with_active_leap_seconds(|entries| {
+            entries.windows(2).find_map(|leaps| {
+                let (start, end) = self.window(
+                    leaps[0].delta_at,
+                    leaps[1].timestamp_utc_s,
+                    leaps[1].delta_at,
+                );
+                if (start..end).contains(&tai) {
+                    let (old, new) = (i128::from(leaps[0].delta_at), i128::from(leaps[1].delta_at));
+                    Some(
+                        old * NANOSECONDS_PER_SECOND
+                            + (new - old) * NANOSECONDS_PER_SECOND * (tai - start) / (end - start),
+                    )
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    /// Returns the smeared TAI - UTC offset at the provided smeared UTC duration, in nanoseconds,
+    /// or `None` if this reading is outside of all smear windows.
+    fn offset_at_utc(&self, utc: Duration) -> Option<i128> {
+        let utc = utc.total_nanoseconds();
+        with_active_leap_seconds(|entries| {
+            entries.windows(2).find_map(|leaps| {
+                let (old, new) = (i128::from(leaps[0].delta_at), i128::from(leaps[1].delta_at));
+                let (start, end) = self.window(
+                    leaps[0].delta_at,
+                    leaps[1].timestamp_utc_s,
+                    leaps[1].delta_at,
+                );
+                // The UTC readings of the window, which span its TAI duration minus the leap second
+                let utc_start = start - old * NANOSECONDS_PER_SECOND;
+                let utc_end = end - new * NANOSECONDS_PER_SECOND;
+                if (utc_start..utc_end).contains(&utc) {
+                    let elapsed = (utc - utc_start) * (end - start) / (utc_end - utc_start);
+                    Some(start + elapsed - utc)
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    /// Returns the TAI nanoseconds of the start and end of the smear window of a leap second, from
+    /// the previous offset and the leap second instant and new offset.
+    fn window(&self, old: i32, timestamp_utc_s: i64, new: i32) -> (i128, i128) {
+        let instant = i128::from(timestamp_utc_s) * NANOSECONDS_PER_SECOND;
+        (
+            instant - self.before.total_nanoseconds() + i128::from(old) * NANOSECONDS_PER_SECOND,
+            instant + self.after.total_nanoseconds() + i128::from(new) * NANOSECONDS_PER_SECOND,
+        )
+    }
+}
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+
+impl Epoch {
+    /// Returns this time in a Duration past 1900 January 01 at midnight counted in smeared UTC, cf.
+    /// [`LeapSmear`].
+    #[must_use]
+    pub fn as_utc_smeared_duration(&self, smear: LeapSmear) -> Duration {
+        let tai = self.as_tai_duration();
+        match smear.offset_at_tai(tai) {
+            Some(offset) => tai - Duration::from_total_nanoseconds(offset),
+            None => self.as_utc_duration(),
+        }
+    }
+
+    /// Returns the number of seconds past 1900 January 01 at midnight counted in smeared UTC.
+    #[must_use]
+    pub fn as_utc_smeared_seconds(&self, smear: LeapSmear) -> f64 {
+        self.as_utc_smeared_duration(smear).in_seconds()
+    }
+
+    /// Initialize an Epoch from the provided Duration past 1900 January 01 at midnight counted in
+    /// smeared UTC, cf. [`LeapSmear`].
+    #[must_use]
+    pub fn from_utc_smeared_duration(duration: Duration, smear: LeapSmear) -> Self {
+        match smear.offset_at_utc(duration) {
+            Some(offset) => {
+                Self::from_tai_duration(duration + Duration::from_total_nanoseconds(offset))
+            }
+            None => {
+                with_active_leap_seconds(|entries| Self::from_utc_duration_with(duration, entries))
+            }
+        }
+    }
+
+    /// Initialize an Epoch from the provided seconds past 1900 January 01 at midnight counted in
+    /// smeared UTC.
+    #[must_use]
+    pub fn from_utc_smeared_seconds(seconds: f64, smear: LeapSmear) -> Self {
+        Self::from_utc_smeared_duration(seconds * Unit::Second, smear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, LeapSmear, TimeSeries, TimeUnits, Unit};
+
+    #[test]
+    fn test_leap_smear() {
+        let smear = LeapSmear::NOON_TO_NOON;
+        let noon = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+        let next_noon = Epoch::from_gregorian_utc_at_noon(2017, 1, 1);
+        assert_eq!(next_noon - noon, 24.hours() + 1.seconds());
+
+        // Continuous and increasing across the window, and always within one second of UTC
+        let mut prev = (noon - 1.hours() - 1.minutes()).as_utc_smeared_duration(smear);
+        for epoch in TimeSeries::inclusive(noon - 1.hours(), next_noon + 1.hours(), 1.minutes()) {
+            let smeared = epoch.as_utc_smeared_duration(smear);
+            let step = smeared - prev;
+            assert!(
+                step > 59.99.seconds() && step <= 60.seconds(),
+                "{} {}",
+                epoch,
+                step
+            );
+            assert!((smeared - epoch.as_utc_duration()).abs() <= 1.seconds());
+            assert_eq!(Epoch::from_utc_smeared_duration(smeared, smear), epoch);
+            prev = smeared;
+        }
+
+        // The smeared clock reads noon at both ends of the window
+        assert_eq!(noon.as_utc_smeared_duration(smear), noon.as_utc_duration());
+        assert_eq!(
+            next_noon.as_utc_smeared_duration(smear),
+            next_noon.as_utc_duration()
+        );
+        // The smeared clock is slower by less than a nanosecond here
+        assert_eq!(
+            (next_noon - 1.nanoseconds()).as_utc_smeared_duration(smear),
+            next_noon.as_utc_duration()
+        );
+        // Smeared midnight is half a second after the leap second instant
+        let midnight = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+        let smeared_midnight = Epoch::from_utc_smeared_duration(midnight.as_utc_duration(), smear);
+        assert_eq!(smeared_midnight - midnight, -(500.milliseconds()));
+        assert_eq!(
+            Epoch::from_utc_smeared_seconds(midnight.as_utc_seconds(), smear),
+            smeared_midnight
+        );
+        assert_eq!(
+            smeared_midnight.as_utc_smeared_seconds(smear),
+            midnight.as_utc_seconds()
+        );
+
+        // An empty window holds the clock during the leap second
+        let step = LeapSmear::linear(0.seconds(), -(1.hours()));
+        assert_eq!(step.before(), 0.seconds());
+        assert_eq!(step.after(), 0.seconds());
+        let leap_start = midnight - 1.seconds();
+        for epoch in [
+            leap_start,
+            leap_start + 500.milliseconds(),
+            midnight - 1.nanoseconds(),
+        ] {
+            assert_eq!(
+                epoch.as_utc_smeared_duration(step),
+                midnight.as_utc_duration()
+            );
+        }
+        for epoch in [
+            leap_start - 1.nanoseconds(),
+            midnight,
+            midnight + 1.seconds(),
+        ] {
+            assert_eq!(epoch.as_utc_smeared_duration(step), epoch.as_utc_duration());
+        }
+
+        // Asymmetric window, e.g. over the last 10 hours of the day
+        let smear = LeapSmear::linear(Unit::Hour * 10, 0.seconds());
+        let start = Epoch::from_gregorian_utc_hms(2016, 12, 31, 14, 0, 0);
+        assert_eq!(
+            start.as_utc_smeared_duration(smear),
+            start.as_utc_duration()
+        );
+        assert_eq!(
+            midnight.as_utc_smeared_duration(smear),
+            midnight.as_utc_duration()
+        );
+        let half = start + 5.hours() + 500.milliseconds();
+        assert_eq!(
+            half.as_utc_smeared_duration(smear),
+            half.as_utc_duration() - 500.milliseconds()
+        );
+    }
+}
@@ -0,0 +1,254 @@
+//! Equivalents of the SOFA `iauDtf2d` and `iauD2dtf` functions, which convert between calendar
+//! date and time fields and two-part Julian dates, with the same arguments and outputs, so that
+//! validated C or FORTRAN code calling SOFA can be ported to hifitime with few changes.
+//!
+//! As in SOFA, the scale is only used to detect UTC days which end with a leap second, i.e. which
+//! last 86401 seconds, and the time of day is a fraction of the length of the day. Unlike SOFA, the
+//! UTC days prior to 1972 last 86400 seconds, and invalid fields are reported as `Errors::Carry`.
+
+use crate::epoch::{
+    days_from_gregorian, days_in_month, gregorian_from_days, JANUARY_YEARS, JULY_YEARS,
+};
+use crate::math::{floor, powi};
+use crate::{Errors, J1900_NAIF};
+
+/// Julian date of 1900 January 01 at midnight
+const J1900_MIDNIGHT: f64 = J1900_NAIF + 0.5;
+
+/// Returns whether the provided UTC day ends with a leap second.
+fn is_leap_second_day(year: i32, month: u8, day: u8) -> bool {
+    (month == 6 && day == 30 && JULY_YEARS.contains(&year))
+        || (month == 12 && day == 31 && JANUARY_YEARS.contains(&(year + 1)))
+}
+
+/// Returns the length of the provided day in seconds in the provided scale.
+fn day_length_s(scale: &str, year: i32, month: u8, day: u8) -> f64 {
+    if scale == "UTC" && is_leap_second_day(year, month, day) {
+        86_401.0
+    } else {
+        86_400.0
+    }
+}
+
+/// Converts a date and time in the provided scale to a two-part Julian date, as SOFA's `iauDtf2d`.
+///
+/// The first part is the Julian date of the start of the day, and the second part is the time of
+/// day as a fraction of the length of that day. In UTC, the seconds may reach 60 in the last minute
+/// of a day ending with a leap second. As in SOFA, years before -4799 are rejected as
+/// `Errors::Overflow`.
+///
+/// # Example
+/// ```
+/// use hifitime::sofa::dtf2d;
+///
+/// assert_eq!(dtf2d("TT", 2000, 1, 1, 12, 0, 0.0), Ok((2_451_544.5, 0.5)));
+/// let (d1, d2) = dtf2d("UTC", 2016, 12, 31, 23, 59, 60.5).unwrap();
+/// assert_eq!(d1, 2_457_753.5);
+/// assert_eq!(d2, 86_400.5 / 86_401.0);
+/// ```
+pub fn dtf2d(
+    scale: &str,
+    iy: i32,
+    im: i32,
+    id: i32,
+    ihr: i32,
+    imn: i32,
+    sec: f64,
+) -> Result<(f64, f64), Errors> {
+    if iy < -4799 {
+        return Err(Errors::Overflow);
+    } else if !(1..=12).contains(&im) || id < 1 {
+        return Err(Errors::Carry);
+    }
+    let (month, day) = (im as u8, id as u8);
+    if id > i32::from(days_in_month(iy, month))
+        || !(0..=23).contains(&ihr)
+        || !(0..=59).contains(&imn)
+    {
+        return Err(Errors::Carry);
+    }
+    let day_s = day_length_s(scale, iy, month, day);
+    // Only the last minute of the day lasts more than 60 seconds
+    let minute_s = if ihr == 23 && imn == 59 {
+        day_s - 86_340.0
+    } else {
+        60.0
+    };
+    if !(0.0..minute_s).contains(&sec) {
+        return Err(Errors::Carry);
+    }
+    let d1 = J1900_MIDNIGHT + days_from_gregorian(iy, month, day) as f64;
+    let d2 = (f64::from(60 * (60 * ihr + imn)) + sec) / day_s;
+    Ok((d1, d2))
+}
+
+/// Converts a two-part Julian date in the provided scale to a date and time, as SOFA's `iauD2dtf`,
+/// where the time is returned as the hours, minutes, seconds and fraction of seconds rounded to
+/// `ndp` decimal places, between 0 and 9.
+///
+/// The Julian date may be split in any way between both parts. In UTC, the seconds reach 60 during
+/// the leap second at the end of a day. As in SOFA, Julian dates before -68569.5 or after 1e9 are
+/// rejected, and so is any part beyond 1e9 in magnitude: these are reported as `Errors::Overflow`.
+///
+/// # Example
+/// ```
+/// use hifitime::sofa::d2dtf;
+///
+/// assert_eq!(d2dtf("TT", 3, 2_451_544.5, 0.5), Ok((2000, 1, 1, [12, 0, 0, 0])));
+/// assert_eq!(
+///     d2dtf("UTC", 3, 2_457_753.5, 86_400.5 / 86_401.0),
+///     Ok((2016, 12, 31, [23, 59, 60, 500]))
+/// );
+/// ```
+pub fn d2dtf(scale: &str, ndp: i32, d1: f64, d2: f64) -> Result<(i32, i32, i32, [i32; 4]), Errors> {
+    if !(0..=9).contains(&ndp) || !(d1 + d2).is_finite() {
+        return Err(Errors::Carry);
+    }
+    if !(-68_569.5..=1e9).contains(&(d1 + d2)) || d1.abs() > 1e9 || d2.abs() > 1e9 {
+        return Err(Errors::Overflow);
+    }
+    // Split each part separately to preserve the precision of the fraction of the day
+    let (i1, i2) = (floor(d1), floor(d2));
+    let mut fraction = (d1 - i1) + (d2 - i2) - (J1900_MIDNIGHT - floor(J1900_MIDNIGHT));
+    let whole = floor(fraction);
+    fraction -= whole;
+    let mut days = (i1 - floor(J1900_MIDNIGHT)) as i64 + i2 as i64 + whole as i64;
+
+    let (mut year, mut month, mut day) = gregorian_from_days(days);
+    let precision = powi(10.0, ndp) as i64;
+    let day_s = day_length_s(scale, year, month, day);
+    let mut units = floor(fraction * day_s * precision as f64 + 0.5) as i64;
+    // Rounding may reach the start of the next day
+    if units >= day_s as i64 * precision {
+        units -= day_s as i64 * precision;
+        days += 1;
+        let (y, m, d) = gregorian_from_days(days);
+        year = y;
+        month = m;
+        day = d;
+    }
+    let fraction = (units % precision) as i32;
+    let seconds = units / precision;
+    let ihmsf = if seconds >= 86_400 {
+        // During the leap second
+        [23, 59, 60, fraction]
+    } else {
+        let seconds = seconds as i32;
+        [seconds / 3_600, seconds / 60 % 60, seconds % 60, fraction]
+    };
+    Ok((year, i32::from(month), i32::from(day), ihmsf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{d2dtf, dtf2d};
+    use crate::{Epoch, Errors, TimeSystem};
+
+    #[test]
+    fn test_dtf2d() {
+        // SOFA t_sofa_c: iauDtf2d("UTC", 1994, 6, 30, 23, 59, 60.13599) = 2449534.49999 in total
+        let (d1, d2) = dtf2d("UTC", 1994, 6, 30, 23, 59, 60.135_99).unwrap();
+        assert!((d1 + d2 - 2_449_534.499_99).abs() < 1e-6);
+        assert_eq!(d1, 2_449_533.5);
+
+        // Agrees with the epochs
+        let (d1, d2) = dtf2d("TAI", 2022, 5, 20, 17, 57, 43.25).unwrap();
+        let e = Epoch::from_gregorian_tai(2022, 5, 20, 17, 57, 43, 250_000_000);
+        assert!((d1 + d2 - e.as_jde_tai_days()).abs() < 1e-9);
+        // Before 1900
+        assert_eq!(dtf2d("TT", 1858, 11, 17, 0, 0, 0.0), Ok((2_400_000.5, 0.0)));
+        assert_eq!(
+            dtf2d("TT", -4712, 1, 1, 12, 0, 0.0).map(|(d1, _)| d1),
+            Ok(37.5)
+        );
+
+        // Earliest year of SOFA
+        assert_eq!(dtf2d("TT", -4799, 1, 1, 0, 0, 0.0), Ok((-31_738.5, 0.0)));
+        assert_eq!(dtf2d("TT", -4800, 12, 31, 0, 0, 0.0), Err(Errors::Overflow));
+        assert_eq!(
+            dtf2d("UTC", i32::MIN, 1, 1, 0, 0, 0.0),
+            Err(Errors::Overflow)
+        );
+
+        // Sixty seconds are only valid in UTC at the end of a leap second day
+        assert_eq!(dtf2d("TAI", 1994, 6, 30, 23, 59, 60.1), Err(Errors::Carry));
+        assert_eq!(dtf2d("UTC", 1994, 6, 29, 23, 59, 60.1), Err(Errors::Carry));
+        assert_eq!(dtf2d("UTC", 1994, 6, 30, 23, 58, 60.0), Err(Errors::Carry));
+        for (iy, im, id, ihr, imn, sec) in [
+            (2022, 0, 1, 0, 0, 0.0),
+            (2022, 13, 1, 0, 0, 0.0),
+            (2022, 2, 29, 0, 0, 0.0),
+            (2022, 4, 0, 0, 0, 0.0),
+            (2022, 4, 1, 24, 0, 0.0),
+            (2022, 4, 1, 0, 60, 0.0),
+            (2022, 4, 1, 0, 0, -1.0),
+        ] {
+            assert_eq!(dtf2d("TT", iy, im, id, ihr, imn, sec), Err(Errors::Carry));
+        }
+        assert!(dtf2d("TT", 2020, 2, 29, 0, 0, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_d2dtf() {
+        // SOFA t_sofa_c: iauD2dtf("UTC", 5, 2400000.5, 49533.99999) = 1994-06-30 23:59:60.13599
+        assert_eq!(
+            d2dtf("UTC", 5, 2_400_000.5, 49_533.999_99),
+            Ok((1994, 6, 30, [23, 59, 60, 13_599]))
+        );
+        // The same instant in TAI has no leap second
+        assert_eq!(
+            d2dtf("TAI", 5, 2_400_000.5, 49_533.999_99),
+            Ok((1994, 6, 30, [23, 59, 59, 13_600]))
+        );
+        // Any split of the Julian date
+        let e = Epoch::from_gregorian_tai(2022, 5, 20, 17, 57, 43, 250_000_000);
+        let expected = Ok((2022, 5, 20, [17, 57, 43, 250]));
+        assert_eq!(d2dtf("TAI", 3, e.as_jde_tai_days(), 0.0), expected);
+        assert_eq!(d2dtf("TAI", 3, 2_400_000.5, e.as_mjd_tai_days()), expected);
+        assert_eq!(
+            d2dtf("TAI", 3, 2_459_719.0, 0.5 + 0.748_417_245_370_370_4),
+            expected
+        );
+        assert_eq!(
+            d2dtf("TAI", 3, 2_459_721.0, -0.751_582_754_629_629_6),
+            expected
+        );
+        let (y, mm, dd, hh, min, s, _) = e.as_gregorian(TimeSystem::TAI);
+        assert_eq!(
+            d2dtf("TAI", 0, e.as_jde_tai_days(), 0.0),
+            Ok((
+                y,
+                mm.into(),
+                dd.into(),
+                [hh.into(), min.into(), s.into(), 0]
+            ))
+        );
+
+        // Rounding to the next day, and to the leap second
+        assert_eq!(
+            d2dtf("TT", 2, 2_459_719.5, 0.999_999_99),
+            Ok((2022, 5, 21, [0, 0, 0, 0]))
+        );
+        assert_eq!(
+            d2dtf("UTC", 2, 2_457_753.5, 86_399.999_9 / 86_401.0),
+            Ok((2016, 12, 31, [23, 59, 60, 0]))
+        );
+
+        // Round trip
+        for (scale, sec) in [("UTC", 60.25), ("TT", 59.25)] {
+            let (d1, d2) = dtf2d(scale, 2016, 12, 31, 23, 59, sec).unwrap();
+            let s = sec as i32;
+            assert_eq!(d2dtf(scale, 2, d1, d2), Ok((2016, 12, 31, [23, 59, s, 25])));
+        }
+
+        assert_eq!(d2dtf("TT", 10, 2_459_720.5, 0.0), Err(Errors::Carry));
+        assert_eq!(d2dtf("TT", 3, f64::NAN, 0.0), Err(Errors::Carry));
+        // Out of range but finite dates
+        assert_eq!(d2dtf("TT", 3, 1e300, 0.0), Err(Errors::Overflow));
+        assert_eq!(d2dtf("TT", 3, -1e300, 0.0), Err(Errors::Overflow));
+        assert_eq!(d2dtf("TT", 3, 1e300, -1e300), Err(Errors::Overflow));
+        assert_eq!(d2dtf("TT", 3, -68_570.0, 0.0), Err(Errors::Overflow));
+        assert!(d2dtf("TT", 3, -68_569.5, 0.0).is_ok());
+        assert!(d2dtf("TT", 3, 1e9, 0.0).is_ok());
+    }
+}
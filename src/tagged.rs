@@ -0,0 +1,217 @@
+use crate::Epoch;
+use core::cmp::Ordering;
+use core::iter::Peekable;
+
+#[cfg(feature = "std")]
+use serde_derive::Deserialize;
+
+/// A value tagged with the epoch at which it was measured or is valid, e.g. a measurement or a state.
+///
+/// **Important:** time tagged values are compared only by their epochs, so two values tagged with the
+/// same epoch are equal, and sorting a slice of time tagged values sorts it chronologically.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, TimeTagged, TimeUnits};
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let mut ranges = vec![
+///     TimeTagged::new(start + 2.seconds(), 7_000.2),
+///     TimeTagged::new(start, 7_000.0),
+///     TimeTagged::new(start + 1.seconds(), 7_000.1),
+/// ];
+/// ranges.sort_by(|a, b| a.cmp(b));
+/// assert_eq!(ranges[0].value, 7_000.0);
+/// assert_eq!(ranges[2].epoch, start + 2.seconds());
+/// ```
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Deserialize))]
+pub struct TimeTagged<T> {
+    /// Epoch at which the value was measured or is valid
+    pub epoch: Epoch,
+    /// The tagged value, which is ignored by comparisons
+    pub value: T,
+}
+
+impl<T> TimeTagged<T> {
+    /// Tags the provided value with the provided epoch.
+    #[must_use]
+    pub const fn new(epoch: Epoch, value: T) -> Self {
+        Self { epoch, value }
+    }
+
+    /// Returns a time tagged reference to the value.
+    #[must_use]
+    pub const fn as_ref(&self) -> TimeTagged<&T> {
+        TimeTagged {
+            epoch: self.epoch,
+            value: &self.value,
+        }
+    }
+
+    /// Maps the value, keeping the same epoch.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> TimeTagged<U> {
+        TimeTagged {
+            epoch: self.epoch,
+            value: f(self.value),
+        }
+    }
+}
+
+impl<T> PartialEq for TimeTagged<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.epoch == other.epoch
+    }
+}
+
+impl<T> Eq for TimeTagged<T> {}
+
+impl<T> PartialOrd for TimeTagged<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for TimeTagged<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch.cmp(&other.epoch)
+    }
+}
+
+impl<T> From<(Epoch, T)> for TimeTagged<T> {
+    fn from((epoch, value): (Epoch, T)) -> Self {
+        Self { epoch, value }
+    }
+}
+
+/// Returns true if the provided time tagged values are in chronological order (repeated epochs allowed).
+#[must_use]
+pub fn is_chronological<T>(values: &[TimeTagged<T>]) -> bool {
+    values.windows(2).all(|pair| pair[0].epoch <= pair[1].epoch)
+}
+
+/// Merges two chronological streams of time tagged values into a single chronological stream.
+/// On equal epochs, the values of the first stream come first.
+///
+/// # Example
+/// ```
+/// use hifitime::{merge_chronological, Epoch, TimeTagged, TimeUnits};
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let gps = (0..3).map(|i| TimeTagged::new(start + i.seconds(), "gps"));
+/// let imu = (0..4).map(|i| TimeTagged::new(start + (i * 500).milliseconds(), "imu"));
+/// let merged: Vec<&str> = merge_chronological(gps, imu).map(|m| m.value).collect();
+/// assert_eq!(merged, ["gps", "imu", "imu", "gps", "imu", "imu", "gps"]);
+/// ```
+pub fn merge_chronological<T, I, J>(
+    first: I,
+    second: J,
+) -> MergeChronological<I::IntoIter, J::IntoIter>
+where
+    I: IntoIterator<Item = TimeTagged<T>>,
+    J: IntoIterator<Item = TimeTagged<T>>,
+{
+    MergeChronological {
+        first: first.into_iter().peekable(),
+        second: second.into_iter().peekable(),
+    }
+}
+
+/// An iterator merging two chronological streams of time tagged values, cf. [`merge_chronological`].
+pub struct MergeChronological<I: Iterator, J: Iterator> {
+    first: Peekable<I>,
+    second: Peekable<J>,
+}
+
+impl<T, I, J> Iterator for MergeChronological<I, J>
+where
+    I: Iterator<Item = TimeTagged<T>>,
+    J: Iterator<Item = TimeTagged<T>>,
+{
+    type Item = TimeTagged<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let take_first = match (self.first.peek(), self.second.peek()) {
+            (Some(a), Some(b)) => a.epoch <= b.epoch,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if take_first {
+            self.first.next()
+        } else {
+            self.second.next()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_low, first_high) = self.first.size_hint();
+        let (second_low, second_high) = self.second.size_hint();
+        let high = match (first_high, second_high) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (first_low.saturating_add(second_low), high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{is_chronological, merge_chronological, Epoch, TimeTagged, TimeUnits};
+
+    #[test]
+    fn test_time_tagged() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+        let a = TimeTagged::new(start, 1.5);
+        let b = TimeTagged::from((start, -3.0));
+        let c = TimeTagged::new(start + 1.nanoseconds(), 1.5);
+        assert_eq!(a, b);
+        assert!(a < c);
+        assert_eq!(a.max(c).value, 1.5);
+        assert_eq!(a.as_ref().value, &1.5);
+        assert_eq!(c.map(|v| v > 1.0), TimeTagged::new(c.epoch, true));
+
+        let mut values = [c, a, b];
+        assert!(!is_chronological(&values));
+        values.sort_unstable();
+        assert!(is_chronological(&values));
+        assert_eq!(values[2].epoch, c.epoch);
+
+        let first = [a, c];
+        let second = [TimeTagged::new(start - 1.days(), 0.0), b];
+        let merged = merge_chronological(first, second);
+        assert_eq!(merged.size_hint(), (4, Some(4)));
+        let mut merged = merged.map(|m| m.value);
+        assert_eq!(merged.next(), Some(0.0));
+        assert_eq!(merged.next(), Some(1.5));
+        assert_eq!(merged.next(), Some(-3.0));
+        assert_eq!(merged.next(), Some(1.5));
+        assert_eq!(merged.next(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn deser_test() {
+        use serde::de::value::{Error, MapDeserializer};
+        use serde::Deserialize;
+
+        let fields = [("epoch", "2022-05-20T17:57:43 UTC"), ("value", "AOS")];
+        let tagged = TimeTagged::<String>::deserialize(MapDeserializer::<_, Error>::new(
+            fields.iter().copied(),
+        ))
+        .unwrap();
+        assert_eq!(
+            tagged.epoch,
+            Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 57, 43)
+        );
+        assert_eq!(tagged.value, "AOS");
+
+        // Invalid epoch
+        let fields = [("epoch", "2022-05-20T17:57"), ("value", "AOS")];
+        assert!(
+            TimeTagged::<String>::deserialize(MapDeserializer::<_, Error>::new(
+                fields.iter().copied()
+            ))
+            .is_err()
+        );
+    }
+}
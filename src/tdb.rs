@@ -0,0 +1,165 @@
+use crate::duration::{Duration, Unit};
+use crate::epoch::TT_OFFSET_MS;
+use crate::math::sin;
+use crate::{Epoch, ET_EPOCH_S};
+
+/// The largest terms of the Fairhead & Bretagnon (1990) series of TDB - TT at the geocenter, as
+/// tabulated in SOFA's `iauDtdb`, grouped by power of the time: each term is an amplitude in
+/// seconds, a frequency in radians per Julian millennium of TT and a phase in radians.
+///
+/// All the terms of amplitude larger than 0.375 microseconds are kept, which leaves an error of a
+/// few tens of nanoseconds with respect to the 787 terms of the full series.
+const FB_TERMS_T0: [(f64, f64, f64); 20] = [
+    (1_656.674_564e-6, 6_283.075_849_991, 6.240_054_195),
+    (22.417_471e-6, 5_753.384_884_897, 4.296_977_442),
+    (13.839_792e-6, 12_566.151_699_983, 6.196_904_410),
+    (4.770_086e-6, 529.690_965_095, 0.444_401_603),
+    (4.676_740e-6, 6_069.776_754_553, 4.021_195_093),
+    (2.256_707e-6, 213.299_095_438, 5.543_113_262),
+    (1.694_205e-6, -3.523_118_349, 5.025_132_748),
+    (1.554_905e-6, 77_713.771_467_920, 5.198_467_090),
+    (1.276_839e-6, 7_860.419_392_439, 5.988_822_341),
+    (1.193_379e-6, 5_223.693_919_802, 3.649_823_730),
+    (1.115_322e-6, 3_930.209_696_220, 1.422_745_069),
+    (0.794_185e-6, 11_506.769_769_794, 2.322_313_077),
+    (0.447_061e-6, 26.298_319_800, 3.615_796_498),
+    (0.435_206e-6, -398.149_003_408, 4.349_338_347),
+    (0.600_309e-6, 1_577.343_542_448, 2.678_271_909),
+    (0.496_817e-6, 6_208.294_251_424, 5.696_701_824),
+    (0.486_306e-6, 5_884.926_846_583, 0.520_007_179),
+    (0.432_392e-6, 74.781_598_567, 2.435_898_309),
+    (0.468_597e-6, 6_244.942_814_354, 5.866_398_759),
+    (0.375_510e-6, 5_507.553_238_667, 4.103_476_804),
+];
+const FB_TERMS_T1: [(f64, f64, f64); 6] = [
+    (102.156_724e-6, 6_283.075_849_991, 4.249_032_005),
+    (1.706_807e-6, 12_566.151_699_983, 4.205_904_248),
+    (0.269_668e-6, 213.299_095_438, 3.400_290_479),
+    (0.265_919e-6, 529.690_965_095, 5.836_047_367),
+    (0.210_568e-6, -3.523_118_349, 6.262_738_348),
+    (0.077_996e-6, 5_223.693_919_802, 4.670_344_204),
+];
+const FB_TERMS_T2: [(f64, f64, f64); 1] = [(4.322_990e-6, 6_283.075_849_991, 2.642_893_748)];
+const FB_TERMS_T3: [(f64, f64, f64); 1] = [(0.143_388e-6, 6_283.075_849_991, 1.131_453_581)];
+
+/// Returns TDB - TT at the geocenter in seconds, at the provided Julian millennia of TT past J2000.
+fn tdb_minus_tt_s(t: f64) -> f64 {
+    let sum = |terms: &[(f64, f64, f64)]| -> f64 {
+        terms
+            .iter()
+            .map(|(amplitude, frequency, phase)| amplitude * sin(frequency * t + phase))
+            .sum()
+    };
+    let series = sum(&FB_TERMS_T0)
+        + t * (sum(&FB_TERMS_T1) + t * (sum(&FB_TERMS_T2) + t * sum(&FB_TERMS_T3)));
+    // Adjustments of SOFA to use the JPL planetary masses instead of the IAU ones
+    let jpl = 0.000_65e-6 * sin(6_069.776_754 * t + 4.021_194)
+        + 0.000_33e-6 * sin(213.299_095 * t + 5.543_132)
+        - 0.001_96e-6 * sin(6_208.294_251 * t + 5.696_701)
+        - 0.001_73e-6 * sin(74.781_599 * t + 2.435_900)
+        + 0.036_38e-6 * t * t;
+    series + jpl
+}
+
+impl Epoch {
+    #[must_use]
+    /// Returns TDB - TT at the geocenter from the Fairhead & Bretagnon (1990) series, cf.
+    /// [`as_tdb_duration_precise`](Self::as_tdb_duration_precise).
+    pub fn tdb_tt_offset_precise(&self) -> Duration {
+        tdb_minus_tt_s(self.as_tt_centuries_j2k() / 10.0) * Unit::Second
+    }
+
+    #[must_use]
+    /// Returns the Dynamic Barycentric Time (TDB) past J2000 as a high precision Duration, as
+    /// [`as_tdb_duration`](Self::as_tdb_duration) but computing TDB - TT with the largest terms of
+    /// the Fairhead & Bretagnon (1990) series instead of a single periodic term.
+    ///
+    /// This is within a few tens of nanoseconds of SOFA's `iauDtdb` at the geocenter, whereas the
+    /// single term used by default, as by SPICE, departs from it by up to tens of microseconds.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_at_noon(2022, 5, 20);
+    /// let tdb = epoch.as_tdb_duration_precise();
+    /// assert!((tdb - epoch.as_tdb_duration()).abs() < 50.microseconds());
+    /// assert_eq!(Epoch::from_tdb_duration_precise(tdb), epoch);
+    /// ```
+    pub fn as_tdb_duration_precise(&self) -> Duration {
+        self.as_tt_duration() - Unit::Second * ET_EPOCH_S + self.tdb_tt_offset_precise()
+    }
+
+    #[must_use]
+    /// Returns the Dynamic Barycentric Time (TDB) in seconds past J2000, cf.
+    /// [`as_tdb_duration_precise`](Self::as_tdb_duration_precise).
+    pub fn as_tdb_seconds_precise(&self) -> f64 {
+        self.as_tdb_duration_precise().in_seconds()
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Dynamic Barycentric Time (TDB) duration past J2000,
+    /// using the Fairhead & Bretagnon (1990) series, cf.
+    /// [`as_tdb_duration_precise`](Self::as_tdb_duration_precise).
+    pub fn from_tdb_duration_precise(duration: Duration) -> Self {
+        let tdb_wrt_1900 = duration + Unit::Second * ET_EPOCH_S;
+        let tt_offset = Unit::Millisecond * TT_OFFSET_MS;
+        // TDB - TT varies by less than a nanosecond per second, so two iterations from TT = TDB
+        // converge well below the nanosecond.
+        let mut tt = tdb_wrt_1900;
+        for _ in 0..2 {
+            tt = tdb_wrt_1900 - Self::from_tai_duration(tt - tt_offset).tdb_tt_offset_precise();
+        }
+        Self::from_tai_duration(tt - tt_offset)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Dynamic Barycentric Time (TDB) seconds past J2000,
+    /// using the Fairhead & Bretagnon (1990) series, cf.
+    /// [`as_tdb_duration_precise`](Self::as_tdb_duration_precise).
+    pub fn from_tdb_seconds_precise(seconds: f64) -> Self {
+        assert!(
+            seconds.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tdb_duration_precise(seconds * Unit::Second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, TimeUnits};
+
+    #[test]
+    fn test_tdb_precise() {
+        // SOFA's `iauDtdb` test case at TT = JD 2448939.623, from which its topocentric terms
+        // (-383.573 ns for the observer of that test) are removed.
+        let epoch =
+            Epoch::from_mjd_tai(48_939.0) - 32_184.milliseconds() + 10_627_200.milliseconds();
+        let offset = epoch.tdb_tt_offset_precise().in_seconds();
+        assert!((offset - -1.279_984_433_218e-3).abs() < 50e-9, "{}", offset);
+        // The single term of the default TDB is about ten microseconds away at that epoch
+        let default_offset =
+            epoch.as_tdb_duration() - epoch.as_tt_duration() + 3_155_716_800.seconds();
+        assert!((default_offset.in_seconds() - offset).abs() > 5e-6);
+        assert!((default_offset.in_seconds() - offset).abs() < 50e-6);
+
+        // Round trips over the mission and pre-J2000 epochs
+        for epoch in [
+            epoch,
+            Epoch::from_gregorian_tai_at_noon(2000, 1, 1),
+            Epoch::from_gregorian_utc(2022, 5, 20, 17, 57, 43, 123_456_789),
+            Epoch::from_gregorian_utc_at_midnight(1972, 1, 1),
+            Epoch::from_gregorian_tai_at_midnight(2150, 6, 1),
+        ] {
+            let tdb = epoch.as_tdb_duration_precise();
+            assert_eq!(Epoch::from_tdb_duration_precise(tdb), epoch);
+            assert!((tdb - epoch.as_tdb_duration()).abs() < 50.microseconds());
+            assert!((epoch.as_tdb_seconds_precise() - tdb.in_seconds()).abs() < 1e-6);
+            assert!(
+                (Epoch::from_tdb_seconds_precise(epoch.as_tdb_seconds_precise()) - epoch).abs()
+                    < 1.microseconds()
+            );
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Overrides of [`Epoch::now`] for deterministic tests, available with the `test_clock` feature.
+//!
+//! Setting the current time returns a guard: `Epoch::now()` returns the mocked epoch until the
+//! guard is dropped, after which the previous override (if any) or the system time is restored.
+//! Overrides are per thread, so tests running in parallel do not interfere with each other.
+//!
+//! # Example
+//! ```
+//! use hifitime::{test_clock, Epoch, TimeUnits};
+//!
+//! let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+//! {
+//!     let mut clock = test_clock::set_now(start);
+//!     assert_eq!(Epoch::now().unwrap(), start);
+//!     clock.advance(1.hours());
+//!     assert_eq!(Epoch::now().unwrap(), start + 1.hours());
+//! }
+//! // Back to the system time
+//! assert!(Epoch::now().unwrap() > start + 1.hours());
+//! ```
+
+use crate::{Duration, Epoch};
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+thread_local! {
+    static MOCK_NOW: Cell<Option<Epoch>> = const { Cell::new(None) };
+}
+
+/// Returns the mocked current time of this thread, if any.
+pub(crate) fn mocked_now() -> Option<Epoch> {
+    MOCK_NOW.with(Cell::get)
+}
+
+/// Overrides the current time returned by `Epoch::now` on this thread until the returned guard is dropped.
+#[must_use = "the current time is only mocked until the guard is dropped"]
+pub fn set_now(epoch: Epoch) -> MockNowGuard {
+    let previous = MOCK_NOW.with(|now| now.replace(Some(epoch)));
+    MockNowGuard {
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+/// Guard of a mocked current time, which restores the previous current time when dropped.
+/// It cannot be sent to another thread since the override only applies to the current thread.
+#[derive(Debug)]
+pub struct MockNowGuard {
+    previous: Option<Epoch>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl MockNowGuard {
+    /// Changes the mocked current time.
+    pub fn set(&mut self, epoch: Epoch) {
+        MOCK_NOW.with(|now| now.set(Some(epoch)));
+    }
+
+    /// Moves the mocked current time by the provided duration.
+    pub fn advance(&mut self, duration: Duration) {
+        MOCK_NOW.with(|now| now.set(now.get().map(|epoch| epoch + duration)));
+    }
+}
+
+impl Drop for MockNowGuard {
+    fn drop(&mut self) {
+        MOCK_NOW.with(|now| now.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::set_now;
+    use crate::{Epoch, TimeUnits};
+
+    #[test]
+    fn test_mocked_now() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+        let outer = set_now(start);
+        assert_eq!(Epoch::now(), Ok(start));
+        {
+            let mut inner = set_now(start - 1.days());
+            assert_eq!(Epoch::now(), Ok(start - 1.days()));
+            inner.set(start + 1.days());
+            assert_eq!(Epoch::now(), Ok(start + 1.days()));
+        }
+        // The outer override is restored
+        assert_eq!(Epoch::now(), Ok(start));
+        // Other threads are not affected
+        let other = std::thread::spawn(|| Epoch::now().unwrap()).join().unwrap();
+        assert!(other > Epoch::from_gregorian_utc_at_midnight(2022, 6, 1));
+        drop(outer);
+        assert!(Epoch::now().unwrap() > start + 10.days());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::math::div_euclid_i128;
+use crate::{Duration, Epoch};
+
+/// A drift-free periodic ticker for control loops, where the deadline of tick `i` is computed
+/// exactly as `start + i * period`, instead of accumulating `last + period` (which drifts by the
+/// latency of each wake up).
+///
+/// The ticker does not sleep: the control loop sleeps until [`Ticker::next_deadline`], then calls
+/// [`Ticker::tick`] with the current epoch. If the loop overran and several deadlines passed, only
+/// the latest tick fires and the skipped ones are counted as missed.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, Ticker, TimeUnits};
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let mut ticker = Ticker::new(start, 100.milliseconds());
+/// assert_eq!(ticker.tick(start - 1.milliseconds()), None);
+/// assert_eq!(ticker.tick(start + 2.milliseconds()), Some(0));
+/// assert_eq!(ticker.next_deadline(), start + 100.milliseconds());
+/// // The loop overran: ticks 1 and 2 are missed
+/// assert_eq!(ticker.tick(start + 350.milliseconds()), Some(3));
+/// assert_eq!(ticker.missed_ticks(), 2);
+/// assert_eq!(ticker.next_deadline(), start + 400.milliseconds());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ticker {
+    start: Epoch,
+    period: Duration,
+    /// Index of the next tick to fire
+    next_index: u64,
+    missed: u64,
+}
+
+impl Ticker {
+    /// Builds a ticker whose first tick is at `start`, and then every `period`.
+    ///
+    /// # Panics
+    /// If the period is not positive.
+    #[must_use]
+    pub fn new(start: Epoch, period: Duration) -> Self {
+        assert!(
+            period.total_nanoseconds() > 0,
+            "ticker period must be positive"
+        );
+        Self {
+            start,
+            period,
+            next_index: 0,
+            missed: 0,
+        }
+    }
+
+    /// Returns the deadline of the first tick.
+    #[must_use]
+    pub const fn start(&self) -> Epoch {
+        self.start
+    }
+
+    /// Returns the duration between the deadlines of two consecutive ticks.
+    #[must_use]
+    pub const fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Returns the index of the next tick to fire.
+    #[must_use]
+    pub const fn next_index(&self) -> u64 {
+        self.next_index
+    }
+
+    /// Returns the number of ticks skipped because their deadline passed before the ticker was
+    /// ticked, i.e. the overruns of the control loop.
+    #[must_use]
+    pub const fn missed_ticks(&self) -> u64 {
+        self.missed
+    }
+
+    /// Returns the deadline of the provided tick, i.e. `start + index * period`, computed exactly.
+    #[must_use]
+    pub fn deadline(&self, index: u64) -> Epoch {
+        self.start
+            + Duration::from_total_nanoseconds(self.period.total_nanoseconds() * i128::from(index))
+    }
+
+    /// Returns the deadline of the next tick to fire.
+    #[must_use]
+    pub fn next_deadline(&self) -> Epoch {
+        self.deadline(self.next_index)
+    }
+
+    /// Returns the duration from the provided epoch until the next deadline, which is negative if
+    /// that deadline has passed.
+    #[must_use]
+    pub fn time_until_next(&self, now: Epoch) -> Duration {
+        self.next_deadline() - now
+    }
+
+    /// Fires the latest tick whose deadline is at or before the provided epoch and returns its index,
+    /// counting any earlier unfired ticks as missed. Returns None if the next deadline has not been
+    /// reached yet.
+    pub fn tick(&mut self, now: Epoch) -> Option<u64> {
+        if now < self.next_deadline() {
+            return None;
+        }
+        let latest = div_euclid_i128(
+            (now - self.start).total_nanoseconds(),
+            self.period.total_nanoseconds(),
+        ) as u64;
+        self.missed += latest - self.next_index;
+        self.next_index = latest + 1;
+        Some(latest)
+    }
+
+    /// Restarts the ticker from the provided epoch, e.g. after a pause of the control loop, and
+    /// resets the missed ticks.
+    pub fn reset(&mut self, start: Epoch) {
+        self.start = start;
+        self.next_index = 0;
+        self.missed = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, Ticker, TimeUnits};
+
+    #[test]
+    fn test_ticker_drift_free() {
+        let start = Epoch::from_gregorian_tai_at_midnight(2022, 5, 20);
+        // A period which is not a round number of nanoseconds per second
+        let period = 1.seconds() / 3;
+        let mut ticker = Ticker::new(start, period);
+        assert_eq!(ticker.period(), 333_333_333.nanoseconds());
+        // Each wake up is late by a few microseconds, which does not accumulate
+        for i in 0..3_000_u64 {
+            let now = ticker.next_deadline() + 7.microseconds();
+            assert_eq!(ticker.tick(now), Some(i));
+            assert_eq!(ticker.tick(now), None);
+        }
+        assert_eq!(ticker.next_index(), 3_000);
+        assert_eq!(
+            ticker.next_deadline(),
+            start + 999_999_999_000.nanoseconds()
+        );
+        assert_eq!(ticker.missed_ticks(), 0);
+        assert_eq!(
+            ticker.time_until_next(start + 1_000.seconds()),
+            -(1.microseconds())
+        );
+    }
+
+    #[test]
+    fn test_ticker_missed() {
+        let start = Epoch::from_gregorian_tai_at_midnight(2022, 5, 20);
+        let mut ticker = Ticker::new(start, 1.seconds());
+        assert_eq!(ticker.tick(start), Some(0));
+        assert_eq!(ticker.tick(start + 10.seconds()), Some(10));
+        assert_eq!(ticker.missed_ticks(), 9);
+        assert_eq!(ticker.tick(start + 11.seconds() - 1.nanoseconds()), None);
+        assert_eq!(ticker.tick(start + 11.seconds()), Some(11));
+        assert_eq!(ticker.missed_ticks(), 9);
+
+        ticker.reset(start + 1.days());
+        assert_eq!(ticker.start(), start + 1.days());
+        assert_eq!(ticker.missed_ticks(), 0);
+        assert_eq!(ticker.tick(start + 1.days() + 1.seconds()), Some(1));
+        assert_eq!(ticker.missed_ticks(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ticker_zero_period() {
+        let _ = Ticker::new(
+            Epoch::from_gregorian_tai_at_midnight(2022, 5, 20),
+            0.seconds(),
+        );
+    }
+}
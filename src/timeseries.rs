@@ -1,4 +1,6 @@
-use super::{Duration, Epoch};
+use super::{Duration, Epoch, Interval};
+use core::convert::TryFrom;
+use core::fmt;
 /*
 
 NOTE: This is taken from itertools: https://docs.rs/itertools-num/0.1.3/src/itertools_num/linspace.rs.html#78-93 .
@@ -6,13 +8,33 @@ NOTE: This is taken from itertools: https://docs.rs/itertools-num/0.1.3/src/iter
 */
 
 /// An iterator of a sequence of evenly spaced Epochs.
+///
+/// Each epoch is computed as `start + index * step` with integer arithmetic, so no error accumulates
+/// over long series, and the index of the next epoch is available for array alignment.
+/// A series whose step is not positive is empty.
 #[derive(Clone, Debug)]
 pub struct TimeSeries {
     start: Epoch,
     end: Epoch,
     step: Duration,
-    cur: Epoch,
     incl: bool,
+    /// Index of the next epoch to return from the front
+    index: u64,
+    /// Index past the last epoch to return from the back
+    end_index: u64,
+}
+
+/// Returns the number of epochs `start + i * step` before the end (or at the end if inclusive).
+fn count_between(start: Epoch, end: Epoch, step_ns: i128, incl: bool) -> u64 {
+    let elapsed = (end - start).total_nanoseconds();
+    let count = if step_ns <= 0 || elapsed < 0 || (elapsed == 0 && !incl) {
+        0
+    } else if incl {
+        elapsed / step_ns + 1
+    } else {
+        (elapsed + step_ns - 1) / step_ns
+    };
+    u64::try_from(count).unwrap_or(u64::MAX)
 }
 
 impl TimeSeries {
@@ -32,14 +54,7 @@ impl TimeSeries {
     /// ```
     #[inline]
     pub fn exclusive(start: Epoch, end: Epoch, step: Duration) -> TimeSeries {
-        // Start one step prior to start because next() just moves forward
-        Self {
-            start,
-            end,
-            step,
-            cur: start - step,
-            incl: false,
-        }
+        Self::new(start, end, step, false)
     }
 
     /// Return an iterator of evenly spaced Epochs, inclusive on start **and** on end.
@@ -58,49 +73,359 @@ impl TimeSeries {
     /// ```
     #[inline]
     pub fn inclusive(start: Epoch, end: Epoch, step: Duration) -> TimeSeries {
-        // Start one step prior to start because next() just moves forward
+        Self::new(start, end, step, true)
+    }
+
+    /// Return an iterator of exactly `count` evenly spaced Epochs from the start, e.g. to align the
+    /// series with an array of `count` samples produced elsewhere. The series is empty if the step
+    /// is not positive.
+    /// ```
+    /// use hifitime::{Epoch, TimeSeries, TimeUnits};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+    /// let time_series = TimeSeries::from_count(start, 1.seconds() / 3, 10);
+    /// assert_eq!(time_series.len(), 10);
+    /// assert_eq!(time_series.last(), Some(start + 2_999_999_997.nanoseconds()));
+    /// ```
+    #[must_use]
+    pub fn from_count(start: Epoch, step: Duration, count: u64) -> TimeSeries {
+        if count == 0 {
+            return Self::new(start, start, step, false);
+        }
+        let end = start
+            + Duration::from_total_nanoseconds(step.total_nanoseconds() * i128::from(count - 1));
+        Self::new(start, end, step, true)
+    }
+
+    fn new(start: Epoch, end: Epoch, step: Duration, incl: bool) -> Self {
         Self {
             start,
             end,
             step,
-            cur: start - step,
-            incl: true,
+            incl,
+            index: 0,
+            end_index: count_between(start, end, step.total_nanoseconds(), incl),
+        }
+    }
+
+    /// Returns the index of the next epoch of this series, i.e. the number of epochs already
+    /// returned from the front.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSeries, TimeUnits};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+    /// let mut time_series = TimeSeries::exclusive(start, start + 1.days(), 1.seconds());
+    /// assert_eq!(time_series.nth(1000), Some(start + 1000.seconds()));
+    /// assert_eq!(time_series.index(), 1001);
+    /// assert_eq!(time_series.epoch_at(86_399), start + 86_399.seconds());
+    /// ```
+    #[must_use]
+    pub const fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Returns the epoch at the provided index of this series, i.e. `start + index * step`,
+    /// computed exactly. The index may be past the end of the series.
+    #[must_use]
+    pub fn epoch_at(&self, index: u64) -> Epoch {
+        self.start
+            + Duration::from_total_nanoseconds(self.step.total_nanoseconds() * i128::from(index))
+    }
+
+    /// Returns the index of the first epoch of this series at or after the provided epoch, which
+    /// may be past the end of the series, or `None` if the step is zero or negative, since such a
+    /// series is empty.
+    fn first_index_from(&self, epoch: Epoch) -> Option<u64> {
+        let elapsed = (epoch - self.start).total_nanoseconds();
+        let step = self.step.total_nanoseconds();
+        if step <= 0 {
+            None
+        } else if elapsed <= 0 {
+            Some(0)
+        } else {
+            // Ceiling of the division, both numbers being positive
+            Some(u64::try_from((elapsed + step - 1) / step).unwrap_or(u64::MAX))
+        }
+    }
+
+    /// Returns the first epoch of this series which is strictly after the provided epoch, if any.
+    /// This is computed arithmetically, without iterating over the series.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSeries, TimeUnits};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+    /// let time_series = TimeSeries::inclusive(start, start + 1.days(), 2.hours());
+    /// assert_eq!(time_series.first_epoch_after(start + 3.hours()), Some(start + 4.hours()));
+    /// assert_eq!(time_series.first_epoch_after(start + 4.hours()), Some(start + 6.hours()));
+    /// assert_eq!(time_series.first_epoch_after(start + 1.days()), None);
+    /// ```
+    #[must_use]
+    pub fn first_epoch_after(&self, epoch: Epoch) -> Option<Epoch> {
+        let mut index = self.first_index_from(epoch)?;
+        if self.epoch_at(index) == epoch {
+            index += 1;
+        }
+        if index
+            < count_between(
+                self.start,
+                self.end,
+                self.step.total_nanoseconds(),
+                self.incl,
+            )
+        {
+            Some(self.epoch_at(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the time series of the epochs of this series which are within the provided interval.
+    /// The resulting series may be empty, and is always empty if the step is zero or negative. This
+    /// is computed arithmetically, without iterating over the series.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Interval, TimeSeries, TimeUnits};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+    /// let time_series = TimeSeries::exclusive(start, start + 1.days(), 2.hours());
+    /// let window = Interval::new(start + 3.hours(), start + 8.hours());
+    /// let epochs: Vec<Epoch> = time_series.intersect(window).collect();
+    /// assert_eq!(epochs, vec![start + 4.hours(), start + 6.hours(), start + 8.hours()]);
+    /// ```
+    #[must_use]
+    pub fn intersect(&self, interval: Interval) -> TimeSeries {
+        let start = match self.first_index_from(interval.start()) {
+            Some(index) => self.epoch_at(index),
+            None => return Self::new(self.start, self.start, self.step, false),
+        };
+        let (end, incl) = if interval.end() < self.end {
+            (interval.end(), true)
+        } else {
+            (self.end, self.incl)
+        };
+        Self::new(start, end, self.step, incl)
+    }
+
+    /// Returns the summary of this whole series, regardless of how many epochs were already iterated.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeSeries, TimeUnits};
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// let time_series = TimeSeries::exclusive(start, start + 1.days(), 1.minutes());
+    /// let summary = time_series.summary();
+    /// assert_eq!(summary.count, 1440);
+    /// assert!(!summary.inclusive);
+    /// assert_eq!(
+    ///     format!("{}", time_series),
+    ///     "TimeSeries [2022-05-20T00:00:00 UTC : 2022-05-21T00:00:00 UTC : 1 min, 1440 steps, exclusive]"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn summary(&self) -> TimeSeriesSummary {
+        TimeSeriesSummary {
+            start: self.start,
+            end: self.end,
+            step: self.step,
+            count: count_between(
+                self.start,
+                self.end,
+                self.step.total_nanoseconds(),
+                self.incl,
+            ),
+            inclusive: self.incl,
         }
     }
 }
 
+/// The summary of a time series, cf. [`TimeSeries::summary`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimeSeriesSummary {
+    /// First epoch of the series
+    pub start: Epoch,
+    /// End epoch of the series, only part of it if the series is inclusive and it is on the grid
+    pub end: Epoch,
+    /// Duration between two consecutive epochs of the series
+    pub step: Duration,
+    /// Number of epochs in the series
+    pub count: u64,
+    /// Whether the end epoch is included in the series, if it is on the grid
+    pub inclusive: bool,
+}
+
+impl fmt::Display for TimeSeriesSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "TimeSeries [{} : {} : {}, {} steps, {}]",
+            self.start,
+            self.end,
+            self.step,
+            self.count,
+            if self.inclusive {
+                "inclusive"
+            } else {
+                "exclusive"
+            }
+        )
+    }
+}
+
+impl fmt::Display for TimeSeries {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.summary())
+    }
+}
+
 impl Iterator for TimeSeries {
     type Item = Epoch;
 
     #[inline]
     fn next(&mut self) -> Option<Epoch> {
-        let next_item = self.cur + self.step;
-        if (!self.incl && next_item >= self.end) || (self.incl && next_item > self.end) {
-            None
+        if self.index < self.end_index {
+            self.index += 1;
+            Some(self.epoch_at(self.index - 1))
         } else {
-            self.cur = next_item;
-            Some(next_item)
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end_index - self.index;
+        match usize::try_from(remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
         }
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Epoch> {
+        self.index = match u64::try_from(n) {
+            Ok(n) => self.index.saturating_add(n).min(self.end_index),
+            Err(_) => self.end_index,
+        };
+        self.next()
+    }
 }
 
 impl DoubleEndedIterator for TimeSeries {
     #[inline]
     fn next_back(&mut self) -> Option<Epoch> {
-        let next_item = self.cur - self.step;
-        if next_item < self.start {
+        if self.index < self.end_index {
+            self.end_index -= 1;
+            Some(self.epoch_at(self.end_index))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for TimeSeries {}
+
+/// An iterator of a sequence of Epochs spaced by a step of `STEP_NS` nanoseconds known at compile time.
+///
+/// This is meant for high rate series (e.g. kHz signal simulation) where the step arithmetic may
+/// be constant folded by the compiler. Each epoch is computed from the start epoch and its index,
+/// so no error accumulates over long series.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, FixedStepTimeSeries, TimeUnits};
+/// let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+/// // 1 kHz sampling
+/// let mut series = FixedStepTimeSeries::<1_000_000>::exclusive(start, start + 1.seconds());
+/// assert_eq!(series.len(), 1000);
+/// assert_eq!(series.next_back(), Some(start + 999.milliseconds()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedStepTimeSeries<const STEP_NS: u64> {
+    start: Epoch,
+    /// Index of the next epoch to return from the front
+    index: u64,
+    /// Index past the last epoch to return from the back
+    end_index: u64,
+}
+
+impl<const STEP_NS: u64> FixedStepTimeSeries<STEP_NS> {
+    /// Return an iterator of evenly spaced Epochs, **inclusive** on start and **exclusive** on end.
+    #[must_use]
+    pub fn exclusive(start: Epoch, end: Epoch) -> Self {
+        Self {
+            start,
+            index: 0,
+            end_index: count_between(start, end, i128::from(Self::step_ns()), false),
+        }
+    }
+
+    /// Return an iterator of evenly spaced Epochs, inclusive on start **and** on end.
+    #[must_use]
+    pub fn inclusive(start: Epoch, end: Epoch) -> Self {
+        Self {
+            start,
+            index: 0,
+            end_index: count_between(start, end, i128::from(Self::step_ns()), true),
+        }
+    }
+
+    /// Returns the step of this series.
+    #[must_use]
+    pub fn step() -> Duration {
+        Duration::from_total_nanoseconds(i128::from(Self::step_ns()))
+    }
+
+    const fn step_ns() -> u64 {
+        assert!(STEP_NS > 0, "the step of a time series must be positive");
+        STEP_NS
+    }
+
+    fn epoch_at(&self, index: u64) -> Epoch {
+        self.start
+            + Duration::from_total_nanoseconds(i128::from(Self::step_ns()) * i128::from(index))
+    }
+}
+
+impl<const STEP_NS: u64> Iterator for FixedStepTimeSeries<STEP_NS> {
+    type Item = Epoch;
+
+    #[inline]
+    fn next(&mut self) -> Option<Epoch> {
+        if self.index < self.end_index {
+            self.index += 1;
+            Some(self.epoch_at(self.index - 1))
+        } else {
             None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end_index - self.index;
+        match usize::try_from(remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<const STEP_NS: u64> DoubleEndedIterator for FixedStepTimeSeries<STEP_NS> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Epoch> {
+        if self.index < self.end_index {
+            self.end_index -= 1;
+            Some(self.epoch_at(self.end_index))
         } else {
-            Some(next_item)
+            None
         }
     }
 }
 
-impl ExactSizeIterator for TimeSeries where TimeSeries: Iterator {}
+impl<const STEP_NS: u64> ExactSizeIterator for FixedStepTimeSeries<STEP_NS> {}
 
 #[cfg(test)]
 mod tests {
-    use crate::{Epoch, TimeSeries, Unit};
+    use crate::{Epoch, FixedStepTimeSeries, Interval, TimeSeries, TimeUnits, Unit};
 
     #[test]
     fn test_timeseries() {
@@ -144,4 +469,212 @@ mod tests {
 
         assert_eq!(count, 7, "Should have six items in this iterator");
     }
+
+    #[test]
+    fn test_timeseries_intersect() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let end = Epoch::from_gregorian_utc_at_noon(2017, 1, 14);
+        let series = TimeSeries::exclusive(start, end, 2.hours());
+
+        // Window within the series, on and off the grid
+        let window = Interval::new(start + 2.hours(), start + 6.hours());
+        let mut within = series.intersect(window);
+        assert_eq!(within.next(), Some(start + 2.hours()));
+        assert_eq!(within.next(), Some(start + 4.hours()));
+        assert_eq!(within.next(), Some(start + 6.hours()));
+        assert_eq!(within.next(), None);
+        let window = Interval::new(start + 1.hours(), start + 5.hours());
+        assert_eq!(series.intersect(window).count(), 2);
+
+        // Window overlapping the bounds of the series
+        let window = Interval::new(start - 1.days(), start + 1.days());
+        assert_eq!(series.intersect(window).count(), 6);
+        let inclusive = TimeSeries::inclusive(start, end, 2.hours());
+        assert_eq!(inclusive.intersect(window).count(), 7);
+        let window = Interval::new(start + 11.hours(), end);
+        assert_eq!(series.intersect(window).count(), 0);
+        assert_eq!(inclusive.intersect(window).count(), 1);
+
+        // Window between two samples or outside the series
+        let window = Interval::new(start + 1.hours(), start + 90.minutes());
+        assert_eq!(series.intersect(window).count(), 0);
+        let window = Interval::new(end + 1.hours(), end + 2.hours());
+        assert_eq!(series.intersect(window).count(), 0);
+
+        // Every intersection matches the brute force filtering
+        for offset in 0..30 {
+            let window = Interval::new(
+                start + (offset * 17).minutes(),
+                start + (offset * 23 + 5).minutes(),
+            );
+            let expected = series.clone().filter(|e| window.contains(*e)).count();
+            assert_eq!(series.intersect(window).count(), expected, "{:?}", window);
+            let first = series.clone().find(|e| *e > window.start());
+            assert_eq!(series.first_epoch_after(window.start()), first);
+        }
+
+        assert_eq!(series.first_epoch_after(start - 1.days()), Some(start));
+        assert_eq!(series.first_epoch_after(start), Some(start + 2.hours()));
+        assert_eq!(series.first_epoch_after(end - 2.hours()), None);
+        assert_eq!(inclusive.first_epoch_after(end - 2.hours()), Some(end));
+
+        // Zero and negative steps make empty series
+        let window = Interval::new(start - 1.days(), end + 1.days());
+        for step in [0.hours(), -(2.hours())] {
+            for series in [
+                TimeSeries::exclusive(start, end, step),
+                TimeSeries::inclusive(start, end, step),
+                TimeSeries::inclusive(end, start, step),
+            ] {
+                assert_eq!(series.len(), 0);
+                assert_eq!(series.intersect(window).count(), 0);
+                assert_eq!(series.first_epoch_after(start - 1.days()), None);
+                assert_eq!(series.first_epoch_after(start + 1.hours()), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_timeseries_from_count() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let step = 333_333_333.nanoseconds();
+        let mut series = TimeSeries::from_count(start, step, 259_200);
+        assert_eq!(series.len(), 259_200);
+        assert_eq!(series.summary().count, 259_200);
+        assert!(series.summary().inclusive);
+        assert_eq!(series.next(), Some(start));
+        assert_eq!(
+            series.next_back(),
+            Some(start + (333_333_333 * 259_199_i64).nanoseconds())
+        );
+
+        let mut single = TimeSeries::from_count(start, 1.hours(), 1);
+        assert_eq!(single.next(), Some(start));
+        assert_eq!(single.next(), None);
+        assert_eq!(TimeSeries::from_count(start, 1.hours(), 0).len(), 0);
+        assert_eq!(TimeSeries::from_count(start, 0.hours(), 5).len(), 0);
+        assert_eq!(TimeSeries::from_count(start, -(1.hours()), 5).len(), 0);
+    }
+
+    #[test]
+    fn test_timeseries_exact() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let step = 333_333_333.nanoseconds();
+        let mut series = TimeSeries::exclusive(start, start + 1.days(), step);
+        assert_eq!(series.len(), 259_201);
+        assert_eq!(series.index(), 0);
+        assert_eq!(series.next(), Some(start));
+        assert_eq!(series.index(), 1);
+        assert_eq!(series.nth(999_998), None);
+        assert_eq!(series.index(), 259_201);
+
+        let mut series = TimeSeries::inclusive(start, start + 1.days(), step);
+        assert_eq!(
+            series.nth(1_000),
+            Some(start + 333_333_333_000.nanoseconds())
+        );
+        assert_eq!(series.index(), 1_001);
+        assert_eq!(
+            series.next_back(),
+            Some(start + (333_333_333 * 259_200_i64).nanoseconds())
+        );
+        assert_eq!(series.len(), 259_200 - 1_001);
+        assert_eq!(series.epoch_at(3), start + 999_999_999.nanoseconds());
+
+        // Iterating from both ends meets in the middle
+        let mut series = TimeSeries::inclusive(start, start + 4.hours(), 1.hours());
+        assert_eq!(series.next_back(), Some(start + 4.hours()));
+        assert_eq!(series.next(), Some(start));
+        assert_eq!(series.next_back(), Some(start + 3.hours()));
+        assert_eq!(series.next(), Some(start + 1.hours()));
+        assert_eq!(series.next_back(), Some(start + 2.hours()));
+        assert_eq!(series.next(), None);
+        assert_eq!(series.next_back(), None);
+
+        // Non positive steps and reversed bounds are empty
+        assert_eq!(
+            TimeSeries::inclusive(start, start + 1.hours(), 0.seconds()).count(),
+            0
+        );
+        assert_eq!(
+            TimeSeries::inclusive(start, start - 1.hours(), 1.seconds()).count(),
+            0
+        );
+        assert_eq!(TimeSeries::inclusive(start, start, 1.seconds()).count(), 1);
+        assert_eq!(TimeSeries::exclusive(start, start, 1.seconds()).count(), 0);
+    }
+
+    #[test]
+    fn test_timeseries_summary() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let mut series = TimeSeries::inclusive(start, start + 12.hours(), 2.hours());
+        let summary = series.summary();
+        assert_eq!(summary.start, start);
+        assert_eq!(summary.end, start + 12.hours());
+        assert_eq!(summary.step, 2.hours());
+        assert_eq!(summary.count, 7);
+        assert!(summary.inclusive);
+        // The summary is that of the whole series
+        series.next();
+        assert_eq!(series.summary(), summary);
+        #[cfg(feature = "std")]
+        assert_eq!(
+            format!("{}", series),
+            "TimeSeries [2017-01-14T00:00:00 UTC : 2017-01-14T12:00:00 UTC : 2 h, 7 steps, inclusive]"
+        );
+    }
+
+    #[test]
+    fn test_fixed_step_timeseries() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2017, 1, 14);
+        let end = Epoch::from_gregorian_utc_at_noon(2017, 1, 14);
+        const TWO_HOURS_NS: u64 = 7_200_000_000_000;
+
+        // Same epochs as the dynamic time series
+        let fixed = FixedStepTimeSeries::<TWO_HOURS_NS>::exclusive(start, end);
+        assert_eq!(fixed.len(), 6);
+        assert!(fixed.eq(TimeSeries::exclusive(start, end, 2.hours())));
+        let fixed = FixedStepTimeSeries::<TWO_HOURS_NS>::inclusive(start, end);
+        assert_eq!(fixed.len(), 7);
+        assert!(fixed.eq(TimeSeries::inclusive(start, end, 2.hours())));
+        assert_eq!(FixedStepTimeSeries::<TWO_HOURS_NS>::step(), 2.hours());
+
+        // Off grid end, empty and reversed series
+        assert_eq!(
+            FixedStepTimeSeries::<TWO_HOURS_NS>::exclusive(start, end + 1.nanoseconds()).len(),
+            7
+        );
+        assert_eq!(
+            FixedStepTimeSeries::<TWO_HOURS_NS>::inclusive(start, end - 1.nanoseconds()).len(),
+            6
+        );
+        assert_eq!(
+            FixedStepTimeSeries::<TWO_HOURS_NS>::exclusive(start, start).len(),
+            0
+        );
+        assert_eq!(
+            FixedStepTimeSeries::<TWO_HOURS_NS>::inclusive(start, start).len(),
+            1
+        );
+        assert_eq!(
+            FixedStepTimeSeries::<TWO_HOURS_NS>::inclusive(end, start).len(),
+            0
+        );
+
+        // Both ends
+        let mut fixed = FixedStepTimeSeries::<TWO_HOURS_NS>::inclusive(start, end);
+        assert_eq!(fixed.next_back(), Some(end));
+        assert_eq!(fixed.next(), Some(start));
+        assert_eq!(fixed.len(), 5);
+        assert_eq!(fixed.next_back(), Some(end - 2.hours()));
+
+        // No error accumulation at high rate: 1 MHz over one second
+        let mut fixed = FixedStepTimeSeries::<1_000>::exclusive(start, start + 1.seconds());
+        assert_eq!(fixed.len(), 1_000_000);
+        assert_eq!(fixed.nth(123_456), Some(start + 123_456.microseconds()));
+        assert_eq!(
+            fixed.next_back(),
+            Some(start + 1.seconds() - 1.microseconds())
+        );
+    }
 }
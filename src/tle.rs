@@ -0,0 +1,228 @@
+use crate::duration::{Duration, Unit};
+use crate::epoch::days_in_year;
+use crate::{Epoch, Errors, ParsingErrors};
+use core::fmt;
+
+/// Number of nanoseconds in the smallest representable TLE day fraction, 1e-8 day.
+const NANOSECONDS_PER_FRACTION: i128 = 864_000;
+/// Number of day fraction units in a day.
+const FRACTIONS_PER_DAY: u32 = 100_000_000;
+
+/// The epoch of a two-line element set (TLE), i.e. a UTC year and a day of year with eight decimals,
+/// written as the 14 character field `YYDDD.DDDDDDDD`.
+///
+/// The day fraction is stored as an integer number of 1e-8 days (864 microseconds), so the
+/// conversion to an Epoch is exact and the field written back is byte for byte what was parsed.
+/// As per the TLE convention, two digit years 57 to 99 map to 1957 to 1999, and 00 to 56 map to
+/// 2000 to 2056.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, TimeUnits, TleEpoch};
+///
+/// let tle_epoch = TleEpoch::parse("22140.74791667").unwrap();
+/// assert_eq!(tle_epoch.year(), 2022);
+/// assert_eq!(tle_epoch.day_of_year(), 140);
+/// assert_eq!(format!("{}", tle_epoch), "22140.74791667");
+/// let epoch = tle_epoch.to_epoch();
+/// assert_eq!(epoch, Epoch::from_gregorian_utc_at_midnight(2022, 5, 20) + 74_791_667 * 864.microseconds());
+/// assert_eq!(TleEpoch::from_epoch(epoch), Ok(tle_epoch));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TleEpoch {
+    year: i32,
+    day_of_year: u16,
+    fraction: u32,
+}
+
+impl TleEpoch {
+    /// Builds a TLE epoch from its full year (1957 to 2056), its day of year (starting at 1) and its
+    /// day fraction in units of 1e-8 day. Returns an error if any of these is out of range.
+    pub fn new(year: i32, day_of_year: u16, fraction: u32) -> Result<Self, Errors> {
+        let days_in_year = days_in_year(year);
+        if !(1957..=2056).contains(&year)
+            || day_of_year == 0
+            || day_of_year > days_in_year
+            || fraction >= FRACTIONS_PER_DAY
+        {
+            Err(Errors::Carry)
+        } else {
+            Ok(Self {
+                year,
+                day_of_year,
+                fraction,
+            })
+        }
+    }
+
+    /// Parses the 14 character TLE epoch field `YYDDD.DDDDDDDD`, leading and trailing spaces allowed.
+    pub fn parse(s: &str) -> Result<Self, Errors> {
+        let s = s.trim().as_bytes();
+        if s.len() != 14 || s[5] != b'.' {
+            return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+        }
+        let digits = |bytes: &[u8]| -> Result<u32, Errors> {
+            bytes.iter().try_fold(0_u32, |acc, c| {
+                if c.is_ascii_digit() {
+                    Ok(acc * 10 + u32::from(c - b'0'))
+                } else {
+                    Err(Errors::ParseError(ParsingErrors::ParseIntError))
+                }
+            })
+        };
+        let short_year = digits(&s[..2])? as i32;
+        let year = if short_year < 57 {
+            2000 + short_year
+        } else {
+            1900 + short_year
+        };
+        Self::new(year, digits(&s[2..5])? as u16, digits(&s[6..])?)
+    }
+
+    /// Builds the TLE epoch of the provided epoch, rounded to the closest 1e-8 day. Returns an error
+    /// if its UTC year is not within 1957 and 2056 (or if it rounds up out of 2056).
+    pub fn from_epoch(epoch: Epoch) -> Result<Self, Errors> {
+        let (mut year, _, _, _, _, _, _) = epoch.as_gregorian_utc();
+        let jan1 = Epoch::from_gregorian_utc_at_midnight(year, 1, 1);
+        let elapsed = (epoch.as_utc_duration() - jan1.as_utc_duration()).total_nanoseconds();
+        let units = (elapsed + NANOSECONDS_PER_FRACTION / 2) / NANOSECONDS_PER_FRACTION;
+        let mut day_of_year = (units / i128::from(FRACTIONS_PER_DAY)) as u16 + 1;
+        let fraction = (units % i128::from(FRACTIONS_PER_DAY)) as u32;
+        let days_in_year = days_in_year(year);
+        if day_of_year > days_in_year {
+            // Rounded up to the first instant of the next year
+            year += 1;
+            day_of_year = 1;
+        }
+        Self::new(year, day_of_year, fraction)
+    }
+
+    /// Returns the UTC epoch of this TLE epoch, computed exactly.
+    #[must_use]
+    pub fn to_epoch(&self) -> Epoch {
+        Epoch::from_gregorian_utc_at_midnight(self.year, 1, 1)
+            + Unit::Day * i64::from(self.day_of_year - 1)
+            + Duration::from_total_nanoseconds(i128::from(self.fraction) * NANOSECONDS_PER_FRACTION)
+    }
+
+    /// Returns the full year, from 1957 to 2056.
+    #[must_use]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the day of year, starting at 1 on January 1st.
+    #[must_use]
+    pub const fn day_of_year(&self) -> u16 {
+        self.day_of_year
+    }
+
+    /// Returns the day fraction, in units of 1e-8 day.
+    #[must_use]
+    pub const fn fraction(&self) -> u32 {
+        self.fraction
+    }
+}
+
+impl fmt::Display for TleEpoch {
+    /// Prints the 14 character TLE epoch field
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:02}{:03}.{:08}",
+            self.year % 100,
+            self.day_of_year,
+            self.fraction
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, Errors, ParsingErrors, TimeUnits, TleEpoch};
+
+    #[test]
+    fn test_tle_epoch() {
+        // ISS (ZARYA) TLE of 2008, from the Wikipedia example
+        let tle_epoch = TleEpoch::parse("08264.51782528").unwrap();
+        assert_eq!(tle_epoch.year(), 2008);
+        assert_eq!(tle_epoch.day_of_year(), 264);
+        assert_eq!(tle_epoch.fraction(), 51_782_528);
+        let epoch = tle_epoch.to_epoch();
+        assert_eq!(
+            epoch,
+            Epoch::from_gregorian_utc_at_midnight(2008, 9, 20) + 51_782_528 * 864.microseconds()
+        );
+        assert_eq!(TleEpoch::from_epoch(epoch), Ok(tle_epoch));
+        // Rounding to the closest fraction
+        assert_eq!(
+            TleEpoch::from_epoch(epoch + 431.microseconds()),
+            Ok(tle_epoch)
+        );
+        assert_eq!(
+            TleEpoch::from_epoch(epoch - 432.microseconds()),
+            Ok(tle_epoch)
+        );
+        assert_eq!(
+            TleEpoch::from_epoch(epoch + 432.microseconds()).map(|e| e.fraction()),
+            Ok(51_782_529)
+        );
+
+        // Year pivot and rounding into the next year
+        assert_eq!(TleEpoch::parse("57001.00000000").unwrap().year(), 1957);
+        assert_eq!(TleEpoch::parse(" 56366.99999999 ").unwrap().year(), 2056);
+        let new_year = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+        assert_eq!(
+            TleEpoch::from_epoch(new_year - 1.microseconds()),
+            TleEpoch::new(2023, 1, 0)
+        );
+        assert_eq!(
+            TleEpoch::from_epoch(new_year - 1.milliseconds()),
+            TleEpoch::new(2022, 365, 99_999_999)
+        );
+
+        // Out of the TLE range of years
+        for (year, month, day) in [(1956, 12, 31), (2057, 1, 1)] {
+            assert_eq!(
+                TleEpoch::from_epoch(Epoch::from_gregorian_utc_at_noon(year, month, day)),
+                Err(Errors::Carry)
+            );
+        }
+        assert_eq!(
+            TleEpoch::from_epoch(
+                Epoch::from_gregorian_utc_at_midnight(2057, 1, 1) - 1.microseconds()
+            ),
+            Err(Errors::Carry)
+        );
+
+        assert_eq!(
+            TleEpoch::parse("08264.5178252"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            TleEpoch::parse("08264,51782528"),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            TleEpoch::parse("08264.5178252a"),
+            Err(Errors::ParseError(ParsingErrors::ParseIntError))
+        );
+        assert_eq!(TleEpoch::parse("22366.00000000"), Err(Errors::Carry));
+        assert_eq!(TleEpoch::parse("22000.00000000"), Err(Errors::Carry));
+        assert!(TleEpoch::parse("20366.00000000").is_ok());
+        assert_eq!(TleEpoch::new(2057, 1, 0), Err(Errors::Carry));
+        assert_eq!(TleEpoch::new(2022, 1, 100_000_000), Err(Errors::Carry));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_tle_epoch_display() {
+        for field in ["08264.51782528", "57001.00000000", "00060.00000001"] {
+            assert_eq!(format!("{}", TleEpoch::parse(field).unwrap()), field);
+        }
+        assert_eq!(
+            format!("{}", TleEpoch::new(2000, 1, 5).unwrap()),
+            "00001.00000005"
+        );
+    }
+}
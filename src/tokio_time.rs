@@ -0,0 +1,326 @@
+//! Schedules [tokio](https://tokio.rs) timers directly from epochs and time series, so that async
+//! software can sleep until an epoch, tick at each epoch of a time series, or stream the epochs of
+//! a time series paced in real time or at a replay rate.
+//!
+//! Epochs are mapped to the monotonic [`Instant`] of tokio by comparing them to the current system
+//! time ([`Epoch::now`]) once, when the timer is created: the timers are not affected by later
+//! adjustments of the system clock. Durations are converted exactly to the nanosecond.
+
+use crate::{Duration, Epoch, Errors, TimeSeries};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+use std::time::Duration as StdDuration;
+use tokio::time::{Instant, Interval, MissedTickBehavior, Sleep};
+
+/// Converts a duration to a standard duration exactly, where negative durations become zero.
+pub(crate) fn to_std_duration(duration: Duration) -> StdDuration {
+    let nanoseconds = duration.total_nanoseconds().max(0);
+    StdDuration::new(
+        (nanoseconds / 1_000_000_000) as u64,
+        (nanoseconds % 1_000_000_000) as u32,
+    )
+}
+
+/// A reference epoch and its tokio instant, mapping epochs to instants.
+#[derive(Copy, Clone, Debug)]
+struct InstantMapping {
+    epoch: Epoch,
+    instant: Instant,
+}
+
+impl InstantMapping {
+    /// Maps the current system time to the current instant.
+    fn now() -> Result<Self, Errors> {
+        Ok(Self {
+            epoch: Epoch::now()?,
+            instant: Instant::now(),
+        })
+    }
+
+    /// Returns the instant of the provided epoch. Epochs too far before the reference epoch to be
+    /// represented map to the reference instant.
+    fn instant(&self, epoch: Epoch) -> Instant {
+        self.after(epoch - self.epoch)
+    }
+
+    /// Returns the instant at the provided duration after the reference instant.
+    fn after(&self, delta: Duration) -> Instant {
+        if delta.total_nanoseconds() < 0 {
+            self.instant
+                .checked_sub(to_std_duration(-delta))
+                .unwrap_or(self.instant)
+        } else {
+            self.instant + to_std_duration(delta)
+        }
+    }
+}
+
+/// Returns the tokio instant corresponding to the provided epoch, using the current system time as
+/// the reference. Epochs too far in the past to be represented map to the current instant.
+pub fn instant_at(epoch: Epoch) -> Result<Instant, Errors> {
+    Ok(InstantMapping::now()?.instant(epoch))
+}
+
+/// Returns a tokio sleep future which completes at the provided epoch, or immediately if that epoch
+/// is in the past.
+pub fn sleep_until(epoch: Epoch) -> Result<Sleep, Errors> {
+    Ok(tokio::time::sleep_until(instant_at(epoch)?))
+}
+
+/// A tokio interval which ticks at each epoch of a time series, and yields that epoch.
+///
+/// Ticks are scheduled from the start of the series by exact multiples of its step, so they do
+/// not drift. If a tick is missed (e.g. the task was busy), the missed ticks are yielded as soon
+/// as possible, so that every epoch of the series is yielded exactly once.
+#[derive(Debug)]
+pub struct EpochInterval {
+    series: TimeSeries,
+    interval: Interval,
+}
+
+impl EpochInterval {
+    /// Builds an interval ticking at each epoch of the provided time series.
+    pub fn new(series: TimeSeries) -> Result<Self, Errors> {
+        let summary = series.summary();
+        // Empty series with a non-positive step never tick, but tokio requires a positive period
+        let period = to_std_duration(summary.step).max(StdDuration::from_nanos(1));
+        let mut interval = tokio::time::interval_at(instant_at(summary.start)?, period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Burst);
+        Ok(Self { series, interval })
+    }
+
+    /// Returns the remaining epochs of the time series.
+    #[must_use]
+    pub fn series(&self) -> &TimeSeries {
+        &self.series
+    }
+
+    /// Polls for the next tick, which yields the next epoch of the series, or None once all of
+    /// the epochs of the series have been yielded.
+    pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Option<Epoch>> {
+        if self.series.len() == 0 {
+            return Poll::Ready(None);
+        }
+        match self.interval.poll_tick(cx) {
+            Poll::Ready(_) => Poll::Ready(self.series.next()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    /// Returns a future which completes at the next tick, cf. [`EpochInterval::poll_tick`].
+    pub fn tick(&mut self) -> Tick<'_> {
+        Tick { interval: self }
+    }
+}
+
+/// The future returned by [`EpochInterval::tick`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct Tick<'a> {
+    interval: &'a mut EpochInterval,
+}
+
+impl<'a> Future for Tick<'a> {
+    type Output = Option<Epoch>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.interval.poll_tick(cx)
+    }
+}
+
+/// A stream of the epochs of a time series, each yielded at its real time instant, or paced at a
+/// replay rate from the moment the stream is created.
+///
+/// Each deadline is computed from the start of the stream, so the pacing does not drift. Epochs
+/// whose deadline is already past are yielded immediately.
+#[derive(Debug)]
+pub struct EpochStream {
+    series: TimeSeries,
+    mapping: InstantMapping,
+    /// The replay rate, if the stream is paced at a rate rather than in real time
+    rate: Option<f64>,
+    sleep: Pin<Box<Sleep>>,
+    /// Whether the sleep is set to the deadline of the next epoch
+    armed: bool,
+}
+
+impl EpochStream {
+    /// Builds a stream yielding each epoch of the time series at that epoch, according to the
+    /// current system time.
+    pub fn new(series: TimeSeries) -> Result<Self, Errors> {
+        Ok(Self::build(series, InstantMapping::now()?, None))
+    }
+
+    /// Builds a stream yielding the first remaining epoch of the time series now, and the next ones
+    /// as if the time elapsed `rate` times as fast, e.g. every six seconds for a series of one
+    /// minute steps replayed ten times faster. A non-positive or infinite rate yields all of the
+    /// epochs immediately.
+    pub fn replayed(series: TimeSeries, rate: f64) -> Result<Self, Errors> {
+        let mut mapping = InstantMapping::now()?;
+        mapping.epoch = series.epoch_at(series.index());
+        Ok(Self::build(series, mapping, Some(rate)))
+    }
+
+    fn build(series: TimeSeries, mapping: InstantMapping, rate: Option<f64>) -> Self {
+        Self {
+            series,
+            mapping,
+            rate,
+            sleep: Box::pin(tokio::time::sleep_until(mapping.instant)),
+            armed: false,
+        }
+    }
+
+    /// Returns the remaining epochs of the time series.
+    #[must_use]
+    pub fn series(&self) -> &TimeSeries {
+        &self.series
+    }
+
+    /// Returns the instant at which the provided epoch of the series is yielded.
+    fn deadline(&self, epoch: Epoch) -> Instant {
+        match self.rate {
+            None => self.mapping.instant(epoch),
+            Some(rate) if rate > 0.0 && rate.is_finite() => self
+                .mapping
+                .after((epoch - self.mapping.epoch) * (1.0 / rate)),
+            Some(_) => self.mapping.instant,
+        }
+    }
+}
+
+impl Stream for EpochStream {
+    type Item = Epoch;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Epoch>> {
+        if self.series.len() == 0 {
+            return Poll::Ready(None);
+        }
+        if !self.armed {
+            let deadline = self.deadline(self.series.epoch_at(self.series.index()));
+            self.sleep.as_mut().reset(deadline);
+            self.armed = true;
+        }
+        match self.sleep.as_mut().poll(cx) {
+            Poll::Ready(()) => {
+                self.armed = false;
+                Poll::Ready(self.series.next())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.series.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sleep_until, to_std_duration, EpochInterval, EpochStream};
+    use crate::{Epoch, TimeSeries, TimeUnits};
+    use core::pin::Pin;
+    use futures_core::Stream;
+    use std::time::Duration as StdDuration;
+    use tokio::runtime::{Builder, Runtime};
+    use tokio::time::Instant;
+
+    fn paused_runtime() -> Runtime {
+        Builder::new_current_thread()
+            .enable_time()
+            .start_paused(true)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_to_std_duration() {
+        assert_eq!(
+            to_std_duration(1.days() + 1.nanoseconds()),
+            StdDuration::new(86_400, 1)
+        );
+        assert_eq!(to_std_duration(-(1.seconds())), StdDuration::ZERO);
+    }
+
+    #[test]
+    fn test_sleep_until() {
+        let rt = paused_runtime();
+        let _guard = rt.enter();
+        let start = Instant::now();
+        rt.block_on(sleep_until(Epoch::now().unwrap() + 1.hours()).unwrap());
+        let slept = start.elapsed();
+        // The system time elapses while the epoch is mapped to an instant
+        assert!(slept <= StdDuration::from_secs(3_600));
+        assert!(slept > StdDuration::from_secs(3_599));
+        // Past epochs complete immediately
+        let start = Instant::now();
+        rt.block_on(sleep_until(Epoch::now().unwrap() - 1.hours()).unwrap());
+        assert_eq!(start.elapsed(), StdDuration::ZERO);
+    }
+
+    #[test]
+    fn test_epoch_interval() {
+        let rt = paused_runtime();
+        let _guard = rt.enter();
+        let start = Epoch::now().unwrap() + 1.minutes();
+        let series = TimeSeries::inclusive(start, start + 2.minutes(), 1.minutes());
+        let mut interval = EpochInterval::new(series).unwrap();
+        assert_eq!(interval.series().len(), 3);
+        let t0 = Instant::now();
+        for i in 0..3_i64 {
+            assert_eq!(rt.block_on(interval.tick()), Some(start + i * 1.minutes()));
+        }
+        assert_eq!(rt.block_on(interval.tick()), None);
+        let elapsed = t0.elapsed();
+        assert!(elapsed <= StdDuration::from_secs(180) && elapsed > StdDuration::from_secs(179));
+
+        // Empty series end immediately
+        let series = TimeSeries::exclusive(start, start, 1.minutes());
+        let mut interval = EpochInterval::new(series).unwrap();
+        assert_eq!(rt.block_on(interval.tick()), None);
+    }
+
+    #[test]
+    fn test_epoch_stream() {
+        let rt = paused_runtime();
+        let _guard = rt.enter();
+        let next = |stream: &mut EpochStream| {
+            rt.block_on(std::future::poll_fn(|cx| {
+                Pin::new(&mut *stream).poll_next(cx)
+            }))
+        };
+
+        // Real time
+        let start = Epoch::now().unwrap() + 1.minutes();
+        let series = TimeSeries::inclusive(start, start + 2.minutes(), 1.minutes());
+        let mut stream = EpochStream::new(series).unwrap();
+        assert_eq!(stream.size_hint(), (3, Some(3)));
+        let t0 = Instant::now();
+        assert_eq!(next(&mut stream), Some(start));
+        assert!(t0.elapsed() > StdDuration::from_secs(59));
+        assert_eq!(next(&mut stream), Some(start + 1.minutes()));
+        assert_eq!(next(&mut stream), Some(start + 2.minutes()));
+        assert_eq!(next(&mut stream), None);
+        assert!(t0.elapsed() <= StdDuration::from_secs(180));
+
+        // Replayed ten times faster, from a past series
+        let start = Epoch::from_gregorian_utc_at_midnight(2015, 2, 7);
+        let series = TimeSeries::exclusive(start, start + 3.minutes(), 1.minutes());
+        let mut stream = EpochStream::replayed(series, 10.0).unwrap();
+        let t0 = Instant::now();
+        for i in 0..3_i64 {
+            assert_eq!(next(&mut stream), Some(start + i * 1.minutes()));
+            assert_eq!(t0.elapsed(), StdDuration::from_secs(6 * i as u64));
+        }
+        assert_eq!(next(&mut stream), None);
+
+        // Unpaced
+        let series = TimeSeries::exclusive(start, start + 3.minutes(), 1.minutes());
+        let mut stream = EpochStream::replayed(series, f64::INFINITY).unwrap();
+        let t0 = Instant::now();
+        while next(&mut stream).is_some() {}
+        assert_eq!(t0.elapsed(), StdDuration::ZERO);
+    }
+}
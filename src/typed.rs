@@ -0,0 +1,521 @@
+use crate::epoch::TAI_GPST_OFFSET_S;
+use crate::{Duration, Epoch, Errors, TimeSystem, Unit};
+use core::fmt;
+use core::ops::{Add, Deref, Sub};
+
+/// Defines a thin wrapper of an epoch which documents, and lets the compiler check, the time
+/// system in which that epoch is interpreted at API boundaries.
+macro_rules! typed_epoch {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name(pub Epoch);
+
+        impl $name {
+            /// Returns the wrapped epoch.
+            #[must_use]
+            pub const fn epoch(&self) -> Epoch {
+                self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = Epoch;
+
+            fn deref(&self) -> &Epoch {
+                &self.0
+            }
+        }
+
+        impl From<Epoch> for $name {
+            fn from(epoch: Epoch) -> Self {
+                Self(epoch)
+            }
+        }
+
+        impl From<$name> for Epoch {
+            fn from(epoch: $name) -> Self {
+                epoch.0
+            }
+        }
+
+        impl Add<Duration> for $name {
+            type Output = Self;
+
+            fn add(self, duration: Duration) -> Self {
+                Self(self.0 + duration)
+            }
+        }
+
+        impl Sub<Duration> for $name {
+            type Output = Self;
+
+            fn sub(self, duration: Duration) -> Self {
+                Self(self.0 - duration)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Duration;
+
+            fn sub(self, other: Self) -> Duration {
+                self.0 - other.0
+            }
+        }
+    };
+}
+
+/// Converts between two wrappers: both wrap the same instant, which is then interpreted in the
+/// time system of the target wrapper. These conversions cannot fail, so `TryFrom` and `TryInto`
+/// are also available through the blanket implementations of the standard library.
+macro_rules! typed_epoch_conversion {
+    ($from:ident => $($to:ident),+) => {
+        $(
+            impl From<$from> for $to {
+                fn from(epoch: $from) -> Self {
+                    Self(epoch.0)
+                }
+            }
+        )+
+    };
+}
+
+typed_epoch!(
+    /// An epoch interpreted in UTC.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Gpst, Utc};
+    ///
+    /// let utc = Utc::from_gregorian(2022, 5, 20, 17, 57, 43, 0);
+    /// assert_eq!(format!("{}", utc), "2022-05-20T17:57:43 UTC");
+    /// // An API expecting GPS time must be given an explicit conversion
+    /// let gpst = Gpst::from(utc);
+    /// assert_eq!(gpst.seconds(), utc.as_gpst_seconds());
+    /// ```
+    Utc
+);
+
+typed_epoch!(
+    /// An epoch interpreted in TAI.
+    Tai
+);
+
+typed_epoch!(
+    /// An epoch interpreted in GPS time.
+    Gpst
+);
+
+typed_epoch!(
+    /// An epoch interpreted in TT.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Tt, Utc};
+    ///
+    /// let utc = Utc::from_gregorian(2022, 5, 20, 17, 57, 43, 0);
+    /// let tt: Tt = utc.into();
+    /// assert_eq!(tt.gregorian(), (2022, 5, 20, 17, 58, 52, 184_000_015));
+    /// assert_eq!(Utc::from(tt), utc);
+    /// ```
+    Tt
+);
+
+typed_epoch_conversion!(Utc => Tai, Gpst, Tt);
+typed_epoch_conversion!(Tai => Utc, Gpst, Tt);
+typed_epoch_conversion!(Gpst => Utc, Tai, Tt);
+typed_epoch_conversion!(Tt => Utc, Tai, Gpst);
+
+impl Utc {
+    /// Builds a UTC epoch from its Gregorian date and time, cf. [`Epoch::maybe_from_gregorian_utc`].
+    pub fn maybe_from_gregorian(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Result<Self, Errors> {
+        Epoch::maybe_from_gregorian_utc(year, month, day, hour, minute, second, nanos).map(Self)
+    }
+
+    /// Builds a UTC epoch from its Gregorian date and time, cf. [`Epoch::from_gregorian_utc`].
+    #[must_use]
+    pub fn from_gregorian(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Self {
+        Self(Epoch::from_gregorian_utc(
+            year, month, day, hour, minute, second, nanos,
+        ))
+    }
+
+    /// Builds a UTC epoch from the seconds since the UTC reference epoch, cf. [`Epoch::from_utc_seconds`].
+    #[must_use]
+    pub fn from_seconds(seconds: f64) -> Self {
+        Self(Epoch::from_utc_seconds(seconds))
+    }
+
+    /// Returns the seconds since the UTC reference epoch, cf. [`Epoch::as_utc_seconds`].
+    #[must_use]
+    pub fn seconds(&self) -> f64 {
+        self.0.as_utc_seconds()
+    }
+
+    /// Returns the Gregorian date and time, cf. [`Epoch::as_gregorian_utc`].
+    #[must_use]
+    pub fn gregorian(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        self.0.as_gregorian_utc()
+    }
+}
+
+impl Tai {
+    /// Builds a TAI epoch from its Gregorian date and time, cf. [`Epoch::maybe_from_gregorian_tai`].
+    pub fn maybe_from_gregorian(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Result<Self, Errors> {
+        Epoch::maybe_from_gregorian_tai(year, month, day, hour, minute, second, nanos).map(Self)
+    }
+
+    /// Builds a TAI epoch from its Gregorian date and time, cf. [`Epoch::from_gregorian_tai`].
+    #[must_use]
+    pub fn from_gregorian(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Self {
+        Self(Epoch::from_gregorian_tai(
+            year, month, day, hour, minute, second, nanos,
+        ))
+    }
+
+    /// Builds a TAI epoch from the seconds since the TAI reference epoch, cf. [`Epoch::from_tai_seconds`].
+    #[must_use]
+    pub fn from_seconds(seconds: f64) -> Self {
+        Self(Epoch::from_tai_seconds(seconds))
+    }
+
+    /// Returns the seconds since the TAI reference epoch, cf. [`Epoch::as_tai_seconds`].
+    #[must_use]
+    pub fn seconds(&self) -> f64 {
+        self.0.as_tai_seconds()
+    }
+
+    /// Returns the Gregorian date and time, cf. [`Epoch::as_gregorian_tai`].
+    #[must_use]
+    pub fn gregorian(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        self.0.as_gregorian_tai()
+    }
+}
+
+impl Gpst {
+    /// Builds a GPS time epoch from the seconds since the GPS epoch, cf. [`Epoch::from_gpst_seconds`].
+    #[must_use]
+    pub fn from_seconds(seconds: f64) -> Self {
+        Self(Epoch::from_gpst_seconds(seconds))
+    }
+
+    /// Builds a GPS time epoch from the nanoseconds since the GPS epoch, cf. [`Epoch::from_gpst_nanoseconds`].
+    #[must_use]
+    pub fn from_nanoseconds(nanoseconds: u64) -> Self {
+        Self(Epoch::from_gpst_nanoseconds(nanoseconds))
+    }
+
+    /// Returns the seconds since the GPS epoch, cf. [`Epoch::as_gpst_seconds`].
+    #[must_use]
+    pub fn seconds(&self) -> f64 {
+        self.0.as_gpst_seconds()
+    }
+
+    /// Returns the nanoseconds since the GPS epoch, cf. [`Epoch::as_gpst_nanoseconds`].
+    pub fn nanoseconds(&self) -> Result<u64, Errors> {
+        self.0.as_gpst_nanoseconds()
+    }
+}
+
+impl Tt {
+    /// Builds a TT epoch from its Gregorian date and time, cf. [`Epoch::maybe_from_gregorian`].
+    pub fn maybe_from_gregorian(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Result<Self, Errors> {
+        Epoch::maybe_from_gregorian(
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanos,
+            TimeSystem::TT,
+        )
+        .map(Self)
+    }
+
+    /// Builds a TT epoch from its Gregorian date and time, panicking if it is invalid.
+    #[must_use]
+    pub fn from_gregorian(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Self {
+        Self::maybe_from_gregorian(year, month, day, hour, minute, second, nanos)
+            .expect("invalid Gregorian date")
+    }
+
+    /// Builds a TT epoch from the seconds since the TT reference epoch, cf. [`Epoch::from_tt_seconds`].
+    #[must_use]
+    pub fn from_seconds(seconds: f64) -> Self {
+        Self(Epoch::from_tt_seconds(seconds))
+    }
+
+    /// Returns the seconds since the TT reference epoch, cf. [`Epoch::as_tt_seconds`].
+    #[must_use]
+    pub fn seconds(&self) -> f64 {
+        self.0.as_tt_seconds()
+    }
+
+    /// Returns the Gregorian date and time, cf. [`Epoch::as_gregorian`].
+    #[must_use]
+    pub fn gregorian(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        self.0.as_gregorian(TimeSystem::TT)
+    }
+}
+
+/// An epoch together with the reference epoch from which it is counted, e.g. the start of a
+/// mission, which converts into the duration since that reference. This lets APIs which store time
+/// as a duration since a reference accept `impl Into<Duration>`.
+///
+/// # Example
+/// ```
+/// use hifitime::{Duration, Epoch, EpochRef, TimeUnits};
+///
+/// fn store_met(met: impl Into<Duration>) -> Duration {
+///     met.into()
+/// }
+///
+/// let mission_start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let epoch = mission_start + 90.minutes();
+/// assert_eq!(store_met(epoch.relative_to(mission_start)), 90.minutes());
+/// assert_eq!(EpochRef::new(epoch, mission_start).epoch(), epoch);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EpochRef {
+    epoch: Epoch,
+    reference: Epoch,
+}
+
+impl EpochRef {
+    /// Builds the provided epoch counted from the provided reference epoch, cf. [`Epoch::relative_to`].
+    #[must_use]
+    pub const fn new(epoch: Epoch, reference: Epoch) -> Self {
+        Self { epoch, reference }
+    }
+
+    /// Returns the epoch itself.
+    #[must_use]
+    pub const fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Returns the reference epoch from which this epoch is counted.
+    #[must_use]
+    pub const fn reference(&self) -> Epoch {
+        self.reference
+    }
+
+    /// Returns the duration elapsed since the reference epoch, cf. [`Epoch::since`].
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.epoch.since(self.reference)
+    }
+}
+
+impl From<EpochRef> for Duration {
+    fn from(epoch: EpochRef) -> Self {
+        epoch.duration()
+    }
+}
+
+impl From<EpochRef> for Epoch {
+    fn from(epoch: EpochRef) -> Self {
+        epoch.epoch
+    }
+}
+
+impl AsRef<Epoch> for EpochRef {
+    fn as_ref(&self) -> &Epoch {
+        &self.epoch
+    }
+}
+
+impl fmt::Display for Utc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for Tai {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for Tt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for Gpst {
+    /// Prints the Gregorian date and time in GPS time, i.e. TAI - 19 seconds
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (y, mm, dd, hh, min, s, nanos) =
+            (self.0 - Unit::Second * TAI_GPST_OFFSET_S).as_gregorian_tai();
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            y, mm, dd, hh, min, s
+        )?;
+        if nanos != 0 {
+            write!(f, ".{:09}", nanos)?;
+        }
+        write!(f, " GPST")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Duration, Epoch, EpochRef, Errors, Gpst, Tai, TimeUnits, Tt, Utc};
+
+    #[test]
+    fn test_typed_epochs() {
+        let epoch = Epoch::from_gregorian_utc(2017, 1, 14, 0, 31, 55, 0);
+        let utc = Utc::from_gregorian(2017, 1, 14, 0, 31, 55, 0);
+        assert_eq!(utc.epoch(), epoch);
+        assert_eq!(Epoch::from(utc), epoch);
+        assert_eq!(Utc::from(epoch), utc);
+        assert_eq!(utc.gregorian(), (2017, 1, 14, 0, 31, 55, 0));
+        // Deref to the epoch
+        assert_eq!(utc.as_tai_seconds(), epoch.as_tai_seconds());
+        assert_eq!(Utc::from_seconds(utc.seconds()), utc);
+
+        // Conversions keep the instant, but interpret it in another time system
+        let tai = Tai::from(utc);
+        assert_eq!(tai.epoch(), epoch);
+        assert_eq!(tai.gregorian(), (2017, 1, 14, 0, 32, 32, 0));
+        assert_eq!(tai, Tai::from_gregorian(2017, 1, 14, 0, 32, 32, 0));
+        assert_eq!(Tai::from_seconds(tai.seconds()), tai);
+        let gpst = Gpst::from(tai);
+        assert_eq!(gpst.seconds(), tai.seconds() - 2_524_953_619.0);
+        assert_eq!(Gpst::from_nanoseconds(gpst.nanoseconds().unwrap()), gpst);
+        assert_eq!(Utc::from(gpst), utc);
+        assert_eq!(Tai::from(gpst), tai);
+        assert_eq!(Gpst::from(utc), gpst);
+        assert_eq!(Utc::from(tai), utc);
+        assert_eq!(
+            Gpst::from_seconds(0.0).epoch(),
+            Epoch::from_gpst_seconds(0.0)
+        );
+        let tt: Tt = utc.into();
+        assert_eq!(tt.epoch(), epoch);
+        assert_eq!(tt.seconds(), tai.seconds() + 32.184);
+        assert_eq!(tt.gregorian().5, 4);
+        assert!(
+            (Tt::from_gregorian(2017, 1, 14, 0, 33, 4, 184_000_000) - tt).abs() < 1.microseconds()
+        );
+        assert_eq!(Tt::from_seconds(tt.seconds()), tt);
+        assert_eq!(Tt::from(tai), tt);
+        assert_eq!(Tt::from(gpst), tt);
+        assert_eq!(Tai::from(tt), tai);
+        assert_eq!(Gpst::from(tt), gpst);
+
+        // Arithmetic keeps the time system
+        let later: Utc = utc + 1.hours();
+        assert_eq!(later - utc, 1.hours());
+        assert_eq!(later - 1.hours(), utc);
+        assert!(later > utc);
+
+        assert_eq!(
+            Utc::maybe_from_gregorian(2017, 1, 14, 0, 31, 60, 0),
+            Err(Errors::Carry)
+        );
+        assert_eq!(
+            Tai::maybe_from_gregorian(2017, 1, 14, 0, 32, 32, 0),
+            Ok(tai)
+        );
+        assert_eq!(
+            Tt::maybe_from_gregorian(2017, 2, 29, 0, 0, 0, 0),
+            Err(Errors::Carry)
+        );
+    }
+
+    #[test]
+    fn test_epoch_ref() {
+        let start = Epoch::from_gregorian_tai_at_midnight(2022, 5, 20);
+        let epoch = start + 1.days() + 3.5.seconds();
+        assert_eq!(epoch.since(start), 1.days() + 3.5.seconds());
+        assert_eq!(start.since(epoch), -(1.days() + 3.5.seconds()));
+        assert_eq!(epoch.since(epoch), Duration::from_parts(0, 0));
+
+        let rel = epoch.relative_to(start);
+        assert_eq!(rel, EpochRef::new(epoch, start));
+        assert_eq!(rel.epoch(), epoch);
+        assert_eq!(rel.reference(), start);
+        assert_eq!(rel.as_ref(), &epoch);
+        let met: Duration = rel.into();
+        assert_eq!(met, 1.days() + 3.5.seconds());
+        assert_eq!(Epoch::from(rel), epoch);
+        assert_eq!(start + Duration::from(rel), epoch);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_typed_epochs_display() {
+        let utc = Utc::from_gregorian(2022, 5, 20, 17, 57, 43, 0);
+        assert_eq!(format!("{}", utc), "2022-05-20T17:57:43 UTC");
+        assert_eq!(format!("{}", Tai::from(utc)), "2022-05-20T17:58:20 TAI");
+        assert_eq!(
+            format!("{}", Tt::from(utc)),
+            "2022-05-20T17:58:52.184000015 TT"
+        );
+        assert_eq!(format!("{}", Gpst::from(utc)), "2022-05-20T17:58:01 GPST");
+        assert_eq!(
+            format!("{}", Gpst::from(utc + Duration::from_parts(0, 250_000_000))),
+            "2022-05-20T17:58:01.250000000 GPST"
+        );
+        assert_eq!(
+            format!("{}", Gpst::from(utc + Duration::from_parts(0, 62_500_000))),
+            "2022-05-20T17:58:01.062500000 GPST"
+        );
+        assert_eq!(
+            format!("{}", Gpst::from_seconds(0.0)),
+            "1980-01-06T00:00:00 GPST"
+        );
+    }
+}
@@ -0,0 +1,383 @@
+use crate::duration::{Duration, Unit};
+use crate::{gps_utc_offset_at, tai_utc_offset_at, Epoch, TimeSeries, J1900_OFFSET_DURATION};
+#[cfg(feature = "std")]
+use crate::{Errors, ParsingErrors};
+
+/// A provider of the Earth Orientation Parameter `DUT1 = UT1 - UTC`, as published by the IERS in
+/// Bulletins A and B, or in the `finals2000A` files.
+///
+/// Implementors return DUT1 at the provided epoch. This trait is implemented for any
+/// `Fn(Epoch) -> Duration`, e.g. a constant DUT1, and for [`Dut1Table`].
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, TimeUnits};
+///
+/// let dut1 = |_| -(123.milliseconds());
+/// let epoch = Epoch::from_gregorian_utc_at_noon(2022, 5, 20);
+/// assert_eq!(epoch.as_ut1_duration(&dut1), epoch.as_utc_duration() - 123.milliseconds());
+/// assert_eq!(Epoch::from_ut1_duration(epoch.as_ut1_duration(&dut1), &dut1), epoch);
+/// ```
+pub trait Ut1Provider {
+    /// Returns `DUT1 = UT1 - UTC` at the provided epoch.
+    fn ut1_utc(&self, epoch: Epoch) -> Duration;
+}
+
+impl<F: Fn(Epoch) -> Duration> Ut1Provider for F {
+    fn ut1_utc(&self, epoch: Epoch) -> Duration {
+        self(epoch)
+    }
+}
+
+/// A table of DUT1 values, e.g. the daily values of the IERS `finals2000A` files loaded with
+/// [`parse_iers_finals2000a`], held constant before the first entry and after the last one.
+///
+/// The entries must be sorted by increasing epoch. DUT1 jumps by one second at each leap second, so
+/// the table interpolates `UT1 - TAI` linearly between its entries, in integer nanoseconds, and
+/// returns it as DUT1 at the requested instant.
+///
+/// # Example
+/// ```
+/// use hifitime::{Dut1Table, Epoch, TimeUnits, Ut1Provider};
+///
+/// let entries = [
+///     (Epoch::from_mjd_utc(59_719.0), -(100.milliseconds())),
+///     (Epoch::from_mjd_utc(59_720.0), -(102.milliseconds())),
+/// ];
+/// let table = Dut1Table::new(&entries);
+/// assert_eq!(table.ut1_utc(Epoch::from_mjd_utc(59_719.5)), -(101.milliseconds()));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Dut1Table<'a> {
+    entries: &'a [(Epoch, Duration)],
+}
+
+impl<'a> Dut1Table<'a> {
+    /// Builds a new DUT1 table from entries sorted by increasing epoch.
+    #[must_use]
+    pub const fn new(entries: &'a [(Epoch, Duration)]) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the epochs and DUT1 values of this table, sorted by increasing epoch.
+    #[must_use]
+    pub const fn entries(&self) -> &'a [(Epoch, Duration)] {
+        self.entries
+    }
+}
+
+impl<'a> Ut1Provider for Dut1Table<'a> {
+    fn ut1_utc(&self, epoch: Epoch) -> Duration {
+        let idx = self.entries.partition_point(|(e, _)| *e <= epoch);
+        if idx == 0 {
+            self.entries.first().map_or(
+                Duration {
+                    centuries: 0,
+                    nanoseconds: 0,
+                },
+                |(_, dut1)| *dut1,
+            )
+        } else if idx == self.entries.len() {
+            self.entries[idx - 1].1
+        } else {
+            let (e0, dut0) = self.entries[idx - 1];
+            let (e1, dut1) = self.entries[idx];
+            // UT1 - TAI is continuous across leap seconds, unlike DUT1
+            let ut1_tai0 = dut0 - tai_utc_offset_at(e0);
+            let ut1_tai1 = dut1 - tai_utc_offset_at(e1);
+            let span = (e1 - e0).total_nanoseconds();
+            let elapsed = (epoch - e0).total_nanoseconds();
+            let delta = (ut1_tai1 - ut1_tai0).total_nanoseconds();
+            ut1_tai0
+                + Duration::from_total_nanoseconds(delta * elapsed / span)
+                + tai_utc_offset_at(epoch)
+        }
+    }
+}
+
+/// Parses the daily DUT1 values of an IERS `finals2000A` file (e.g. `finals2000A.all` or
+/// `finals2000A.daily`), in the format described in its `readme.finals2000A`.
+///
+/// The epoch of each line is its MJD in UTC, in columns 8 to 15, and its DUT1 is the Bulletin A
+/// `UT1-UTC` value in seconds, in columns 59 to 68. Lines without a DUT1 value, e.g. beyond the
+/// predictions, are skipped. Returns `Errors::ParseError(ParsingErrors::UnknownFormat)` if a line
+/// cannot be parsed.
+///
+/// # Example
+/// ```
+/// use hifitime::{parse_iers_finals2000a, Dut1Table, Epoch, TimeUnits, Ut1Provider};
+///
+/// let finals = "22 520 59719.00 I  0.030283 0.000091  0.422197 0.000091  I-0.0597436 0.0000074  0.5087 0.0072";
+/// let entries = parse_iers_finals2000a(finals).unwrap();
+/// assert_eq!(entries, vec![(Epoch::from_mjd_utc(59_719.0), -(59_743_600.nanoseconds()))]);
+/// assert_eq!(
+///     Dut1Table::new(&entries).ut1_utc(Epoch::from_mjd_utc(59_719.0)),
+///     -(59_743_600.nanoseconds())
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub fn parse_iers_finals2000a(contents: &str) -> Result<Vec<(Epoch, Duration)>, Errors> {
+    let mut entries = Vec::new();
+    for line in contents.lines() {
+        let dut1 = match line.get(58..68).map(str::trim) {
+            Some(dut1) if !dut1.is_empty() => dut1,
+            _ => continue,
+        };
+        let mjd = line
+            .get(7..15)
+            .and_then(|mjd| mjd.trim().parse::<f64>().ok())
+            .ok_or(Errors::ParseError(ParsingErrors::UnknownFormat))?;
+        let dut1 = dut1
+            .parse::<f64>()
+            .map_err(|_| Errors::ParseError(ParsingErrors::UnknownFormat))?;
+        entries.push((Epoch::from_mjd_utc(mjd), dut1 * Unit::Second));
+    }
+    Ok(entries)
+}
+
+impl Epoch {
+    /// Returns the duration past 1900 January 01 at midnight in UT1, i.e. the UTC duration past
+    /// TAI epoch plus DUT1 as returned by the provider at this epoch.
+    #[must_use]
+    pub fn as_ut1_duration<P: Ut1Provider>(&self, provider: &P) -> Duration {
+        self.as_tai_duration() - tai_utc_offset_at(*self) + provider.ut1_utc(*self)
+    }
+
+    /// Returns seconds past 1900 January 01 at midnight in UT1.
+    #[must_use]
+    pub fn as_ut1_seconds<P: Ut1Provider>(&self, provider: &P) -> f64 {
+        self.as_ut1_duration(provider).in_seconds()
+    }
+
+    /// Returns days past 1900 January 01 at midnight in UT1.
+    #[must_use]
+    pub fn as_ut1_days<P: Ut1Provider>(&self, provider: &P) -> f64 {
+        self.as_ut1_duration(provider).in_unit(Unit::Day)
+    }
+
+    /// Returns the Modified Julian Date in days UT1.
+    #[must_use]
+    pub fn as_mjd_ut1_days<P: Ut1Provider>(&self, provider: &P) -> f64 {
+        (self.as_ut1_duration(provider) + J1900_OFFSET_DURATION).in_unit(Unit::Day)
+    }
+
+    /// Initialize an Epoch from the provided duration past 1900 January 01 at midnight in UT1.
+    ///
+    /// DUT1 is first evaluated at the UT1 duration taken as a TAI duration, and then again at the
+    /// resulting epoch until it converges, which is quick since DUT1 only varies by milliseconds
+    /// per day.
+    #[must_use]
+    pub fn from_ut1_duration<P: Ut1Provider>(duration: Duration, provider: &P) -> Self {
+        let mut epoch = Self::from_tai_duration(duration);
+        for _ in 0..4 {
+            let ut1_tai = provider.ut1_utc(epoch) - tai_utc_offset_at(epoch);
+            let next = Self::from_tai_duration(duration - ut1_tai);
+            if next == epoch {
+                break;
+            }
+            epoch = next;
+        }
+        epoch
+    }
+
+    /// Initialize an Epoch from the provided seconds past 1900 January 01 at midnight in UT1.
+    #[must_use]
+    pub fn from_ut1_seconds<P: Ut1Provider>(seconds: f64, provider: &P) -> Self {
+        Self::from_ut1_duration(seconds * Unit::Second, provider)
+    }
+
+    /// Initialize an Epoch from the provided Modified Julian Date in days UT1.
+    #[must_use]
+    pub fn from_mjd_ut1<P: Ut1Provider>(days: f64, provider: &P) -> Self {
+        Self::from_ut1_duration(days * Unit::Day - J1900_OFFSET_DURATION, provider)
+    }
+}
+
+/// The offsets between the main time scales at an epoch, cf. [`time_scale_offsets`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimeScaleOffsets {
+    /// Epoch of these offsets
+    pub epoch: Epoch,
+    /// TAI - UTC, from the active leap second table
+    pub tai_utc: Duration,
+    /// TT - UT1, i.e. ΔT, estimated from the DUT1 of the provider
+    pub tt_ut1: Duration,
+    /// GPS - UTC, from the active leap second table
+    pub gps_utc: Duration,
+}
+
+/// Returns the offsets between TAI, UTC, TT, UT1 and GPS time at each epoch of the provided time
+/// series, combining the active leap second table, the DUT1 of the provider and the constant
+/// offsets of TT and GPS time from TAI, e.g. to plot them or to publish them on a dashboard.
+///
+/// # Example
+/// ```
+/// use hifitime::{time_scale_offsets, Epoch, TimeSeries, TimeUnits};
+///
+/// let dut1 = |_| -(100.milliseconds());
+/// let start = Epoch::from_gregorian_utc_at_midnight(2016, 12, 31);
+/// let series = TimeSeries::inclusive(start, start + 2.days(), 1.days());
+/// let offsets: Vec<_> = time_scale_offsets(series, &dut1).collect();
+/// assert_eq!(offsets.len(), 3);
+/// assert_eq!(offsets[0].tai_utc, 36.seconds());
+/// assert_eq!(offsets[2].tai_utc, 37.seconds());
+/// assert_eq!(offsets[2].gps_utc, 18.seconds());
+/// // TT - UT1 = 32.184 s + (TAI - UTC) - DUT1
+/// assert_eq!(offsets[2].tt_ut1, 69.seconds() + 284.milliseconds());
+/// ```
+pub fn time_scale_offsets<P: Ut1Provider>(
+    series: TimeSeries,
+    provider: &P,
+) -> impl Iterator<Item = TimeScaleOffsets> + '_ {
+    series.map(move |epoch| TimeScaleOffsets {
+        epoch,
+        tai_utc: tai_utc_offset_at(epoch),
+        tt_ut1: epoch.as_tt_duration() - epoch.as_ut1_duration(provider),
+        gps_utc: gps_utc_offset_at(epoch),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Duration, Dut1Table, Epoch, TimeUnits, Ut1Provider};
+
+    #[test]
+    fn test_dut1_table() {
+        // DUT1 jumps from about -0.4 s to 0.6 s at the leap second of 2016 December 31
+        let entries = [
+            (Epoch::from_mjd_utc(57_753.0), -(408_300.microseconds())),
+            (Epoch::from_mjd_utc(57_754.0), 590_700.microseconds()),
+            (Epoch::from_mjd_utc(57_755.0), 589_600.microseconds()),
+        ];
+        let table = Dut1Table::new(&entries);
+        assert_eq!(table.entries().len(), 3);
+        assert_eq!(
+            table.ut1_utc(Epoch::from_mjd_utc(57_700.0)),
+            -(408_300.microseconds())
+        );
+        // The last day of 2016 lasted 86401 seconds, so its noon is slightly before the midpoint
+        assert_eq!(
+            table.ut1_utc(Epoch::from_mjd_utc(57_753.0) + 12.hours()),
+            -(408_799_994.nanoseconds())
+        );
+        assert_eq!(
+            table.ut1_utc(Epoch::from_mjd_utc(57_754.0)),
+            590_700.microseconds()
+        );
+        assert_eq!(
+            table.ut1_utc(Epoch::from_mjd_utc(57_754.0) + 12.hours()),
+            590_150.microseconds()
+        );
+        assert_eq!(
+            table.ut1_utc(Epoch::from_mjd_utc(57_800.0)),
+            589_600.microseconds()
+        );
+        assert_eq!(
+            Dut1Table::new(&[]).ut1_utc(Epoch::from_mjd_utc(57_754.0)),
+            Duration::from_parts(0, 0)
+        );
+
+        // UT1 is continuous across the leap second
+        let before = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 0);
+        let after = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+        assert_eq!(after - before, 2.seconds());
+        let ut1_elapsed = after.as_ut1_duration(&table) - before.as_ut1_duration(&table);
+        assert!((ut1_elapsed - 2.seconds()).abs() < 1.microseconds());
+
+        for epoch in [
+            before,
+            after,
+            after - 500.milliseconds(),
+            Epoch::from_mjd_utc(57_753.0) + 6.hours(),
+        ] {
+            // UT1 runs slightly slower than TAI, so some UT1 nanoseconds match two TAI nanoseconds
+            let round_trip = Epoch::from_ut1_duration(epoch.as_ut1_duration(&table), &table);
+            assert!((round_trip - epoch).abs() <= 1.nanoseconds());
+        }
+        let epoch = Epoch::from_mjd_utc(57_754.0) + 12.hours();
+        assert_eq!(
+            epoch.as_mjd_ut1_days(&table),
+            57_754.5 + 0.590_15 / 86_400.0
+        );
+        assert!(
+            (Epoch::from_mjd_ut1(57_754.5 + 0.590_15 / 86_400.0, &table) - epoch).abs()
+                < 1.microseconds()
+        );
+        assert!(
+            (Epoch::from_ut1_seconds(epoch.as_ut1_seconds(&table), &table) - epoch).abs()
+                < 1.microseconds()
+        );
+        assert!((epoch.as_ut1_days(&table) - epoch.as_utc_days()).abs() < 1e-5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_iers_finals2000a() {
+        use crate::{parse_iers_finals2000a, Errors, ParsingErrors};
+
+        let finals = "161231 57753.00 I  0.120733 0.009786  0.136966 0.015902  I-0.4083000 0.0002710  0.0000 0.1916
+17 1 1 57754.00 I  0.120733 0.009786  0.136966 0.015902  I 0.5907000 0.0002710  0.0000 0.1916
+17 1 2 57755.00 I  0.120733 0.009786  0.136966 0.015902  I 0.5896000 0.0002710  0.0000 0.1916
+17 1 3 57756.00
+";
+        let entries = parse_iers_finals2000a(finals).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                (Epoch::from_mjd_utc(57_753.0), -(408_300.microseconds())),
+                (Epoch::from_mjd_utc(57_754.0), 590_700.microseconds()),
+                (Epoch::from_mjd_utc(57_755.0), 589_600.microseconds()),
+            ]
+        );
+        assert_eq!(
+            Dut1Table::new(&entries).ut1_utc(Epoch::from_mjd_utc(57_754.0) + 12.hours()),
+            590_150.microseconds()
+        );
+
+        assert_eq!(
+            parse_iers_finals2000a(
+                "17 1 2 5775x.00 I  0.120733 0.009786  0.136966 0.015902  I 0.5896000 0.0002710"
+            ),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+        assert_eq!(
+            parse_iers_finals2000a(
+                "17 1 2 57755.00 I  0.120733 0.009786  0.136966 0.015902  I 0.58x6000 0.0002710"
+            ),
+            Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_time_scale_offsets() {
+        use crate::{time_scale_offsets, TimeSeries};
+
+        let entries = [
+            (Epoch::from_mjd_utc(57_753.0), -(408_300.microseconds())),
+            (Epoch::from_mjd_utc(57_754.0), 590_700.microseconds()),
+        ];
+        let table = Dut1Table::new(&entries);
+        let start = Epoch::from_mjd_utc(57_753.0);
+        let series = TimeSeries::inclusive(
+            start,
+            start + 1.days() + 1.seconds(),
+            6.hours() + 250.milliseconds(),
+        );
+        let offsets: Vec<_> = time_scale_offsets(series.clone(), &table).collect();
+        assert_eq!(offsets.len(), 5);
+        for (offset, epoch) in offsets.iter().zip(series) {
+            assert_eq!(offset.epoch, epoch);
+            assert_eq!(offset.gps_utc, offset.tai_utc - 19.seconds());
+            assert_eq!(
+                offset.tt_ut1,
+                32_184.milliseconds() + offset.tai_utc - table.ut1_utc(epoch)
+            );
+        }
+        assert_eq!(offsets[0].tai_utc, 36.seconds());
+        assert_eq!(offsets[0].tt_ut1, 68_592_300.microseconds());
+        // ΔT is continuous across the leap second, unlike TAI - UTC
+        assert_eq!(offsets[4].tai_utc, 37.seconds());
+        assert_eq!(offsets[4].tt_ut1, 68_593_300.microseconds());
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{Duration, Epoch, Interval, Unit};
+use core::iter::FromIterator;
+
+/// A set of time windows, e.g. the visibility passes of a spacecraft over a ground station, stored
+/// as sorted and disjoint intervals: overlapping or touching intervals are merged on construction.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, Interval, TimeUnits, Windows};
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+/// let passes = Windows::new(vec![
+///     Interval::new(start + 2.hours(), start + 3.hours()),
+///     Interval::new(start, start + 1.hours()),
+///     Interval::new(start + 30.minutes(), start + 90.minutes()),
+/// ]);
+/// assert_eq!(
+///     passes.intervals(),
+///     &[
+///         Interval::new(start, start + 90.minutes()),
+///         Interval::new(start + 2.hours(), start + 3.hours())
+///     ]
+/// );
+/// assert!(passes.contains(start + 1.hours()));
+/// assert!(!passes.contains(start + 100.minutes()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Windows {
+    intervals: Vec<Interval>,
+}
+
+impl Windows {
+    /// Builds a set of windows from the provided intervals, in any order.
+    #[must_use]
+    pub fn new(mut intervals: Vec<Interval>) -> Self {
+        intervals.sort_by_key(|interval| interval.start());
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start() <= last.end() => {
+                    if interval.end() > last.end() {
+                        *last = Interval::new(last.start(), interval.end());
+                    }
+                }
+                _ => merged.push(interval),
+            }
+        }
+        Self { intervals: merged }
+    }
+
+    /// Returns the disjoint intervals of these windows, sorted chronologically.
+    #[must_use]
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Returns the number of disjoint windows, after merging.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns true if there are no windows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the total duration of these windows, e.g. the total contact time. Clip the windows to
+    /// a period first to get the total within that period, cf. [`Interval::clip`].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Interval, TimeUnits, Windows};
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// let passes = Windows::new(vec![
+    ///     Interval::new(start - 1.hours(), start + 1.hours()),
+    ///     Interval::new(start + 30.minutes(), start + 90.minutes()),
+    ///     Interval::new(start + 12.hours(), start + 13.hours()),
+    /// ]);
+    /// assert_eq!(passes.total_duration(), 3.hours() + 30.minutes());
+    ///
+    /// let day = Interval::new(start, start + 1.days());
+    /// let on_day: Windows = passes.intervals().iter().map(|pass| pass.clip(&day)).collect();
+    /// assert_eq!(on_day.total_duration(), 2.hours() + 30.minutes());
+    /// ```
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        self.intervals
+            .iter()
+            .fold(Unit::Second * 0, |total, interval| {
+                total + interval.duration()
+            })
+    }
+
+    /// Returns true if the provided epoch is within any of these windows, bounds included.
+    #[must_use]
+    pub fn contains(&self, epoch: Epoch) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.end() < epoch);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains(epoch))
+    }
+
+    /// Returns the report of the conflicts between these windows and the other ones, i.e. of all
+    /// their overlaps. Windows which only share a bound, e.g. back to back passes, do not conflict.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Interval, TimeUnits, Windows};
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+    /// let station_a = Windows::new(vec![
+    ///     Interval::new(start, start + 1.hours()),
+    ///     Interval::new(start + 2.hours(), start + 3.hours()),
+    /// ]);
+    /// let station_b = Windows::new(vec![Interval::new(start + 50.minutes(), start + 2.hours() + 5.minutes())]);
+    ///
+    /// let conflicts = station_a.conflicts_with(&station_b);
+    /// assert!(conflicts.has_conflicts());
+    /// assert_eq!(conflicts.len(), 2);
+    /// assert_eq!(conflicts.total_duration(), 15.minutes());
+    /// let first = conflicts.overlaps()[0];
+    /// assert_eq!(first.interval(), Interval::new(start + 50.minutes(), start + 1.hours()));
+    /// assert_eq!((first.index(), first.other_index()), (0, 0));
+    /// assert_eq!(first.duration(), 10.minutes());
+    /// ```
+    #[must_use]
+    pub fn conflicts_with(&self, other: &Self) -> WindowConflicts {
+        let mut overlaps = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (mine, theirs) = (&self.intervals[i], &other.intervals[j]);
+            if let Some(interval) = mine.intersection(theirs) {
+                if interval.duration() > Unit::Second * 0 {
+                    overlaps.push(WindowOverlap {
+                        interval,
+                        index: i,
+                        other_index: j,
+                    });
+                }
+            }
+            // Move on from the window which ends first, as it cannot overlap any later window
+            if mine.end() < theirs.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        WindowConflicts { overlaps }
+    }
+}
+
+impl FromIterator<Interval> for Windows {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// An overlap between two sets of windows, cf. [`Windows::conflicts_with`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WindowOverlap {
+    interval: Interval,
+    index: usize,
+    other_index: usize,
+}
+
+impl WindowOverlap {
+    /// Returns the overlapping segment.
+    #[must_use]
+    pub const fn interval(&self) -> Interval {
+        self.interval
+    }
+
+    /// Returns the index of the conflicting window in the windows which generated the report.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the index of the conflicting window in the other windows.
+    #[must_use]
+    pub const fn other_index(&self) -> usize {
+        self.other_index
+    }
+
+    /// Returns the duration of the overlap.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.interval.duration()
+    }
+}
+
+/// The report of the overlaps between two sets of windows, sorted chronologically, cf.
+/// [`Windows::conflicts_with`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WindowConflicts {
+    overlaps: Vec<WindowOverlap>,
+}
+
+impl WindowConflicts {
+    /// Returns true if any window overlaps another one.
+    #[must_use]
+    pub fn has_conflicts(&self) -> bool {
+        !self.overlaps.is_empty()
+    }
+
+    /// Returns the overlaps, sorted chronologically.
+    #[must_use]
+    pub fn overlaps(&self) -> &[WindowOverlap] {
+        &self.overlaps
+    }
+
+    /// Returns the number of overlaps.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.overlaps.len()
+    }
+
+    /// Returns true if there are no overlaps, i.e. no conflicts.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.overlaps.is_empty()
+    }
+
+    /// Returns the total duration of the overlaps.
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        self.overlaps
+            .iter()
+            .fold(Unit::Second * 0, |total, overlap| {
+                total + overlap.duration()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Epoch, Interval, TimeUnits, Windows};
+
+    #[test]
+    fn test_windows() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+        let windows: Windows = [
+            Interval::new(start + 5.hours(), start + 6.hours()),
+            Interval::new(start, start + 1.hours()),
+            // Touching and contained windows are merged
+            Interval::new(start + 1.hours(), start + 2.hours()),
+            Interval::new(start + 10.minutes(), start + 20.minutes()),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(
+            windows.intervals(),
+            &[
+                Interval::new(start, start + 2.hours()),
+                Interval::new(start + 5.hours(), start + 6.hours())
+            ]
+        );
+        assert!(windows.contains(start));
+        assert!(windows.contains(start + 2.hours()));
+        assert!(!windows.contains(start + 3.hours()));
+        assert!(windows.contains(start + 6.hours()));
+        assert!(!windows.contains(start + 6.hours() + 1.nanoseconds()));
+        assert!(!windows.contains(start - 1.nanoseconds()));
+        assert!(Windows::default().is_empty());
+        assert!(!Windows::default().contains(start));
+        // Merged windows are only counted once
+        assert_eq!(windows.total_duration(), 3.hours());
+        assert_eq!(Windows::default().total_duration(), 0.hours());
+    }
+
+    #[test]
+    fn test_window_conflicts() {
+        let start = Epoch::from_gregorian_utc_at_midnight(2022, 5, 20);
+        let passes = Windows::new(vec![
+            Interval::new(start, start + 1.hours()),
+            Interval::new(start + 2.hours(), start + 3.hours()),
+            Interval::new(start + 4.hours(), start + 5.hours()),
+        ]);
+        // One long window overlapping the first two passes, and one back to back with the third
+        let maintenance = Windows::new(vec![
+            Interval::new(start + 30.minutes(), start + 150.minutes()),
+            Interval::new(start + 5.hours(), start + 6.hours()),
+        ]);
+        let conflicts = passes.conflicts_with(&maintenance);
+        assert!(conflicts.has_conflicts());
+        assert_eq!(conflicts.len(), 2);
+        let overlaps = conflicts.overlaps();
+        assert_eq!(
+            overlaps[0].interval(),
+            Interval::new(start + 30.minutes(), start + 1.hours())
+        );
+        assert_eq!((overlaps[0].index(), overlaps[0].other_index()), (0, 0));
+        assert_eq!(
+            overlaps[1].interval(),
+            Interval::new(start + 2.hours(), start + 150.minutes())
+        );
+        assert_eq!((overlaps[1].index(), overlaps[1].other_index()), (1, 0));
+        assert_eq!(conflicts.total_duration(), 1.hours());
+
+        // The report is symmetric but for the indices
+        let reverse = maintenance.conflicts_with(&passes);
+        assert_eq!(reverse.total_duration(), 1.hours());
+        assert_eq!(
+            (
+                reverse.overlaps()[1].index(),
+                reverse.overlaps()[1].other_index()
+            ),
+            (0, 1)
+        );
+
+        // No conflicts
+        let night = Windows::new(vec![Interval::new(start + 6.hours(), start + 12.hours())]);
+        let conflicts = passes.conflicts_with(&night);
+        assert!(!conflicts.has_conflicts());
+        assert!(conflicts.is_empty());
+        assert_eq!(conflicts.total_duration(), 0.seconds());
+        assert!(passes.conflicts_with(&Windows::default()).is_empty());
+    }
+}
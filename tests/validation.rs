@@ -0,0 +1,152 @@
+//! Cross-validation of hifitime against reference outputs of external tools.
+//!
+//! Each file in `tests/validation/` is named after the tool which generated it, and every
+//! non-comment line is `epoch,representation,expected,tolerance` where:
+//!  + `epoch` is any string supported by `Epoch::from_str`;
+//!  + `representation` is one of `SEC <ts>`, `JD <ts>`, `MJD <ts>`, `DAT` (TAI - UTC in seconds,
+//!    in effect at the epoch as per `tai_utc_offset_at`), or `DTDB` (TDB - TT in seconds, from the
+//!    precise TDB series);
+//!  + `expected` is the value computed by the reference tool;
+//!  + `tolerance` is the maximum absolute error allowed, in the unit of the representation.
+//!
+//! The maximum error is reported per source and per pair of time systems.
+//! Run with `cargo test --features validation --test validation -- --nocapture`.
+extern crate hifitime;
+
+use hifitime::{tai_utc_offset_at, Epoch, TimeSystem};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Statistics of a given source and pair of time systems
+#[derive(Default)]
+struct Stats {
+    count: usize,
+    max_error: f64,
+    worst: String,
+}
+
+/// Returns the time system in which the provided epoch string is expressed (UTC by default).
+fn input_time_system(epoch: &str) -> String {
+    let last = epoch.split_whitespace().last().unwrap_or("UTC");
+    match TimeSystem::from_str(last) {
+        Ok(ts) => format!("{:?}", ts),
+        Err(_) => "UTC".to_string(),
+    }
+}
+
+/// Computes the requested representation of this epoch, and returns it with its output time system.
+fn compute(epoch: &Epoch, representation: &str) -> (String, f64) {
+    let mut tokens = representation.split_whitespace();
+    let format = tokens.next().unwrap();
+    if format == "DAT" {
+        return (
+            "TAI-UTC".to_string(),
+            tai_utc_offset_at(*epoch).in_seconds(),
+        );
+    }
+    if format == "DTDB" {
+        return (
+            "TDB-TT".to_string(),
+            epoch.tdb_tt_offset_precise().in_seconds(),
+        );
+    }
+    let ts = TimeSystem::from_str(tokens.next().expect("missing time system"))
+        .expect("unknown time system");
+    let value = match (format, ts) {
+        ("SEC", TimeSystem::TAI) => epoch.as_tai_seconds(),
+        ("SEC", TimeSystem::UTC) => epoch.as_utc_seconds(),
+        ("SEC", TimeSystem::TT) => epoch.as_tt_seconds(),
+        ("SEC", TimeSystem::ET) => epoch.as_et_seconds(),
+        ("SEC", TimeSystem::TDB) => epoch.as_tdb_seconds(),
+        ("JD", TimeSystem::TAI) => epoch.as_jde_tai_days(),
+        ("JD", TimeSystem::UTC) => epoch.as_jde_utc_days(),
+        ("JD", TimeSystem::TT) => epoch.as_jde_tt_days(),
+        ("JD", TimeSystem::ET) => epoch.as_jde_et_days(),
+        ("JD", TimeSystem::TDB) => epoch.as_jde_tdb_days(),
+        ("MJD", TimeSystem::TAI) => epoch.as_mjd_tai_days(),
+        ("MJD", TimeSystem::UTC) => epoch.as_mjd_utc_days(),
+        ("MJD", TimeSystem::TT) => epoch.as_mjd_tt_days(),
+        _ => panic!("unsupported representation `{}`", representation),
+    };
+    (format!("{:?}", ts), value)
+}
+
+#[test]
+fn cross_validation() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/validation");
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .expect("could not read validation directory")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "csv"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "no validation data found");
+
+    // Maps (source, input system, output system) to its statistics
+    let mut report: BTreeMap<(String, String, String), Stats> = BTreeMap::new();
+    let mut failures = Vec::new();
+
+    for path in &paths {
+        let source = path.file_stem().unwrap().to_string_lossy().to_string();
+        let contents = fs::read_to_string(path).unwrap();
+        for (lno, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            assert_eq!(
+                fields.len(),
+                4,
+                "{}:{}: expected four fields",
+                path.display(),
+                lno + 1
+            );
+            let epoch = Epoch::from_str(fields[0])
+                .unwrap_or_else(|e| panic!("{}:{}: {}", path.display(), lno + 1, e));
+            let expected = f64::from_str(fields[2]).unwrap();
+            let tolerance = f64::from_str(fields[3]).unwrap();
+
+            let (output_ts, value) = compute(&epoch, fields[1]);
+            let error = (value - expected).abs();
+
+            let entry = report
+                .entry((source.clone(), input_time_system(fields[0]), output_ts))
+                .or_default();
+            entry.count += 1;
+            if error >= entry.max_error {
+                entry.max_error = error;
+                entry.worst = fields[0].to_string();
+            }
+
+            if error > tolerance {
+                failures.push(format!(
+                    "{}:{}: {} as {}: expected {} got {} (error {:e} > {:e})",
+                    path.display(),
+                    lno + 1,
+                    fields[0],
+                    fields[1],
+                    expected,
+                    value,
+                    error,
+                    tolerance
+                ));
+            }
+        }
+    }
+
+    println!(
+        "{:<10} {:<6} {:<8} {:>6} {:>12}  worst epoch",
+        "source", "from", "to", "count", "max error"
+    );
+    for ((source, from, to), stats) in &report {
+        println!(
+            "{:<10} {:<6} {:<8} {:>6} {:>12.3e}  {}",
+            source, from, to, stats.count, stats.max_error, stats.worst
+        );
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}